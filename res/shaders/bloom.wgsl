@@ -0,0 +1,164 @@
+#import global.wgsl::{GlobalUniforms, ViewProjectionUniforms};
+
+@group(0) @binding(0)
+var t_diffuse: texture_2d<f32>;
+@group(0) @binding(1)
+var s_diffuse: sampler;
+
+@group(1) @binding(0)
+var<uniform> global_uniforms: GlobalUniforms;
+
+@group(2) @binding(0)
+var<uniform> view_proj_uniforms: ViewProjectionUniforms;
+
+struct VertexInput {
+    @location(0) position: vec4<f32>,
+    @location(1) tex_coords: vec2<f32>,
+}
+
+struct InstanceInput {
+    @location(2) uv_scale: vec2<f32>,
+    @location(3) uv_offset: vec2<f32>,
+    @location(4) tint: vec4<f32>,
+    @location(5) model_1: vec4<f32>,
+    @location(6) model_2: vec4<f32>,
+    @location(7) model_3: vec4<f32>,
+    @location(8) model_4: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+}
+
+@vertex
+fn vs_main(
+    vertex: VertexInput,
+    instance: InstanceInput,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.tex_coords = instance.uv_offset + instance.uv_scale * vertex.tex_coords;
+    var model = vertex.position;
+    model.x = model.x * 2.0 - 1.0;
+    model.y = model.y * 2.0 - 1.0;
+    out.clip_position = model;
+    return out;
+}
+
+@export
+struct BloomUniforms {
+    // Brightness above which pixels start to bloom.
+    threshold: f32,
+    // Softness of the threshold, as a fraction of it.
+    knee: f32,
+    intensity: f32,
+    // Upsampling filter radius, in texels of the smaller level.
+    filter_radius: f32,
+    level_count: u32,
+}
+
+// The same size level of the downsample chain when upsampling, the bloom
+// when compositing.
+@group(3) @binding(0)
+var t_bloom: texture_2d<f32>;
+@group(3) @binding(1)
+var s_bloom: sampler;
+
+@group(4) @binding(0)
+var<uniform> bloom: BloomUniforms;
+
+fn luminance(color: vec3<f32>) -> f32 {
+    return dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+}
+
+// 13 tap filter from "Next Generation Post Processing in Call of Duty:
+// Advanced Warfare", as five overlapping boxes of four bilinear samples.
+// With `karis`, boxes are weighted by inverse luminance so single very bright
+// pixels don't flicker as they move.
+fn downsample(uv: vec2<f32>, karis: bool) -> vec3<f32> {
+    let texel = 1.0 / vec2<f32>(textureDimensions(t_diffuse, 0));
+    let a = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(-2.0, 2.0)).rgb;
+    let b = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(0.0, 2.0)).rgb;
+    let c = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(2.0, 2.0)).rgb;
+    let d = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(-2.0, 0.0)).rgb;
+    let e = textureSample(t_diffuse, s_diffuse, uv).rgb;
+    let f = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(2.0, 0.0)).rgb;
+    let g = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(-2.0, -2.0)).rgb;
+    let h = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(0.0, -2.0)).rgb;
+    let i = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(2.0, -2.0)).rgb;
+    let j = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(-1.0, 1.0)).rgb;
+    let k = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(1.0, 1.0)).rgb;
+    let l = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(-1.0, -1.0)).rgb;
+    let m = textureSample(t_diffuse, s_diffuse, uv + texel * vec2<f32>(1.0, -1.0)).rgb;
+
+    let boxes = array<vec3<f32>, 5>(
+        (j + k + l + m) * 0.25,
+        (a + b + d + e) * 0.25,
+        (b + c + e + f) * 0.25,
+        (d + e + g + h) * 0.25,
+        (e + f + h + i) * 0.25,
+    );
+    var result = vec3<f32>(0.0);
+    var total_weight = 0.0;
+    for (var n = 0; n < 5; n++) {
+        var weight = select(0.125, 0.5, n == 0);
+        if karis {
+            weight /= 1.0 + luminance(boxes[n]);
+        }
+        result += boxes[n] * weight;
+        total_weight += weight;
+    }
+    return result / total_weight;
+}
+
+// Quadratic soft threshold, fading in over `knee` below the threshold.
+fn threshold(color: vec3<f32>) -> vec3<f32> {
+    let brightness = max(color.r, max(color.g, color.b));
+    let knee = bloom.threshold * bloom.knee;
+    var soft = clamp(brightness - bloom.threshold + knee, 0.0, 2.0 * knee);
+    soft = soft * soft / (4.0 * knee + 1e-5);
+    let contribution = max(soft, brightness - bloom.threshold) / max(brightness, 1e-5);
+    return color * contribution;
+}
+
+// 3x3 tent filter.
+fn upsample(uv: vec2<f32>) -> vec3<f32> {
+    let r = bloom.filter_radius / vec2<f32>(textureDimensions(t_diffuse, 0));
+    var result = textureSample(t_diffuse, s_diffuse, uv).rgb * 4.0;
+    result += textureSample(t_diffuse, s_diffuse, uv + vec2<f32>(-r.x, 0.0)).rgb * 2.0;
+    result += textureSample(t_diffuse, s_diffuse, uv + vec2<f32>(r.x, 0.0)).rgb * 2.0;
+    result += textureSample(t_diffuse, s_diffuse, uv + vec2<f32>(0.0, -r.y)).rgb * 2.0;
+    result += textureSample(t_diffuse, s_diffuse, uv + vec2<f32>(0.0, r.y)).rgb * 2.0;
+    result += textureSample(t_diffuse, s_diffuse, uv + vec2<f32>(-r.x, -r.y)).rgb;
+    result += textureSample(t_diffuse, s_diffuse, uv + vec2<f32>(r.x, -r.y)).rgb;
+    result += textureSample(t_diffuse, s_diffuse, uv + vec2<f32>(-r.x, r.y)).rgb;
+    result += textureSample(t_diffuse, s_diffuse, uv + vec2<f32>(r.x, r.y)).rgb;
+    return result / 16.0;
+}
+
+// First downsample, from the scene color.
+@fragment
+fn fs_prefilter(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(threshold(downsample(in.tex_coords, true)), 1.0);
+}
+
+@fragment
+fn fs_downsample(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(downsample(in.tex_coords, false), 1.0);
+}
+
+// Adds the blurred smaller level to this one.
+@fragment
+fn fs_upsample(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSample(t_bloom, s_bloom, in.tex_coords).rgb + upsample(in.tex_coords);
+    return vec4<f32>(color, 1.0);
+}
+
+// Adds the average of all levels to the scene color.
+@fragment
+fn fs_composite(in: VertexOutput) -> @location(0) vec4<f32> {
+    let scene = textureSample(t_diffuse, s_diffuse, in.tex_coords);
+    let blurred = textureSample(t_bloom, s_bloom, in.tex_coords).rgb;
+    let color = scene.rgb + blurred * bloom.intensity / f32(bloom.level_count);
+    return vec4<f32>(color, scene.a);
+}
@@ -1,3 +1,5 @@
+#import pbr.wgsl::{PI, cook_torrance}
+
 // TODO: remove
 @group(0) @binding(0)
 var t_diffuse: texture_2d<f32>;
@@ -77,20 +79,35 @@ var g_albedo_spec: texture_2d<f32>;
 @group(3) @binding(5)
 var g_albedo_spec_sampler: sampler;
 
+// Same layout as in forward.wgsl
 @export
 struct Light {
-    position: vec4<f32>,
+    direction: vec3<f32>, // spot + directional
+    kind: u32, // 0 = directional, 1 = spot, 2 = point
     color: vec4<f32>,
     view_proj: mat4x4<f32>,
+    position: vec3<f32>, // spot + point
+    radius: f32, // spot
+    reach: f32, // spot + point
+    // First shadow map layer (directional + spot) or cube shadow map (point),
+    // -1 if the light has no shadows
+    shadow_index: i32,
 }
 
 @export
 struct LightsUniform {
     items: array<Light, 8>,
     count: u32,
-    // shadow_bias_minimum: f32,
-    // shadow_bias_factor: f32,
-    // shadow_blur_half_kernel_size: i32,
+    shadow_bias_minimum: f32,
+    shadow_bias_factor: f32,
+    shadow_blur_half_kernel_size: i32,
+    ambient_color: vec4<f32>,
+    shadow_view_projs: array<mat4x4<f32>, 8>,
+    point_shadow_view_projs: array<mat4x4<f32>, 24>,
+    cascade_count: u32,
+    cascade_blend: f32,
+    // Scale of the light from the environment maps
+    environment_intensity: f32,
 }
 
 @group(4) @binding(0)
@@ -102,28 +119,37 @@ var shadow_map_sampler: sampler_comparison;
 
 const AMBIENT_LIGHT_FACTOR = vec3(0.5, 0.5, 0.5);
 
+// Smooth falloff which reaches zero at the radius.
+fn point_attenuation(distance: f32, radius: f32) -> f32 {
+    let falloff = clamp(1.0 - pow(distance / radius, 2.0), 0.0, 1.0);
+    return falloff * falloff;
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    let view_pos = textureSample(g_position, g_position_sampler, in.tex_coords);
-    let view_space_normal = textureSample(g_normal, g_normal_sampler, in.tex_coords);
+    // Metallic and roughness are packed in w, see geometry.wgsl.
+    let position_metallic = textureSample(g_position, g_position_sampler, in.tex_coords);
+    let normal_roughness = textureSample(g_normal, g_normal_sampler, in.tex_coords);
+    let view_pos = vec4(position_metallic.xyz, 1.0);
+    let view_space_normal = vec4(normal_roughness.xyz, 0.0);
+    let metallic = position_metallic.w;
+    let roughness = normal_roughness.w;
     let albedo_spec = in.tint_color * textureSample(g_albedo_spec, g_albedo_spec_sampler, in.tex_coords);
+    let ao = clamp(textureSample(t_diffuse, s_diffuse, in.tex_coords).r, 0.0, 1.0);
 
-    // let view_pos = view_proj_uniforms.view * world_pos;
-
-    var specular = vec4(0.0, 0.0, 0.0, 0.0);
-    var diffuse = vec4(0.0, 0.0, 0.0, 0.0);
-    var total_light = vec4(clamp(textureSample(t_diffuse, s_diffuse, in.tex_coords).r, 0.0, 1.0));
-    // let ao = textureSample(t_diffuse, s_diffuse, in.tex_coords).r;
-    // var total_light = vec4(0.8) * (1.0 - ao);
+    let n = normalize(view_space_normal.xyz);
+    let v = normalize(-view_pos.xyz);
+    var total_light = ao * AMBIENT_LIGHT_FACTOR * albedo_spec.rgb;
 
     for (var i = 0u; i < lights.count; i++) {
-        let light_color = lights.items[i].color;
-        // let ambient_color = light_color * (1.0 - ao);
+        var light_color = lights.items[i].color;
 
-        let light_pos_w = lights.items[i].position;
-        let view_dir_v = normalize(view_pos);
+        let light_pos_w = vec4(lights.items[i].position, 1.0);
         let light_pos_v = view_proj_uniforms.view * light_pos_w;
         let light_dir_v = normalize((light_pos_v - view_pos).xyz);
+        if lights.items[i].kind == 2 { // point light
+            light_color *= point_attenuation(distance(light_pos_v.xyz, view_pos.xyz), lights.items[i].reach);
+        }
 
         // let shadow_pos = lights.items[i].view_proj * world_pos;
         let shadow_pos = lights.items[i].view_proj * view_proj_uniforms.inverse_view * view_pos;
@@ -150,18 +176,8 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
         //     shadow /= weight;
         // }
 
-        let half_dir_v = normalize(view_dir_v.xyz + light_dir_v);
-        let reflect_dir_v = reflect(light_dir_v, view_space_normal.xyz);
-        var specular_factor = clamp(dot(view_space_normal.xyz, half_dir_v), 0.0, 1.0);
-        specular_factor = pow(specular_factor, 32.0);
-        if specular_factor > 0.0 {
-            let specular_intensity = 0.6;
-            total_light += shadow * specular_intensity * specular_factor * light_color;
-        }
-        let d = clamp(dot(view_space_normal.xyz, light_dir_v), 0.0, 1.0);
-        total_light += shadow * d * light_color;
-        // total_light += ambient_color;
+        let radiance = shadow * light_color.rgb * light_color.a * PI;
+        total_light += cook_torrance(n, v, light_dir_v, albedo_spec.rgb, metallic, roughness, radiance);
     }
-    // return vec4(ao * (AMBIENT_LIGHT_FACTOR + diffuse) * albedo_spec.xyz + specular, albedo_spec.w);
-    return vec4(albedo_spec.xyz * total_light.rgb, albedo_spec.w);
+    return vec4(total_light, albedo_spec.w);
 }
@@ -1,4 +1,6 @@
-#import global.wgsl::{GlobalUniforms, ViewProjectionUniforms, ModelVertexData}
+#import global.wgsl::{GlobalUniforms, ViewProjectionUniforms, ModelVertexData, SkinnedVertexData}
+#import pbr.wgsl::{MaterialUniforms, PI, cook_torrance, environment_lighting, tangent_space_normal}
+#import skinning.wgsl::{SkinnedInstanceInput, instance_transform, blend_joints}
 
 @group(0) @binding(0)
 var t_diffuse: texture_2d<f32>;
@@ -12,17 +14,17 @@ var<uniform> global_uniforms: GlobalUniforms;
 var<uniform> view_proj_uniforms: ViewProjectionUniforms;
 
 struct InstanceInput {
-    @location(3) uv_scale: vec2<f32>,
-    @location(4) uv_offset: vec2<f32>,
-    @location(5) tint: vec4<f32>,
-    @location(6) model_1: vec4<f32>,
-    @location(7) model_2: vec4<f32>,
-    @location(8) model_3: vec4<f32>,
-    @location(9) model_4: vec4<f32>,
-    @location(10) normal_1: vec4<f32>,
-    @location(11) normal_2: vec4<f32>,
-    @location(12) normal_3: vec4<f32>,
-    @location(13) normal_4: vec4<f32>,
+    @location(4) uv_scale: vec2<f32>,
+    @location(5) uv_offset: vec2<f32>,
+    @location(6) tint: vec4<f32>,
+    @location(7) model_1: vec4<f32>,
+    @location(8) model_2: vec4<f32>,
+    @location(9) model_3: vec4<f32>,
+    @location(10) model_4: vec4<f32>,
+    @location(11) normal_1: vec4<f32>,
+    @location(12) normal_2: vec4<f32>,
+    @location(13) normal_3: vec4<f32>,
+    @location(14) normal_4: vec4<f32>,
 }
 
 struct VertexOutput {
@@ -32,6 +34,8 @@ struct VertexOutput {
     @location(2) view_space_normal: vec3<f32>,
     @location(3) tint_color: vec4<f32>,
     @location(4) world_pos: vec4<f32>,
+    // Zero if the mesh has no tangents
+    @location(5) view_space_tangent: vec4<f32>,
 }
 
 @vertex
@@ -57,6 +61,36 @@ fn vs_main(
     let model_view_pos = model_view * vertex.position;
     out.clip_position = view_proj_uniforms.projection * model_view_pos;
     out.view_space_normal = (normal_matrix * vec4(vertex.normal, 1.0)).xyz;
+    out.view_space_tangent = vec4((model_view * vec4(vertex.tangent.xyz, 0.0)).xyz, vertex.tangent.w);
+    out.view_pos = model_view_pos;
+    out.tint_color = instance.tint;
+    out.world_pos = model_transform * vertex.position;
+    return out;
+}
+
+@group(7) @binding(0)
+var<storage, read> joint_matrices: array<mat4x4<f32>>;
+
+@vertex
+fn vs_skinned(
+    vertex: SkinnedVertexData,
+    instance: SkinnedInstanceInput,
+) -> VertexOutput {
+    let joints = vertex.joints + instance.joint_offset;
+    let model_transform = instance_transform(instance) * blend_joints(
+        joint_matrices[joints.x],
+        joint_matrices[joints.y],
+        joint_matrices[joints.z],
+        joint_matrices[joints.w],
+        vertex.weights,
+    );
+    var out: VertexOutput;
+    out.tex_coords = instance.uv_offset + instance.uv_scale * vertex.tex_coords;
+    let model_view = (view_proj_uniforms.view * model_transform);
+    let model_view_pos = model_view * vertex.position;
+    out.clip_position = view_proj_uniforms.projection * model_view_pos;
+    out.view_space_normal = (model_view * vec4(vertex.normal, 0.0)).xyz;
+    out.view_space_tangent = vec4((model_view * vec4(vertex.tangent.xyz, 0.0)).xyz, vertex.tangent.w);
     out.view_pos = model_view_pos;
     out.tint_color = instance.tint;
     out.world_pos = model_transform * vertex.position;
@@ -66,12 +100,15 @@ fn vs_main(
 @export
 struct Light {
     direction: vec3<f32>, // spot + directional
-    kind: u32,
+    kind: u32, // 0 = directional, 1 = spot, 2 = point
     color: vec4<f32>,
     view_proj: mat4x4<f32>,
     position: vec3<f32>, // spot + point
     radius: f32, // spot
-    reach: f32, // spot
+    reach: f32, // spot + point
+    // First shadow map layer (directional + spot) or cube shadow map (point),
+    // -1 if the light has no shadows
+    shadow_index: i32,
 }
 
 @export
@@ -82,109 +119,289 @@ struct LightsUniform {
     shadow_bias_factor: f32,
     shadow_blur_half_kernel_size: i32,
     ambient_color: vec4<f32>,
+    // For each layer of the shadow map
+    shadow_view_projs: array<mat4x4<f32>, 8>,
+    // 6 cube faces for each point light shadow
+    point_shadow_view_projs: array<mat4x4<f32>, 24>,
+    cascade_count: u32,
+    cascade_blend: f32,
+    // Scale of the light from the environment maps
+    environment_intensity: f32,
+    // Whether the environment maps are set, they replace the flat ambient
+    // light then
+    has_environment: u32,
 }
 
 @group(3) @binding(0)
-var<uniform> lights: LightsUniform;
+var<uniform> material: MaterialUniforms;
 @group(3) @binding(1)
-var shadow_map: texture_depth_2d_array;
+var albedo_texture: texture_2d<f32>;
 @group(3) @binding(2)
-var shadow_map_sampler: sampler_comparison;
+var albedo_sampler: sampler;
+@group(3) @binding(3)
+var normal_texture: texture_2d<f32>;
+@group(3) @binding(4)
+var normal_sampler: sampler;
+// Roughness in green, metallic in blue
+@group(3) @binding(5)
+var metallic_roughness_texture: texture_2d<f32>;
+@group(3) @binding(6)
+var metallic_roughness_sampler: sampler;
+@group(3) @binding(7)
+var occlusion_texture: texture_2d<f32>;
+@group(3) @binding(8)
+var occlusion_sampler: sampler;
+@group(3) @binding(9)
+var emissive_texture: texture_2d<f32>;
+@group(3) @binding(10)
+var emissive_sampler: sampler;
 
 @group(4) @binding(0)
-var occlusion_map: texture_2d<f32>;
+var<uniform> lights: LightsUniform;
 @group(4) @binding(1)
+var shadow_map: texture_depth_2d_array;
+@group(4) @binding(2)
+var shadow_map_sampler: sampler_comparison;
+@group(4) @binding(3)
+var point_shadow_map: texture_depth_2d_array;
+// Image based lighting, see ibl.wgsl
+@group(4) @binding(4)
+var irradiance_map: texture_cube<f32>;
+@group(4) @binding(5)
+var prefiltered_map: texture_cube<f32>;
+@group(4) @binding(6)
+var brdf_lut: texture_2d<f32>;
+@group(4) @binding(7)
+var environment_sampler: sampler;
+
+@group(5) @binding(0)
+var occlusion_map: texture_2d<f32>;
+@group(5) @binding(1)
 var occlusion_map_sampler: sampler;
 
-@fragment
-fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    let view_pos = in.view_pos;
-    let view_space_normal = in.view_space_normal;
-    let albedo_spec = in.tint_color * textureSample(t_diffuse, s_diffuse, in.tex_coords);
+@export
+struct ClusterUniforms {
+    // Number of clusters along x and y on screen, and depth slices along z
+    grid_size: vec3<u32>,
+    z_near: f32,
+    z_far: f32,
+    show_heatmap: u32,
+}
+
+// Lights without shadows, binned into a grid of view space clusters
+@group(6) @binding(0)
+var<uniform> clusters_uniform: ClusterUniforms;
+@group(6) @binding(1)
+var<storage, read> cluster_lights: array<Light>;
+// Offset into cluster_light_indices and number of lights of each cluster
+@group(6) @binding(2)
+var<storage, read> clusters: array<vec2<u32>>;
+@group(6) @binding(3)
+var<storage, read> cluster_light_indices: array<u32>;
+
+// Smooth falloff which reaches zero at the radius.
+fn point_attenuation(distance: f32, radius: f32) -> f32 {
+    let falloff = clamp(1.0 - pow(distance / radius, 2.0), 0.0, 1.0);
+    return falloff * falloff;
+}
+
+// Index of the cube face (+x, -x, +y, -y, +z, -z) a direction points at.
+fn cube_face(dir: vec3<f32>) -> i32 {
+    let a = abs(dir);
+    if a.x >= a.y && a.x >= a.z {
+        return select(1, 0, dir.x > 0.0);
+    }
+    if a.y >= a.z {
+        return select(3, 2, dir.y > 0.0);
+    }
+    return select(5, 4, dir.z > 0.0);
+}
 
-    let texelSize = 1.0 / f32(textureDimensions(shadow_map).x);
+fn shadow_occlusion(map: texture_depth_2d_array, layer: i32, shadow_pos: vec4<f32>, bias: f32) -> f32 {
+    let texelSize = 1.0 / f32(textureDimensions(map).x);
+    let flip_correction = vec2<f32>(0.5, -0.5);
+    let proj_correction = 1.0 / shadow_pos.w;
 
-    var total_light = vec3(0.0, 0.0, 0.0);
+    let ShadowCoord = shadow_pos.xy * flip_correction * proj_correction + vec2<f32>(0.5, 0.5);
+    var occlusion = 0.0;
 
-    // Material properties
+    var weight = 0.0;
+    for (var x = -lights.shadow_blur_half_kernel_size; x <= lights.shadow_blur_half_kernel_size; x++) {
+        for (var y = -lights.shadow_blur_half_kernel_size; y <= lights.shadow_blur_half_kernel_size; y++) {
+            occlusion += 1.0 - textureSampleCompareLevel(map, shadow_map_sampler, ShadowCoord.xy + vec2(f32(x), f32(y)) * texelSize, layer, (shadow_pos.z - bias) / shadow_pos.w);
+            weight += 1.0;
+        }
+    }
+    return occlusion / weight;
+}
+
+// Occlusion from the first cascade of a directional light which contains the
+// fragment, blended with the next one near its edges.
+fn cascaded_shadow_occlusion(first_layer: i32, world_pos: vec4<f32>, bias: f32) -> f32 {
+    for (var c = 0; c < i32(lights.cascade_count); c++) {
+        let layer = first_layer + c;
+        let shadow_pos = lights.shadow_view_projs[layer] * world_pos;
+        let uv = shadow_pos.xy * vec2(0.5, -0.5) + vec2(0.5, 0.5);
+        // Distance to the closest edge of the cascade, 0.5 at its center
+        let edge = min(min(uv.x, 1.0 - uv.x), min(uv.y, 1.0 - uv.y));
+        if edge < 0.0 {
+            continue;
+        }
+        let occlusion = shadow_occlusion(shadow_map, layer, shadow_pos, bias);
+        let blend = 1.0 - edge / max(0.5 * lights.cascade_blend, 0.0001);
+        if blend <= 0.0 || c + 1 >= i32(lights.cascade_count) {
+            return occlusion;
+        }
+        let next_shadow_pos = lights.shadow_view_projs[layer + 1] * world_pos;
+        let next_occlusion = shadow_occlusion(shadow_map, layer + 1, next_shadow_pos, bias);
+        return mix(occlusion, next_occlusion, blend);
+    }
+    return 0.0;
+}
+
+// Index of the cluster containing a fragment, depth slices are spaced
+// logarithmically between the near and far planes.
+fn cluster_index(frag_coord: vec2<f32>, view_depth: f32) -> u32 {
+    let grid = clusters_uniform.grid_size;
+    let tile = vec2<u32>(clamp(
+        frag_coord / global_uniforms.screen_size * vec2<f32>(grid.xy),
+        vec2(0.0),
+        vec2<f32>(grid.xy - 1u),
+    ));
+    let depth_ratio = log(max(view_depth, clusters_uniform.z_near) / clusters_uniform.z_near)
+        / log(clusters_uniform.z_far / clusters_uniform.z_near);
+    let slice = u32(clamp(depth_ratio * f32(grid.z), 0.0, f32(grid.z - 1u)));
+    return tile.x + grid.x * (tile.y + grid.y * slice);
+}
+
+// Fraction of the light reaching a point, ignoring shadows.
+fn light_attenuation(light: Light, world_pos: vec3<f32>) -> f32 {
+    let light_to_fragment = world_pos - light.position;
+    if light.kind == 2 { // point light
+        return point_attenuation(length(light_to_fragment), light.reach);
+    } else if light.kind == 1 { // spot light
+        let light_dist_sqr = dot(light_to_fragment, light_to_fragment);
+        let spot_factor = dot(normalize(light_to_fragment), light.direction);
+        let reach_sqr = pow(light.reach, 2.0);
+        if spot_factor > light.radius && light_dist_sqr < reach_sqr {
+            return (1.0 - (1.0 - spot_factor) * 1.0 / (1.0 - light.radius));
+        }
+        return 0.0;
+    }
+    return 1.0;
+}
+
+// Light reflected towards the camera.
+fn shade(
+    light: Light,
+    visibility: f32,
+    world_pos: vec3<f32>,
+    view_pos: vec3<f32>,
+    n: vec3<f32>,
+    albedo: vec3<f32>,
+    metallic: f32,
+    roughness: f32,
+) -> vec3<f32> {
+    // Direction of the light (from the fragment to the light)
+    var l = normalize((view_proj_uniforms.view * vec4(light.position, 0.0)).xyz);
+    if light.kind == 2 {
+        l = normalize((view_proj_uniforms.view * vec4(light.position - world_pos, 0.0)).xyz);
+    }
+    // Eye vector (towards the camera)
+    let v = normalize(-view_pos);
+    // Scaled by PI so that a light of power 1 shining straight at a white
+    // diffuse surface lights it fully.
+    let radiance = light.color.rgb * light.color.a * PI * visibility;
+    return cook_torrance(n, v, l, albedo, metallic, roughness, radiance);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     // TODO: this ignores tint alpha
-    let MaterialAmbientColor = lights.ambient_color.rgb;
-    let MaterialDiffuseColor = albedo_spec.rgb;
-    let MaterialSpecularColor = vec3(0.4, 0.4, 0.4);
+    let albedo = in.tint_color
+        * textureSample(t_diffuse, s_diffuse, in.tex_coords)
+        * material.base_color
+        * textureSample(albedo_texture, albedo_sampler, in.tex_coords);
+    let metallic_roughness = textureSample(metallic_roughness_texture, metallic_roughness_sampler, in.tex_coords);
+    let metallic = clamp(material.metallic * metallic_roughness.b, 0.0, 1.0);
+    let roughness = clamp(material.roughness * metallic_roughness.g, 0.04, 1.0);
+    let material_occlusion = mix(1.0, textureSample(occlusion_texture, occlusion_sampler, in.tex_coords).r, material.occlusion_strength);
+    let emissive = material.emissive * textureSample(emissive_texture, emissive_sampler, in.tex_coords).rgb;
 
     // Normal of the computed fragment, in camera space
-    let n = normalize(in.view_space_normal);
+    let map_normal = textureSample(normal_texture, normal_sampler, in.tex_coords).xyz * 2.0 - 1.0;
+    let n = tangent_space_normal(
+        normalize(in.view_space_normal),
+        in.view_space_tangent,
+        in.view_pos.xyz,
+        in.tex_coords,
+        map_normal,
+        material.normal_scale,
+    );
     let ao = textureSample(occlusion_map, occlusion_map_sampler, in.clip_position.xy / global_uniforms.screen_size).r;
 
-    total_light += ao * MaterialAmbientColor * MaterialDiffuseColor;
+    // Ambient : simulates indirect lighting, from the environment looked up
+    // in world space if there is one
+    var ambient = lights.ambient_color.rgb * albedo.rgb;
+    if lights.has_environment != 0u {
+        let v = normalize(-in.view_pos.xyz);
+        let n_dot_v = max(dot(n, v), 0.0001);
+        let world_n = (view_proj_uniforms.inverse_view * vec4(n, 0.0)).xyz;
+        let world_r = (view_proj_uniforms.inverse_view * vec4(reflect(-v, n), 0.0)).xyz;
+        let max_lod = f32(textureNumLevels(prefiltered_map) - 1u);
+        ambient = lights.environment_intensity * environment_lighting(
+            n_dot_v,
+            albedo.rgb,
+            metallic,
+            roughness,
+            textureSample(irradiance_map, environment_sampler, world_n).rgb,
+            textureSampleLevel(prefiltered_map, environment_sampler, world_r, roughness * max_lod).rgb,
+            textureSample(brdf_lut, environment_sampler, vec2(n_dot_v, roughness)).rg,
+        );
+    }
+    var total_light = ao * material_occlusion * ambient + emissive;
 
     for (var i = 0u; i < lights.count; i++) {
-        var visibility = 1.0;
-
-        if lights.items[i].kind == 1 { // spot light
-            let light_to_fragment = in.world_pos.xyz - lights.items[i].position;
-            let light_dist_sqr = dot(light_to_fragment, light_to_fragment);
-            let spot_factor = dot(normalize(light_to_fragment), lights.items[i].direction);
-            let reach_sqr = pow(lights.items[i].reach, 2.0);
-            if spot_factor > lights.items[i].radius && light_dist_sqr < reach_sqr {
-                visibility = (1.0 - (1.0 - spot_factor) * 1.0 / (1.0 - lights.items[i].radius));
-            } else {
-                visibility = 0.0;
-            }
-        }
-
-        let LightColor = lights.items[i].color.rgb;
-        let LightPower = lights.items[i].color.a;
-        // Direction of the light (from the fragment to the light)
-        let l = normalize((view_proj_uniforms.view * vec4(lights.items[i].position, 0.0)).xyz);
-        // Cosine of the angle between the normal and the light direction, 
-        // clamped above 0
-        //  - light is at the vertical of the triangle -> 1
-        //  - light is perpendicular to the triangle -> 0
-        //  - light is behind the triangle -> 0
-        let cosTheta = clamp(dot(n, l), 0.0, 1.0);
-
-        // Eye vector (towards the camera)
-        let E = normalize(-in.view_pos.xyz);
-        // Direction in which the triangle reflects the light
-        let R = reflect(-l, n);
-        // Cosine of the angle between the Eye vector and the Reflect vector,
-        // clamped to 0
-        //  - Looking into the reflection -> 1
-        //  - Looking elsewhere -> < 1
-        let cosAlpha = clamp(dot(E, R), 0.0, 1.0);
+        let light = lights.items[i];
+        let light_to_fragment = in.world_pos.xyz - light.position;
 
         // var bias = lights.shadow_bias_factor * tan(acos(cosTheta));
         // bias = clamp(bias, 0.0, 0.01);
         var bias = 0.0;
 
-        let shadow_pos = lights.items[i].view_proj * in.world_pos;
-
-        let flip_correction = vec2<f32>(0.5, -0.5);
-        let proj_correction = 1.0 / shadow_pos.w;
-
-        let ShadowCoord = shadow_pos.xy * flip_correction * proj_correction + vec2<f32>(0.5, 0.5);
         var occlusion = 0.0;
-
-        var weight = 0.0;
-        for (var x = -lights.shadow_blur_half_kernel_size; x <= lights.shadow_blur_half_kernel_size; x++) {
-            for (var y = -lights.shadow_blur_half_kernel_size; y <= lights.shadow_blur_half_kernel_size; y++) {
-                occlusion += 1.0 - textureSampleCompare(shadow_map, shadow_map_sampler, ShadowCoord.xy + vec2(f32(x), f32(y)) * texelSize, i, (shadow_pos.z - bias) / shadow_pos.w);
-                weight += 1.0;
+        if light.kind == 2 {
+            if light.shadow_index >= 0 {
+                let layer = light.shadow_index * 6 + cube_face(light_to_fragment);
+                let shadow_pos = lights.point_shadow_view_projs[layer] * in.world_pos;
+                occlusion = shadow_occlusion(point_shadow_map, layer, shadow_pos, bias);
+            }
+        } else if light.shadow_index >= 0 {
+            if light.kind == 0 {
+                occlusion = cascaded_shadow_occlusion(light.shadow_index, in.world_pos, bias);
+            } else {
+                let shadow_pos = light.view_proj * in.world_pos;
+                occlusion = shadow_occlusion(shadow_map, light.shadow_index, shadow_pos, bias);
             }
         }
-        occlusion /= weight;
-        // occlusion += ao;
 
-        visibility = clamp(visibility - occlusion, 0.0, 1.0);
+        let visibility = clamp(light_attenuation(light, in.world_pos.xyz) - occlusion, 0.0, 1.0);
+        total_light += shade(light, visibility, in.world_pos.xyz, in.view_pos.xyz, n, albedo.rgb, metallic, roughness);
+    }
+
+    let cluster = clusters[cluster_index(in.clip_position.xy, -in.view_pos.z)];
+    for (var i = 0u; i < cluster.y; i++) {
+        let light = cluster_lights[cluster_light_indices[cluster.x + i]];
+        let visibility = light_attenuation(light, in.world_pos.xyz);
+        total_light += shade(light, visibility, in.world_pos.xyz, in.view_pos.xyz, n, albedo.rgb, metallic, roughness);
+    }
 
-        total_light += // Ambient : simulates indirect lighting
-                // ao * MaterialAmbientColor + // Diffuse : "color" of the object
-                visibility * MaterialDiffuseColor * LightColor * LightPower * cosTheta + // Specular : reflective highlight, like a mirror
-                visibility * MaterialSpecularColor * LightColor * LightPower * pow(cosAlpha, 5.0);
+    if clusters_uniform.show_heatmap != 0u && cluster.y > 0u {
+        let heat = clamp(f32(cluster.y) / 16.0, 0.0, 1.0);
+        total_light = mix(total_light, vec3(heat, 1.0 - heat, 0.0), 0.5);
     }
 
-    return vec4(total_light.xyz, albedo_spec.w);
+    return vec4(total_light, albedo.a);
 }
 
 // [old lighting shader code]
@@ -1,4 +1,5 @@
 #import global.wgsl::{GlobalUniforms, ViewProjectionUniforms}
+#import pbr.wgsl::{MaterialUniforms, tangent_space_normal}
 
 @group(0) @binding(0)
 var t_diffuse: texture_2d<f32>;
@@ -11,24 +12,49 @@ var<uniform> global_uniforms: GlobalUniforms;
 @group(2) @binding(0)
 var<uniform> view_proj_uniforms: ViewProjectionUniforms;
 
+// Same layout as in forward.wgsl
+@group(3) @binding(0)
+var<uniform> material: MaterialUniforms;
+@group(3) @binding(1)
+var albedo_texture: texture_2d<f32>;
+@group(3) @binding(2)
+var albedo_sampler: sampler;
+@group(3) @binding(3)
+var normal_texture: texture_2d<f32>;
+@group(3) @binding(4)
+var normal_sampler: sampler;
+@group(3) @binding(5)
+var metallic_roughness_texture: texture_2d<f32>;
+@group(3) @binding(6)
+var metallic_roughness_sampler: sampler;
+@group(3) @binding(7)
+var occlusion_texture: texture_2d<f32>;
+@group(3) @binding(8)
+var occlusion_sampler: sampler;
+@group(3) @binding(9)
+var emissive_texture: texture_2d<f32>;
+@group(3) @binding(10)
+var emissive_sampler: sampler;
+
 struct VertexInput {
     @location(0) position: vec4<f32>,
-    @location(1) tex_coords: vec2<f32>,
-    @location(2) normal: vec3<f32>,
+    @location(1) tangent: vec4<f32>,
+    @location(2) tex_coords: vec2<f32>,
+    @location(3) normal: vec3<f32>,
 }
 
 struct InstanceInput {
-    @location(3) uv_scale: vec2<f32>,
-    @location(4) uv_offset: vec2<f32>,
-    @location(5) tint: vec4<f32>,
-    @location(6) model_1: vec4<f32>,
-    @location(7) model_2: vec4<f32>,
-    @location(8) model_3: vec4<f32>,
-    @location(9) model_4: vec4<f32>,
-    @location(10) normal_1: vec4<f32>,
-    @location(11) normal_2: vec4<f32>,
-    @location(12) normal_3: vec4<f32>,
-    @location(13) normal_4: vec4<f32>,
+    @location(4) uv_scale: vec2<f32>,
+    @location(5) uv_offset: vec2<f32>,
+    @location(6) tint: vec4<f32>,
+    @location(7) model_1: vec4<f32>,
+    @location(8) model_2: vec4<f32>,
+    @location(9) model_3: vec4<f32>,
+    @location(10) model_4: vec4<f32>,
+    @location(11) normal_1: vec4<f32>,
+    @location(12) normal_2: vec4<f32>,
+    @location(13) normal_3: vec4<f32>,
+    @location(14) normal_4: vec4<f32>,
 }
 
 struct VertexOutput {
@@ -37,6 +63,7 @@ struct VertexOutput {
     @location(1) view_pos: vec4<f32>,
     @location(2) view_space_normal: vec4<f32>,
     @location(3) tint_color: vec4<f32>,
+    @location(4) view_space_tangent: vec4<f32>,
 }
 
 @vertex
@@ -62,6 +89,7 @@ fn vs_main(
     let model_view_pos = model_view * vertex.position;
     out.clip_position = view_proj_uniforms.projection * model_view_pos;
     out.view_space_normal = (normal_matrix * vec4(vertex.normal, 1.0));
+    out.view_space_tangent = vec4((model_view * vec4(vertex.tangent.xyz, 0.0)).xyz, vertex.tangent.w);
     out.view_pos = model_view_pos;
     out.tint_color = instance.tint;
     return out;
@@ -76,11 +104,30 @@ struct FragmentOutput {
     g_albedo_spec: vec4<f32>,
 }
 
+// The G-buffer targets already use all of the 32 bytes per sample wgpu
+// guarantees, so metallic and roughness go in the otherwise unused w
+// components of the position and normal. Occlusion and emissive textures are
+// only supported by the forward pass.
 @fragment
 fn fs_main(in: VertexOutput) -> FragmentOutput {
     var out: FragmentOutput;
-    out.g_position = in.view_pos;
-    out.g_normal = normalize(in.view_space_normal);
-    out.g_albedo_spec = in.tint_color * textureSample(t_diffuse, s_diffuse, in.tex_coords);
+    let metallic_roughness = textureSample(metallic_roughness_texture, metallic_roughness_sampler, in.tex_coords);
+    let metallic = clamp(material.metallic * metallic_roughness.b, 0.0, 1.0);
+    let roughness = clamp(material.roughness * metallic_roughness.g, 0.04, 1.0);
+    out.g_position = vec4(in.view_pos.xyz, metallic);
+    let map_normal = textureSample(normal_texture, normal_sampler, in.tex_coords).xyz * 2.0 - 1.0;
+    let n = tangent_space_normal(
+        normalize(in.view_space_normal.xyz),
+        in.view_space_tangent,
+        in.view_pos.xyz,
+        in.tex_coords,
+        map_normal,
+        material.normal_scale,
+    );
+    out.g_normal = vec4(n, roughness);
+    out.g_albedo_spec = in.tint_color
+        * textureSample(t_diffuse, s_diffuse, in.tex_coords)
+        * material.base_color
+        * textureSample(albedo_texture, albedo_sampler, in.tex_coords);
     return out;
 }
@@ -12,11 +12,25 @@ struct ViewProjectionUniforms {
     inverse_view: mat4x4<f32>,
 }
 
+// Vertex attributes are tightly packed, so the vec4 fields go first to match
+// the alignment of the generated struct.
 @export
 struct ModelVertexData {
     @location(0) position: vec4<f32>,
-    @location(1) tex_coords: vec2<f32>,
-    @location(2) normal: vec3<f32>,
+    // MikkTSpace tangent, w is the handedness of the bitangent
+    @location(1) tangent: vec4<f32>,
+    @location(2) tex_coords: vec2<f32>,
+    @location(3) normal: vec3<f32>,
+}
+
+@export
+struct SkinnedVertexData {
+    @location(0) position: vec4<f32>,
+    @location(1) tangent: vec4<f32>,
+    @location(2) weights: vec4<f32>,
+    @location(3) joints: vec4<u32>,
+    @location(4) tex_coords: vec2<f32>,
+    @location(5) normal: vec3<f32>,
 }
 
 @fragment
@@ -0,0 +1,160 @@
+// Filters an environment cubemap into the maps used for image based
+// lighting: diffuse irradiance, specular radiance prefiltered for increasing
+// roughness in each mip level, and the BRDF lookup table.
+
+#import pbr.wgsl::{PI, distribution_ggx}
+
+@export
+struct FilterUniforms {
+    // Cube face being rendered, in the order of its layers
+    face: u32,
+    roughness: f32,
+    sample_count: u32,
+    // Width of the environment's first mip level
+    environment_size: f32,
+}
+
+@group(0) @binding(0)
+var environment: texture_cube<f32>;
+@group(0) @binding(1)
+var environment_sampler: sampler;
+@group(0) @binding(2)
+var<uniform> params: FilterUniforms;
+
+const BRDF_LUT_SAMPLES: u32 = 256u;
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0, 0.0, 1.0);
+    out.uv = uv;
+    return out;
+}
+
+// Direction through a point of a cube face, uv pointing right and down.
+fn face_direction(face: u32, uv: vec2<f32>) -> vec3<f32> {
+    let u = uv.x * 2.0 - 1.0;
+    let v = uv.y * 2.0 - 1.0;
+    switch face {
+        case 0u: { return normalize(vec3(1.0, -v, -u)); }
+        case 1u: { return normalize(vec3(-1.0, -v, u)); }
+        case 2u: { return normalize(vec3(u, 1.0, v)); }
+        case 3u: { return normalize(vec3(u, -1.0, -v)); }
+        case 4u: { return normalize(vec3(u, -v, 1.0)); }
+        default: { return normalize(vec3(-u, -v, -1.0)); }
+    }
+}
+
+fn hammersley(i: u32, n: u32) -> vec2<f32> {
+    return vec2(f32(i) / f32(n), f32(reverseBits(i)) * 2.3283064365386963e-10);
+}
+
+// Rotates tangent space, with z up, to be around `n`.
+fn tangent_frame(n: vec3<f32>) -> mat3x3<f32> {
+    let up = select(vec3(0.0, 0.0, 1.0), vec3(1.0, 0.0, 0.0), abs(n.z) > 0.999);
+    let t = normalize(cross(up, n));
+    return mat3x3<f32>(t, cross(n, t), n);
+}
+
+// Tangent space half vector distributed like the GGX normal distribution.
+fn importance_sample_ggx(xi: vec2<f32>, roughness: f32) -> vec3<f32> {
+    let a = roughness * roughness;
+    let phi = 2.0 * PI * xi.x;
+    let cos_theta = sqrt((1.0 - xi.y) / (1.0 + (a * a - 1.0) * xi.y));
+    let sin_theta = sqrt(1.0 - cos_theta * cos_theta);
+    return vec3(cos(phi) * sin_theta, sin(phi) * sin_theta, cos_theta);
+}
+
+// Mip level of the environment whose texels cover about as much of the
+// sphere as a sample with probability density `pdf`, which keeps few samples
+// from aliasing.
+fn sample_lod(pdf: f32) -> f32 {
+    let texel = 4.0 * PI / (6.0 * params.environment_size * params.environment_size);
+    let sample = 1.0 / (f32(params.sample_count) * pdf + 0.0001);
+    return max(0.5 * log2(sample / texel) + 1.0, 0.0);
+}
+
+// Cosine weighted average of the radiance over the hemisphere around each
+// direction, so irradiance divided by PI.
+@fragment
+fn fs_irradiance(in: VertexOutput) -> @location(0) vec4<f32> {
+    let n = face_direction(params.face, in.uv);
+    let frame = tangent_frame(n);
+    var irradiance = vec3(0.0);
+    for (var i = 0u; i < params.sample_count; i++) {
+        let xi = hammersley(i, params.sample_count);
+        let phi = 2.0 * PI * xi.x;
+        let cos_theta = sqrt(1.0 - xi.y);
+        let sin_theta = sqrt(xi.y);
+        let l = frame * vec3(cos(phi) * sin_theta, sin(phi) * sin_theta, cos_theta);
+        let lod = sample_lod(cos_theta / PI);
+        irradiance += textureSampleLevel(environment, environment_sampler, l, lod).rgb;
+    }
+    return vec4(irradiance / f32(params.sample_count), 1.0);
+}
+
+// Radiance reflected by a surface of `params.roughness` which faces the
+// direction it is viewed from (the split sum approximation).
+@fragment
+fn fs_prefilter(in: VertexOutput) -> @location(0) vec4<f32> {
+    let n = face_direction(params.face, in.uv);
+    if params.roughness <= 0.0 {
+        return vec4(textureSampleLevel(environment, environment_sampler, n, 0.0).rgb, 1.0);
+    }
+    let frame = tangent_frame(n);
+    var color = vec3(0.0);
+    var weight = 0.0;
+    for (var i = 0u; i < params.sample_count; i++) {
+        let h = frame * importance_sample_ggx(hammersley(i, params.sample_count), params.roughness);
+        let l = 2.0 * dot(n, h) * h - n;
+        let n_dot_l = dot(n, l);
+        if n_dot_l > 0.0 {
+            // With the view along the normal, the pdf of l is D / 4.
+            let pdf = distribution_ggx(max(dot(n, h), 0.0), params.roughness) / 4.0;
+            let lod = sample_lod(pdf);
+            color += textureSampleLevel(environment, environment_sampler, l, lod).rgb * n_dot_l;
+            weight += n_dot_l;
+        }
+    }
+    return vec4(color / max(weight, 0.0001), 1.0);
+}
+
+// Smith's geometry term with Schlick-GGX, remapped for image based lighting.
+fn geometry_smith_ibl(n_dot_v: f32, n_dot_l: f32, roughness: f32) -> f32 {
+    let k = roughness * roughness / 2.0;
+    let g_v = n_dot_v / (n_dot_v * (1.0 - k) + k);
+    let g_l = n_dot_l / (n_dot_l * (1.0 - k) + k);
+    return g_v * g_l;
+}
+
+// Scale (r) and bias (g) to f0 of the specular BRDF integrated over the
+// hemisphere, for n dot v along u and roughness along v.
+@fragment
+fn fs_brdf_lut(in: VertexOutput) -> @location(0) vec4<f32> {
+    let n_dot_v = max(in.uv.x, 0.001);
+    let roughness = in.uv.y;
+    let v = vec3(sqrt(1.0 - n_dot_v * n_dot_v), 0.0, n_dot_v);
+    var scale = 0.0;
+    var bias = 0.0;
+    for (var i = 0u; i < BRDF_LUT_SAMPLES; i++) {
+        let h = importance_sample_ggx(hammersley(i, BRDF_LUT_SAMPLES), roughness);
+        let l = 2.0 * dot(v, h) * h - v;
+        let n_dot_l = max(l.z, 0.0);
+        let n_dot_h = max(h.z, 0.0);
+        let v_dot_h = max(dot(v, h), 0.0);
+        if n_dot_l > 0.0 {
+            let g = geometry_smith_ibl(n_dot_v, n_dot_l, roughness);
+            let g_vis = g * v_dot_h / max(n_dot_h * n_dot_v, 0.0001);
+            let fc = pow(1.0 - v_dot_h, 5.0);
+            scale += (1.0 - fc) * g_vis;
+            bias += fc * g_vis;
+        }
+    }
+    return vec4(scale, bias, 0.0, 1.0) / vec4(f32(BRDF_LUT_SAMPLES), f32(BRDF_LUT_SAMPLES), 1.0, 1.0);
+}
@@ -0,0 +1,131 @@
+// Metallic/roughness materials with a Cook-Torrance BRDF, shared by the
+// forward and deferred shaders.
+
+@export
+struct MaterialUniforms {
+    base_color: vec4<f32>,
+    emissive: vec3<f32>,
+    metallic: f32,
+    roughness: f32,
+    occlusion_strength: f32,
+    normal_scale: f32,
+}
+
+const PI: f32 = 3.14159265359;
+
+// Trowbridge-Reitz GGX normal distribution.
+fn distribution_ggx(n_dot_h: f32, roughness: f32) -> f32 {
+    let a = roughness * roughness;
+    let a2 = a * a;
+    let d = n_dot_h * n_dot_h * (a2 - 1.0) + 1.0;
+    return a2 / (PI * d * d);
+}
+
+// Smith's geometry term with Schlick-GGX, remapped for direct lighting.
+fn geometry_smith(n_dot_v: f32, n_dot_l: f32, roughness: f32) -> f32 {
+    let k = (roughness + 1.0) * (roughness + 1.0) / 8.0;
+    let g_v = n_dot_v / (n_dot_v * (1.0 - k) + k);
+    let g_l = n_dot_l / (n_dot_l * (1.0 - k) + k);
+    return g_v * g_l;
+}
+
+fn fresnel_schlick(cos_theta: f32, f0: vec3<f32>) -> vec3<f32> {
+    return f0 + (1.0 - f0) * pow(clamp(1.0 - cos_theta, 0.0, 1.0), 5.0);
+}
+
+// Light reflected towards `v` from light of `radiance` arriving from `l`. All
+// directions point away from the surface and are in the same space as `n`.
+fn cook_torrance(
+    n: vec3<f32>,
+    v: vec3<f32>,
+    l: vec3<f32>,
+    albedo: vec3<f32>,
+    metallic: f32,
+    roughness: f32,
+    radiance: vec3<f32>,
+) -> vec3<f32> {
+    let h = normalize(v + l);
+    let n_dot_l = max(dot(n, l), 0.0);
+    let n_dot_v = max(dot(n, v), 0.0001);
+    // Dielectrics reflect about 4% head on, metals tint reflections instead
+    // of scattering light diffusely.
+    let f0 = mix(vec3(0.04), albedo, metallic);
+    let f = fresnel_schlick(max(dot(h, v), 0.0), f0);
+    let d = distribution_ggx(max(dot(n, h), 0.0), roughness);
+    let g = geometry_smith(n_dot_v, n_dot_l, roughness);
+    let specular = d * g * f / max(4.0 * n_dot_v * n_dot_l, 0.0001);
+    let k_d = (vec3(1.0) - f) * (1.0 - metallic);
+    return (k_d * albedo / PI + specular) * radiance * n_dot_l;
+}
+
+// Fresnel for light from the whole environment, rough surfaces reflect less
+// at grazing angles.
+fn fresnel_schlick_roughness(cos_theta: f32, f0: vec3<f32>, roughness: f32) -> vec3<f32> {
+    return f0 + (max(vec3(1.0 - roughness), f0) - f0) * pow(clamp(1.0 - cos_theta, 0.0, 1.0), 5.0);
+}
+
+// Light reflected towards the camera from the environment, given its
+// irradiance around the normal, its prefiltered radiance in the reflected
+// direction and the scale and bias to f0 from the BRDF lookup table.
+fn environment_lighting(
+    n_dot_v: f32,
+    albedo: vec3<f32>,
+    metallic: f32,
+    roughness: f32,
+    irradiance: vec3<f32>,
+    prefiltered: vec3<f32>,
+    brdf: vec2<f32>,
+) -> vec3<f32> {
+    let f0 = mix(vec3(0.04), albedo, metallic);
+    let f = fresnel_schlick_roughness(n_dot_v, f0, roughness);
+    let k_d = (vec3(1.0) - f) * (1.0 - metallic);
+    return k_d * irradiance * albedo + prefiltered * (f0 * brdf.x + brdf.y);
+}
+
+// Applies a tangent space normal map sample (in [-1, 1]) to the normal `n`
+// of a surface at `pos`, with the tangent frame derived from screen space
+// derivatives of the position and texture coordinates.
+fn perturb_normal(
+    n: vec3<f32>,
+    pos: vec3<f32>,
+    uv: vec2<f32>,
+    map_normal: vec3<f32>,
+    scale: f32,
+) -> vec3<f32> {
+    let dp1 = dpdx(pos);
+    let dp2 = dpdy(pos);
+    let duv1 = dpdx(uv);
+    let duv2 = dpdy(uv);
+    let dp2_perp = cross(dp2, n);
+    let dp1_perp = cross(n, dp1);
+    let t = dp2_perp * duv1.x + dp1_perp * duv2.x;
+    let b = dp2_perp * duv1.y + dp1_perp * duv2.y;
+    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 1e-12));
+    let tbn = mat3x3<f32>(t * inv_max, b * inv_max, n);
+    return normalize(tbn * vec3(map_normal.xy * scale, map_normal.z));
+}
+
+// Like `perturb_normal`, with the tangent frame of the mesh: a MikkTSpace
+// tangent whose w is the handedness of the bitangent. Meshes without tangents
+// (all zero) fall back to `perturb_normal`.
+fn tangent_space_normal(
+    n: vec3<f32>,
+    tangent: vec4<f32>,
+    pos: vec3<f32>,
+    uv: vec2<f32>,
+    map_normal: vec3<f32>,
+    scale: f32,
+) -> vec3<f32> {
+    // Derivatives need uniform control flow, so both are computed.
+    let derived = perturb_normal(n, pos, uv, map_normal, scale);
+    if dot(tangent.xyz, tangent.xyz) < 1e-12 {
+        return derived;
+    }
+    let t = normalize(tangent.xyz - n * dot(n, tangent.xyz));
+    let b = sign(tangent.w) * cross(n, t);
+    let tbn = mat3x3<f32>(t, b, n);
+    return normalize(tbn * vec3(map_normal.xy * scale, map_normal.z));
+}
+
+@fragment
+fn main() { }
@@ -21,16 +21,17 @@ var<uniform> view_proj_uniforms: ViewProjectionUniforms;
 
 struct VertexInput {
     @location(0) position: vec4<f32>,
-    @location(1) tex_coords: vec2<f32>,
-    @location(2) normal: vec3<f32>,
+    @location(1) tangent: vec4<f32>,
+    @location(2) tex_coords: vec2<f32>,
+    @location(3) normal: vec3<f32>,
 }
 
 struct InstanceInput {
-    @location(3) model_1: vec4<f32>,
-    @location(4) model_2: vec4<f32>,
-    @location(5) model_3: vec4<f32>,
-    @location(6) model_4: vec4<f32>,
-    @location(7) id_color: vec2<u32>,
+    @location(4) model_1: vec4<f32>,
+    @location(5) model_2: vec4<f32>,
+    @location(6) model_3: vec4<f32>,
+    @location(7) model_4: vec4<f32>,
+    @location(8) id_color: vec2<u32>,
 }
 
 struct VertexOutput {
@@ -1,5 +1,6 @@
-#import global.wgsl::{GlobalUniforms, ViewProjectionUniforms}
+#import global.wgsl::{GlobalUniforms, ViewProjectionUniforms, SkinnedVertexData}
 #import inputs.wgsl::{VertexInput, InstanceInput}
+#import skinning.wgsl::{SkinnedInstanceInput, instance_transform, blend_joints}
 
 @group(0) @binding(0)
 var t_diffuse: texture_2d<f32>;
@@ -28,6 +29,26 @@ fn vs_main(
     return view_proj_uniforms.projection * model_view;
 }
 
+@group(3) @binding(0)
+var<storage, read> joint_matrices: array<mat4x4<f32>>;
+
+@vertex
+fn vs_skinned(
+    vertex: SkinnedVertexData,
+    instance: SkinnedInstanceInput,
+) -> @builtin(position) vec4<f32> {
+    let joints = vertex.joints + instance.joint_offset;
+    let model_transform = instance_transform(instance) * blend_joints(
+        joint_matrices[joints.x],
+        joint_matrices[joints.y],
+        joint_matrices[joints.z],
+        joint_matrices[joints.w],
+        vertex.weights,
+    );
+    let model_view = view_proj_uniforms.view * model_transform;
+    return view_proj_uniforms.projection * model_view * vertex.position;
+}
+
 @fragment
 fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
     return vec4(position.z, position.z, position.z, 1.0);
@@ -0,0 +1,39 @@
+// Linear blend skinning for the vertex shaders of skinned meshes. The joint
+// matrices are declared by each shader, as their bind group differs.
+
+// Like InstanceInput but without the normal matrix, which doesn't fit in the
+// 16 vertex attributes next to the joints and weights. Normals are transformed
+// by the skinned model matrix instead, assuming uniform scaling.
+struct SkinnedInstanceInput {
+    @location(6) uv_scale: vec2<f32>,
+    @location(7) uv_offset: vec2<f32>,
+    @location(8) tint: vec4<f32>,
+    @location(9) model_1x: vec4<f32>,
+    @location(10) model_2x: vec4<f32>,
+    @location(11) model_3x: vec4<f32>,
+    @location(12) model_4x: vec4<f32>,
+    // Index of the first joint matrix of the instance's skeleton
+    @location(13) joint_offset: u32,
+}
+
+fn instance_transform(instance: SkinnedInstanceInput) -> mat4x4<f32> {
+    return mat4x4<f32>(
+        instance.model_1x,
+        instance.model_2x,
+        instance.model_3x,
+        instance.model_4x,
+    );
+}
+
+fn blend_joints(
+    joint_0: mat4x4<f32>,
+    joint_1: mat4x4<f32>,
+    joint_2: mat4x4<f32>,
+    joint_3: mat4x4<f32>,
+    weights: vec4<f32>,
+) -> mat4x4<f32> {
+    return joint_0 * weights.x + joint_1 * weights.y + joint_2 * weights.z + joint_3 * weights.w;
+}
+
+@fragment
+fn main() { }
@@ -0,0 +1,44 @@
+#import global.wgsl::{GlobalUniforms, ViewProjectionUniforms}
+
+@group(0) @binding(0)
+var t_diffuse: texture_2d<f32>;
+@group(0) @binding(1)
+var s_diffuse: sampler;
+
+@group(1) @binding(0)
+var<uniform> global_uniforms: GlobalUniforms;
+
+@group(2) @binding(0)
+var<uniform> view_proj_uniforms: ViewProjectionUniforms;
+
+@group(3) @binding(0)
+var t_skybox: texture_cube<f32>;
+@group(3) @binding(1)
+var s_skybox: sampler;
+
+struct VertexInput {
+    @location(0) position: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) direction: vec3<f32>,
+}
+
+// Draws a unit cube around the camera, rotated by the view but not moved
+// with it, at the far plane.
+@vertex
+fn vs_main(vertex: VertexInput) -> VertexOutput {
+    let view = view_proj_uniforms.view;
+    let rotation = mat3x3<f32>(view[0].xyz, view[1].xyz, view[2].xyz);
+    let clip_position = view_proj_uniforms.projection * vec4<f32>(rotation * vertex.position.xyz, 1.0);
+    var out: VertexOutput;
+    out.clip_position = clip_position.xyww;
+    out.direction = vertex.position.xyz;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(textureSample(t_skybox, s_skybox, in.direction).rgb, 1.0);
+}
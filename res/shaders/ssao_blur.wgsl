@@ -65,9 +65,15 @@ struct BlurUniforms {
 var<uniform> blur_settings: BlurUniforms;
 
 @group(3) @binding(0)
-var depth_buffer: texture_depth_2d;
-@group(3) @binding(1)
-var depth_buffer_sampler: sampler;
+var depth_buffer: texture_2d<f32>;
+
+// The depth buffer is bound as an unfilterable float texture and loaded, GL
+// can only sample depth textures with a comparison.
+fn load_depth(coords: vec2<f32>) -> f32 {
+    let size = vec2<i32>(textureDimensions(depth_buffer));
+    let texel = clamp(vec2<i32>(coords * vec2<f32>(size)), vec2<i32>(0), size - 1);
+    return textureLoad(depth_buffer, texel, 0).r;
+}
 
 fn blur_weight(radius: f32, center_depth: f32, sample_depth: f32) -> f32 {
     let blur_sigma = (f32(blur_settings.half_kernel_size) + 1.0) * 0.5;
@@ -82,14 +88,14 @@ fn fs_main(in: VertexOutput) -> @location(0) f32 {
     let texelSize = vec2<f32>(1.0, 1.0) / vec2<f32>(textureDimensions(t_diffuse, 0));
 
     var result = textureSample(t_diffuse, s_diffuse, in.tex_coords).r;
-    var center_depth = textureSample(depth_buffer, depth_buffer_sampler, in.tex_coords);
+    var center_depth = load_depth(in.tex_coords);
     var weight = 1.0;
 
     for (var i = 1; i <= blur_settings.half_kernel_size; i++) {
         let r = f32(i);
         let uv = in.tex_coords + r * blur_settings.step;
         let sample_color = textureSample(t_diffuse, s_diffuse, uv).r;
-        let sample_depth = textureSample(depth_buffer, depth_buffer_sampler, in.tex_coords);
+        let sample_depth = load_depth(in.tex_coords);
         let w = blur_weight(r, center_depth, sample_depth);
         weight += w;
         result += sample_color * w;
@@ -99,7 +105,7 @@ fn fs_main(in: VertexOutput) -> @location(0) f32 {
         let r = f32(i);
         let uv = in.tex_coords - r * blur_settings.step;
         let sample_color = textureSample(t_diffuse, s_diffuse, uv).r;
-        let sample_depth = textureSample(depth_buffer, depth_buffer_sampler, in.tex_coords);
+        let sample_depth = load_depth(in.tex_coords);
         let w = blur_weight(r, center_depth, sample_depth);
         weight += w;
         result += sample_color * w;
@@ -12,9 +12,15 @@ var<uniform> global_uniforms: GlobalUniforms;
 var<uniform> view_proj_uniforms: ViewProjectionUniforms;
 
 @group(3) @binding(0)
-var depth_buffer: texture_depth_2d;
-@group(3) @binding(1)
-var depth_buffer_sampler: sampler;
+var depth_buffer: texture_2d<f32>;
+
+// The depth buffer is bound as an unfilterable float texture and loaded, GL
+// can only sample depth textures with a comparison.
+fn load_depth(coords: vec2<f32>) -> f32 {
+    let size = vec2<i32>(textureDimensions(depth_buffer));
+    let texel = clamp(vec2<i32>(coords * vec2<f32>(size)), vec2<i32>(0), size - 1);
+    return textureLoad(depth_buffer, texel, 0).r;
+}
 
 const KERNEL_SIZE: u32 = 64;
 
@@ -78,7 +84,7 @@ fn vs_main(
 fn reconstructPosition(coords: vec2<f32>) -> vec3<f32> {
     let x = coords.x * 2.0 - 1.0;
     let y = (1.0 - coords.y) * 2.0 - 1.0;
-    let z = textureSample(depth_buffer, depth_buffer_sampler, coords);
+    let z = load_depth(coords);
     let position_s = vec4(x, y, z, 1.0);
     let position_v = kernel.inverse_proj * position_s;
     return position_v.xyz / position_v.w;
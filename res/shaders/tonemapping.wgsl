@@ -0,0 +1,176 @@
+#import global.wgsl::{GlobalUniforms, ViewProjectionUniforms};
+
+@group(0) @binding(0)
+var t_diffuse: texture_2d<f32>;
+@group(0) @binding(1)
+var s_diffuse: sampler;
+
+@group(1) @binding(0)
+var<uniform> global_uniforms: GlobalUniforms;
+
+@group(2) @binding(0)
+var<uniform> view_proj_uniforms: ViewProjectionUniforms;
+
+struct VertexInput {
+    @location(0) position: vec4<f32>,
+    @location(1) tex_coords: vec2<f32>,
+}
+
+struct InstanceInput {
+    @location(2) uv_scale: vec2<f32>,
+    @location(3) uv_offset: vec2<f32>,
+    @location(4) tint: vec4<f32>,
+    @location(5) model_1: vec4<f32>,
+    @location(6) model_2: vec4<f32>,
+    @location(7) model_3: vec4<f32>,
+    @location(8) model_4: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) tex_coords: vec2<f32>,
+}
+
+@vertex
+fn vs_main(
+    vertex: VertexInput,
+    instance: InstanceInput,
+) -> VertexOutput {
+    var out: VertexOutput;
+    out.tex_coords = instance.uv_offset + instance.uv_scale * vertex.tex_coords;
+    var model = vertex.position;
+    model.x = model.x * 2.0 - 1.0;
+    model.y = model.y * 2.0 - 1.0;
+    out.clip_position = model;
+    return out;
+}
+
+@export
+struct TonemappingUniforms {
+    // 0: clamp, 1: ACES, 2: Reinhard, 3: AgX
+    tonemapper: u32,
+    auto_exposure: u32,
+    // Exposure compensation in stops.
+    exposure: f32,
+    // Blend factor from the previous to the current average luminance.
+    adaptation: f32,
+    min_luminance: f32,
+    max_luminance: f32,
+    // Mip level of the luminance texture holding the average.
+    luminance_level: f32,
+}
+
+@group(3) @binding(0)
+var adapted_luminance: texture_2d<f32>;
+@group(3) @binding(1)
+var adapted_luminance_sampler: sampler;
+
+@group(4) @binding(0)
+var<uniform> params: TonemappingUniforms;
+
+// Average scene luminance is exposed to this middle grey.
+const KEY_VALUE: f32 = 0.18;
+
+fn luminance(color: vec3<f32>) -> f32 {
+    return dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+}
+
+// Log luminance of the scene, averaged by downsampling into its mip chain.
+@fragment
+fn fs_luminance(in: VertexOutput) -> @location(0) f32 {
+    let color = textureSample(t_diffuse, s_diffuse, in.tex_coords).rgb;
+    return log2(max(luminance(color), 1e-4));
+}
+
+// Moves the previously adapted luminance towards the current average.
+@fragment
+fn fs_adapt(in: VertexOutput) -> @location(0) f32 {
+    let average = exp2(textureSampleLevel(t_diffuse, s_diffuse, vec2<f32>(0.5), params.luminance_level).r);
+    let previous = textureSample(adapted_luminance, adapted_luminance_sampler, vec2<f32>(0.5)).r;
+    return mix(previous, average, params.adaptation);
+}
+
+// ACES filmic curve fitted by Stephen Hill, operating on linear sRGB.
+fn tonemap_aces(color: vec3<f32>) -> vec3<f32> {
+    let input = mat3x3<f32>(
+        vec3<f32>(0.59719, 0.07600, 0.02840),
+        vec3<f32>(0.35458, 0.90834, 0.13383),
+        vec3<f32>(0.04823, 0.01566, 0.83777),
+    );
+    let output = mat3x3<f32>(
+        vec3<f32>(1.60475, -0.10208, -0.00327),
+        vec3<f32>(-0.53108, 1.10813, -0.07276),
+        vec3<f32>(-0.07367, -0.00605, 1.07602),
+    );
+    let v = input * color;
+    let a = v * (v + 0.0245786) - 0.000090537;
+    let b = v * (0.983729 * v + 0.4329510) + 0.238081;
+    return clamp(output * (a / b), vec3<f32>(0.0), vec3<f32>(1.0));
+}
+
+fn tonemap_reinhard(color: vec3<f32>) -> vec3<f32> {
+    return color / (1.0 + luminance(color));
+}
+
+fn agx_contrast(x: vec3<f32>) -> vec3<f32> {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    return 15.5 * x4 * x2
+        - 40.14 * x4 * x
+        + 31.96 * x4
+        - 6.868 * x2 * x
+        + 0.4298 * x2
+        + 0.1191 * x
+        - 0.00232;
+}
+
+// Troy Sobotka's AgX with the default look, using a polynomial fit of the
+// contrast curve.
+fn tonemap_agx(color: vec3<f32>) -> vec3<f32> {
+    let inset = mat3x3<f32>(
+        vec3<f32>(0.842479062253094, 0.0423282422610123, 0.0423756549057051),
+        vec3<f32>(0.0784335999999992, 0.878468636469772, 0.0784336),
+        vec3<f32>(0.0792237451477643, 0.0791661274605434, 0.879142973793104),
+    );
+    let outset = mat3x3<f32>(
+        vec3<f32>(1.19687900512017, -0.0528968517574562, -0.0529716355144438),
+        vec3<f32>(-0.0980208811401368, 1.15190312990417, -0.0980434501171241),
+        vec3<f32>(-0.0990297440797205, -0.0989611768448433, 1.15107367264116),
+    );
+    let min_ev = -12.47393;
+    let max_ev = 4.026069;
+    var v = inset * color;
+    v = clamp(log2(max(v, vec3<f32>(1e-10))), vec3<f32>(min_ev), vec3<f32>(max_ev));
+    v = (v - min_ev) / (max_ev - min_ev);
+    v = outset * agx_contrast(v);
+    // The curve targets a 2.2 gamma display, the output target encodes sRGB.
+    return pow(max(v, vec3<f32>(0.0)), vec3<f32>(2.2));
+}
+
+fn tonemap(color: vec3<f32>) -> vec3<f32> {
+    switch params.tonemapper {
+        case 1u: {
+            return tonemap_aces(color);
+        }
+        case 2u: {
+            return tonemap_reinhard(color);
+        }
+        case 3u: {
+            return tonemap_agx(color);
+        }
+        default: {
+            return clamp(color, vec3<f32>(0.0), vec3<f32>(1.0));
+        }
+    }
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSample(t_diffuse, s_diffuse, in.tex_coords).rgb;
+    let adapted = textureSample(adapted_luminance, adapted_luminance_sampler, vec2<f32>(0.5)).r;
+    var exposure = exp2(params.exposure);
+    if params.auto_exposure != 0u {
+        exposure *= KEY_VALUE / clamp(adapted, params.min_luminance, params.max_luminance);
+    }
+    return vec4<f32>(tonemap(color * exposure), 1.0);
+}
@@ -9,9 +9,10 @@ use winit::{
 };
 
 use crate::{
+    geom::Point,
     input::{AnalogInput, ControlSet, InputManager, Key, MouseButton},
-    renderer::{egui::EguiRenderer, Display, RenderState},
-    time::FrameTiming,
+    renderer::{capture::FrameCapture, egui::EguiRenderer, Display, RenderState},
+    time::{FixedTimestep, FrameTiming},
 };
 
 pub struct Context<C: ControlSet> {
@@ -20,6 +21,7 @@ pub struct Context<C: ControlSet> {
     pub frame_timing: FrameTiming,
     pub input: InputManager<C>,
     pub egui: EguiRenderer,
+    pub capture: FrameCapture,
 }
 
 impl<C: ControlSet> Context<C> {
@@ -31,6 +33,7 @@ impl<C: ControlSet> Context<C> {
             frame_timing: Default::default(),
             input: Default::default(),
             egui,
+            capture: Default::default(),
         }
     }
 
@@ -51,9 +54,11 @@ impl<C: ControlSet> Context<C> {
     }
 
     pub fn handle_input(&mut self, event: &WindowEvent) {
-        if self.egui.handle_input(self.display.window(), event) {
-            // input consumed
-            return;
+        if let Some(window) = self.display.window() {
+            if self.egui.handle_input(window, event) {
+                // input consumed
+                return;
+            }
         }
         match *event {
             WindowEvent::CursorMoved { position, .. } => {
@@ -97,23 +102,20 @@ impl<C: ControlSet> Context<C> {
     }
 
     pub fn set_cursor_captured(&self, captured: bool) {
+        let Some(window) = self.display.window() else {
+            return;
+        };
         if captured {
-            self.display
-                .window()
+            window
                 .set_cursor_grab(winit::window::CursorGrabMode::Confined)
-                .or_else(|_| {
-                    self.display
-                        .window()
-                        .set_cursor_grab(winit::window::CursorGrabMode::Locked)
-                })
+                .or_else(|_| window.set_cursor_grab(winit::window::CursorGrabMode::Locked))
                 .unwrap();
         } else {
-            self.display
-                .window()
+            window
                 .set_cursor_grab(winit::window::CursorGrabMode::None)
                 .unwrap();
         }
-        self.display.window().set_cursor_visible(!captured);
+        window.set_cursor_visible(!captured);
     }
 }
 
@@ -126,6 +128,82 @@ impl<A: AppState> App<A> {
     pub fn new(size: Size) -> Self {
         Self { size, state: None }
     }
+
+    pub fn context(&self) -> Option<&Context<A::Controls>> {
+        self.state.as_ref().map(|(ctx, _)| ctx)
+    }
+
+    pub fn context_mut(&mut self) -> Option<&mut Context<A::Controls>> {
+        self.state.as_mut().map(|(ctx, _)| ctx)
+    }
+
+    pub fn state(&self) -> Option<&A> {
+        self.state.as_ref().map(|(_, state)| state)
+    }
+
+    fn init(&mut self, display: Display) {
+        let render_state = A::init_render_state(&display);
+        let mut ctx = Context::new(display, render_state);
+        ctx.frame_timing.set_fixed_timestep(A::fixed_timestep());
+        let app_state = A::new(&mut ctx);
+        self.state = Some((ctx, app_state));
+    }
+
+    /// Drives the app without a window for up to `frames` frames, rendering
+    /// into the headless display's offscreen texture. Returns the number of
+    /// frames that were run, which is less than `frames` if `update` asked to
+    /// exit early.
+    pub fn run_headless(&mut self, frames: usize) -> usize {
+        if self.state.is_none() {
+            let size = self.size.to_physical::<u32>(1.0);
+            let display =
+                pollster::block_on(Display::headless(Point::new(size.width, size.height)));
+            self.init(display);
+        }
+        let (ctx, state) = self.state.as_mut().unwrap();
+        for frame in 0..frames {
+            if !Self::run_frame(ctx, state) {
+                return frame;
+            }
+        }
+        frames
+    }
+
+    /// Updates and renders a single frame, returning false if the app should exit.
+    fn run_frame(ctx: &mut Context<A::Controls>, state: &mut A) -> bool {
+        ctx.frame_timing.update();
+        for _ in 0..ctx.frame_timing.take_fixed_steps() {
+            state.fixed_update(ctx);
+        }
+        if !state.update(ctx) {
+            return false;
+        }
+        // Do this after the frame is done updating, so we can clear state and update controls for the next frame.
+        ctx.input.end_frame_update();
+
+        ctx.capture.before_render(&mut ctx.display);
+        match state.render(ctx) {
+            Ok(_) => {
+                ctx.capture.after_render(
+                    &mut ctx.display,
+                    &ctx.render_state,
+                    ctx.frame_timing.delta(),
+                );
+            }
+            // Reconfigure the surface if it's lost or outdated
+            Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                ctx.display.reconfigure();
+            }
+            // The system is out of memory, we should probably quit
+            Err(wgpu::SurfaceError::OutOfMemory) => {
+                log::error!("Out of memory?!");
+                return false;
+            }
+            Err(wgpu::SurfaceError::Timeout) => log::warn!("Surface timeout"),
+        }
+        ctx.render_state.after_frame(&ctx.display);
+        true
+    }
 }
 
 impl<A: AppState> ApplicationHandler for App<A> {
@@ -136,10 +214,7 @@ impl<A: AppState> ApplicationHandler for App<A> {
                 .unwrap();
 
             let display = pollster::block_on(Display::from_window(window));
-            let render_state = A::init_render_state(&display);
-            let mut ctx = Context::new(display, render_state);
-            let app_state = A::new(&mut ctx);
-            self.state = Some((ctx, app_state));
+            self.init(display);
         }
     }
 
@@ -163,7 +238,10 @@ impl<A: AppState> ApplicationHandler for App<A> {
         let Some((ctx, state)) = &mut self.state else {
             return;
         };
-        if window_id != ctx.display.window().id() {
+        let Some(window) = ctx.display.window() else {
+            return;
+        };
+        if window_id != window.id() {
             return;
         }
         ctx.handle_input(&event);
@@ -172,33 +250,15 @@ impl<A: AppState> ApplicationHandler for App<A> {
             WindowEvent::Resized(new_size) => {
                 self.size = Size::Physical(new_size);
                 ctx.display.resize(new_size);
-                ctx.display.window().request_redraw();
+                ctx.display.window().unwrap().request_redraw();
             }
             // WindowEvent::ScaleFactorChanged { scale_factor, .. } => {}
             WindowEvent::RedrawRequested => {
-                ctx.frame_timing.update();
-                if !state.update(ctx) {
+                if !Self::run_frame(ctx, state) {
                     event_loop.exit();
                     return;
                 }
-                // Do this after the frame is done updating, so we can clear state and update controls for the next frame.
-                ctx.input.end_frame_update();
-
-                match state.render(ctx) {
-                    Ok(_) => {}
-                    // Reconfigure the surface if it's lost or outdated
-                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
-                        ctx.display.reconfigure();
-                    }
-                    // The system is out of memory, we should probably quit
-                    Err(wgpu::SurfaceError::OutOfMemory) => {
-                        log::error!("Out of memory?!");
-                        event_loop.exit();
-                    }
-                    Err(wgpu::SurfaceError::Timeout) => log::warn!("Surface timeout"),
-                }
-                ctx.render_state.after_frame();
-                ctx.display.window().request_redraw();
+                ctx.display.window().unwrap().request_redraw();
             }
             _ => {}
         }
@@ -210,6 +270,14 @@ pub trait AppState {
 
     fn new(ctx: &mut Context<Self::Controls>) -> Self;
     fn update(&mut self, ctx: &mut Context<Self::Controls>) -> bool;
+
+    /// Opt in to fixed timestep mode by returning `Some` here, `fixed_update`
+    /// is then called zero or more times per frame, before `update`.
+    fn fixed_timestep() -> Option<FixedTimestep> {
+        None
+    }
+    fn fixed_update(&mut self, _ctx: &mut Context<Self::Controls>) {}
+
     fn render(&mut self, ctx: &mut Context<Self::Controls>) -> Result<(), wgpu::SurfaceError>;
     fn destroy(&mut self, _ctx: &mut Context<Self::Controls>) {}
 
@@ -225,3 +293,81 @@ pub trait AppState {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use controlset_derive::ControlSet;
+    use winit::dpi::PhysicalSize;
+
+    use super::*;
+    use crate::input::Button;
+    use crate::renderer::{state::ViewProjectionUniforms, RenderTarget};
+
+    #[derive(ControlSet)]
+    struct TestControls {
+        #[bind(Key::Escape)]
+        quit: Button,
+    }
+
+    struct CountingApp {
+        updates: usize,
+        renders: usize,
+        exit_after: usize,
+    }
+
+    impl AppState for CountingApp {
+        type Controls = TestControls;
+
+        fn new(_ctx: &mut Context<Self::Controls>) -> Self {
+            Self {
+                updates: 0,
+                renders: 0,
+                exit_after: 5,
+            }
+        }
+
+        fn update(&mut self, _ctx: &mut Context<Self::Controls>) -> bool {
+            self.updates += 1;
+            self.updates <= self.exit_after
+        }
+
+        fn render(&mut self, ctx: &mut Context<Self::Controls>) -> Result<(), wgpu::SurfaceError> {
+            self.renders += 1;
+            let display_view = ctx.display.view()?;
+            ctx.render_state
+                .render_pass(
+                    &ctx.display,
+                    "Clear Pass",
+                    &[RenderTarget::TextureView(display_view.view())],
+                    None,
+                    &ViewProjectionUniforms::default(),
+                    |_| {},
+                )
+                .submit();
+            display_view.present();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_run_headless() {
+        let mut app = App::<CountingApp>::new(PhysicalSize::new(8, 8).into());
+        assert_eq!(app.run_headless(3), 3);
+        assert_eq!(app.state().unwrap().renders, 3);
+
+        // Frames are rendered into the display framebuffer, cleared to
+        // opaque black from transparent.
+        let ctx = app.context_mut().unwrap();
+        let framebuffer = ctx.render_state.display_framebuffer(&ctx.display).unwrap();
+        assert_eq!(framebuffer.size_pixels(), Point::new(8, 8));
+        let image = ctx
+            .render_state
+            .capture_texture(&mut ctx.display, framebuffer.color)
+            .unwrap();
+        assert_eq!(image.get_pixel(4, 4).0, [0, 0, 0, 255]);
+
+        // Stops early once update asks to exit.
+        assert_eq!(app.run_headless(10), 2);
+        assert_eq!(app.state().unwrap().updates, 6);
+    }
+}
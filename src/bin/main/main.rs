@@ -1,19 +1,27 @@
 use std::ops::DerefMut;
+use std::time::Duration;
 
 use bytemuck::Zeroable;
 use glam::{vec2, vec3, vec4, Mat3, Mat4, Quat, Vec2, Vec3};
 use itertools::Itertools;
 use rust_game_engine::app::{App, AppState, Context};
 use rust_game_engine::color::Color;
+use rust_game_engine::renderer::bloom::BloomPass;
+use rust_game_engine::renderer::capture::CaptureSource;
+use rust_game_engine::renderer::cubemap::EquirectangularImage;
 use rust_game_engine::renderer::forward::ForwardGeometryPass;
 use rust_game_engine::renderer::geometry::GeometryPass;
 use rust_game_engine::renderer::lighting::{Light, LightKind};
-use rust_game_engine::renderer::model::LoadModel;
+use rust_game_engine::renderer::model::{LoadModel, PreparedModel};
 use rust_game_engine::renderer::shader_type::GlobalUniforms;
 use rust_game_engine::renderer::shadow_mapping::ShadowMappingPass;
+use rust_game_engine::renderer::skinning::{SkinnedInstanceData, SkinnedScene};
 use rust_game_engine::renderer::ssao_from_depth::SSAOPass;
 use rust_game_engine::renderer::text::RenderableFont;
-use rust_game_engine::renderer::{InstanceDataWithNormalMatrix, MeshRef, RenderTarget};
+use rust_game_engine::renderer::tonemapping::TonemappingPass;
+use rust_game_engine::renderer::{
+    InstanceDataWithNormalMatrix, Material, MaterialRef, MeshRef, RenderTarget,
+};
 use winit::dpi::PhysicalSize;
 use winit::event_loop::EventLoop;
 
@@ -51,6 +59,10 @@ struct GameControls {
     show_help: Toggle,
     #[bind(Key::GraveAccent)]
     debug: Toggle,
+    #[bind(Key::F12)]
+    screenshot: Button,
+    #[bind(Key::F11)]
+    record: Button,
 }
 
 #[derive(Default)]
@@ -72,6 +84,9 @@ struct State {
     ssao_enabled: bool,
     // deferred_lighting_pass: LightingPass,
     forward_pass: ForwardGeometryPass,
+    bloom_pass: BloomPass,
+    bloom_enabled: bool,
+    tonemapping_pass: TonemappingPass,
 
     // "game" state
     camera: Camera,
@@ -80,8 +95,10 @@ struct State {
     crate_texture: TextureRef,
     cat_texture: TextureRef,
     cube_mesh: MeshRef<ModelVertexData>,
+    cube_material: MaterialRef,
 
-    model_meshes: Vec<(MeshRef<ModelVertexData>, Option<tobj::Material>)>,
+    model: PreparedModel,
+    skinned_scene: SkinnedScene,
     cubes: Vec<Transform3D>,
 
     scene: Scene,
@@ -168,11 +185,8 @@ impl AppState for State {
             .load_model("./res/models/room_thickwalls.obj")
             .unwrap();
 
-        let model_meshes = model
-            .meshes
-            .into_iter()
-            .map(|m| (ctx.render_state.prepare_mesh(m.mesh), m.material))
-            .collect();
+        let model = ctx.render_state.prepare_model(&ctx.display, model);
+        let skinned_scene = SkinnedScene::new(&ctx.display);
 
         let fb_size = Point::from((
             (WINDOW_SIZE.width as f32 / RENDER_SCALE) as u32,
@@ -190,12 +204,26 @@ impl AppState for State {
         ctx.set_cursor_captured(true);
 
         let shadow_mapping_pass = ShadowMappingPass::new(&mut ctx.render_state, &ctx.display);
-        let forward_pass = ForwardGeometryPass::new(
+        let mut forward_pass = ForwardGeometryPass::new(
             &mut ctx.render_state,
             &ctx.display,
             fb_size,
             &shadow_mapping_pass.shadow_map_texture(),
+            &shadow_mapping_pass.point_shadow_map_texture(),
         );
+        match EquirectangularImage::open("./res/images/sky.hdr") {
+            Ok(sky) => {
+                let skybox = TextureBuilder::cubemap()
+                    .with_label("skybox")
+                    .with_filter_mode(wgpu::FilterMode::Linear)
+                    .with_mipmaps()
+                    .from_equirectangular(ctx.display.device(), ctx.display.queue(), &sky, 256);
+                let skybox = ctx.render_state.load_texture(&ctx.display, skybox);
+                forward_pass.set_environment(&ctx.render_state, &ctx.display, skybox);
+                forward_pass.skybox = Some(skybox);
+            }
+            Err(e) => log::warn!("{:#}", e),
+        }
         let geometry_pass = GeometryPass::new(&mut ctx.render_state, &ctx.display, fb_size);
         let occlusion_pass = SSAOPass::new(
             &mut ctx.render_state,
@@ -204,7 +232,42 @@ impl AppState for State {
             &forward_pass.depth_target,
             &camera,
         );
+        let bloom_pass = BloomPass::new(&mut ctx.render_state, &ctx.display, fb_size);
+        let tonemapping_pass = TonemappingPass::new(
+            &mut ctx.render_state,
+            &ctx.display,
+            fb_size,
+            ctx.display.format(),
+        );
 
+        // Small unshadowed lights scattered between the cubes
+        forward_pass.light_clusters.lights = (0..256)
+            .map(|_| Light {
+                color: Color::from((
+                    rand::random::<f32>(),
+                    rand::random::<f32>(),
+                    rand::random::<f32>(),
+                    1.0,
+                )),
+                kind: LightKind::Point {
+                    position: vec3(
+                        64.0 * rand::random::<f32>() - 32.0,
+                        0.5 + 1.5 * rand::random::<f32>(),
+                        64.0 * rand::random::<f32>() - 32.0,
+                    ),
+                    radius: 3.0,
+                },
+            })
+            .collect();
+
+        let cube_material = ctx.render_state.load_material(
+            &ctx.display,
+            Material {
+                metallic: 0.5,
+                roughness: 0.3,
+                ..Default::default()
+            },
+        );
         let mut cubes = vec![];
         for x in 0..32 {
             for z in 0..32 {
@@ -224,16 +287,17 @@ impl AppState for State {
             crate_texture,
             cat_texture,
             cube_mesh,
+            cube_material,
             default_font: RenderableFont::new(ctx),
             sprite_instances: vec![],
             camera,
             // lights: vec![],
             lights: vec![
-                // LightKind::Directional {
-                //     theta: -65.0,
-                //     phi: -30.0,
-                // }
-                // .into(),
+                LightKind::Directional {
+                    theta: -65.0,
+                    phi: -30.0,
+                }
+                .into(),
                 LightKind::Spot {
                     position: vec3(0.0, 5.0, 0.0),
                     direction: vec3(0.0, -8.0, 30.0),
@@ -259,17 +323,28 @@ impl AppState for State {
                         reach: 40.0,
                     },
                 },
+                Light {
+                    color: Color::BLUE,
+                    kind: LightKind::Point {
+                        position: vec3(8.0, 4.0, 0.0),
+                        radius: 20.0,
+                    },
+                },
             ],
             shadow_mapping_pass,
             geometry_pass,
             forward_pass,
+            bloom_pass,
+            bloom_enabled: true,
+            tonemapping_pass,
             occlusion_pass,
             ssao_enabled: true,
             // font_render_data: Default::default(),
             sprite_render_data,
             offscreen_framebuffer,
             // render_pipelines: Default::default(),
-            model_meshes,
+            model,
+            skinned_scene,
             // deferred_lighting_pass,
             cubes,
             scene: Scene::Cubes,
@@ -316,6 +391,17 @@ impl AppState for State {
         if ctx.input.debug.just_pressed() {
             ctx.set_cursor_captured(!ctx.input.debug.on);
         }
+        if ctx.input.screenshot.just_pressed() {
+            ctx.capture.screenshot(CaptureSource::Display);
+        }
+        if ctx.input.record.just_pressed() {
+            if ctx.capture.is_recording() {
+                ctx.capture.stop_sequence();
+            } else {
+                ctx.capture
+                    .record_sequence(CaptureSource::Display, Duration::from_secs(5));
+            }
+        }
         true
     }
 
@@ -381,6 +467,7 @@ impl AppState for State {
             //     pipeline: None,
             // },
         ];
+        self.skinned_scene.clear();
         match self.scene {
             Scene::Cubes => {
                 scene.extend(self.cubes.iter().map(|t| InstanceRenderData {
@@ -393,39 +480,77 @@ impl AppState for State {
                         view_proj.view,
                     ),
                     texture: None,
+                    material: Some(self.cube_material),
                     pipeline: None,
                 }));
             }
             Scene::Model => {
-                for (mesh, mat) in &self.model_meshes {
+                let model_transform = Transform3D {
+                    position: vec3(0.0, 0.0, 5.0),
+                    // scale: vec3(0.02, 0.02, 0.02),
+                    ..Default::default()
+                }
+                .as_mat4();
+                for model_mesh in &self.model.meshes {
                     scene.push(InstanceRenderData {
-                        mesh: *mesh,
+                        mesh: model_mesh.mesh,
                         instance: InstanceDataWithNormalMatrix::from_basic(
                             BasicInstanceData {
-                                tint: mat.as_ref().map(|m| m.diffuse.into()).unwrap_or_default(),
-                                transform: Transform3D {
-                                    position: vec3(0.0, 0.0, 5.0),
-                                    // scale: vec3(0.02, 0.02, 0.02),
-                                    ..Default::default()
-                                }
-                                .as_mat4(),
+                                transform: model_transform * model_mesh.transform,
                                 ..Default::default()
                             },
                             view_proj.view,
                         ),
                         texture: None,
+                        material: model_mesh.material,
+                        pipeline: None,
+                    });
+                }
+
+                // Loop the first animation of each skin
+                let joint_offsets = self
+                    .model
+                    .skins
+                    .iter()
+                    .map(|skin| {
+                        let pose = match skin.animations.first() {
+                            Some(clip) if clip.duration > 0.0 => clip.pose(
+                                &skin.skeleton,
+                                ctx.frame_timing.time().rem_euclid(clip.duration),
+                            ),
+                            _ => skin.skeleton.rest_pose(),
+                        };
+                        self.skinned_scene
+                            .add_joint_matrices(&skin.skeleton.joint_matrices(&pose))
+                    })
+                    .collect_vec();
+                for skinned_mesh in &self.model.skinned_meshes {
+                    self.skinned_scene.instances.push(InstanceRenderData {
+                        mesh: skinned_mesh.mesh,
+                        instance: SkinnedInstanceData::new(
+                            model_transform,
+                            joint_offsets[skinned_mesh.skin],
+                        ),
+                        texture: None,
+                        material: skinned_mesh.material,
                         pipeline: None,
                     });
                 }
             }
         }
+        self.skinned_scene.upload(&ctx.display);
 
         // Populate G buffers
         // self.geometry_pass
         //     .run(&mut ctx.render_state, &ctx.display, &view_proj, &scene);
 
-        self.forward_pass
-            .depth_prepass(&mut ctx.render_state, &ctx.display, &view_proj, &scene);
+        self.forward_pass.depth_prepass(
+            &mut ctx.render_state,
+            &ctx.display,
+            &view_proj,
+            &scene,
+            &self.skinned_scene,
+        );
 
         let occlusion_map = if self.ssao_enabled {
             self.occlusion_pass
@@ -438,10 +563,13 @@ impl AppState for State {
             .iter_mut()
             .enumerate()
             .for_each(|(i, light)| match &mut light.kind {
-                LightKind::Directional { .. } => todo!(),
+                LightKind::Directional { .. } => {}
                 LightKind::Spot { direction, .. } => {
                     *direction = Mat3::from_rotation_y((i as f32 + 0.5) * 0.01) * *direction;
                 }
+                LightKind::Point { position, .. } => {
+                    *position = Mat3::from_rotation_y(0.01) * *position;
+                }
             });
 
         self.forward_pass
@@ -456,6 +584,7 @@ impl AppState for State {
             &ctx.display,
             &self.forward_pass.lights_uniform,
             &scene,
+            &self.skinned_scene,
         );
 
         if ctx.input.debug.on {
@@ -476,6 +605,7 @@ impl AppState for State {
                         view_proj.view,
                     ),
                     texture: None,
+                    material: None,
                     pipeline: None,
                 });
 
@@ -495,13 +625,32 @@ impl AppState for State {
             }
         }
 
+        self.forward_pass
+            .light_clusters
+            .update(&ctx.display, &self.camera);
         self.forward_pass.run(
             &mut ctx.render_state,
             &ctx.display,
             &view_proj,
             &scene,
+            &self.skinned_scene,
             occlusion_map,
         );
+        let hdr = if self.bloom_enabled {
+            self.bloom_pass.run(
+                &mut ctx.render_state,
+                &ctx.display,
+                self.forward_pass.color_target,
+            )
+        } else {
+            self.forward_pass.color_target
+        };
+        let color = self.tonemapping_pass.run(
+            &mut ctx.render_state,
+            &ctx.display,
+            hdr,
+            ctx.frame_timing.delta(),
+        );
 
         // Draw offscreen buffer, overlay with 2d elements
         let display_view = ctx.display.view()?;
@@ -521,7 +670,7 @@ impl AppState for State {
                 |r| {
                     r.draw_quad(
                         // self.offscreen_framebuffer.color,
-                        self.forward_pass.color_target,
+                        color,
                         ScalingMode::Centered.view_matrix(
                             self.offscreen_framebuffer.size_pixels().as_vec2(),
                             ctx.display.size_pixels().as_vec2(),
@@ -604,6 +753,47 @@ impl AppState for State {
                                     ui.selectable_value(&mut self.scene, Scene::Model, "model");
                                 });
 
+                            ui.separator();
+                            ui.label("Frame time");
+                            let history = ctx.frame_timing.history_mut();
+                            history.debug_ui(ui);
+                            if ui.button("Export CSV").clicked() {
+                                let path = ctx.capture.output_dir.join(format!(
+                                    "frame_times_{}.csv",
+                                    std::time::SystemTime::now()
+                                        .duration_since(std::time::UNIX_EPOCH)
+                                        .unwrap_or_default()
+                                        .as_secs()
+                                ));
+                                match history.save_csv(&path) {
+                                    Ok(()) => log::info!("saved {}", path.display()),
+                                    Err(e) => log::error!("{:#}", e),
+                                }
+                            }
+
+                            ui.separator();
+                            ui.label("GPU passes");
+                            ctx.render_state.profiler.debug_ui(ui);
+
+                            ui.separator();
+                            ui.label("Draw stats");
+                            ctx.render_state.draw_stats().debug_ui(ui);
+
+                            ui.separator();
+                            ui.label("Cube material");
+                            let mut material = ctx
+                                .render_state
+                                .get_material(self.cube_material)
+                                .material()
+                                .clone();
+                            if material.debug_ui(ui).changed() {
+                                ctx.render_state.update_material_factors(
+                                    &ctx.display,
+                                    self.cube_material,
+                                    &material,
+                                );
+                            }
+
                             ui.separator();
                             ui.label("Lights");
                             let lights_uniform = self.forward_pass.lights_uniform.deref_mut();
@@ -627,6 +817,8 @@ impl AppState for State {
                                 &mut lights_uniform.shadow_blur_half_kernel_size,
                                 0..=10,
                             ));
+                            ui.label("Shadow cascades");
+                            lights_uniform.debug_ui(ui);
                             {
                                 let mut c = egui::Rgba::from_rgba_premultiplied(
                                     lights_uniform.ambient_color.r,
@@ -644,6 +836,7 @@ impl AppState for State {
                                 });
                                 lights_uniform.ambient_color = c.into();
                             }
+                            self.forward_pass.light_clusters.debug_ui(ui);
 
                             for mut i in 0..(self.lights.len() as usize) {
                                 if i >= self.lights.len() {
@@ -687,6 +880,17 @@ impl AppState for State {
                             //     }
                             // }
 
+                            ui.separator();
+                            ui.label("Bloom");
+                            ui.add(egui::Checkbox::new(&mut self.bloom_enabled, "enabled"));
+                            if self.bloom_enabled {
+                                self.bloom_pass.debug_ui(ui);
+                            }
+
+                            ui.separator();
+                            ui.label("Tonemapping");
+                            self.tonemapping_pass.debug_ui(ui);
+
                             ui.separator();
                             ui.label("SSAO");
                             ui.add(egui::Checkbox::new(&mut self.ssao_enabled, "enabled"));
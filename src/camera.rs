@@ -49,6 +49,14 @@ impl Camera {
         self.yaw
     }
 
+    pub fn z_near(&self) -> f32 {
+        self.z_near
+    }
+
+    pub fn z_far(&self) -> f32 {
+        self.z_far
+    }
+
     pub fn view_matrix(&self) -> Mat4 {
         Mat4::look_to_rh(self.position, self.look_dir, Vec3::Y)
     }
@@ -77,14 +85,14 @@ impl Camera {
     pub fn frustum(&self) -> Frustum {
         let inverse_proj_view = (self.perspective_matrix() * self.view_matrix()).inverse();
         let mut ndc = Frustum {
-            nlt: vec4(-1.0, 1.0, 1.0, 1.0),
-            nrt: vec4(1.0, 1.0, 1.0, 1.0),
-            nlb: vec4(-1.0, -1.0, 1.0, 1.0),
-            nrb: vec4(1.0, -1.0, 1.0, 1.0),
-            flt: vec4(-1.0, 1.0, -1.0, 1.0),
-            frt: vec4(1.0, 1.0, -1.0, 1.0),
-            flb: vec4(-1.0, -1.0, -1.0, 1.0),
-            frb: vec4(1.0, -1.0, -1.0, 1.0),
+            nlt: vec4(-1.0, 1.0, 0.0, 1.0),
+            nrt: vec4(1.0, 1.0, 0.0, 1.0),
+            nlb: vec4(-1.0, -1.0, 0.0, 1.0),
+            nrb: vec4(1.0, -1.0, 0.0, 1.0),
+            flt: vec4(-1.0, 1.0, 1.0, 1.0),
+            frt: vec4(1.0, 1.0, 1.0, 1.0),
+            flb: vec4(-1.0, -1.0, 1.0, 1.0),
+            frb: vec4(1.0, -1.0, 1.0, 1.0),
         };
         ndc.nlt = inverse_proj_view * ndc.nlt;
         ndc.nlt /= ndc.nlt.w;
@@ -157,6 +165,21 @@ impl Frustum {
         }
     }
 
+    /// The part of the frustum between `near` and `far`, given as fractions of
+    /// the distance from the near plane to the far plane.
+    pub fn slice(&self, near: f32, far: f32) -> Frustum {
+        Frustum {
+            nlt: self.nlt.lerp(self.flt, near),
+            nrt: self.nrt.lerp(self.frt, near),
+            nlb: self.nlb.lerp(self.flb, near),
+            nrb: self.nrb.lerp(self.frb, near),
+            flt: self.nlt.lerp(self.flt, far),
+            frt: self.nrt.lerp(self.frt, far),
+            flb: self.nlb.lerp(self.flb, far),
+            frb: self.nrb.lerp(self.frb, far),
+        }
+    }
+
     pub fn aabb(&self) -> (Vec3, Vec3) {
         let mut min = vec3(f32::INFINITY, f32::INFINITY, f32::INFINITY);
         let mut max = vec3(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY);
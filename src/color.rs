@@ -38,6 +38,12 @@ impl Color {
         b: 0.0,
         a: 1.0,
     };
+    pub const BLUE: Self = Self {
+        r: 0.0,
+        g: 0.0,
+        b: 1.0,
+        a: 1.0,
+    };
 
     pub fn as_u8(self) -> [u8; 4] {
         [
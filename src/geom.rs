@@ -39,6 +39,7 @@ impl Default for ModelVertexData {
     fn default() -> Self {
         Self {
             position: Default::default(),
+            tangent: Default::default(),
             tex_coords: Default::default(),
             normal: Default::default(),
         }
@@ -53,7 +54,32 @@ impl VertexLayout for ModelVertexData {
 
 impl VertexData for ModelVertexData {}
 
-#[derive(Clone, Copy, Debug, Default)]
+/// `ModelVertexData` with the four joints influencing the vertex and their
+/// weights, which should add up to one.
+pub type SkinnedVertexData = shaders::global::types::SkinnedVertexData;
+
+impl Default for SkinnedVertexData {
+    fn default() -> Self {
+        Self {
+            position: Default::default(),
+            tangent: Default::default(),
+            weights: Default::default(),
+            joints: Default::default(),
+            tex_coords: Default::default(),
+            normal: Default::default(),
+        }
+    }
+}
+
+impl VertexLayout for SkinnedVertexData {
+    fn vertex_layout() -> VertexBufferLayout<'static> {
+        Self::vertex_buffer_layout()
+    }
+}
+
+impl VertexData for SkinnedVertexData {}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Point<T = i32> {
     pub x: T,
     pub y: T,
@@ -244,6 +270,8 @@ pub mod cube {
     const fn v([x, y, z]: [f32; 3], [s, t]: [f32; 2], [nx, ny, nz]: [f32; 3]) -> ModelVertexData {
         ModelVertexData {
             position: vec4(x, y, z, 1.0),
+            // Along increasing s on each face, see `renderer::tangents`
+            tangent: vec4(ny * ny - nz, 0.0, nx, 1.0),
             tex_coords: vec2(s, t),
             normal: vec3(nx, ny, nz),
             // position: vec4(x, y, z, 1.0).into(),
@@ -0,0 +1,364 @@
+//! Skeletons and keyframed animation clips for skinned meshes.
+
+use std::ops::{Add, Mul};
+
+use glam::{Mat4, Quat, Vec3};
+
+/// Local transform of a joint, relative to its parent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JointTransform {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl Default for JointTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl JointTransform {
+    pub const IDENTITY: Self = Self {
+        translation: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        scale: Vec3::ONE,
+    };
+
+    pub fn as_mat4(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Interpolates towards `other`, `t` = 0 is `self` and 1 is `other`.
+    pub fn blend(&self, other: &Self, t: f32) -> Self {
+        Self {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation.slerp(other.rotation, t),
+            scale: self.scale.lerp(other.scale, t),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Joint {
+    pub name: Option<String>,
+    /// Index into `Skeleton::joints`.
+    pub parent: Option<usize>,
+    /// Transforms from model space to the joint's space in the bind pose.
+    pub inverse_bind_matrix: Mat4,
+    pub rest: JointTransform,
+}
+
+#[derive(Debug, Clone)]
+pub struct Skeleton {
+    /// Joint indices are the ones used by `SkinnedVertexData::joints`.
+    pub joints: Vec<Joint>,
+    /// Applied on top of the root joints, e.g. for the transforms of their
+    /// parent nodes in a model.
+    pub root_transform: Mat4,
+}
+
+/// Local transforms for each joint of a skeleton.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pose {
+    pub joints: Vec<JointTransform>,
+}
+
+impl Pose {
+    /// Blends each joint towards the one of `other`, which has to be a pose of
+    /// the same skeleton.
+    pub fn blend(&self, other: &Pose, t: f32) -> Pose {
+        debug_assert_eq!(self.joints.len(), other.joints.len());
+        Pose {
+            joints: self
+                .joints
+                .iter()
+                .zip(&other.joints)
+                .map(|(a, b)| a.blend(b, t))
+                .collect(),
+        }
+    }
+}
+
+impl Skeleton {
+    pub fn rest_pose(&self) -> Pose {
+        Pose {
+            joints: self.joints.iter().map(|j| j.rest).collect(),
+        }
+    }
+
+    /// Model space transform of each joint. Joints may be listed in any
+    /// order.
+    pub fn joint_transforms(&self, pose: &Pose) -> Vec<Mat4> {
+        fn resolve(
+            skeleton: &Skeleton,
+            pose: &Pose,
+            index: usize,
+            transforms: &mut Vec<Option<Mat4>>,
+        ) -> Mat4 {
+            if let Some(transform) = transforms[index] {
+                return transform;
+            }
+            let parent = match skeleton.joints[index].parent {
+                Some(parent) => resolve(skeleton, pose, parent, transforms),
+                None => skeleton.root_transform,
+            };
+            let transform = parent * pose.joints[index].as_mat4();
+            transforms[index] = Some(transform);
+            transform
+        }
+
+        let mut transforms = vec![None; self.joints.len()];
+        (0..self.joints.len())
+            .map(|i| resolve(self, pose, i, &mut transforms))
+            .collect()
+    }
+
+    /// Matrices moving vertices from the bind pose into `pose`, in the order
+    /// `SkinnedScene::add_joint_matrices` expects them.
+    pub fn joint_matrices(&self, pose: &Pose) -> Vec<Mat4> {
+        self.joint_transforms(pose)
+            .iter()
+            .zip(&self.joints)
+            .map(|(transform, joint)| *transform * joint.inverse_bind_matrix)
+            .collect()
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    Step,
+    #[default]
+    Linear,
+    /// Hermite spline through the keyframes, using the tangents stored with
+    /// them.
+    CubicSpline,
+}
+
+#[derive(Debug, Clone)]
+pub enum Keyframes {
+    Translation(Vec<Vec3>),
+    Rotation(Vec<Quat>),
+    Scale(Vec<Vec3>),
+}
+
+/// Keyframes of one property of a joint.
+#[derive(Debug, Clone)]
+pub struct Channel {
+    /// Index into `Skeleton::joints`.
+    pub joint: usize,
+    pub interpolation: Interpolation,
+    /// Time of each keyframe in seconds, in ascending order.
+    pub times: Vec<f32>,
+    /// One value per keyframe, or for `Interpolation::CubicSpline` an
+    /// in-tangent, the value and an out-tangent.
+    pub keyframes: Keyframes,
+}
+
+impl Channel {
+    /// The keyframes around `time` and how far it is between them.
+    fn keyframes_at(&self, time: f32) -> (usize, usize, f32) {
+        let next = self.times.partition_point(|&t| t <= time);
+        if next == 0 {
+            return (0, 0, 0.0);
+        }
+        if next == self.times.len() {
+            return (next - 1, next - 1, 0.0);
+        }
+        let (start, end) = (self.times[next - 1], self.times[next]);
+        let t = match self.interpolation {
+            Interpolation::Step => 0.0,
+            Interpolation::Linear | Interpolation::CubicSpline => (time - start) / (end - start),
+        };
+        (next - 1, next, t)
+    }
+
+    /// The value `t` of the way from keyframe `a` to `b`, using `lerp` unless
+    /// the channel is a cubic spline.
+    fn interpolate<T>(
+        &self,
+        values: &[T],
+        (a, b, t): (usize, usize, f32),
+        lerp: impl Fn(T, T, f32) -> T,
+    ) -> T
+    where
+        T: Copy + Add<Output = T> + Mul<f32, Output = T>,
+    {
+        if self.interpolation != Interpolation::CubicSpline {
+            return lerp(values[a], values[b], t);
+        }
+        let [in_tangent, value, out_tangent] = [0, 1, 2].map(|i| move |k: usize| values[3 * k + i]);
+        if a == b {
+            return value(a);
+        }
+        let dt = self.times[b] - self.times[a];
+        let (t2, t3) = (t * t, t * t * t);
+        value(a) * (2.0 * t3 - 3.0 * t2 + 1.0)
+            + out_tangent(a) * ((t3 - 2.0 * t2 + t) * dt)
+            + value(b) * (3.0 * t2 - 2.0 * t3)
+            + in_tangent(b) * ((t3 - t2) * dt)
+    }
+
+    fn apply(&self, time: f32, transform: &mut JointTransform) {
+        if self.times.is_empty() {
+            return;
+        }
+        let keyframes = self.keyframes_at(time);
+        match &self.keyframes {
+            Keyframes::Translation(values) => {
+                transform.translation = self.interpolate(values, keyframes, Vec3::lerp);
+            }
+            Keyframes::Rotation(values) => {
+                transform.rotation = self.interpolate(values, keyframes, Quat::slerp).normalize();
+            }
+            Keyframes::Scale(values) => {
+                transform.scale = self.interpolate(values, keyframes, Vec3::lerp);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AnimationClip {
+    pub name: Option<String>,
+    /// Time of the last keyframe in seconds.
+    pub duration: f32,
+    pub channels: Vec<Channel>,
+}
+
+impl AnimationClip {
+    pub fn new(name: Option<String>, channels: Vec<Channel>) -> Self {
+        let duration = channels
+            .iter()
+            .filter_map(|c| c.times.last().copied())
+            .fold(0.0, f32::max);
+        Self {
+            name,
+            duration,
+            channels,
+        }
+    }
+
+    /// Sets the animated properties of `pose` to their value at `time`,
+    /// holding the first and last keyframes outside of the clip. Pass
+    /// `time.rem_euclid(clip.duration)` to loop the clip.
+    pub fn sample(&self, time: f32, pose: &mut Pose) {
+        for channel in &self.channels {
+            if let Some(transform) = pose.joints.get_mut(channel.joint) {
+                channel.apply(time, transform);
+            }
+        }
+    }
+
+    /// Samples the clip on top of the rest pose of `skeleton`.
+    pub fn pose(&self, skeleton: &Skeleton, time: f32) -> Pose {
+        let mut pose = skeleton.rest_pose();
+        self.sample(time, &mut pose);
+        pose
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use glam::vec3;
+
+    use super::*;
+
+    #[test]
+    fn test_sample_and_blend() {
+        let skeleton = Skeleton {
+            joints: vec![
+                Joint {
+                    name: None,
+                    parent: None,
+                    inverse_bind_matrix: Mat4::IDENTITY,
+                    rest: JointTransform::IDENTITY,
+                },
+                Joint {
+                    name: None,
+                    parent: Some(0),
+                    inverse_bind_matrix: Mat4::from_translation(vec3(0.0, -1.0, 0.0)),
+                    rest: JointTransform {
+                        translation: vec3(0.0, 1.0, 0.0),
+                        ..JointTransform::IDENTITY
+                    },
+                },
+            ],
+            root_transform: Mat4::IDENTITY,
+        };
+        // The bind pose is the rest pose.
+        for m in skeleton.joint_matrices(&skeleton.rest_pose()) {
+            assert!(m.abs_diff_eq(Mat4::IDENTITY, 1e-6));
+        }
+
+        let clip = AnimationClip::new(
+            None,
+            vec![
+                Channel {
+                    joint: 0,
+                    interpolation: Interpolation::Linear,
+                    times: vec![0.0, 2.0],
+                    keyframes: Keyframes::Rotation(vec![
+                        Quat::IDENTITY,
+                        Quat::from_rotation_z(FRAC_PI_2),
+                    ]),
+                },
+                Channel {
+                    joint: 1,
+                    interpolation: Interpolation::Step,
+                    times: vec![0.0, 1.0],
+                    keyframes: Keyframes::Scale(vec![Vec3::ONE, Vec3::splat(2.0)]),
+                },
+            ],
+        );
+        assert_eq!(clip.duration, 2.0);
+        let pose = clip.pose(&skeleton, 1.0);
+        let angle = pose.joints[0].rotation.angle_between(Quat::IDENTITY);
+        assert!((angle - FRAC_PI_2 / 2.0).abs() < 1e-5, "{}", angle);
+        assert_eq!(clip.pose(&skeleton, 0.5).joints[1].scale, Vec3::ONE);
+        assert_eq!(pose.joints[1].scale, Vec3::splat(2.0));
+        // Held after the end.
+        let end = clip.pose(&skeleton, 5.0);
+        assert!(end.joints[0]
+            .rotation
+            .abs_diff_eq(Quat::from_rotation_z(FRAC_PI_2), 1e-6));
+
+        // The child joint follows its rotated parent.
+        let transforms = skeleton.joint_transforms(&end);
+        let child = transforms[1].transform_point3(Vec3::ZERO);
+        assert!(child.abs_diff_eq(vec3(-1.0, 0.0, 0.0), 1e-6), "{}", child);
+
+        let blended = skeleton.rest_pose().blend(&end, 0.5);
+        assert!(blended.joints[0]
+            .rotation
+            .abs_diff_eq(pose.joints[0].rotation, 1e-6));
+        assert_eq!(blended.joints[1].scale, Vec3::splat(1.5));
+    }
+
+    #[test]
+    fn test_cubic_spline() {
+        let channel = |times, values: Vec<f32>| Channel {
+            joint: 0,
+            interpolation: Interpolation::CubicSpline,
+            times,
+            keyframes: Keyframes::Translation(values.into_iter().map(Vec3::splat).collect()),
+        };
+        let sample = |channel: &Channel, time| {
+            let mut transform = JointTransform::IDENTITY;
+            channel.apply(time, &mut transform);
+            transform.translation.x
+        };
+
+        // Flat tangents ease in and out.
+        let eased = channel(vec![0.0, 1.0], vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+        assert!((sample(&eased, 0.25) - 0.15625).abs() < 1e-6);
+        assert_eq!(sample(&eased, 1.0), 1.0);
+        assert_eq!(sample(&eased, 3.0), 1.0);
+
+        // Tangents are per second, scaled by the keyframe interval.
+        let bump = channel(vec![0.0, 2.0], vec![0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+        assert!((sample(&bump, 1.0) - 0.25).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,284 @@
+//! Bloom on the HDR scene color, blurring bright areas by downsampling them
+//! through a chain of smaller textures and upsampling back.
+
+use std::ops::Deref;
+
+use crate::geom::{BasicVertexData, Point};
+
+use super::{
+    forward::ForwardGeometryPass,
+    instance::InstanceRenderData,
+    shaders::bloom as shader,
+    state::{BindingType, ViewProjectionUniforms},
+    BasicInstanceData, Display, PipelineRef, RenderState, RenderTarget, TextureBuilder, TextureRef,
+    UniformBindGroup,
+};
+
+pub type BloomUniforms = shader::types::BloomUniforms;
+
+impl Default for BloomUniforms {
+    fn default() -> Self {
+        Self {
+            threshold: 1.0,
+            knee: 0.5,
+            intensity: 1.0,
+            filter_radius: 1.0,
+            level_count: 1,
+        }
+    }
+}
+
+pub struct BloomPass {
+    uniforms: UniformBindGroup<BloomUniforms>,
+    prefilter_pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+    downsample_pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+    upsample_pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+    composite_pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+    /// Thresholded scene color at half size, then halved at each level.
+    downsampled: Vec<TextureRef>,
+    /// Each downsampled level plus the blurred levels below it, except for
+    /// the smallest one.
+    upsampled: Vec<TextureRef>,
+    output_texture: TextureRef,
+}
+
+impl BloomPass {
+    pub const FORMAT: wgpu::TextureFormat = ForwardGeometryPass::COLOR_FORMAT;
+
+    const MAX_LEVELS: u32 = 6;
+
+    /// Sizes of the downsampled levels, halving `size` until its shorter side
+    /// is down to a single pixel, or `MAX_LEVELS` are reached.
+    fn level_sizes(size: Point<u32>) -> Vec<Point<u32>> {
+        let level_count = Self::MAX_LEVELS.min(size.x.min(size.y).max(2).ilog2());
+        (1..=level_count)
+            .map(|level| Point::new((size.x >> level).max(1), (size.y >> level).max(1)))
+            .collect()
+    }
+
+    pub fn new(state: &mut RenderState, display: &Display, size: Point<u32>) -> Self {
+        let level_sizes = Self::level_sizes(size);
+        let level_count = level_sizes.len() as u32;
+        let mut level = |label: &str, level_size: Point<u32>| {
+            state.load_texture(
+                display,
+                TextureBuilder::labeled(label)
+                    .with_format(Self::FORMAT)
+                    .with_filter_mode(wgpu::FilterMode::Linear)
+                    .build(display.device(), level_size),
+            )
+        };
+        let downsampled = level_sizes
+            .iter()
+            .map(|&size| level("bloom downsample", size))
+            .collect();
+        let upsampled = level_sizes[..level_sizes.len() - 1]
+            .iter()
+            .map(|&size| level("bloom upsample", size))
+            .collect();
+        let output_texture = state.load_texture(
+            display,
+            TextureBuilder::render_target()
+                .with_label("bloom")
+                .with_format(Self::FORMAT)
+                .with_filter_mode(wgpu::FilterMode::Linear)
+                .with_usage(
+                    wgpu::TextureUsages::RENDER_ATTACHMENT
+                        | wgpu::TextureUsages::TEXTURE_BINDING
+                        | wgpu::TextureUsages::COPY_SRC,
+                )
+                .build(display.device(), size),
+        );
+
+        let (uniforms, uniform_bgl) = state.create_uniform_bind_group(
+            display.device(),
+            BloomUniforms {
+                level_count,
+                ..Default::default()
+            },
+        );
+        let module = display
+            .device()
+            .create_shader_module(shader::DESCRIPTOR.clone());
+        let mut pipeline = |label, entry_point| {
+            state
+                .pipeline_builder()
+                .with_label(label)
+                .with_fragment_entry_point(entry_point)
+                .with_extra_bindings(vec![BindingType::Texture {
+                    format: Self::FORMAT,
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                }])
+                .with_extra_bind_group_layouts(vec![&uniform_bgl])
+                .with_color_target_states(vec![Some(wgpu::ColorTargetState {
+                    blend: None,
+                    format: Self::FORMAT,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })])
+                .with_depth_stencil_state(None)
+                .build(display.device(), &module)
+        };
+        Self {
+            prefilter_pipeline: pipeline("Bloom Prefilter Pipeline", "fs_prefilter"),
+            downsample_pipeline: pipeline("Bloom Downsample Pipeline", "fs_downsample"),
+            upsample_pipeline: pipeline("Bloom Upsample Pipeline", "fs_upsample"),
+            composite_pipeline: pipeline("Bloom Composite Pipeline", "fs_composite"),
+            uniforms,
+            downsampled,
+            upsampled,
+            output_texture,
+        }
+    }
+
+    /// Adds bloom to `hdr`, returning the result.
+    pub fn run(
+        &mut self,
+        state: &mut RenderState,
+        display: &Display,
+        hdr: TextureRef,
+    ) -> TextureRef {
+        let uniforms = *self.uniforms.uniform();
+        self.uniforms.update(display.queue(), uniforms);
+
+        // Passes not reading the second texture bind the scene color there,
+        // which is never a target.
+        let mut source = hdr;
+        for (i, &target) in self.downsampled.iter().enumerate() {
+            let pipeline = if i == 0 {
+                self.prefilter_pipeline
+            } else {
+                self.downsample_pipeline
+            };
+            self.fullscreen_pass(
+                state,
+                display,
+                "Bloom Downsample Pass",
+                target,
+                source,
+                hdr,
+                pipeline,
+            );
+            source = target;
+        }
+        for (&target, &level) in self.upsampled.iter().zip(&self.downsampled).rev() {
+            self.fullscreen_pass(
+                state,
+                display,
+                "Bloom Upsample Pass",
+                target,
+                source,
+                level,
+                self.upsample_pipeline,
+            );
+            source = target;
+        }
+        self.fullscreen_pass(
+            state,
+            display,
+            "Bloom Composite Pass",
+            self.output_texture,
+            hdr,
+            source,
+            self.composite_pipeline,
+        );
+        self.output_texture
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn fullscreen_pass(
+        &self,
+        state: &mut RenderState,
+        display: &Display,
+        label: &str,
+        target: TextureRef,
+        source: TextureRef,
+        bloom_texture: TextureRef,
+        pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+    ) {
+        let quad = state.quad_mesh();
+        let bloom_texture = state.get_texture(bloom_texture).bind_group().clone();
+        state
+            .render_pass(
+                display,
+                label,
+                &[RenderTarget::TextureRef(target)],
+                None,
+                &ViewProjectionUniforms::default(),
+                |r| {
+                    use shader::globals::*;
+                    r.set_bind_group(t_bloom::GROUP, bloom_texture.deref(), &[]);
+                    r.set_bind_group(bloom::GROUP, self.uniforms.bind_group().deref(), &[]);
+                    r.draw_instance(&InstanceRenderData {
+                        mesh: quad,
+                        instance: BasicInstanceData::default(),
+                        texture: Some(source),
+                        material: None,
+                        pipeline: Some(pipeline),
+                    });
+                },
+            )
+            .submit();
+    }
+
+    pub fn debug_ui(&mut self, ui: &mut egui::Ui) {
+        ui.add(egui::Slider::new(&mut self.uniforms.threshold, 0.0..=10.0).text("threshold"));
+        ui.add(egui::Slider::new(&mut self.uniforms.knee, 0.0..=1.0).text("knee"));
+        ui.add(egui::Slider::new(&mut self.uniforms.intensity, 0.0..=4.0).text("intensity"));
+        ui.add(
+            egui::Slider::new(&mut self.uniforms.filter_radius, 0.0..=4.0).text("filter radius"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_sizes() {
+        let sizes = BloomPass::level_sizes(Point::new(128, 96));
+        let expected = [(64, 48), (32, 24), (16, 12), (8, 6), (4, 3), (2, 1)];
+        assert_eq!(sizes, expected.map(|(x, y)| Point::new(x, y)));
+        // Limited to `MAX_LEVELS`.
+        assert_eq!(BloomPass::level_sizes(Point::new(1024, 1024)).len(), 6);
+        assert_eq!(BloomPass::level_sizes(Point::new(1, 1)), [Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn test_threshold() {
+        let size = Point::new(16, 16);
+        let (mut display, mut state) = RenderState::headless(size);
+        // 0.25 in half precision.
+        let pixels = vec![0x3400u16; 16 * 16 * 4];
+        let hdr = state.load_texture(
+            &display,
+            TextureBuilder::labeled("hdr")
+                .with_format(BloomPass::FORMAT)
+                .from_raw_bytes(
+                    display.device(),
+                    display.queue(),
+                    bytemuck::cast_slice(&pixels),
+                    size,
+                ),
+        );
+        let mut pass = BloomPass::new(&mut state, &display, size);
+        let mut run = |pass: &mut BloomPass| {
+            let output = pass.run(&mut state, &display, hdr);
+            state
+                .capture_texture(&mut display, output)
+                .unwrap()
+                .get_pixel(8, 8)
+                .0
+        };
+
+        // Below the threshold nothing blooms.
+        let unchanged = run(&mut pass);
+        assert!((63..=65).contains(&unchanged[0]), "{:?}", unchanged);
+
+        // Above it, 0.15 passes the soft threshold. Every level adds that and
+        // the composite averages them, giving 0.4.
+        pass.uniforms.threshold = 0.1;
+        let bloomed = run(&mut pass);
+        assert!((100..=104).contains(&bloomed[0]), "{:?}", bloomed);
+    }
+}
@@ -0,0 +1,439 @@
+use std::{
+    collections::VecDeque,
+    path::PathBuf,
+    sync::mpsc::{self, SyncSender},
+    thread::JoinHandle,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context};
+use image::RgbaImage;
+
+use crate::geom::Point;
+
+use super::{Display, ReadbackData, RenderState, Texture, TextureReadback, TextureRef};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureSource {
+    /// The frame presented to the display.
+    Display,
+    /// Any texture, e.g. the color target of an `OffscreenFramebuffer`.
+    Texture(TextureRef),
+}
+
+#[derive(Debug)]
+struct FrameSequence {
+    source: CaptureSource,
+    dir: PathBuf,
+    remaining: Duration,
+    next_frame: usize,
+}
+
+/// Saves screenshots and numbered frame sequences as PNG files. Requests are
+/// fulfilled after the next frame is rendered by copying it into one of a
+/// ring of staging buffers, which are read back on later frames once the GPU
+/// has caught up. Only when nothing is being recorded anymore does this wait
+/// for the remaining copies, so a screenshot or a whole sequence stalls a
+/// single frame. Encoding and writing the files happens on a separate thread.
+#[derive(Debug)]
+pub struct FrameCapture {
+    pub output_dir: PathBuf,
+    screenshot: Option<CaptureSource>,
+    sequence: Option<FrameSequence>,
+    screenshot_counter: usize,
+    /// Copies waiting for the GPU, oldest first.
+    readbacks: VecDeque<(TextureReadback, PathBuf)>,
+    /// Staging buffers of finished readbacks, to be reused.
+    free_buffers: Vec<wgpu::Buffer>,
+    writer: Option<ImageWriter>,
+}
+
+impl Default for FrameCapture {
+    fn default() -> Self {
+        Self {
+            output_dir: PathBuf::from("captures"),
+            screenshot: None,
+            sequence: None,
+            screenshot_counter: 0,
+            readbacks: VecDeque::new(),
+            free_buffers: Vec::new(),
+            writer: None,
+        }
+    }
+}
+
+impl FrameCapture {
+    /// Frames that can be waiting for the GPU at once, more are skipped.
+    const MAX_READBACKS: usize = 4;
+
+    pub fn screenshot(&mut self, source: CaptureSource) {
+        self.screenshot = Some(source);
+    }
+
+    /// Saves every frame for `duration` into a new directory under `output_dir`.
+    pub fn record_sequence(&mut self, source: CaptureSource, duration: Duration) {
+        self.sequence = Some(FrameSequence {
+            source,
+            dir: self
+                .output_dir
+                .join(format!("sequence_{}", unix_timestamp())),
+            remaining: duration,
+            next_frame: 0,
+        });
+    }
+
+    pub fn stop_sequence(&mut self) {
+        self.sequence = None;
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.sequence.is_some()
+    }
+
+    fn sources(&self) -> impl Iterator<Item = CaptureSource> + '_ {
+        self.screenshot
+            .iter()
+            .copied()
+            .chain(self.sequence.iter().map(|s| s.source))
+    }
+
+    pub fn before_render(&mut self, display: &mut Display) {
+        if self.sources().any(|s| s == CaptureSource::Display) {
+            display.request_frame_capture();
+        }
+    }
+
+    pub fn after_render(
+        &mut self,
+        display: &mut Display,
+        render_state: &RenderState,
+        frame_delta: Duration,
+    ) {
+        let display_frame = display.take_frame_captured();
+        let display: &Display = display;
+        let source_texture = |source| match source {
+            CaptureSource::Display => display_frame.then(|| display.captured_frame()).flatten(),
+            CaptureSource::Texture(texture) => {
+                let texture: &Texture = render_state.get_texture(texture);
+                Some(texture)
+            }
+        };
+
+        if let Some(source) = self.screenshot.take() {
+            match source_texture(source) {
+                Some(texture) => {
+                    let path = self.output_dir.join(format!(
+                        "screenshot_{}_{}.png",
+                        unix_timestamp(),
+                        self.screenshot_counter
+                    ));
+                    self.screenshot_counter += 1;
+                    self.start_readback(display, texture, path);
+                }
+                None => log::warn!("no frame was presented, screenshot skipped"),
+            }
+        }
+
+        let frame = self.sequence.as_ref().and_then(|sequence| {
+            let path = sequence
+                .dir
+                .join(format!("frame_{:05}.png", sequence.next_frame));
+            Some((source_texture(sequence.source)?, path))
+        });
+        let queued =
+            frame.is_some_and(|(texture, path)| self.start_readback(display, texture, path));
+        if let Some(sequence) = &mut self.sequence {
+            // Skipped frames don't use up a number, so there are no gaps.
+            if queued {
+                sequence.next_frame += 1;
+            }
+            sequence.remaining = sequence.remaining.saturating_sub(frame_delta);
+            if sequence.remaining.is_zero() {
+                log::info!(
+                    "recorded {} frames to {}",
+                    sequence.next_frame,
+                    sequence.dir.display()
+                );
+                self.sequence = None;
+            }
+        }
+
+        if self.sequence.is_none() && !self.readbacks.is_empty() {
+            display
+                .device()
+                .poll(wgpu::Maintain::wait())
+                .panic_on_timeout();
+        } else {
+            display.device().poll(wgpu::Maintain::Poll);
+        }
+        self.finish_readbacks();
+    }
+
+    /// Returns whether the readback was queued, it is skipped while
+    /// `MAX_READBACKS` are pending.
+    fn start_readback(&mut self, display: &Display, texture: &Texture, path: PathBuf) -> bool {
+        if self.readbacks.len() >= Self::MAX_READBACKS {
+            log::warn!("GPU is behind on captures, skipped {}", path.display());
+            return false;
+        }
+        let readback = display.start_texture_readback(texture, self.free_buffers.pop());
+        self.readbacks.push_back((readback, path));
+        true
+    }
+
+    fn finish_readbacks(&mut self) {
+        while self.readbacks.front().is_some_and(|(r, _)| r.is_ready()) {
+            let (readback, path) = self.readbacks.pop_front().unwrap();
+            match readback.finish() {
+                Ok((data, buffer)) => {
+                    self.free_buffers.push(buffer);
+                    self.writer
+                        .get_or_insert_with(ImageWriter::new)
+                        .save(data, path);
+                }
+                Err(e) => log::error!("capturing {} failed: {:#}", path.display(), e),
+            }
+        }
+    }
+}
+
+/// Converts and saves read back images on a single thread, in order. Saving
+/// blocks once `QUEUE_SIZE` images are waiting.
+#[derive(Debug)]
+struct ImageWriter {
+    sender: Option<SyncSender<(ReadbackData, PathBuf)>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ImageWriter {
+    const QUEUE_SIZE: usize = 8;
+
+    fn new() -> Self {
+        let (sender, receiver) = mpsc::sync_channel::<(ReadbackData, PathBuf)>(Self::QUEUE_SIZE);
+        let thread = std::thread::spawn(move || {
+            for (data, path) in receiver {
+                if let Err(e) = data.to_image().and_then(|image| save_image(&image, path)) {
+                    log::error!("{:#}", e);
+                }
+            }
+        });
+        Self {
+            sender: Some(sender),
+            thread: Some(thread),
+        }
+    }
+
+    fn save(&self, data: ReadbackData, path: PathBuf) {
+        if let Some(sender) = &self.sender {
+            if sender.send((data, path)).is_err() {
+                log::error!("image writer thread has stopped");
+            }
+        }
+    }
+}
+
+impl Drop for ImageWriter {
+    /// Finishes writing the queued images.
+    fn drop(&mut self) {
+        self.sender.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl ReadbackData {
+    /// Converts the texels to 8-bit RGBA like `Display::capture_texture`.
+    pub fn to_image(&self) -> anyhow::Result<RgbaImage> {
+        texels_to_image(self.format, self.size, self.rows())
+    }
+}
+
+impl Display {
+    /// Reads `texture` back from the GPU into an 8-bit RGBA image. Single
+    /// channel and depth formats are expanded to greyscale, other formats
+    /// without a conversion return an error.
+    pub fn capture_texture(&mut self, texture: &Texture) -> anyhow::Result<RgbaImage> {
+        self.capture_texture_layer(texture, 0)
+    }
+
+    pub fn capture_texture_layer(
+        &mut self,
+        texture: &Texture,
+        layer: u32,
+    ) -> anyhow::Result<RgbaImage> {
+        let format = texture.format();
+        let size = texture.size_pixels();
+        let data = self.read_texture_layer_data(texture, layer);
+        texels_to_image(format, size, data.rows())
+    }
+}
+
+fn texels_to_image<'a>(
+    format: wgpu::TextureFormat,
+    size: Point<u32>,
+    rows: impl Iterator<Item = &'a [u8]>,
+) -> anyhow::Result<RgbaImage> {
+    let block_size = format.block_copy_size(None).unwrap() as usize;
+    let mut pixels = Vec::with_capacity((size.x * size.y * 4) as usize);
+    for row in rows {
+        for texel in row[..size.x as usize * block_size].chunks_exact(block_size) {
+            pixels.extend_from_slice(&texel_to_rgba(format, texel)?);
+        }
+    }
+    Ok(RgbaImage::from_raw(size.x, size.y, pixels).unwrap())
+}
+
+impl RenderState {
+    pub fn capture_texture(
+        &self,
+        display: &mut Display,
+        texture: TextureRef,
+    ) -> anyhow::Result<RgbaImage> {
+        display.capture_texture(self.get_texture(texture))
+    }
+}
+
+pub fn save_image(image: &RgbaImage, path: impl Into<PathBuf>) -> anyhow::Result<()> {
+    let path = path.into();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    image
+        .save(&path)
+        .with_context(|| format!("failed to save {}", path.display()))
+}
+
+fn texel_to_rgba(format: wgpu::TextureFormat, texel: &[u8]) -> anyhow::Result<[u8; 4]> {
+    use wgpu::TextureFormat::*;
+    let unorm = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let grey = |v: u8| [v, v, v, 255];
+    Ok(match format {
+        Rgba8Unorm | Rgba8UnormSrgb => [texel[0], texel[1], texel[2], texel[3]],
+        Bgra8Unorm | Bgra8UnormSrgb => [texel[2], texel[1], texel[0], texel[3]],
+        Rgb10a2Unorm => {
+            let bits = u32::from_le_bytes(texel.try_into().unwrap());
+            let channel = |i: u32| ((bits >> (10 * i)) & 0x3ff) as f32 / 1023.0;
+            [
+                unorm(channel(0)),
+                unorm(channel(1)),
+                unorm(channel(2)),
+                unorm((bits >> 30) as f32 / 3.0),
+            ]
+        }
+        R8Unorm => grey(texel[0]),
+        R16Float => grey(unorm(texel_f16(texel, 0))),
+        R32Float | Depth32Float => grey(unorm(f32::from_le_bytes(texel.try_into().unwrap()))),
+        Rg16Float => [
+            unorm(texel_f16(texel, 0)),
+            unorm(texel_f16(texel, 1)),
+            0,
+            255,
+        ],
+        Rgba16Float => std::array::from_fn(|i| unorm(texel_f16(texel, i))),
+        Rgba32Float => std::array::from_fn(|i| {
+            unorm(f32::from_le_bytes(
+                texel[4 * i..4 * i + 4].try_into().unwrap(),
+            ))
+        }),
+        _ => bail!("capturing textures of format {:?} is not supported", format),
+    })
+}
+
+fn texel_f16(texel: &[u8], i: usize) -> f32 {
+    half::f16::from_le_bytes([texel[2 * i], texel[2 * i + 1]]).to_f32()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::TextureBuilder;
+
+    #[test]
+    fn test_texel_to_rgba() {
+        assert_eq!(
+            texel_to_rgba(wgpu::TextureFormat::Bgra8UnormSrgb, &[1, 2, 3, 4]).unwrap(),
+            [3, 2, 1, 4]
+        );
+        assert_eq!(
+            texel_to_rgba(wgpu::TextureFormat::Depth32Float, &1.0f32.to_le_bytes()).unwrap(),
+            [255, 255, 255, 255]
+        );
+        assert!(texel_to_rgba(wgpu::TextureFormat::Rg8Uint, &[1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_record_sequence() {
+        let size = Point::new(4, 4);
+        let (mut display, mut state) = RenderState::headless(size);
+        let texture = state.load_texture(
+            &display,
+            TextureBuilder::labeled("frame")
+                .with_format(wgpu::TextureFormat::Rgba8Unorm)
+                .with_usage(
+                    wgpu::TextureUsages::TEXTURE_BINDING
+                        | wgpu::TextureUsages::COPY_DST
+                        | wgpu::TextureUsages::COPY_SRC,
+                )
+                .from_raw_bytes(display.device(), display.queue(), &[10; 4 * 4 * 4], size),
+        );
+
+        let output_dir = std::env::temp_dir().join(format!("capture_test_{}", std::process::id()));
+        let mut capture = FrameCapture {
+            output_dir: output_dir.clone(),
+            ..Default::default()
+        };
+        capture.record_sequence(CaptureSource::Texture(texture), Duration::from_millis(30));
+
+        // Frames skipped while the GPU is behind don't use up a number.
+        for i in 0..FrameCapture::MAX_READBACKS {
+            let readback = display.start_texture_readback(state.get_texture(texture), None);
+            let path = output_dir.join(format!("pending/{}.png", i));
+            capture.readbacks.push_back((readback, path));
+        }
+        capture.before_render(&mut display);
+        capture.after_render(&mut display, &state, Duration::ZERO);
+        assert_eq!(capture.sequence.as_ref().unwrap().next_frame, 0);
+        display
+            .device()
+            .poll(wgpu::Maintain::wait())
+            .panic_on_timeout();
+        capture.finish_readbacks();
+
+        for _ in 0..3 {
+            capture.before_render(&mut display);
+            capture.after_render(&mut display, &state, Duration::from_millis(10));
+        }
+        assert!(!capture.is_recording());
+        assert!(capture.readbacks.is_empty());
+        // Waits for the writer thread.
+        drop(capture);
+
+        let sequence_dir = std::fs::read_dir(&output_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| !path.ends_with("pending"))
+            .unwrap();
+        let mut frames: Vec<_> = std::fs::read_dir(&sequence_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        frames.sort();
+        assert_eq!(
+            frames,
+            ["frame_00000.png", "frame_00001.png", "frame_00002.png"]
+        );
+        let frame = image::open(sequence_dir.join("frame_00002.png"))
+            .unwrap()
+            .into_rgba8();
+        assert_eq!(frame.get_pixel(3, 3).0, [10; 4]);
+        std::fs::remove_dir_all(output_dir).unwrap();
+    }
+}
@@ -0,0 +1,141 @@
+//! Cube face conventions and conversion of equirectangular panoramas into
+//! cubemap faces.
+
+use std::{f32::consts::PI, fs::File, io::BufReader, path::Path};
+
+use anyhow::Context;
+use glam::{vec3, Vec3};
+use half::f16;
+use image::codecs::hdr::HdrDecoder;
+
+use crate::geom::Point;
+
+/// Direction through texture coordinates `u`, `v` (0..1, `v` pointing down)
+/// of a cube face, with faces in wgpu's layer order: +X, -X, +Y, -Y, +Z, -Z.
+pub fn face_direction(face: usize, u: f32, v: f32) -> Vec3 {
+    let (u, v) = (2.0 * u - 1.0, 2.0 * v - 1.0);
+    match face {
+        0 => vec3(1.0, -v, -u),
+        1 => vec3(-1.0, -v, u),
+        2 => vec3(u, 1.0, v),
+        3 => vec3(u, -1.0, -v),
+        4 => vec3(u, -v, 1.0),
+        5 => vec3(-u, -v, -1.0),
+        _ => panic!("invalid cube face {}", face),
+    }
+    .normalize()
+}
+
+/// A panorama in equirectangular projection, with its center looking down -Z
+/// and the top row straight up.
+pub struct EquirectangularImage {
+    pub size: Point<u32>,
+    /// Linear RGB, row by row from the top.
+    pub pixels: Vec<Vec3>,
+}
+
+impl EquirectangularImage {
+    /// Loads a Radiance HDR (.hdr) file.
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let decode = || -> anyhow::Result<Self> {
+            let decoder = HdrDecoder::new(BufReader::new(File::open(path)?))?;
+            let meta = decoder.metadata();
+            let pixels = decoder
+                .read_image_hdr()?
+                .into_iter()
+                .map(|p| Vec3::from(p.0))
+                .collect();
+            Ok(Self {
+                size: Point::new(meta.width, meta.height),
+                pixels,
+            })
+        };
+        decode().with_context(|| format!("failed to load HDR image {}", path.display()))
+    }
+
+    fn pixel(&self, x: i64, y: i64) -> Vec3 {
+        let x = x.rem_euclid(self.size.x as i64);
+        let y = y.clamp(0, self.size.y as i64 - 1);
+        self.pixels[(y * self.size.x as i64 + x) as usize]
+    }
+
+    /// Bilinearly filtered color in `direction`, wrapping around horizontally.
+    pub fn sample(&self, direction: Vec3) -> Vec3 {
+        let d = direction.normalize();
+        let u = 0.5 + d.x.atan2(-d.z) / (2.0 * PI);
+        let v = d.y.clamp(-1.0, 1.0).acos() / PI;
+        let x = u * self.size.x as f32 - 0.5;
+        let y = v * self.size.y as f32 - 0.5;
+        let (x0, y0) = (x.floor(), y.floor());
+        let (tx, ty) = (x - x0, y - y0);
+        let (x0, y0) = (x0 as i64, y0 as i64);
+        let top = self.pixel(x0, y0).lerp(self.pixel(x0 + 1, y0), tx);
+        let bottom = self.pixel(x0, y0 + 1).lerp(self.pixel(x0 + 1, y0 + 1), tx);
+        top.lerp(bottom, ty)
+    }
+
+    /// Resamples the panorama into six square faces of `face_size` pixels,
+    /// as `Rgba16Float` texels in the order of `face_direction`.
+    pub fn to_cube_faces(&self, face_size: u32) -> Vec<[u16; 4]> {
+        let mut texels = Vec::with_capacity(6 * (face_size * face_size) as usize);
+        for face in 0..6 {
+            for y in 0..face_size {
+                for x in 0..face_size {
+                    let u = (x as f32 + 0.5) / face_size as f32;
+                    let v = (y as f32 + 0.5) / face_size as f32;
+                    let c = self.sample(face_direction(face, u, v));
+                    texels.push([c.x, c.y, c.z, 1.0].map(|c| f16::from_f32(c).to_bits()));
+                }
+            }
+        }
+        texels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equirectangular_to_cube_faces() {
+        for (face, expected) in [Vec3::X, -Vec3::X, Vec3::Y, -Vec3::Y, Vec3::Z, -Vec3::Z]
+            .into_iter()
+            .enumerate()
+        {
+            assert!(face_direction(face, 0.5, 0.5).abs_diff_eq(expected, 1e-6));
+        }
+        // The top of side faces is up.
+        assert!(face_direction(4, 0.5, 0.0).y > 0.0);
+
+        // Bright ahead (-Z) and dark behind, blue above.
+        let size = Point::new(8, 4);
+        let pixels = (0..size.y)
+            .flat_map(|y| {
+                (0..size.x).map(move |x| match (x, y) {
+                    (_, 0) => Vec3::Z,
+                    (3 | 4, _) => Vec3::splat(4.0),
+                    _ => Vec3::ZERO,
+                })
+            })
+            .collect();
+        let image = EquirectangularImage { size, pixels };
+        assert!(image.sample(-Vec3::Z).abs_diff_eq(Vec3::splat(4.0), 1e-6));
+        assert!(image.sample(Vec3::Z).abs_diff_eq(Vec3::ZERO, 1e-6));
+        assert!(image.sample(Vec3::Y).abs_diff_eq(Vec3::Z, 1e-6));
+
+        let texels = image.to_cube_faces(2);
+        assert_eq!(texels.len(), 6 * 4);
+        let face_texels = |face: usize| texels[face * 4..][..4].to_vec();
+        assert!(face_texels(5).iter().all(|t| t[0] > 0x3c00));
+        assert!(face_texels(4).iter().all(|t| t[0] == 0));
+        assert!(face_texels(2).iter().all(|t| t[2] > 0x3800));
+
+        let sky =
+            EquirectangularImage::open(concat!(env!("CARGO_MANIFEST_DIR"), "/res/images/sky.hdr"))
+                .unwrap();
+        assert_eq!(sky.size, Point::new(128, 64));
+        let (up, down) = (sky.sample(Vec3::Y), sky.sample(-Vec3::Y));
+        assert!(up.z > up.x && up.z > down.z, "{} {}", up, down);
+    }
+}
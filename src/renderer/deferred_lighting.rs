@@ -72,6 +72,7 @@ impl LightingPass {
                         mesh: quad,
                         instance: Default::default(),
                         texture: Some(occlusion_map),
+                        material: None,
                         pipeline: Some(self.pipeline),
                     });
                 },
@@ -1,4 +1,8 @@
-use std::{ops::Deref, sync::Arc};
+use std::{
+    cell::Cell,
+    ops::Deref,
+    sync::{Arc, OnceLock},
+};
 
 use super::texture::{Texture, TextureBuilder};
 use crate::geom::Point;
@@ -35,6 +39,57 @@ impl ScalingMode {
     }
 }
 
+/// Bytes per row and total size of a buffer holding a layer of `texture`,
+/// with rows padded as copies require.
+fn readback_layout(texture: &Texture) -> (u32, u64) {
+    let dim = texture.size_pixels();
+    let block_size = texture.format().block_copy_size(None).unwrap();
+    let bytes_per_row = (dim.x * block_size).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+    (bytes_per_row, (dim.y * bytes_per_row) as u64)
+}
+
+fn create_staging_buffer(device: &wgpu::Device, size: u64) -> wgpu::Buffer {
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("display staging buffer"),
+        size,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    })
+}
+
+fn copy_texture_layer_to_buffer(
+    enc: &mut wgpu::CommandEncoder,
+    texture: &Texture,
+    layer: u32,
+    buffer: &wgpu::Buffer,
+    bytes_per_row: u32,
+) {
+    let dim = texture.size_pixels();
+    enc.copy_texture_to_buffer(
+        wgpu::ImageCopyTexture {
+            origin: wgpu::Origin3d {
+                x: 0,
+                y: 0,
+                z: layer,
+            },
+            ..texture.texture.as_image_copy()
+        },
+        wgpu::ImageCopyBuffer {
+            buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: Some(dim.y),
+            },
+        },
+        wgpu::Extent3d {
+            width: dim.x,
+            height: dim.y,
+            depth_or_array_layers: 1,
+        },
+    );
+}
+
 struct BufferUnmapper<'a>(&'a wgpu::Buffer);
 
 impl Drop for BufferUnmapper<'_> {
@@ -45,9 +100,81 @@ impl Drop for BufferUnmapper<'_> {
 
 pub struct MappedBufferView<'a> {
     buffer_view: wgpu::BufferView<'a>,
+    bytes_per_row: u32,
     _buffer: BufferUnmapper<'a>,
 }
 
+impl MappedBufferView<'_> {
+    /// Stride between rows of the mapped data, which is padded up to a
+    /// multiple of `wgpu::COPY_BYTES_PER_ROW_ALIGNMENT`.
+    pub fn bytes_per_row(&self) -> u32 {
+        self.bytes_per_row
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[u8]> {
+        self.buffer_view.chunks(self.bytes_per_row as usize)
+    }
+}
+
+/// A texture copied into a staging buffer that is mapped asynchronously,
+/// see `Display::start_texture_readback`.
+#[derive(Debug)]
+pub struct TextureReadback {
+    buffer: wgpu::Buffer,
+    format: wgpu::TextureFormat,
+    size: Point<u32>,
+    bytes_per_row: u32,
+    total_size: u64,
+    mapped: Arc<OnceLock<Result<(), wgpu::BufferAsyncError>>>,
+}
+
+impl TextureReadback {
+    /// Whether the copy has finished and `finish` won't fail for lack of it.
+    /// This only changes when the device is polled.
+    pub fn is_ready(&self) -> bool {
+        self.mapped.get().is_some()
+    }
+
+    /// Copies the texels out of the mapped buffer, returning it for reuse.
+    pub fn finish(self) -> anyhow::Result<(ReadbackData, wgpu::Buffer)> {
+        match self.mapped.get() {
+            None => anyhow::bail!("texture readback isn't ready yet"),
+            Some(Err(e)) => anyhow::bail!("failed to map staging buffer: {}", e),
+            Some(Ok(())) => {}
+        }
+        let data = self
+            .buffer
+            .slice(..self.total_size)
+            .get_mapped_range()
+            .to_vec();
+        self.buffer.unmap();
+        Ok((
+            ReadbackData {
+                format: self.format,
+                size: self.size,
+                bytes_per_row: self.bytes_per_row,
+                data,
+            },
+            self.buffer,
+        ))
+    }
+}
+
+/// Texels of a texture read back with `TextureReadback`, with padded rows.
+#[derive(Debug)]
+pub struct ReadbackData {
+    pub format: wgpu::TextureFormat,
+    pub size: Point<u32>,
+    pub bytes_per_row: u32,
+    pub data: Vec<u8>,
+}
+
+impl ReadbackData {
+    pub fn rows(&self) -> impl Iterator<Item = &[u8]> {
+        self.data.chunks(self.bytes_per_row as usize)
+    }
+}
+
 impl<'a> Deref for MappedBufferView<'a> {
     type Target = wgpu::BufferView<'a>;
 
@@ -56,59 +183,78 @@ impl<'a> Deref for MappedBufferView<'a> {
     }
 }
 
+enum DisplayOutput {
+    Surface {
+        surface: wgpu::Surface<'static>,
+        // The window must be declared after the surface so
+        // it gets dropped after it as the surface contains
+        // unsafe references to the window's resources.
+        window: Arc<Window>,
+    },
+    Offscreen {
+        color_texture: Texture,
+    },
+}
+
 pub struct Display {
     config: wgpu::SurfaceConfiguration,
-    surface: wgpu::Surface<'static>,
+    output: DisplayOutput,
     device: wgpu::Device,
     queue: wgpu::Queue,
     depth_texture: Texture,
-    // The window must be declared after the surface so
-    // it gets dropped after it as the surface contains
-    // unsafe references to the window's resources.
-    window: Arc<Window>,
+    downlevel_flags: wgpu::DownlevelFlags,
 
     staging_buffer: Option<wgpu::Buffer>,
+    // Presented frames are copied here while a capture is pending.
+    capture_target: Option<Texture>,
+    capture_pending: bool,
+    frame_captured: Cell<bool>,
 }
 
 impl Display {
-    pub async fn from_window(window: Window) -> Self {
-        let size = window.inner_size();
-        let window = Arc::new(window);
+    const REQUIRED_FEATURES: wgpu::Features = wgpu::Features::POLYGON_MODE_LINE
+        .union(wgpu::Features::CLEAR_TEXTURE)
+        .union(wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER);
+
+    /// Features which are used if available, but aren't required.
+    const OPTIONAL_FEATURES: wgpu::Features = wgpu::Features::TIMESTAMP_QUERY;
+
+    /// Bind groups of the skinned forward pipeline, the most of any. Fewer are
+    /// requested if the adapter doesn't support that many.
+    const MAX_BIND_GROUPS: u32 = 8;
 
-        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+    const OFFSCREEN_USAGE: wgpu::TextureUsages = wgpu::TextureUsages::RENDER_ATTACHMENT
+        .union(wgpu::TextureUsages::TEXTURE_BINDING)
+        .union(wgpu::TextureUsages::COPY_SRC);
+
+    fn create_instance() -> wgpu::Instance {
+        wgpu::Instance::new(wgpu::InstanceDescriptor {
             backends: wgpu::Backends::all(),
             flags: wgpu::InstanceFlags::from_build_config(),
             dx12_shader_compiler: Default::default(),
             gles_minor_version: wgpu::Gles3MinorVersion::Automatic,
-        });
-
-        // The surface needs to live as long as the window that created it.
-        // State owns the window so this should be safe.
-        let surface = instance.create_surface(window.clone()).unwrap();
-
-        let adapter = instance
-            .request_adapter(&wgpu::RequestAdapterOptions {
-                power_preference: wgpu::PowerPreference::HighPerformance,
-                compatible_surface: Some(&surface),
-                force_fallback_adapter: false,
-            })
-            .await
-            .unwrap();
+        })
+    }
 
-        let (device, queue) = adapter
+    async fn request_device(
+        adapter: &wgpu::Adapter,
+        required_features: wgpu::Features,
+    ) -> (wgpu::Device, wgpu::Queue) {
+        adapter
             .request_device(
                 &wgpu::DeviceDescriptor {
                     label: None,
-                    required_features: wgpu::Features::POLYGON_MODE_LINE
-                        | wgpu::Features::CLEAR_TEXTURE
-                        | wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER,
+                    required_features,
                     // WebGL doesn't support all of wgpu's features, so if
                     // we're building for the web we'll have to disable some.
                     required_limits: if cfg!(target_arch = "wasm32") {
                         wgpu::Limits::downlevel_webgl2_defaults()
                     } else {
                         wgpu::Limits {
-                            max_bind_groups: 6,
+                            max_bind_groups: adapter
+                                .limits()
+                                .max_bind_groups
+                                .min(Self::MAX_BIND_GROUPS),
                             ..wgpu::Limits::default()
                         }
                     },
@@ -117,8 +263,34 @@ impl Display {
                 None,
             )
             .await
+            .unwrap()
+    }
+
+    pub async fn from_window(window: Window) -> Self {
+        let size = window.inner_size();
+        let window = Arc::new(window);
+
+        let instance = Self::create_instance();
+
+        // The surface needs to live as long as the window that created it.
+        // State owns the window so this should be safe.
+        let surface = instance.create_surface(window.clone()).unwrap();
+
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: Some(&surface),
+                force_fallback_adapter: false,
+            })
+            .await
             .unwrap();
 
+        let (device, queue) = Self::request_device(
+            &adapter,
+            Self::REQUIRED_FEATURES | (Self::OPTIONAL_FEATURES & adapter.features()),
+        )
+        .await;
+
         let surface_caps = surface.get_capabilities(&adapter);
         let surface_format = surface_caps
             .formats
@@ -127,7 +299,9 @@ impl Display {
             .find(|f| f.is_srgb())
             .unwrap_or(surface_caps.formats[0]);
         let config = wgpu::SurfaceConfiguration {
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            // Copying out of the surface is needed for frame captures, but isn't supported everywhere.
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | (surface_caps.usages & wgpu::TextureUsages::COPY_SRC),
             format: surface_format,
             width: size.width,
             height: size.height,
@@ -142,15 +316,93 @@ impl Display {
             .build(&device, Point::new(size.width, size.height));
         Self {
             config,
-            surface,
+            output: DisplayOutput::Surface { surface, window },
             device,
             queue,
             depth_texture,
-            window,
+            downlevel_flags: adapter.get_downlevel_capabilities().flags,
             staging_buffer: None,
+            capture_target: None,
+            capture_pending: false,
+            frame_captured: Cell::new(false),
         }
     }
 
+    /// Creates a display without a window or surface, which renders into an
+    /// offscreen color target instead, available to `RenderState` as
+    /// `display_framebuffer`. Any available adapter is accepted, falling back
+    /// to a software adapter if there is no hardware one.
+    pub async fn headless(size: Point<u32>) -> Self {
+        let instance = Self::create_instance();
+
+        let mut adapter = None;
+        for force_fallback_adapter in [false, true] {
+            adapter = instance
+                .request_adapter(&wgpu::RequestAdapterOptions {
+                    power_preference: wgpu::PowerPreference::HighPerformance,
+                    compatible_surface: None,
+                    force_fallback_adapter,
+                })
+                .await;
+            if adapter.is_some() {
+                break;
+            }
+        }
+        let adapter = adapter.expect("no suitable adapter found for headless display");
+        log::info!("headless display using adapter {:?}", adapter.get_info());
+
+        // Software adapters don't always support everything we'd like, so
+        // make do with what's there rather than failing outright.
+        let missing_features = Self::REQUIRED_FEATURES - adapter.features();
+        if !missing_features.is_empty() {
+            log::warn!("headless adapter is missing features {missing_features:?}");
+        }
+        let (device, queue) = Self::request_device(
+            &adapter,
+            (Self::REQUIRED_FEATURES | Self::OPTIONAL_FEATURES) & adapter.features(),
+        )
+        .await;
+
+        let format = TextureBuilder::DEFAULT_RENDER_FORMAT;
+        let config = wgpu::SurfaceConfiguration {
+            usage: Self::OFFSCREEN_USAGE,
+            format,
+            width: size.x,
+            height: size.y,
+            present_mode: wgpu::PresentMode::AutoNoVsync,
+            alpha_mode: wgpu::CompositeAlphaMode::Auto,
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+        let color_texture = Self::create_offscreen_texture(&device, &config);
+        let depth_texture = TextureBuilder::depth()
+            .with_label("display_depth_texture")
+            .build(&device, size);
+        Self {
+            config,
+            output: DisplayOutput::Offscreen { color_texture },
+            device,
+            queue,
+            depth_texture,
+            downlevel_flags: adapter.get_downlevel_capabilities().flags,
+            staging_buffer: None,
+            capture_target: None,
+            capture_pending: false,
+            frame_captured: Cell::new(false),
+        }
+    }
+
+    fn create_offscreen_texture(
+        device: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+    ) -> Texture {
+        TextureBuilder::render_target()
+            .with_label("display_offscreen_texture")
+            .with_format(config.format)
+            .with_usage(config.usage)
+            .build(device, Point::new(config.width, config.height))
+    }
+
     pub fn reconfigure(&mut self) {
         self.resize(PhysicalSize {
             width: self.config.width,
@@ -162,15 +414,42 @@ impl Display {
         if new_size.width > 0 && new_size.height > 0 {
             self.config.width = new_size.width;
             self.config.height = new_size.height;
-            self.surface.configure(&self.device, &self.config);
+            match &mut self.output {
+                DisplayOutput::Surface { surface, .. } => {
+                    surface.configure(&self.device, &self.config)
+                }
+                DisplayOutput::Offscreen { color_texture } => {
+                    *color_texture = Self::create_offscreen_texture(&self.device, &self.config)
+                }
+            }
             self.depth_texture = TextureBuilder::depth()
                 .with_label("display_depth_texture")
                 .build(self.device(), Point::new(new_size.width, new_size.height));
         }
     }
 
-    pub fn window(&self) -> &Window {
-        &self.window
+    /// The window this display presents to, or `None` for a headless display.
+    pub fn window(&self) -> Option<&Window> {
+        match &self.output {
+            DisplayOutput::Surface { window, .. } => Some(window),
+            DisplayOutput::Offscreen { .. } => None,
+        }
+    }
+
+    pub fn is_headless(&self) -> bool {
+        matches!(self.output, DisplayOutput::Offscreen { .. })
+    }
+
+    pub fn scale_factor(&self) -> f64 {
+        self.window().map_or(1.0, Window::scale_factor)
+    }
+
+    /// The color target that a headless display renders into.
+    pub fn offscreen_texture(&self) -> Option<&Texture> {
+        match &self.output {
+            DisplayOutput::Surface { .. } => None,
+            DisplayOutput::Offscreen { color_texture } => Some(color_texture),
+        }
     }
 
     pub fn device(&self) -> &wgpu::Device {
@@ -181,12 +460,14 @@ impl Display {
         &self.queue
     }
 
-    pub fn format(&self) -> wgpu::TextureFormat {
-        self.config.format
+    /// Capabilities of the adapter beyond what WebGPU guarantees, these are
+    /// usually only missing on GL or software adapters.
+    pub fn downlevel_flags(&self) -> wgpu::DownlevelFlags {
+        self.downlevel_flags
     }
 
-    fn output_texture(&self) -> Result<wgpu::SurfaceTexture, wgpu::SurfaceError> {
-        self.surface.get_current_texture()
+    pub fn format(&self) -> wgpu::TextureFormat {
+        self.config.format
     }
 
     pub fn size_pixels(&self) -> Point<u32> {
@@ -201,10 +482,21 @@ impl Display {
     }
 
     pub fn view(&self) -> Result<DisplayView, wgpu::SurfaceError> {
-        let output_texture = self.output_texture()?;
-        let view = output_texture
-            .texture
-            .create_view(&wgpu::TextureViewDescriptor::default());
+        let (output_texture, view) = match &self.output {
+            DisplayOutput::Surface { surface, .. } => {
+                let output_texture = surface.get_current_texture()?;
+                let view = output_texture
+                    .texture
+                    .create_view(&wgpu::TextureViewDescriptor::default());
+                (Some(output_texture), view)
+            }
+            DisplayOutput::Offscreen { color_texture } => (
+                None,
+                color_texture
+                    .texture
+                    .create_view(&wgpu::TextureViewDescriptor::default()),
+            ),
+        };
         Ok(DisplayView {
             display: self,
             output_texture,
@@ -224,47 +516,130 @@ impl Display {
     }
 
     pub fn read_texture_data<'a>(&'a mut self, texture: &Texture) -> MappedBufferView<'a> {
-        let dim = texture.size_pixels();
-        let block_size = texture.format().block_copy_size(None).unwrap();
-        let bytes_per_row = dim.x * block_size;
-        let total_size = (dim.y * bytes_per_row) as u64;
+        self.read_texture_layer_data(texture, 0)
+    }
 
+    /// Copies a single array layer of `texture` back to the CPU, blocking
+    /// until the copy is done.
+    pub fn read_texture_layer_data<'a>(
+        &'a mut self,
+        texture: &Texture,
+        layer: u32,
+    ) -> MappedBufferView<'a> {
+        let (bytes_per_row, total_size) = readback_layout(texture);
         if let Some(buf) = &self.staging_buffer {
             if buf.size() < total_size {
                 self.staging_buffer.take();
             }
         }
-        let buffer = self.staging_buffer.get_or_insert_with(|| {
-            self.device.create_buffer(&wgpu::BufferDescriptor {
-                label: Some("display staging buffer"),
-                size: total_size,
-                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
-                mapped_at_creation: false,
-            })
-        });
+        let buffer = self
+            .staging_buffer
+            .get_or_insert_with(|| create_staging_buffer(&self.device, total_size));
         let mut enc = self.device.create_command_encoder(&Default::default());
-        enc.copy_texture_to_buffer(
-            texture.texture.as_image_copy(),
-            wgpu::ImageCopyBuffer {
-                buffer,
-                layout: wgpu::ImageDataLayout {
-                    offset: 0,
-                    bytes_per_row: Some(bytes_per_row),
-                    rows_per_image: Some(dim.y),
-                },
-            },
-            texture.texture.size(),
-        );
+        copy_texture_layer_to_buffer(&mut enc, texture, layer, buffer, bytes_per_row);
         self.queue.submit([enc.finish()]);
-        let slice = buffer.slice(..);
+        let slice = buffer.slice(..total_size);
         slice.map_async(wgpu::MapMode::Read, |res| res.expect("buffer map failed"));
         self.device.poll(wgpu::Maintain::wait()).panic_on_timeout();
         MappedBufferView {
             _buffer: BufferUnmapper(buffer),
+            bytes_per_row,
             buffer_view: slice.get_mapped_range(),
         }
     }
 
+    /// Starts copying `texture` back to the CPU without waiting for it,
+    /// reusing `buffer` if it's large enough. The readback is ready once the
+    /// device has been polled after the GPU finishes the copy.
+    pub fn start_texture_readback(
+        &self,
+        texture: &Texture,
+        buffer: Option<wgpu::Buffer>,
+    ) -> TextureReadback {
+        let (bytes_per_row, total_size) = readback_layout(texture);
+        let buffer = buffer
+            .filter(|b| b.size() >= total_size)
+            .unwrap_or_else(|| create_staging_buffer(&self.device, total_size));
+        let mut enc = self.device.create_command_encoder(&Default::default());
+        copy_texture_layer_to_buffer(&mut enc, texture, 0, &buffer, bytes_per_row);
+        self.queue.submit([enc.finish()]);
+        let mapped = Arc::new(OnceLock::new());
+        buffer.slice(..total_size).map_async(wgpu::MapMode::Read, {
+            let mapped = mapped.clone();
+            move |res| {
+                let _ = mapped.set(res);
+            }
+        });
+        TextureReadback {
+            buffer,
+            format: texture.format(),
+            size: texture.size_pixels(),
+            bytes_per_row,
+            total_size,
+            mapped,
+        }
+    }
+
+    /// Keep a copy of the next presented frame so it can be read back with
+    /// `take_frame_capture` once it has been presented.
+    pub fn request_frame_capture(&mut self) {
+        let usage = match &self.output {
+            DisplayOutput::Surface { .. } => self.config.usage,
+            DisplayOutput::Offscreen { color_texture } => color_texture.texture.usage(),
+        };
+        if !usage.contains(wgpu::TextureUsages::COPY_SRC) {
+            log::warn!("display output doesn't support copies, can't capture frames");
+            return;
+        }
+        let size = self.size_pixels();
+        if self.capture_target.as_ref().map_or(true, |t| {
+            t.size_pixels() != size || t.format() != self.format()
+        }) {
+            self.capture_target = Some(
+                TextureBuilder::labeled("display_capture_target")
+                    .with_format(self.format())
+                    .with_usage(wgpu::TextureUsages::COPY_DST | wgpu::TextureUsages::COPY_SRC)
+                    .build(&self.device, size),
+            );
+        }
+        self.capture_pending = true;
+        self.frame_captured.set(false);
+    }
+
+    fn copy_to_capture_target(&self, output_texture: Option<&wgpu::SurfaceTexture>) {
+        let (Some(target), source) = (&self.capture_target, output_texture) else {
+            return;
+        };
+        let source = match (&self.output, source) {
+            (_, Some(output_texture)) => &output_texture.texture,
+            (DisplayOutput::Offscreen { color_texture }, None) => &color_texture.texture,
+            (DisplayOutput::Surface { .. }, None) => return,
+        };
+        let mut enc = self.command_encoder();
+        enc.copy_texture_to_texture(
+            source.as_image_copy(),
+            target.texture.as_image_copy(),
+            source.size(),
+        );
+        self.queue.submit([enc.finish()]);
+        self.frame_captured.set(true);
+    }
+
+    /// Whether the frame requested with `request_frame_capture` has been
+    /// presented and copied to `captured_frame` since, reported only once.
+    pub fn take_frame_captured(&mut self) -> bool {
+        if !self.frame_captured.replace(false) {
+            return false;
+        }
+        self.capture_pending = false;
+        true
+    }
+
+    /// The copy of the last frame presented while a capture was requested.
+    pub fn captured_frame(&self) -> Option<&Texture> {
+        self.capture_target.as_ref()
+    }
+
     pub fn load_texture_bytes(
         &self,
         buffer: &[u8],
@@ -280,13 +655,19 @@ impl Display {
 
 pub struct DisplayView<'a> {
     display: &'a Display,
-    output_texture: wgpu::SurfaceTexture,
+    output_texture: Option<wgpu::SurfaceTexture>,
     pub(super) view: wgpu::TextureView,
 }
 
 impl DisplayView<'_> {
     pub fn present(self) {
-        self.output_texture.present()
+        if self.display.capture_pending {
+            self.display
+                .copy_to_capture_target(self.output_texture.as_ref());
+        }
+        if let Some(output_texture) = self.output_texture {
+            output_texture.present()
+        }
     }
 
     pub fn display(&self) -> &Display {
@@ -10,7 +10,8 @@ use super::Display;
 
 pub struct EguiRenderer {
     pub context: Context,
-    state: State,
+    // Absent for headless displays, which have no window to take input from.
+    state: Option<State>,
     renderer: Renderer,
 }
 
@@ -18,14 +19,9 @@ impl EguiRenderer {
     pub fn new(display: &Display, msaa_samples: u32) -> EguiRenderer {
         let egui_ctx = Context::default();
         let viewport_id = egui_ctx.viewport_id();
-        let egui_state = egui_winit::State::new(
-            egui_ctx.clone(),
-            viewport_id,
-            display.window(),
-            None,
-            None,
-            None,
-        );
+        let egui_state = display.window().map(|window| {
+            egui_winit::State::new(egui_ctx.clone(), viewport_id, window, None, None, None)
+        });
         let egui_renderer = Renderer::new(
             display.device(),
             display.format(),
@@ -42,7 +38,9 @@ impl EguiRenderer {
     }
 
     pub fn handle_input(&mut self, window: &Window, event: &WindowEvent) -> bool {
-        self.state.on_window_event(window, event).consumed
+        self.state
+            .as_mut()
+            .is_some_and(|state| state.on_window_event(window, event).consumed)
     }
 
     pub fn draw(
@@ -53,11 +51,24 @@ impl EguiRenderer {
         depth_stencil_attachment: Option<wgpu::RenderPassDepthStencilAttachment<'_>>,
         run_ui: impl FnMut(&Context),
     ) {
-        let raw_input = self.state.take_egui_input(display.window());
+        let raw_input = match (&mut self.state, display.window()) {
+            (Some(state), Some(window)) => state.take_egui_input(window),
+            _ => egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    egui::vec2(
+                        display.size_pixels().x as f32,
+                        display.size_pixels().y as f32,
+                    ),
+                )),
+                ..Default::default()
+            },
+        };
         let full_output = self.context.run(raw_input, run_ui);
 
-        self.state
-            .handle_platform_output(display.window(), full_output.platform_output);
+        if let (Some(state), Some(window)) = (&mut self.state, display.window()) {
+            state.handle_platform_output(window, full_output.platform_output);
+        }
 
         let tris = self
             .context
@@ -68,7 +79,7 @@ impl EguiRenderer {
         }
         let screen_descriptor = ScreenDescriptor {
             size_in_pixels: display.size_pixels().into(),
-            pixels_per_point: display.window().scale_factor() as f32,
+            pixels_per_point: display.scale_factor() as f32,
         };
         self.renderer.update_buffers(
             display.device(),
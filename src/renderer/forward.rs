@@ -2,14 +2,18 @@ use std::ops::Deref;
 
 use wgpu::TextureUsages;
 
-use crate::geom::{ModelVertexData, Point};
+use crate::geom::{ModelVertexData, Point, SkinnedVertexData};
 
 use super::{
+    ibl::EnvironmentMaps,
     instance::InstanceRenderData,
+    light_clusters::LightClusters,
     lighting::LightsUniform,
     shaders::{self, forward as shader},
+    skinning::{SkinnedInstanceData, SkinnedScene},
+    skybox::SkyboxPass,
     ssao_from_depth,
-    state::{BindingType, ViewProjectionUniforms},
+    state::{BindingType, DrawOrder, ViewProjectionUniforms},
     Display, InstanceDataWithNormalMatrix, PipelineBuilder, PipelineRef, RenderState, RenderTarget,
     Texture, TextureBuilder, TextureRef, UniformBuffer,
 };
@@ -17,22 +21,44 @@ use super::{
 pub struct ForwardGeometryPass {
     pipeline: PipelineRef<ModelVertexData, InstanceDataWithNormalMatrix>,
     depth_only_pipeline: PipelineRef<ModelVertexData, InstanceDataWithNormalMatrix>,
+    /// `None` when the adapter has too few bind groups for the joint matrices,
+    /// skinned meshes aren't drawn then.
+    skinned_pipeline: Option<PipelineRef<SkinnedVertexData, SkinnedInstanceData>>,
+    skinned_depth_only_pipeline: PipelineRef<SkinnedVertexData, SkinnedInstanceData>,
     pub color_target: TextureRef,
     pub depth_target: Texture,
     pub lights_uniform: UniformBuffer<LightsUniform>,
     lights_bind_group: wgpu::BindGroup,
+    environment_maps: EnvironmentMaps,
+    /// Unshadowed lights, on top of the ones in `lights_uniform`.
+    pub light_clusters: LightClusters,
+    skybox_pass: SkyboxPass,
+    /// Cubemap drawn behind the scene, see `SkyboxPass`.
+    pub skybox: Option<TextureRef>,
 }
 
 impl ForwardGeometryPass {
+    /// Linear HDR scene color, see `TonemappingPass` for mapping it to a
+    /// displayable range.
+    pub const COLOR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
     pub fn new(
         state: &mut RenderState,
         display: &Display,
         size: Point<u32>,
         shadow_map: &Texture,
+        point_shadow_map: &Texture,
     ) -> Self {
         let color_target = TextureBuilder::render_target()
             .with_label("color_target")
-            .with_usage(TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING)
+            .with_format(Self::COLOR_FORMAT)
+            // Post processing samples it between texels, see `BloomPass`.
+            .with_filter_mode(wgpu::FilterMode::Linear)
+            .with_usage(
+                TextureUsages::RENDER_ATTACHMENT
+                    | TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_SRC,
+            )
             .build(display.device(), size);
         let depth_target = TextureBuilder::depth()
             .with_address_mode(wgpu::AddressMode::ClampToBorder)
@@ -49,27 +75,38 @@ impl ForwardGeometryPass {
             stencil: Default::default(),
             bias: Default::default(),
         });
+        let depth_only_shader = display
+            .device()
+            .create_shader_module(shaders::depth_only::DESCRIPTOR.clone());
         let depth_only_pipeline = state
             .pipeline_builder()
             .with_label("Forward Rendering (Depth Prepass)")
             .with_color_target_states(vec![])
             .with_depth_stencil_state(depth_stencil_state.clone())
-            .build(
-                display.device(),
-                &display
-                    .device()
-                    .create_shader_module(shaders::depth_only::DESCRIPTOR.clone()),
-            );
+            .build(display.device(), &depth_only_shader);
+        let depth_only_joints_bgl = display
+            .device()
+            .create_bind_group_layout(&shaders::depth_only::globals::joint_matrices::layout());
+        let skinned_depth_only_pipeline = state
+            .pipeline_builder()
+            .with_label("Forward Rendering (Skinned Depth Prepass)")
+            .with_vertex_entry_point("vs_skinned")
+            .with_color_target_states(vec![])
+            .with_depth_stencil_state(depth_stencil_state.clone())
+            .with_extra_bind_group_layouts(vec![&depth_only_joints_bgl])
+            .build(display.device(), &depth_only_shader);
         let texture_bgl = state.bind_group_layout(
             display.device(),
             BindingType::Texture {
                 format: ssao_from_depth::SSAOPass::OCCLUSION_MAP_FORMAT,
+                view_dimension: wgpu::TextureViewDimension::D2,
             },
         );
         let lights_uniform_bgl = display
             .device()
-            .create_bind_group_layout(&shader::globals::group3::layout());
+            .create_bind_group_layout(&shader::globals::group4::layout());
         let lights_uniform = UniformBuffer::new(display.device(), LightsUniform::default());
+        let environment_maps = EnvironmentMaps::new(display);
         let lights_bind_group = display
             .device()
             .create_bind_group(&wgpu::BindGroupDescriptor {
@@ -88,41 +125,123 @@ impl ForwardGeometryPass {
                         binding: 2,
                         resource: wgpu::BindingResource::Sampler(&shadow_map.sampler),
                     },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: wgpu::BindingResource::TextureView(&point_shadow_map.view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 4,
+                        resource: wgpu::BindingResource::TextureView(
+                            &environment_maps.irradiance.view,
+                        ),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 5,
+                        resource: wgpu::BindingResource::TextureView(
+                            &environment_maps.prefiltered.view,
+                        ),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 6,
+                        resource: wgpu::BindingResource::TextureView(
+                            &environment_maps.brdf_lut.view,
+                        ),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 7,
+                        resource: wgpu::BindingResource::Sampler(
+                            &environment_maps.prefiltered.sampler,
+                        ),
+                    },
                 ],
             });
+        let light_clusters = LightClusters::new(display);
+        let color_target_states = vec![Some(wgpu::ColorTargetState {
+            format: color_target.format(),
+            blend: Some(PipelineBuilder::DEFAULT_BLEND),
+            write_mask: wgpu::ColorWrites::ALL,
+        })];
+        let shader_module = display
+            .device()
+            .create_shader_module(shader::DESCRIPTOR.clone());
         let pipeline = state
             .pipeline_builder()
             .with_label("Forward Rendering")
-            .with_color_target_states(vec![Some(wgpu::ColorTargetState {
-                format: color_target.format(),
-                blend: Some(PipelineBuilder::DEFAULT_BLEND),
-                write_mask: wgpu::ColorWrites::ALL,
-            })])
-            .with_depth_stencil_state(depth_stencil_state)
-            .with_extra_bind_group_layouts(vec![&lights_uniform_bgl, texture_bgl.deref()])
-            .build(
-                display.device(),
-                &display
-                    .device()
-                    .create_shader_module(shader::DESCRIPTOR.clone()),
+            .with_color_target_states(color_target_states.clone())
+            .with_depth_stencil_state(depth_stencil_state.clone())
+            .with_extra_bindings(vec![BindingType::Material])
+            .with_extra_bind_group_layouts(vec![
+                &lights_uniform_bgl,
+                texture_bgl.deref(),
+                light_clusters.bind_group_layout(),
+            ])
+            .build(display.device(), &shader_module);
+        let joints_bgl = display
+            .device()
+            .create_bind_group_layout(&shader::globals::joint_matrices::layout());
+        let max_bind_groups = display.device().limits().max_bind_groups;
+        let skinned_pipeline = if shader::globals::joint_matrices::GROUP < max_bind_groups {
+            Some(
+                state
+                    .pipeline_builder()
+                    .with_label("Forward Rendering (Skinned)")
+                    .with_vertex_entry_point("vs_skinned")
+                    .with_color_target_states(color_target_states)
+                    .with_depth_stencil_state(depth_stencil_state)
+                    .with_extra_bindings(vec![BindingType::Material])
+                    .with_extra_bind_group_layouts(vec![
+                        &lights_uniform_bgl,
+                        texture_bgl.deref(),
+                        light_clusters.bind_group_layout(),
+                        &joints_bgl,
+                    ])
+                    .build(display.device(), &shader_module),
+            )
+        } else {
+            log::warn!(
+                "skinned meshes need {} bind groups but the adapter supports {}, they won't be drawn",
+                shader::globals::joint_matrices::GROUP + 1,
+                max_bind_groups
             );
+            None
+        };
+        let skybox_pass =
+            SkyboxPass::new(state, display, color_target.format(), depth_target.format());
         let color_target = state.load_texture(display, color_target);
         Self {
             pipeline,
             depth_only_pipeline,
+            skinned_pipeline,
+            skinned_depth_only_pipeline,
             color_target,
             depth_target,
             lights_uniform,
             lights_bind_group,
+            environment_maps,
+            light_clusters,
+            skybox_pass,
+            skybox: None,
         }
     }
 
+    /// Lights the scene with the ambient light from `cubemap`, e.g. the same
+    /// one as the `skybox`, instead of the flat `ambient_color`. Filtering it
+    /// takes a while, this is meant for when the environment changes rather
+    /// than every frame.
+    pub fn set_environment(&mut self, state: &RenderState, display: &Display, cubemap: TextureRef) {
+        self.environment_maps
+            .update(display, state.get_texture(cubemap));
+        self.lights_uniform
+            .update_with(display.queue(), |u| u.has_environment = true);
+    }
+
     pub fn depth_prepass(
         &mut self,
         state: &mut RenderState,
         display: &Display,
         view_projection: &ViewProjectionUniforms,
         scene: &[InstanceRenderData<ModelVertexData, InstanceDataWithNormalMatrix>],
+        skinned_scene: &SkinnedScene,
     ) {
         state
             .render_pass(
@@ -132,9 +251,26 @@ impl ForwardGeometryPass {
                 Some(RenderTarget::TextureView(&self.depth_target.view)),
                 view_projection,
                 |r| {
+                    r.set_draw_order(DrawOrder::Sorted);
                     for render_data in scene {
                         r.draw_instance(&InstanceRenderData {
                             pipeline: Some(self.depth_only_pipeline),
+                            material: None,
+                            ..*render_data
+                        });
+                    }
+                    if self.skinned_pipeline.is_none() {
+                        return;
+                    }
+                    r.set_bind_group(
+                        shaders::depth_only::globals::joint_matrices::GROUP,
+                        skinned_scene.bind_group(),
+                        &[],
+                    );
+                    for render_data in &skinned_scene.instances {
+                        r.draw_instance(&InstanceRenderData {
+                            pipeline: Some(self.skinned_depth_only_pipeline),
+                            material: None,
                             ..*render_data
                         });
                     }
@@ -149,9 +285,13 @@ impl ForwardGeometryPass {
         display: &Display,
         view_projection: &ViewProjectionUniforms,
         scene: &[InstanceRenderData<ModelVertexData, InstanceDataWithNormalMatrix>],
+        skinned_scene: &SkinnedScene,
         occlusion_map: TextureRef,
     ) {
         let t = state.get_texture(occlusion_map).bind_group().clone();
+        let skybox = self
+            .skybox
+            .map(|cubemap| state.get_texture(cubemap).bind_group().clone());
         state
             .render_pass(
                 &display,
@@ -161,14 +301,33 @@ impl ForwardGeometryPass {
                 view_projection,
                 |r| {
                     use shader::globals::*;
+                    if let Some(skybox) = &skybox {
+                        self.skybox_pass.draw(r, skybox);
+                    }
                     r.set_bind_group(lights::GROUP, &self.lights_bind_group, &[]);
                     r.set_bind_group(occlusion_map::GROUP, t.deref(), &[]);
+                    r.set_bind_group(
+                        clusters_uniform::GROUP,
+                        self.light_clusters.bind_group(),
+                        &[],
+                    );
+                    r.set_draw_order(DrawOrder::Sorted);
                     for render_data in scene {
                         r.draw_instance(&InstanceRenderData {
                             pipeline: Some(self.pipeline),
                             ..*render_data
                         });
                     }
+                    let Some(skinned_pipeline) = self.skinned_pipeline else {
+                        return;
+                    };
+                    r.set_bind_group(joint_matrices::GROUP, skinned_scene.bind_group(), &[]);
+                    for render_data in &skinned_scene.instances {
+                        r.draw_instance(&InstanceRenderData {
+                            pipeline: Some(skinned_pipeline),
+                            ..*render_data
+                        });
+                    }
                 },
             )
             .submit();
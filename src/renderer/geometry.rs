@@ -5,9 +5,11 @@ use wgpu::TextureUsages;
 use crate::geom::{ModelVertexData, Point};
 
 use super::{
-    instance::InstanceRenderData, shaders, state::ViewProjectionUniforms, Display,
-    InstanceDataWithNormalMatrix, PipelineBuilder, PipelineRef, RenderState, RenderTarget, Texture,
-    TextureBuilder, TextureRef,
+    instance::InstanceRenderData,
+    shaders,
+    state::{BindingType, ViewProjectionUniforms},
+    Display, InstanceDataWithNormalMatrix, PipelineBuilder, PipelineRef, RenderState, RenderTarget,
+    Texture, TextureBuilder, TextureRef,
 };
 
 pub struct GeometryPass {
@@ -70,6 +72,7 @@ impl GeometryPass {
                 stencil: Default::default(),
                 bias: Default::default(),
             }))
+            .with_extra_bindings(vec![BindingType::Material])
             .build(
                 display.device(),
                 &display
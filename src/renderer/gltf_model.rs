@@ -0,0 +1,479 @@
+//! glTF 2.0 import, for both the `.gltf` (JSON with external or data URI
+//! buffers) and the `.glb` (binary) flavours.
+
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context};
+use glam::{Mat4, Quat, UVec4, Vec2, Vec3, Vec4};
+use gltf::animation::util::ReadOutputs;
+use image::RgbaImage;
+
+use crate::color::Color;
+use crate::geom::{ModelVertexData, SkinnedVertexData};
+
+use super::animation::{
+    AnimationClip, Channel, Interpolation, Joint, JointTransform, Keyframes, Skeleton,
+};
+use super::mesh::{LoadMesh, MeshIndices};
+use super::model::{
+    MaterialTextures, Model, ModelMaterial, ModelMesh, ModelNode, ModelSkin, SkinnedModelMesh,
+    TextureSource,
+};
+use super::tangents::{generate_tangents, TangentVertex};
+use super::Material;
+
+pub fn load_gltf(device: &wgpu::Device, path: &Path) -> anyhow::Result<Model> {
+    let gltf::Gltf { document, blob } = gltf::Gltf::open(path)?;
+    let base_dir = path.parent().unwrap_or(Path::new(""));
+    let buffers = gltf::import_buffers(&document, Some(base_dir), blob)?;
+
+    // External image files are left to `RenderState::prepare_model`, so they
+    // can be shared with other models. The rest is decoded the way
+    // `gltf::import_images` does.
+    let mut images = vec![];
+    let mut image_sources = vec![];
+    for image in document.images() {
+        if let gltf::image::Source::Uri { uri, .. } = image.source() {
+            if !uri.starts_with("data:") {
+                let file = urlencoding::decode(uri)
+                    .with_context(|| format!("invalid URI of image {}", image.index()))?;
+                image_sources.push(TextureSource::File(base_dir.join(&*file)));
+                continue;
+            }
+        }
+        let data = gltf::image::Data::from_source(image.source(), Some(base_dir), &buffers)
+            .with_context(|| format!("failed to decode image {}", image.index()))?;
+        image_sources.push(TextureSource::Embedded(images.len()));
+        images.push(rgba_image(data)?);
+    }
+
+    // Sampler settings are ignored, textures always repeat with linear
+    // filtering.
+    let source = |texture: gltf::Texture| image_sources[texture.source().index()].clone();
+    let materials = document
+        .materials()
+        .map(|m| {
+            let pbr = m.pbr_metallic_roughness();
+            let [r, g, b, a] = pbr.base_color_factor();
+            let normal = m.normal_texture();
+            let occlusion = m.occlusion_texture();
+            ModelMaterial {
+                material: Material {
+                    base_color: Color::from((r, g, b, a)),
+                    metallic: pbr.metallic_factor(),
+                    roughness: pbr.roughness_factor(),
+                    emissive: Vec3::from(m.emissive_factor()),
+                    occlusion_strength: occlusion.as_ref().map_or(1.0, |t| t.strength()),
+                    normal_scale: normal.as_ref().map_or(1.0, |t| t.scale()),
+                    ..Default::default()
+                },
+                textures: MaterialTextures {
+                    albedo: pbr.base_color_texture().map(|t| source(t.texture())),
+                    normal: normal.map(|t| source(t.texture())),
+                    metallic_roughness: pbr
+                        .metallic_roughness_texture()
+                        .map(|t| source(t.texture())),
+                    occlusion: occlusion.map(|t| source(t.texture())),
+                    emissive: m.emissive_texture().map(|t| source(t.texture())),
+                },
+            }
+        })
+        .collect();
+
+    // Every primitive becomes a mesh of its own, `mesh_primitives` maps glTF
+    // meshes to them. Primitives with joints and weights become skinned
+    // meshes.
+    let mut meshes = vec![];
+    let mut skinned_meshes = vec![];
+    let mut mesh_primitives = vec![];
+    for mesh in document.meshes() {
+        let mut primitives = vec![];
+        let mut skinned_primitives = vec![];
+        for primitive in mesh.primitives() {
+            if primitive.mode() != gltf::mesh::Mode::Triangles {
+                log::warn!(
+                    "skipping {:?} primitive of mesh {}",
+                    primitive.mode(),
+                    mesh.index()
+                );
+                continue;
+            }
+            let reader = primitive.reader(|b| buffers.get(b.index()).map(|data| &**data));
+            let positions = reader
+                .read_positions()
+                .ok_or_else(|| anyhow!("primitive of mesh {} has no positions", mesh.index()))?;
+            let normals = reader
+                .read_normals()
+                .ok_or_else(|| anyhow!("primitive of mesh {} has no normals", mesh.index()))?;
+            let mut tex_coords = reader.read_tex_coords(0).map(|t| t.into_f32());
+            let has_tex_coords = tex_coords.is_some();
+            let mut tangents = reader.read_tangents();
+            let has_tangents = tangents.is_some();
+            let mut vertices = positions
+                .zip(normals)
+                .map(|(position, normal)| ModelVertexData {
+                    position: Vec3::from(position).extend(1.0),
+                    tangent: tangents
+                        .as_mut()
+                        .and_then(Iterator::next)
+                        .map_or(Vec4::ZERO, Vec4::from),
+                    tex_coords: tex_coords
+                        .as_mut()
+                        .and_then(Iterator::next)
+                        .map_or(Vec2::ZERO, Vec2::from),
+                    normal: normal.into(),
+                })
+                .collect::<Vec<_>>();
+            let indices: Vec<u32> = match reader.read_indices() {
+                Some(indices) => indices.into_u32().collect(),
+                None => (0..vertices.len() as u32).collect(),
+            };
+            // Without texture coordinates there is no normal map to apply.
+            let with_tangents = !has_tangents && has_tex_coords;
+            let material = primitive.material().index();
+            match (reader.read_joints(0), reader.read_weights(0)) {
+                (Some(joints), Some(weights)) => {
+                    let mut vertices = vertices
+                        .iter()
+                        .zip(joints.into_u16().zip(weights.into_f32()))
+                        .map(|(v, (joints, weights))| SkinnedVertexData {
+                            position: v.position,
+                            tangent: v.tangent,
+                            weights: weights.into(),
+                            joints: UVec4::from(joints.map(u32::from)),
+                            tex_coords: v.tex_coords,
+                            normal: v.normal,
+                        })
+                        .collect::<Vec<_>>();
+                    let mesh_indices =
+                        primitive_indices(&mut vertices, indices, with_tangents, mesh.index())?;
+                    skinned_primitives.push(skinned_meshes.len());
+                    skinned_meshes.push(SkinnedModelMesh {
+                        mesh: device
+                            .load_mesh_with_indices(&vertices, &mesh_indices)
+                            .unwrap(),
+                        material,
+                    });
+                }
+                _ => {
+                    let mesh_indices =
+                        primitive_indices(&mut vertices, indices, with_tangents, mesh.index())?;
+                    primitives.push(meshes.len());
+                    meshes.push(ModelMesh {
+                        mesh: device
+                            .load_mesh_with_indices(&vertices, &mesh_indices)
+                            .unwrap(),
+                        material,
+                    });
+                }
+            }
+        }
+        mesh_primitives.push((primitives, skinned_primitives));
+    }
+
+    let nodes: Vec<_> = document
+        .nodes()
+        .map(|node| {
+            let (meshes, skinned_meshes) = node
+                .mesh()
+                .map(|m| mesh_primitives[m.index()].clone())
+                .unwrap_or_default();
+            ModelNode {
+                name: node.name().map(str::to_owned),
+                transform: Mat4::from_cols_array_2d(&node.transform().matrix()),
+                meshes,
+                skinned_meshes,
+                skin: node.skin().map(|s| s.index()),
+                children: node.children().map(|c| c.index()).collect(),
+            }
+        })
+        .collect();
+    let roots = match document
+        .default_scene()
+        .or_else(|| document.scenes().next())
+    {
+        Some(scene) => scene.nodes().map(|n| n.index()).collect(),
+        None => (0..nodes.len())
+            .filter(|&i| !nodes.iter().any(|n| n.children.contains(&i)))
+            .collect(),
+    };
+
+    let mut parents = vec![None; nodes.len()];
+    for (i, node) in nodes.iter().enumerate() {
+        for &child in &node.children {
+            parents[child] = Some(i);
+        }
+    }
+    let skins = document
+        .skins()
+        .map(|skin| load_skin(&document, &skin, &buffers, &nodes, &parents))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(Model {
+        meshes,
+        skinned_meshes,
+        materials,
+        images,
+        nodes,
+        roots,
+        skins,
+    })
+}
+
+fn load_skin(
+    document: &gltf::Document,
+    skin: &gltf::Skin,
+    buffers: &[gltf::buffer::Data],
+    nodes: &[ModelNode],
+    parents: &[Option<usize>],
+) -> anyhow::Result<ModelSkin> {
+    let joint_nodes: Vec<_> = skin.joints().map(|j| j.index()).collect();
+    let reader = skin.reader(|b| buffers.get(b.index()).map(|data| &**data));
+    let mut inverse_bind_matrices: Vec<_> = reader
+        .read_inverse_bind_matrices()
+        .map(|matrices| matrices.map(|m| Mat4::from_cols_array_2d(&m)).collect())
+        .unwrap_or_default();
+    inverse_bind_matrices.resize(joint_nodes.len(), Mat4::IDENTITY);
+
+    // Nodes above the root joints still move the skeleton.
+    let world_transform = |mut index: usize| {
+        let mut transform = nodes[index].transform;
+        while let Some(parent) = parents[index] {
+            transform = nodes[parent].transform * transform;
+            index = parent;
+        }
+        transform
+    };
+    let mut root_transform = Mat4::IDENTITY;
+    let joints = skin
+        .joints()
+        .zip(inverse_bind_matrices)
+        .map(|(node, inverse_bind_matrix)| {
+            let parent_node = parents[node.index()];
+            let parent = parent_node.and_then(|p| joint_nodes.iter().position(|&j| j == p));
+            if let (None, Some(p)) = (parent, parent_node) {
+                root_transform = world_transform(p);
+            }
+            let (translation, rotation, scale) = node.transform().decomposed();
+            Joint {
+                name: node.name().map(str::to_owned),
+                parent,
+                inverse_bind_matrix,
+                rest: JointTransform {
+                    translation: translation.into(),
+                    rotation: Quat::from_array(rotation),
+                    scale: scale.into(),
+                },
+            }
+        })
+        .collect();
+
+    let animations = document
+        .animations()
+        .map(|animation| {
+            let mut channels = vec![];
+            for channel in animation.channels() {
+                let target = channel.target().node().index();
+                let Some(joint) = joint_nodes.iter().position(|&j| j == target) else {
+                    continue;
+                };
+                let reader = channel.reader(|b| buffers.get(b.index()).map(|data| &**data));
+                let (Some(inputs), Some(outputs)) = (reader.read_inputs(), reader.read_outputs())
+                else {
+                    continue;
+                };
+                let times: Vec<f32> = inputs.collect();
+                let interpolation = match channel.sampler().interpolation() {
+                    gltf::animation::Interpolation::Step => Interpolation::Step,
+                    gltf::animation::Interpolation::Linear => Interpolation::Linear,
+                    gltf::animation::Interpolation::CubicSpline => Interpolation::CubicSpline,
+                };
+                let keyframes = match outputs {
+                    ReadOutputs::Translations(t) => {
+                        Keyframes::Translation(t.map(Vec3::from).collect())
+                    }
+                    ReadOutputs::Rotations(r) => {
+                        Keyframes::Rotation(r.into_f32().map(Quat::from_array).collect())
+                    }
+                    ReadOutputs::Scales(s) => Keyframes::Scale(s.map(Vec3::from).collect()),
+                    ReadOutputs::MorphTargetWeights(_) => continue,
+                };
+                channels.push(Channel {
+                    joint,
+                    interpolation,
+                    times,
+                    keyframes,
+                });
+            }
+            AnimationClip::new(animation.name().map(str::to_owned), channels)
+        })
+        .collect();
+
+    Ok(ModelSkin {
+        skeleton: Skeleton {
+            joints,
+            root_transform,
+        },
+        animations,
+    })
+}
+
+/// Generates tangents for the vertices first if `with_tangents`, then rewinds
+/// the faces for the pipelines.
+fn primitive_indices<V: TangentVertex + bytemuck::Pod>(
+    vertices: &mut Vec<V>,
+    mut indices: Vec<u32>,
+    with_tangents: bool,
+    mesh: usize,
+) -> anyhow::Result<MeshIndices> {
+    if with_tangents && !generate_tangents(vertices, &mut indices) {
+        log::warn!("could not generate tangents for mesh {}", mesh);
+    }
+    let rewound = indices
+        // rewind faces to Ccw
+        .chunks_exact(3)
+        .flat_map(|x| [x[0], x[2], x[1]])
+        .collect();
+    MeshIndices::u32(rewound, vertices.len())
+        .with_context(|| format!("invalid indices in mesh {}", mesh))
+}
+
+/// Converts decoded image data to 8 bit RGBA, keeping the high byte of 16 bit
+/// channels.
+fn rgba_image(data: gltf::image::Data) -> anyhow::Result<RgbaImage> {
+    use gltf::image::Format;
+    let channels = match data.format {
+        Format::R8 | Format::R16 => 1,
+        Format::R8G8 | Format::R16G16 => 2,
+        Format::R8G8B8 | Format::R16G16B16 => 3,
+        Format::R8G8B8A8 | Format::R16G16B16A16 => 4,
+        format => bail!("unsupported image format {:?}", format),
+    };
+    let values: Vec<u8> = match data.format {
+        Format::R16 | Format::R16G16 | Format::R16G16B16 | Format::R16G16B16A16 => data
+            .pixels
+            .chunks_exact(2)
+            .map(|c| (u16::from_ne_bytes([c[0], c[1]]) >> 8) as u8)
+            .collect(),
+        _ => data.pixels,
+    };
+    let pixels = values
+        .chunks_exact(channels)
+        .flat_map(|c| match *c {
+            [l] => [l, l, l, 255],
+            [l, a] => [l, l, l, a],
+            [r, g, b] => [r, g, b, 255],
+            [r, g, b, a] => [r, g, b, a],
+            _ => unreachable!(),
+        })
+        .collect();
+    RgbaImage::from_raw(data.width, data.height, pixels)
+        .ok_or_else(|| anyhow!("image data doesn't match its size"))
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::vec3;
+
+    use super::*;
+    use crate::geom::Point;
+    use crate::renderer::{model::LoadModel, Display, RenderState};
+
+    #[test]
+    fn test_load_gltf() {
+        let (display, mut state) = RenderState::headless(Point::new(16, 16));
+        let base_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("res/models/gltf_test");
+        for file in ["hierarchy.glb", "hierarchy.gltf"] {
+            let path = base_dir.join(file);
+            let model = display.device().load_model(path.to_str().unwrap()).unwrap();
+
+            assert_eq!(model.meshes.len(), 3);
+            assert_eq!(model.meshes[0].mesh.index_format, wgpu::IndexFormat::Uint32);
+            assert_eq!(model.roots, vec![0]);
+            assert_eq!(model.nodes[0].children, vec![1]);
+            assert_eq!(model.nodes[1].name.as_deref(), Some("child"));
+            let instances = model.mesh_instances();
+            assert_eq!(instances.len(), 3);
+            let (_, child_transform) = instances.iter().find(|(i, _)| *i == 1).unwrap();
+            let point = child_transform.transform_point3(vec3(1.0, 0.0, 0.0));
+            assert!(point.abs_diff_eq(vec3(3.0, 1.0, 0.0), 1e-6), "{}", point);
+
+            let [embedded, external] = &model.materials[..] else {
+                panic!("expected 2 materials");
+            };
+            assert_eq!(embedded.material.metallic, 0.25);
+            assert_eq!(embedded.material.roughness, 0.75);
+            assert_eq!(embedded.material.base_color.g, 0.5);
+            assert_eq!(embedded.textures.albedo, Some(TextureSource::Embedded(0)));
+            assert_eq!(model.images.len(), 1);
+            assert_eq!(external.material.normal_scale, 0.5);
+            let checker = Some(TextureSource::File(base_dir.join("checker.png")));
+            assert_eq!(external.textures.albedo, checker);
+            assert_eq!(external.textures.occlusion, checker);
+            assert_eq!(model.meshes[2].material, Some(1));
+        }
+
+        // The linear texture slots of the external material share one upload.
+        let model = display
+            .device()
+            .load_model(base_dir.join("hierarchy.glb").to_str().unwrap())
+            .unwrap();
+        let prepared = state.prepare_model(&display, model);
+        assert_eq!(prepared.meshes.len(), 3);
+        let material = state.get_material(prepared.meshes[2].material).material();
+        assert!(material.albedo_texture.is_some());
+        assert_eq!(material.normal_texture, material.occlusion_texture);
+        assert_eq!(material.normal_texture, material.metallic_roughness_texture);
+        assert_ne!(material.normal_texture, material.albedo_texture);
+        // Both embedded and external textures get a mip chain.
+        let embedded = state.get_material(prepared.meshes[0].material).material();
+        for texture in [embedded.albedo_texture, material.albedo_texture] {
+            let texture = &state.get_texture(texture.unwrap()).texture;
+            assert!(texture.mip_level_count() > 1, "{:?}", texture);
+        }
+    }
+
+    #[test]
+    fn test_load_skinned_gltf() {
+        let display = pollster::block_on(Display::headless(Point::new(16, 16)));
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("res/models/gltf_test/skinned.gltf");
+        let model = display.device().load_model(path.to_str().unwrap()).unwrap();
+
+        assert!(model.meshes.is_empty());
+        assert_eq!(model.skinned_meshes.len(), 1);
+        assert_eq!(model.skinned_mesh_instances(), vec![(0, 0)]);
+
+        let [skin] = &model.skins[..] else {
+            panic!("expected 1 skin");
+        };
+        let skeleton = &skin.skeleton;
+        assert_eq!(skeleton.joints.len(), 2);
+        assert_eq!(skeleton.joints[0].parent, None);
+        assert_eq!(skeleton.joints[1].parent, Some(0));
+        assert_eq!(skeleton.joints[1].name.as_deref(), Some("bone"));
+        // The bind pose is the rest pose.
+        for m in skeleton.joint_matrices(&skeleton.rest_pose()) {
+            assert!(m.abs_diff_eq(Mat4::IDENTITY, 1e-6), "{}", m);
+        }
+
+        let [clip] = &skin.animations[..] else {
+            panic!("expected 1 animation");
+        };
+        assert_eq!(clip.name.as_deref(), Some("bend"));
+        assert_eq!(clip.duration, 1.0);
+        let pose = clip.pose(skeleton, 1.0);
+        // The cubic spline's tangents aren't mistaken for its values, but
+        // curve it between them.
+        assert_eq!(pose.joints[0].translation, Vec3::ZERO);
+        let translation = clip.pose(skeleton, 0.25).joints[0].translation;
+        assert!(
+            translation.abs_diff_eq(Vec3::splat(0.84375), 1e-5),
+            "{}",
+            translation
+        );
+        // The top of the strip is bent around the bone.
+        let matrices = skeleton.joint_matrices(&pose);
+        let top = matrices[1].transform_point3(vec3(2.0, 2.0, 0.0));
+        assert!(top.abs_diff_eq(vec3(1.0, 1.0, 0.0), 1e-5), "{}", top);
+    }
+}
@@ -0,0 +1,176 @@
+//! Support for golden-image regression tests: captured frames are compared
+//! against reference PNGs checked in under `res/golden/`.
+//!
+//! Run tests with `UPDATE_GOLDEN=1` to (re)write the references instead of
+//! comparing against them. On a mismatch the actual image and a diff image
+//! are written to `target/golden/` for inspection.
+
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use image::{Rgba, RgbaImage};
+
+use super::capture::save_image;
+
+pub struct ImageDiff {
+    pub mismatched_pixels: usize,
+    pub max_channel_delta: u8,
+    /// Mismatched pixels in red over a faded copy of the expected image.
+    pub image: RgbaImage,
+}
+
+/// Compares two images of the same size, pixels where any channel differs by
+/// more than `tolerance` count as mismatched.
+pub fn diff_images(actual: &RgbaImage, expected: &RgbaImage, tolerance: u8) -> ImageDiff {
+    assert_eq!(actual.dimensions(), expected.dimensions());
+    let mut mismatched_pixels = 0;
+    let mut max_channel_delta = 0;
+    let mut image = RgbaImage::new(actual.width(), actual.height());
+    for (x, y, a) in actual.enumerate_pixels() {
+        let e = expected.get_pixel(x, y);
+        let delta = (0..4).map(|i| a[i].abs_diff(e[i])).max().unwrap();
+        max_channel_delta = max_channel_delta.max(delta);
+        let pixel = if delta > tolerance {
+            mismatched_pixels += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let faded = |c: u8| 128 + c / 4;
+            Rgba([faded(e[0]), faded(e[1]), faded(e[2]), 255])
+        };
+        image.put_pixel(x, y, pixel);
+    }
+    ImageDiff {
+        mismatched_pixels,
+        max_channel_delta,
+        image,
+    }
+}
+
+fn reference_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("res/golden")
+        .join(name)
+        .with_extension("png")
+}
+
+fn output_path(name: &str, suffix: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("target/golden")
+        .join(format!("{}.{}.png", name, suffix))
+}
+
+/// Checks `actual` against the reference image `res/golden/<name>.png`.
+pub fn check_golden(name: &str, actual: &RgbaImage, tolerance: u8) -> anyhow::Result<()> {
+    let reference = reference_path(name);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        return save_image(actual, reference);
+    }
+    let expected = image::open(&reference)
+        .with_context(|| {
+            format!(
+                "missing golden image {}, run with UPDATE_GOLDEN=1 to create it",
+                reference.display()
+            )
+        })?
+        .into_rgba8();
+    if actual.dimensions() != expected.dimensions() {
+        save_image(actual, output_path(name, "actual"))?;
+        bail!(
+            "golden image {} is {:?} but got {:?}",
+            name,
+            expected.dimensions(),
+            actual.dimensions()
+        );
+    }
+    let diff = diff_images(actual, &expected, tolerance);
+    if diff.mismatched_pixels > 0 {
+        save_image(actual, output_path(name, "actual"))?;
+        save_image(&diff.image, output_path(name, "diff"))?;
+        bail!(
+            "golden image {} has {} mismatched pixels (max channel delta {}), see {}",
+            name,
+            diff.mismatched_pixels,
+            diff.max_channel_delta,
+            output_path(name, "diff").display()
+        );
+    }
+    Ok(())
+}
+
+#[track_caller]
+pub fn assert_golden(name: &str, actual: &RgbaImage, tolerance: u8) {
+    if let Err(e) = check_golden(name, actual, tolerance) {
+        panic!("{:#}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{vec3, Vec3};
+
+    use super::*;
+    use crate::{
+        camera::Camera,
+        renderer::{
+            lighting::{Light, LightKind},
+            test_scene::{brightness, LitScene, FLOOR_Y},
+            tonemapping::Tonemapper,
+        },
+    };
+
+    #[test]
+    fn test_diff_images() {
+        let expected = RgbaImage::from_pixel(4, 4, Rgba([10, 20, 30, 255]));
+        let mut actual = expected.clone();
+        actual.put_pixel(1, 2, Rgba([14, 20, 30, 255]));
+        actual.put_pixel(3, 3, Rgba([10, 90, 30, 255]));
+
+        let diff = diff_images(&actual, &expected, 4);
+        assert_eq!(diff.mismatched_pixels, 1);
+        assert_eq!(diff.max_channel_delta, 70);
+        assert_eq!(*diff.image.get_pixel(3, 3), Rgba([255, 0, 0, 255]));
+        assert_eq!(diff_images(&actual, &expected, 70).mismatched_pixels, 0);
+    }
+
+    /// The whole forward path with a cascaded shadow, feature tests assert on
+    /// single pixels of the same scene.
+    #[test]
+    fn test_directional_light_cascades() {
+        // Lit from behind and seen from low in front, so the cube's shadow
+        // reaches towards the camera across the first cascade's far end.
+        let mut scene = LitScene::new(Light::from(LightKind::Directional {
+            theta: -60.0,
+            phi: 0.0,
+        }));
+        scene.camera = Camera::new(vec3(0.0, 1.0, 7.0), scene.camera.aspect_ratio());
+        scene.camera.update_angle(0.0, 10.0f32.to_radians());
+        scene.render();
+
+        let camera = scene.camera;
+        let splits = scene.forward_pass.lights_uniform.cascade_splits;
+        let cascade = |position: Vec3| {
+            let depth = (position - camera.position()).dot(camera.look_dir());
+            let fraction = (depth - camera.z_near()) / (camera.z_far() - camera.z_near());
+            splits.iter().position(|&split| fraction < split).unwrap()
+        };
+        let color = scene.capture_color(Tonemapper::None);
+        for (z, expected_cascade) in [(4.0, 0), (1.3, 1)] {
+            let shadowed = vec3(0.0, FLOOR_Y, z);
+            let lit = vec3(2.5, FLOOR_Y, z);
+            assert_eq!(cascade(shadowed), expected_cascade);
+            assert_eq!(cascade(lit), expected_cascade);
+            let (shadowed, lit) = (
+                scene.pixel_at(&color, shadowed),
+                scene.pixel_at(&color, lit),
+            );
+            assert!(
+                brightness(shadowed) + 100 < brightness(lit),
+                "cascade {}: {:?} {:?}",
+                expected_cascade,
+                shadowed,
+                lit
+            );
+        }
+        assert_golden("forward_directional_light", &color, 8);
+    }
+}
@@ -0,0 +1,291 @@
+//! Image based lighting: filtering an environment cubemap into the maps the
+//! forward shader samples for ambient diffuse and specular light.
+
+use wgpu::util::DeviceExt;
+
+use crate::geom::Point;
+
+use super::{shaders::ibl as shader, Display, Texture, TextureBuilder};
+
+pub type FilterUniforms = shader::types::FilterUniforms;
+
+/// Diffuse irradiance and prefiltered specular maps of an environment, plus
+/// the BRDF lookup table they are combined with. The maps are allocated once
+/// and filtered again in place by `update`, so bind groups using them stay
+/// valid. Until then they are black and add no light.
+pub struct EnvironmentMaps {
+    /// Cosine weighted average radiance around each direction.
+    pub irradiance: Texture,
+    /// Reflected radiance, for roughness increasing from 0 in the first mip
+    /// level to 1 in the last.
+    pub prefiltered: Texture,
+    /// Scale and bias to the specular reflectance at normal incidence, by n
+    /// dot v (u) and roughness (v).
+    pub brdf_lut: Texture,
+    bind_group_layout: wgpu::BindGroupLayout,
+    irradiance_pipeline: wgpu::RenderPipeline,
+    prefilter_pipeline: wgpu::RenderPipeline,
+    sampler: wgpu::Sampler,
+}
+
+impl EnvironmentMaps {
+    pub const FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+    pub const BRDF_LUT_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rg16Float;
+    const IRRADIANCE_SIZE: u32 = 32;
+    const PREFILTERED_SIZE: u32 = 128;
+    const BRDF_LUT_SIZE: u32 = 128;
+    const IRRADIANCE_SAMPLES: u32 = 512;
+    const PREFILTER_SAMPLES: u32 = 256;
+
+    pub fn new(display: &Display) -> Self {
+        let device = display.device();
+        let usage = wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::TEXTURE_BINDING
+            | wgpu::TextureUsages::COPY_SRC;
+        let irradiance = TextureBuilder::cubemap()
+            .with_label("irradiance map")
+            .with_format(Self::FORMAT)
+            .with_usage(usage)
+            .with_filter_mode(wgpu::FilterMode::Linear)
+            .build(
+                device,
+                Point::new(Self::IRRADIANCE_SIZE, Self::IRRADIANCE_SIZE),
+            );
+        let prefiltered = TextureBuilder::cubemap()
+            .with_label("prefiltered environment map")
+            .with_format(Self::FORMAT)
+            .with_usage(usage)
+            .with_filter_mode(wgpu::FilterMode::Linear)
+            .with_mipmaps()
+            .build(
+                device,
+                Point::new(Self::PREFILTERED_SIZE, Self::PREFILTERED_SIZE),
+            );
+        let brdf_lut = TextureBuilder::labeled("BRDF lookup table")
+            .with_format(Self::BRDF_LUT_FORMAT)
+            .with_usage(usage)
+            .with_filter_mode(wgpu::FilterMode::Linear)
+            .build(device, Point::new(Self::BRDF_LUT_SIZE, Self::BRDF_LUT_SIZE));
+
+        let module = device.create_shader_module(shader::DESCRIPTOR);
+        let bind_group_layout = device.create_bind_group_layout(&shader::globals::group0::layout());
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("environment filter pipeline layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = |layout: &wgpu::PipelineLayout, entry_point, format: wgpu::TextureFormat| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(entry_point),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &module,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &module,
+                    entry_point: Some(entry_point),
+                    targets: &[Some(format.into())],
+                    compilation_options: Default::default(),
+                }),
+                primitive: Default::default(),
+                depth_stencil: None,
+                multisample: Default::default(),
+                multiview: None,
+                cache: None,
+            })
+        };
+        let irradiance_pipeline = pipeline(&pipeline_layout, "fs_irradiance", Self::FORMAT);
+        let prefilter_pipeline = pipeline(&pipeline_layout, "fs_prefilter", Self::FORMAT);
+        // The lookup table only depends on the BRDF, it is rendered once.
+        let brdf_lut_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("BRDF lookup table pipeline layout"),
+            bind_group_layouts: &[],
+            push_constant_ranges: &[],
+        });
+        let brdf_lut_pipeline = pipeline(&brdf_lut_layout, "fs_brdf_lut", Self::BRDF_LUT_FORMAT);
+        let mut encoder = display.command_encoder();
+        draw_fullscreen(&mut encoder, &brdf_lut_pipeline, None, &brdf_lut.view);
+        display.queue().submit([encoder.finish()]);
+
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("environment filter sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        Self {
+            irradiance,
+            prefiltered,
+            brdf_lut,
+            bind_group_layout,
+            irradiance_pipeline,
+            prefilter_pipeline,
+            sampler,
+        }
+    }
+
+    /// Filters `environment`, a cubemap of linear radiance. A full mip chain
+    /// on it (see `TextureBuilder::with_mipmaps`) reduces noise from bright
+    /// spots.
+    pub fn update(&self, display: &Display, environment: &Texture) {
+        let device = display.device();
+        let environment_size = environment.texture.width() as f32;
+        let mut encoder = display.command_encoder();
+        let mut filter = |pipeline, target: &Texture, mip: u32, params: FilterUniforms| {
+            let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("environment filter uniforms"),
+                contents: bytemuck::bytes_of(&params),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("environment filter bind group"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&environment.view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(&self.sampler),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: params_buffer.as_entire_binding(),
+                    },
+                ],
+            });
+            let view = target.texture.create_view(&wgpu::TextureViewDescriptor {
+                label: Some("environment filter target"),
+                dimension: Some(wgpu::TextureViewDimension::D2),
+                base_mip_level: mip,
+                mip_level_count: Some(1),
+                base_array_layer: params.face,
+                array_layer_count: Some(1),
+                ..Default::default()
+            });
+            draw_fullscreen(&mut encoder, pipeline, Some(&bind_group), &view);
+        };
+        let mip_count = self.prefiltered.texture.mip_level_count();
+        for face in 0..6 {
+            filter(
+                &self.irradiance_pipeline,
+                &self.irradiance,
+                0,
+                FilterUniforms {
+                    face,
+                    roughness: 1.0,
+                    sample_count: Self::IRRADIANCE_SAMPLES,
+                    environment_size,
+                },
+            );
+            for mip in 0..mip_count {
+                filter(
+                    &self.prefilter_pipeline,
+                    &self.prefiltered,
+                    mip,
+                    FilterUniforms {
+                        face,
+                        roughness: mip as f32 / (mip_count - 1) as f32,
+                        sample_count: Self::PREFILTER_SAMPLES,
+                        environment_size,
+                    },
+                );
+            }
+        }
+        display.queue().submit([encoder.finish()]);
+    }
+}
+
+fn draw_fullscreen(
+    encoder: &mut wgpu::CommandEncoder,
+    pipeline: &wgpu::RenderPipeline,
+    bind_group: Option<&wgpu::BindGroup>,
+    target: &wgpu::TextureView,
+) {
+    let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("environment filter pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: target,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                store: wgpu::StoreOp::Store,
+            },
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    });
+    pass.set_pipeline(pipeline);
+    if let Some(bind_group) = bind_group {
+        pass.set_bind_group(0, bind_group, &[]);
+    }
+    pass.draw(0..3, 0..1);
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::vec3;
+
+    use super::*;
+    use crate::{
+        color::Color,
+        renderer::{
+            test_scene::{directional_light, load_colored_cubemap, LitScene},
+            tonemapping::Tonemapper,
+        },
+    };
+
+    #[test]
+    fn test_brdf_lut() {
+        let mut display = pollster::block_on(Display::headless(Point::new(16, 16)));
+        let maps = EnvironmentMaps::new(&display);
+
+        // Smooth surfaces reflect everything, mostly by the Fresnel bias at
+        // grazing angles, rough ones facing the viewer noticeably less.
+        let lut = display.capture_texture(&maps.brdf_lut).unwrap();
+        let last = EnvironmentMaps::BRDF_LUT_SIZE - 1;
+        let facing = lut.get_pixel(last, 0).0;
+        assert!(facing[0] >= 250 && facing[1] <= 5, "{:?}", facing);
+        let grazing = lut.get_pixel(0, 0).0;
+        assert!(grazing[1] >= 200, "{:?}", grazing);
+        let rough = lut.get_pixel(last, last).0;
+        assert!((rough[0] as u32 + rough[1] as u32) < 200, "{:?}", rough);
+    }
+
+    #[test]
+    fn test_environment_lighting() {
+        let mut scene = LitScene::new(directional_light());
+        scene.cube_material.metallic = 1.0;
+        scene.cube_material.roughness = 0.2;
+        let cubemap = load_colored_cubemap(&mut scene, "environment");
+        scene
+            .forward_pass
+            .set_environment(&mut scene.state, &scene.display, cubemap);
+        scene.render();
+
+        // The mirror-like cube reflects the faces around it: the yellow one
+        // ahead of the camera on its top, the blue one behind the camera on
+        // its front.
+        let color = scene.capture_color(Tonemapper::None);
+        let top = scene.pixel_at(&color, vec3(0.0, 1.0, 0.0)).0;
+        assert!(top[0] > 150 && top[1] > 150 && top[2] < 50, "{:?}", top);
+        let front = scene.pixel_at(&color, vec3(0.0, 0.5, 1.0)).0;
+        assert!(
+            front[2] > 100 && front[2] > 2 * front[0].max(front[1]),
+            "{:?}",
+            front
+        );
+
+        // The environment replaces the flat ambient light rather than adding
+        // to it.
+        scene.forward_pass.lights_uniform.ambient_color = Color::WHITE;
+        scene.render();
+        assert!(scene.capture_color(Tonemapper::None) == color);
+    }
+}
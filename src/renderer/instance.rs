@@ -1,8 +1,8 @@
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 
 use crate::geom::BasicVertexData;
 
-use super::{Display, InstanceData, MeshRef, PipelineRef, TextureRef};
+use super::{Display, InstanceData, MaterialRef, MeshRef, PipelineRef, TextureRef};
 
 use super::BasicInstanceData;
 
@@ -11,6 +11,9 @@ pub struct InstanceRenderData<V = BasicVertexData, I = BasicInstanceData> {
     pub mesh: MeshRef<V>,
     pub instance: I,
     pub texture: Option<TextureRef>,
+    /// Only used by pipelines that bind materials, `None` is the default
+    /// material.
+    pub material: Option<MaterialRef>,
     pub pipeline: Option<PipelineRef<V, I>>,
 }
 
@@ -22,62 +25,155 @@ impl<V, I> Deref for InstanceRenderData<V, I> {
     }
 }
 
+/// A run of instances of the same type within an `InstanceStorage`.
+#[derive(Debug, Clone)]
+pub struct InstanceBatch {
+    /// Byte range of the batch in the instance buffer.
+    pub range: Range<wgpu::BufferAddress>,
+    pub stride: wgpu::BufferAddress,
+    pub count: u32,
+    /// Whether the instance buffer had to be reallocated to fit the batch.
+    pub reallocated: bool,
+}
+
+/// Instance data for a single render pass. Draws are split into batches at
+/// their own offsets, so batches may use different `InstanceData` types. All
+/// of the data is uploaded at once when the pass ends.
 pub struct InstanceStorage {
     raw_instance_bytes: Vec<u8>,
-    instance_buffer: Option<wgpu::Buffer>,
-    destroy_list: Vec<wgpu::Buffer>,
+    batch_start: usize,
+    instance_buffer: wgpu::Buffer,
+    /// Buffers outgrown during the current pass. Batches recorded before the
+    /// reallocation still refer to them, so they get uploaded to as well.
+    retired_buffers: Vec<wgpu::Buffer>,
 }
 
 impl InstanceStorage {
+    const USAGE: wgpu::BufferUsages =
+        wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::VERTEX);
+
     pub fn new(display: &Display, initial_size: usize) -> Self {
         let instance_buffer = display.device().create_buffer(&wgpu::BufferDescriptor {
             label: Some("instance storage"),
             size: (initial_size * std::mem::size_of::<BasicInstanceData>()) as u64,
-            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::VERTEX,
+            usage: Self::USAGE,
             mapped_at_creation: false,
         });
         Self {
             raw_instance_bytes: vec![],
-            instance_buffer: Some(instance_buffer),
-            destroy_list: vec![],
+            batch_start: 0,
+            instance_buffer,
+            retired_buffers: vec![],
         }
     }
 
     pub fn buffer(&self) -> &wgpu::Buffer {
-        self.instance_buffer.as_ref().unwrap()
+        &self.instance_buffer
     }
 
     pub fn add<I: InstanceData>(&mut self, instance: &I) {
-        self.raw_instance_bytes
-            .extend_from_slice(bytemuck::bytes_of(instance));
+        self.add_bytes(bytemuck::bytes_of(instance));
+    }
+
+    pub fn add_bytes(&mut self, bytes: &[u8]) {
+        self.raw_instance_bytes.extend_from_slice(bytes);
     }
 
     pub fn clear(&mut self) {
         self.raw_instance_bytes.clear();
-        for b in self.destroy_list.drain(..) {
-            b.destroy();
-        }
+        self.batch_start = 0;
+        self.retired_buffers.clear();
     }
 
-    pub fn update_buffer(&mut self, display: &Display) {
-        let req_size = self.raw_instance_bytes.len() as wgpu::BufferAddress;
-        if self.buffer().size() < req_size {
-            self.destroy_list.push(self.instance_buffer.take().unwrap());
-            self.instance_buffer = Some(display.device().create_buffer(&wgpu::BufferDescriptor {
+    /// Ends the current batch, which holds everything added since the
+    /// previous one, growing the buffer if it doesn't fit.
+    pub fn end_batch(&mut self, display: &Display, stride: usize) -> InstanceBatch {
+        let range = self.batch_start as u64..self.raw_instance_bytes.len() as u64;
+        let count = if stride == 0 {
+            0
+        } else {
+            debug_assert_eq!((range.end - range.start) % stride as u64, 0);
+            ((range.end - range.start) / stride as u64) as u32
+        };
+        // Vertex buffer offsets and buffer writes need 4 byte alignment.
+        let aligned_len = self
+            .raw_instance_bytes
+            .len()
+            .next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT as usize);
+        self.raw_instance_bytes.resize(aligned_len, 0);
+        self.batch_start = aligned_len;
+
+        let reallocated = self.instance_buffer.size() < aligned_len as u64;
+        if reallocated {
+            let buffer = display.device().create_buffer(&wgpu::BufferDescriptor {
                 label: Some("instance storage (resized)"),
-                size: req_size * 2,
-                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::VERTEX,
+                size: aligned_len as u64 * 2,
+                usage: Self::USAGE,
                 mapped_at_creation: false,
-            }));
+            });
+            self.retired_buffers
+                .push(std::mem::replace(&mut self.instance_buffer, buffer));
+        }
+        InstanceBatch {
+            range,
+            stride: stride as u64,
+            count,
+            reallocated,
         }
-        let mut w = display
-            .queue()
-            .write_buffer_with(
-                self.buffer(),
-                0,
-                std::num::NonZeroU64::new(req_size).unwrap(),
-            )
-            .unwrap();
-        w.copy_from_slice(&self.raw_instance_bytes);
+    }
+
+    /// Uploads the data of all batches, returning the number of bytes written.
+    pub fn upload(&mut self, display: &Display) -> u64 {
+        let mut uploaded = 0;
+        for buffer in self.retired_buffers.iter().chain([&self.instance_buffer]) {
+            let len = (buffer.size() as usize).min(self.batch_start);
+            if len > 0 {
+                display
+                    .queue()
+                    .write_buffer(buffer, 0, &self.raw_instance_bytes[..len]);
+                uploaded += len as u64;
+            }
+        }
+        uploaded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geom::Point, renderer::InstanceDataWithNormalMatrix};
+
+    #[test]
+    fn test_mixed_instance_batches() {
+        let display = pollster::block_on(Display::headless(Point::new(4, 4)));
+        let mut storage = InstanceStorage::new(&display, 4);
+        let basic_size = std::mem::size_of::<BasicInstanceData>();
+        let normal_size = std::mem::size_of::<InstanceDataWithNormalMatrix>();
+
+        storage.add(&BasicInstanceData::default());
+        storage.add(&BasicInstanceData::default());
+        let basic = storage.end_batch(&display, basic_size);
+        assert_eq!(basic.range, 0..2 * basic_size as u64);
+        assert_eq!(basic.count, 2);
+        assert!(!basic.reallocated);
+
+        let normal_instance =
+            InstanceDataWithNormalMatrix::from_basic(Default::default(), Default::default());
+        for _ in 0..3 {
+            storage.add(&normal_instance);
+        }
+        let normal = storage.end_batch(&display, normal_size);
+        assert_eq!(normal.range.start, basic.range.end);
+        assert_eq!(normal.count, 3);
+        assert_eq!(normal.stride, normal_size as u64);
+        assert!(normal.reallocated);
+
+        let total = (2 * basic_size + 3 * normal_size) as u64;
+        // The outgrown buffer is still used by the first batch, and gets
+        // as much of the data as fits in it.
+        assert_eq!(storage.upload(&display), total + 4 * basic_size as u64);
+        storage.clear();
+        storage.add(&BasicInstanceData::default());
+        assert!(!storage.end_batch(&display, basic_size).reallocated);
     }
 }
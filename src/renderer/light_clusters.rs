@@ -0,0 +1,428 @@
+//! Clustered forward lighting: the view frustum is split into a grid of
+//! clusters, tiled on screen and sliced logarithmically in depth, and every
+//! cluster lists the lights which reach it. The forward shader then only
+//! shades the lights of its fragment's cluster, so a scene can have hundreds
+//! of lights as long as they don't cast shadows. Shadowed lights still go in
+//! the [`LightsUniform`](super::lighting::LightsUniform).
+
+use bytemuck::Zeroable;
+use glam::{uvec3, vec2, vec3, Mat4, UVec3, Vec2, Vec3Swizzles};
+
+use crate::camera::{Camera, Frustum};
+
+use super::{
+    lighting::{Light, LightKind, LightRaw},
+    shaders::forward::{globals::group6, types::ClusterUniforms},
+    Display, UniformBuffer,
+};
+
+/// Number of clusters along x and y on screen, and of depth slices.
+pub const CLUSTER_GRID_SIZE: UVec3 = uvec3(16, 9, 24);
+
+/// Depth slice of a view space depth, slices get thicker with the distance
+/// like the shader's.
+fn depth_slice(depth: f32, z_near: f32, z_far: f32, slices: u32) -> u32 {
+    let ratio = (depth.max(z_near) / z_near).ln() / (z_far / z_near).ln();
+    (ratio * slices as f32).clamp(0.0, (slices - 1) as f32) as u32
+}
+
+/// First and last cluster (inclusive) of the box of clusters a light may
+/// reach, or `None` if it is outside of the view frustum.
+fn cluster_range(
+    light: &Light,
+    view: Mat4,
+    projection: Mat4,
+    z_near: f32,
+    z_far: f32,
+    grid: UVec3,
+) -> Option<(UVec3, UVec3)> {
+    let (center, radius) = match light.kind {
+        LightKind::Directional { .. } => return Some((UVec3::ZERO, grid - 1)),
+        LightKind::Spot {
+            position, reach, ..
+        } => (position, reach),
+        LightKind::Point { position, radius } => (position, radius),
+    };
+    let center = view.transform_point3(center);
+    let near_depth = (-center.z - radius).max(z_near);
+    let far_depth = (-center.z + radius).min(z_far);
+    if near_depth > far_depth {
+        return None;
+    }
+
+    // Screen bounds of the light's view space bounding box, cut at the near
+    // plane so that all of its corners project in front of the camera.
+    let mut min = Vec2::INFINITY;
+    let mut max = Vec2::NEG_INFINITY;
+    for corner in 0..8 {
+        let sign = |bit: u32| if corner & bit == 0 { -1.0 } else { 1.0 };
+        let depth = if corner & 4 == 0 {
+            near_depth
+        } else {
+            far_depth
+        };
+        let ndc = projection
+            .project_point3(vec3(
+                center.x + sign(1) * radius,
+                center.y + sign(2) * radius,
+                -depth,
+            ))
+            .xy();
+        min = min.min(ndc);
+        max = max.max(ndc);
+    }
+    if max.cmplt(Vec2::NEG_ONE).any() || min.cmpgt(Vec2::ONE).any() {
+        return None;
+    }
+    // Tiles go down the screen while NDC y goes up.
+    let to_tile = |ndc: Vec2| {
+        ((vec2(ndc.x, -ndc.y) * 0.5 + 0.5) * grid.xy().as_vec2())
+            .clamp(Vec2::ZERO, (grid.xy() - 1).as_vec2())
+            .as_uvec2()
+    };
+    let (a, b) = (to_tile(min), to_tile(max));
+    Some((
+        a.min(b)
+            .extend(depth_slice(near_depth, z_near, z_far, grid.z)),
+        a.max(b)
+            .extend(depth_slice(far_depth, z_near, z_far, grid.z)),
+    ))
+}
+
+/// The lights of every cluster, as the shader reads them.
+#[derive(Debug, Default)]
+pub struct LightBins {
+    /// Offset into `light_indices` and number of lights of each cluster.
+    pub clusters: Vec<[u32; 2]>,
+    pub light_indices: Vec<u32>,
+}
+
+pub fn bin_lights(
+    lights: &[Light],
+    view: Mat4,
+    projection: Mat4,
+    z_near: f32,
+    z_far: f32,
+    grid: UVec3,
+) -> LightBins {
+    let cluster_index = |c: UVec3| (c.x + grid.x * (c.y + grid.y * c.z)) as usize;
+    let ranges: Vec<_> = lights
+        .iter()
+        .map(|l| cluster_range(l, view, projection, z_near, z_far, grid))
+        .collect();
+    let for_each_cluster = |(min, max): (UVec3, UVec3), f: &mut dyn FnMut(usize)| {
+        for z in min.z..=max.z {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    f(cluster_index(uvec3(x, y, z)));
+                }
+            }
+        }
+    };
+
+    let mut clusters = vec![[0, 0]; (grid.x * grid.y * grid.z) as usize];
+    for range in ranges.iter().flatten() {
+        for_each_cluster(*range, &mut |i| clusters[i][1] += 1);
+    }
+    let mut offset = 0;
+    for cluster in &mut clusters {
+        let count = cluster[1];
+        *cluster = [offset, 0];
+        offset += count;
+    }
+    let mut light_indices = vec![0; offset as usize];
+    for (light, range) in ranges.iter().enumerate() {
+        if let Some(range) = range {
+            for_each_cluster(*range, &mut |i| {
+                let [offset, count] = &mut clusters[i];
+                light_indices[(*offset + *count) as usize] = light as u32;
+                *count += 1;
+            });
+        }
+    }
+    LightBins {
+        clusters,
+        light_indices,
+    }
+}
+
+/// Storage buffer which is reallocated when the data outgrows it.
+pub(super) struct StorageBuffer {
+    label: &'static str,
+    pub(super) buffer: wgpu::Buffer,
+}
+
+impl StorageBuffer {
+    pub(super) fn new(device: &wgpu::Device, label: &'static str, size: usize) -> Self {
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: size as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        Self { label, buffer }
+    }
+
+    /// Returns whether the buffer had to be reallocated, in which case bind
+    /// groups using it have to be recreated.
+    pub(super) fn write(&mut self, display: &Display, bytes: &[u8]) -> bool {
+        let reallocated = (self.buffer.size() as usize) < bytes.len();
+        if reallocated {
+            *self = Self::new(display.device(), self.label, bytes.len() * 2);
+        }
+        if !bytes.is_empty() {
+            display.queue().write_buffer(&self.buffer, 0, bytes);
+        }
+        reallocated
+    }
+}
+
+pub struct LightClusters {
+    /// Lights without shadows.
+    pub lights: Vec<Light>,
+    pub show_heatmap: bool,
+    uniform: UniformBuffer<ClusterUniforms>,
+    lights_buffer: StorageBuffer,
+    clusters_buffer: StorageBuffer,
+    light_indices_buffer: StorageBuffer,
+    bind_group_layout: wgpu::BindGroupLayout,
+    bind_group: wgpu::BindGroup,
+    max_lights_per_cluster: u32,
+    light_index_count: usize,
+}
+
+impl LightClusters {
+    pub fn new(display: &Display) -> Self {
+        let device = display.device();
+        let mut uniform = UniformBuffer::new(
+            device,
+            ClusterUniforms {
+                grid_size: CLUSTER_GRID_SIZE,
+                z_near: Camera::DEFAULT_Z_NEAR,
+                z_far: Camera::DEFAULT_Z_FAR,
+                ..Zeroable::zeroed()
+            },
+        );
+        uniform.update_with(display.queue(), |_| {});
+        let cluster_count = CLUSTER_GRID_SIZE.element_product() as usize;
+        // Buffers start out zeroed, so every cluster is empty until the first
+        // update.
+        let lights_buffer =
+            StorageBuffer::new(device, "cluster lights", std::mem::size_of::<LightRaw>());
+        let clusters_buffer = StorageBuffer::new(
+            device,
+            "clusters",
+            cluster_count * std::mem::size_of::<[u32; 2]>(),
+        );
+        let light_indices_buffer =
+            StorageBuffer::new(device, "cluster light indices", std::mem::size_of::<u32>());
+        let bind_group_layout = device.create_bind_group_layout(&group6::layout());
+        let bind_group = Self::create_bind_group(
+            device,
+            &bind_group_layout,
+            &uniform,
+            [&lights_buffer, &clusters_buffer, &light_indices_buffer],
+        );
+        Self {
+            lights: vec![],
+            show_heatmap: false,
+            uniform,
+            lights_buffer,
+            clusters_buffer,
+            light_indices_buffer,
+            bind_group_layout,
+            bind_group,
+            max_lights_per_cluster: 0,
+            light_index_count: 0,
+        }
+    }
+
+    fn create_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        uniform: &UniformBuffer<ClusterUniforms>,
+        storage_buffers: [&StorageBuffer; 3],
+    ) -> wgpu::BindGroup {
+        let mut entries = vec![wgpu::BindGroupEntry {
+            binding: 0,
+            resource: uniform.buffer().as_entire_binding(),
+        }];
+        for (i, storage) in storage_buffers.iter().enumerate() {
+            entries.push(wgpu::BindGroupEntry {
+                binding: i as u32 + 1,
+                resource: storage.buffer.as_entire_binding(),
+            });
+        }
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("light clusters bind group"),
+            layout,
+            entries: &entries,
+        })
+    }
+
+    pub fn bind_group_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.bind_group_layout
+    }
+
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+
+    /// Bins the lights into the clusters of the camera's view frustum and
+    /// uploads them.
+    pub fn update(&mut self, display: &Display, camera: &Camera) {
+        let bins = bin_lights(
+            &self.lights,
+            camera.view_matrix(),
+            camera.perspective_matrix(),
+            camera.z_near(),
+            camera.z_far(),
+            CLUSTER_GRID_SIZE,
+        );
+        // Clustered lights have no shadows, the frustum only matters for
+        // shadow map projections.
+        let frustum = Frustum::default();
+        let lights: Vec<LightRaw> = self
+            .lights
+            .iter()
+            .map(|l| l.to_raw(&frustum, None))
+            .collect();
+
+        let mut reallocated = self
+            .lights_buffer
+            .write(display, bytemuck::cast_slice(&lights));
+        reallocated |= self
+            .clusters_buffer
+            .write(display, bytemuck::cast_slice(&bins.clusters));
+        reallocated |= self
+            .light_indices_buffer
+            .write(display, bytemuck::cast_slice(&bins.light_indices));
+        let show_heatmap = self.show_heatmap;
+        self.uniform.update_with(display.queue(), |u| {
+            u.z_near = camera.z_near();
+            u.z_far = camera.z_far();
+            u.show_heatmap = show_heatmap as u32;
+        });
+        if reallocated {
+            self.bind_group = Self::create_bind_group(
+                display.device(),
+                &self.bind_group_layout,
+                &self.uniform,
+                [
+                    &self.lights_buffer,
+                    &self.clusters_buffer,
+                    &self.light_indices_buffer,
+                ],
+            );
+        }
+
+        self.max_lights_per_cluster = bins.clusters.iter().map(|c| c[1]).max().unwrap_or(0);
+        self.light_index_count = bins.light_indices.len();
+    }
+
+    pub fn debug_ui(&mut self, ui: &mut egui::Ui) {
+        ui.label(format!("Clustered lights: {}", self.lights.len()));
+        ui.label(format!(
+            "Max lights per cluster: {}",
+            self.max_lights_per_cluster
+        ));
+        ui.label(format!("Light indices: {}", self.light_index_count));
+        ui.checkbox(&mut self.show_heatmap, "Show cluster heatmap");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::vec3;
+
+    use super::*;
+    use crate::{
+        color::Color,
+        renderer::{
+            test_scene::{directional_light, LitScene, FLOOR_Y},
+            tonemapping::Tonemapper,
+        },
+    };
+
+    #[test]
+    fn test_bin_lights() {
+        let camera = Camera::new(vec3(0.0, 0.0, 0.0), 16.0 / 9.0);
+        let view = camera.view_matrix();
+        let lights = [
+            // In front of the camera, a bit to the right
+            Light::from(LightKind::Point {
+                position: view.inverse().transform_point3(vec3(1.0, 0.0, -5.0)),
+                radius: 0.5,
+            }),
+            // Behind the camera
+            Light::from(LightKind::Point {
+                position: view.inverse().transform_point3(vec3(0.0, 0.0, 5.0)),
+                radius: 1.0,
+            }),
+            Light::from(LightKind::Directional {
+                theta: 0.0,
+                phi: 0.0,
+            }),
+        ];
+        let bins = bin_lights(
+            &lights,
+            view,
+            camera.perspective_matrix(),
+            camera.z_near(),
+            camera.z_far(),
+            CLUSTER_GRID_SIZE,
+        );
+
+        assert_eq!(
+            bins.clusters.len(),
+            CLUSTER_GRID_SIZE.element_product() as usize
+        );
+        let lights_of = |cluster: &[u32; 2]| {
+            &bins.light_indices[cluster[0] as usize..(cluster[0] + cluster[1]) as usize]
+        };
+        // The directional light reaches every cluster.
+        assert!(bins.clusters.iter().all(|c| lights_of(c).contains(&2)));
+        assert!(!bins.light_indices.contains(&1));
+
+        let point_clusters: Vec<_> = (0..bins.clusters.len())
+            .filter(|&i| lights_of(&bins.clusters[i]).contains(&0))
+            .collect();
+        assert!(!point_clusters.is_empty());
+        let grid = CLUSTER_GRID_SIZE;
+        let slice = depth_slice(5.0, camera.z_near(), camera.z_far(), grid.z);
+        for i in point_clusters {
+            let i = i as u32;
+            let (x, z) = (i % grid.x, i / (grid.x * grid.y));
+            assert!(x >= grid.x / 2, "cluster {} is left of the light", x);
+            assert!(z.abs_diff(slice) <= 1, "slice {} is far from {}", z, slice);
+        }
+    }
+
+    #[test]
+    fn test_clustered_lights() {
+        let mut scene = LitScene::new(Light {
+            color: Color::from((1.0, 1.0, 1.0, 0.2)),
+            ..directional_light()
+        });
+        // A grid of small red, green and blue lights just above the floor,
+        // many more than the shadowed ones.
+        let colors = [Color::RED, Color::GREEN, Color::BLUE];
+        scene.clustered_lights = (0..64)
+            .map(|i| Light {
+                color: colors[i % colors.len()],
+                kind: LightKind::Point {
+                    position: vec3((i % 8) as f32 - 3.5, -1.0, (i / 8) as f32 - 5.0),
+                    radius: 1.0,
+                },
+            })
+            .collect();
+        scene.render();
+
+        // The floor right under each light takes its color.
+        let color = scene.capture_color(Tonemapper::None);
+        let [r, g, b] = [-2.5, -1.5, -0.5].map(|x| scene.pixel_at(&color, vec3(x, FLOOR_Y, 2.0)).0);
+        assert!(r[0] > r[1] + 100 && r[0] > r[2] + 100, "{:?}", r);
+        assert!(g[1] > g[0] + 100 && g[1] > g[2] + 100, "{:?}", g);
+        assert!(b[2] > b[0] + 100 && b[2] > b[1] + 100, "{:?}", b);
+    }
+}
@@ -3,7 +3,12 @@ use glam::{vec3, Mat4, Quat, Vec3, Vec4, Vec4Swizzles};
 
 use crate::{camera::Frustum, color::Color};
 
-use super::{shaders, state::ViewProjectionUniforms, UniformData};
+use super::{
+    shaders,
+    shadow_mapping::{MAX_CASCADES, MAX_LIGHTS, MAX_POINT_SHADOWS},
+    state::ViewProjectionUniforms,
+    UniformData,
+};
 
 pub type LightRaw = shaders::forward::types::Light;
 
@@ -28,8 +33,24 @@ pub enum LightKind {
         fov_degrees: f32,
         reach: f32,
     },
+    Point {
+        position: Vec3,
+        /// Distance at which the light has faded out completely.
+        radius: f32,
+    },
 }
 
+/// View direction and up vector of each cube face, in the order +x, -x, +y,
+/// -y, +z, -z which the shaders use to pick a face.
+const CUBE_FACES: [(Vec3, Vec3); 6] = [
+    (Vec3::X, Vec3::NEG_Y),
+    (Vec3::NEG_X, Vec3::NEG_Y),
+    (Vec3::Y, Vec3::Z),
+    (Vec3::NEG_Y, Vec3::NEG_Z),
+    (Vec3::Z, Vec3::NEG_Y),
+    (Vec3::NEG_Z, Vec3::NEG_Y),
+];
+
 impl LightKind {
     pub fn position(&self) -> Vec3 {
         match self {
@@ -41,7 +62,7 @@ impl LightKind {
                     .xyz()
                     .normalize()
             }
-            LightKind::Spot { position, .. } => *position,
+            LightKind::Spot { position, .. } | LightKind::Point { position, .. } => *position,
         }
     }
 
@@ -61,6 +82,9 @@ impl LightKind {
                 }
                 Mat4::look_to_rh(pos, *direction, up)
             }
+            // Point lights use one view per cube face, this is the one
+            // looking down.
+            LightKind::Point { .. } => Mat4::look_to_rh(pos, Vec3::NEG_Y, Vec3::NEG_Z),
         }
     }
 
@@ -91,20 +115,12 @@ impl Light {
 
         let projection = match self.kind {
             LightKind::Directional { .. } => {
-                let light_view_frustum = view_frustum.mul(view);
-                let (bounds_min, bounds_max) = light_view_frustum.aabb();
-                Mat4::orthographic_rh(
-                    bounds_min.x,
-                    bounds_max.x,
-                    bounds_min.y,
-                    bounds_max.y,
-                    -bounds_max.z,
-                    -bounds_min.z,
-                )
+                Self::fit_orthographic(view, view_frustum, view_frustum)
             }
             LightKind::Spot {
                 fov_degrees, reach, ..
             } => Mat4::perspective_rh(fov_degrees.to_radians(), 1.0, 0.1, reach),
+            LightKind::Point { radius, .. } => Self::cube_face_projection(radius),
         };
         ViewProjectionUniforms {
             view,
@@ -115,6 +131,78 @@ impl Light {
         }
     }
 
+    /// Orthographic projection covering `frustum` as seen from `view`. The
+    /// depth range covers `depth_frustum` instead, so that shadow casters
+    /// between the light and the frustum aren't clipped.
+    fn fit_orthographic(view: Mat4, frustum: &Frustum, depth_frustum: &Frustum) -> Mat4 {
+        let (bounds_min, bounds_max) = frustum.mul(view).aabb();
+        let (depth_min, depth_max) = depth_frustum.mul(view).aabb();
+        Mat4::orthographic_rh(
+            bounds_min.x,
+            bounds_max.x,
+            bounds_min.y,
+            bounds_max.y,
+            -depth_max.z,
+            -depth_min.z,
+        )
+    }
+
+    /// View/projection uniforms for each shadow cascade of a directional
+    /// light, `splits` being where each cascade ends as a fraction of the view
+    /// frustum's depth. `None` for other kinds of lights.
+    pub fn cascade_view_proj_uniforms(
+        &self,
+        view_frustum: &Frustum,
+        splits: &[f32],
+    ) -> Option<Vec<ViewProjectionUniforms>> {
+        let LightKind::Directional { .. } = self.kind else {
+            return None;
+        };
+        let camera_pos = self.kind.position();
+        let view = self.kind.view_matrix_from_position(camera_pos);
+        let inverse_view = view.inverse();
+        let mut near = 0.0;
+        Some(
+            splits
+                .iter()
+                .map(|&far| {
+                    let cascade = view_frustum.slice(near, far);
+                    near = far;
+                    ViewProjectionUniforms {
+                        view,
+                        projection: Self::fit_orthographic(view, &cascade, view_frustum),
+                        camera_pos,
+                        inverse_view,
+                        ..Default::default()
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn cube_face_projection(radius: f32) -> Mat4 {
+        Mat4::perspective_rh(std::f32::consts::FRAC_PI_2, 1.0, 0.1, radius)
+    }
+
+    /// View/projection uniforms for rendering each face of a point light's
+    /// cube shadow map, `None` for other kinds of lights.
+    pub fn cube_face_view_proj_uniforms(&self) -> Option<[ViewProjectionUniforms; 6]> {
+        let LightKind::Point { position, radius } = self.kind else {
+            return None;
+        };
+        let projection = Self::cube_face_projection(radius);
+        Some(CUBE_FACES.map(|(direction, up)| {
+            let view = Mat4::look_to_rh(position, direction, up);
+            ViewProjectionUniforms {
+                view,
+                projection,
+                camera_pos: position,
+                inverse_view: view.inverse(),
+                ..Default::default()
+            }
+        }))
+    }
+
     pub fn debug_ui(&mut self, ui: &mut egui::Ui) {
         match &mut self.kind {
             LightKind::Directional { theta, phi } => {
@@ -138,6 +226,13 @@ impl Light {
                 ui.add(egui::Slider::new(fov_degrees, 1.0..=180.0).text("fov"));
                 ui.add(egui::Slider::new(reach, 0.1..=180.0).text("reach"));
             }
+            LightKind::Point { position, radius } => {
+                ui.label("Position: ");
+                ui.add(egui::Slider::new(&mut position.x, -100.0..=100.0).text("x"));
+                ui.add(egui::Slider::new(&mut position.y, -100.0..=100.0).text("y"));
+                ui.add(egui::Slider::new(&mut position.z, -100.0..=100.0).text("z"));
+                ui.add(egui::Slider::new(radius, 0.1..=180.0).text("radius"));
+            }
         }
         let mut c = egui::Rgba::from_rgba_premultiplied(
             self.color.r,
@@ -156,28 +251,21 @@ impl Light {
         self.color = c.into();
     }
 
-    fn to_raw(&self, view_frustum: &Frustum) -> LightRaw {
+    pub(super) fn to_raw(&self, view_frustum: &Frustum, shadow_index: Option<usize>) -> LightRaw {
+        let shadow_index = shadow_index.map_or(-1, |i| i as i32);
         let position = self.kind.position();
         let view = self.kind.view_matrix_from_position(position);
 
         match self.kind {
             LightKind::Directional { .. } => {
-                let light_view_frustum = view_frustum.mul(view);
-                let (bounds_min, bounds_max) = light_view_frustum.aabb();
-                let projection = Mat4::orthographic_rh(
-                    bounds_min.x,
-                    bounds_max.x,
-                    bounds_min.y,
-                    bounds_max.y,
-                    -bounds_max.z,
-                    -bounds_min.z,
-                );
+                let projection = Self::fit_orthographic(view, view_frustum, view_frustum);
                 LightRaw {
                     kind: 0,
                     color: self.color.into(),
                     view_proj: projection * view,
                     position,
                     direction: -position.normalize(),
+                    shadow_index,
                     ..Default::default()
                 }
             }
@@ -197,9 +285,19 @@ impl Light {
                     direction: direction.normalize(),
                     radius: (fov_radians / 2.0).cos(),
                     reach,
+                    shadow_index,
                     ..Default::default()
                 }
             }
+            LightKind::Point { radius, .. } => LightRaw {
+                kind: 2,
+                color: self.color.into(),
+                view_proj: Self::cube_face_projection(radius) * view,
+                position,
+                reach: radius,
+                shadow_index,
+                ..Default::default()
+            },
         }
     }
 }
@@ -215,6 +313,15 @@ impl From<LightKind> for Light {
 
 pub type LightingUniformsRaw = shaders::forward::types::LightsUniform;
 
+/// A layer of the shadow map, rendered from the point of view of a spot light
+/// or of one cascade of a directional light.
+#[derive(Clone, Debug)]
+pub struct ShadowMapView {
+    pub light: usize,
+    pub layer: usize,
+    pub view_proj: ViewProjectionUniforms,
+}
+
 #[derive(Clone, Debug)]
 pub struct LightsUniform {
     pub lights: Vec<Light>,
@@ -222,7 +329,22 @@ pub struct LightsUniform {
     pub shadow_bias_minimum: f32,
     pub shadow_bias_factor: f32,
     pub shadow_blur_half_kernel_size: i32,
+    /// Flat ambient light, only used while there are no environment maps.
     pub ambient_color: Color,
+    /// Number of shadow cascades of directional lights, each taking a layer
+    /// of the shadow map.
+    pub cascade_count: usize,
+    /// Where each cascade ends, as a fraction of the view frustum's depth.
+    pub cascade_splits: [f32; MAX_CASCADES],
+    /// Fraction of each cascade, at its edges, which is blended with the next
+    /// one.
+    pub cascade_blend: f32,
+    /// Scale of the ambient light from the environment maps, see
+    /// `ForwardGeometryPass::set_environment`.
+    pub environment_intensity: f32,
+    /// Whether the environment maps are set, which then replace
+    /// `ambient_color`.
+    pub has_environment: bool,
 }
 
 impl Default for LightsUniform {
@@ -234,7 +356,80 @@ impl Default for LightsUniform {
             shadow_bias_factor: 0.025,
             shadow_blur_half_kernel_size: 4,
             ambient_color: Color::from(Vec4::splat(0.1)),
+            cascade_count: 3,
+            cascade_splits: [0.1, 0.3, 1.0, 1.0],
+            cascade_blend: 0.1,
+            environment_intensity: 1.0,
+            has_environment: false,
+        }
+    }
+}
+
+impl LightsUniform {
+    /// Pairs of light index and cube shadow map index, for the point lights
+    /// which get a shadow map.
+    pub fn point_shadow_slots(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.lights
+            .iter()
+            .enumerate()
+            .filter(|(_, light)| matches!(light.kind, LightKind::Point { .. }))
+            .map(|(i, _)| i)
+            .take(MAX_POINT_SHADOWS)
+            .enumerate()
+            .map(|(shadow_index, i)| (i, shadow_index))
+    }
+
+    /// Assigns the layers of the shadow map: one per spot light and one per
+    /// cascade of directional lights. Lights which don't fit in the remaining
+    /// layers get no shadows.
+    pub fn shadow_map_views(&self) -> Vec<ShadowMapView> {
+        let splits = &self.cascade_splits[..self.cascade_count.clamp(1, MAX_CASCADES)];
+        let mut views = vec![];
+        for (light, l) in self.lights.iter().enumerate() {
+            let view_projs = match l.kind {
+                LightKind::Directional { .. } => l
+                    .cascade_view_proj_uniforms(&self.view_frustum, splits)
+                    .unwrap(),
+                LightKind::Spot { .. } => vec![l.view_proj_uniforms(&self.view_frustum)],
+                LightKind::Point { .. } => continue,
+            };
+            if views.len() + view_projs.len() > MAX_LIGHTS {
+                continue;
+            }
+            for view_proj in view_projs {
+                views.push(ShadowMapView {
+                    light,
+                    layer: views.len(),
+                    view_proj,
+                });
+            }
+        }
+        views
+    }
+
+    pub fn debug_ui(&mut self, ui: &mut egui::Ui) {
+        ui.add(egui::Slider::new(&mut self.cascade_count, 1..=MAX_CASCADES).text("cascades"));
+        for i in 0..self.cascade_count {
+            let min = if i == 0 {
+                0.0
+            } else {
+                self.cascade_splits[i - 1]
+            };
+            ui.add(
+                egui::Slider::new(&mut self.cascade_splits[i], min..=1.0)
+                    .text(format!("cascade {} end", i)),
+            );
         }
+        // Keep the splits increasing when an earlier one is moved past the
+        // next.
+        for i in 1..MAX_CASCADES {
+            self.cascade_splits[i] = self.cascade_splits[i].max(self.cascade_splits[i - 1]);
+        }
+        ui.add(egui::Slider::new(&mut self.cascade_blend, 0.0..=0.5).text("cascade blend"));
+        ui.add(
+            egui::Slider::new(&mut self.environment_intensity, 0.0..=4.0)
+                .text("environment intensity"),
+        );
     }
 }
 
@@ -242,10 +437,25 @@ impl UniformData for LightsUniform {
     type Raw = LightingUniformsRaw;
 
     fn raw(&self) -> Self::Raw {
+        let mut shadow_indices = vec![None; self.lights.len()];
+        let mut shadow_view_projs = [Mat4::IDENTITY; MAX_LIGHTS];
+        for view in self.shadow_map_views() {
+            shadow_indices[view.light].get_or_insert(view.layer);
+            shadow_view_projs[view.layer] = view.view_proj.projection * view.view_proj.view;
+        }
+        let mut point_shadow_view_projs = [Mat4::IDENTITY; 6 * MAX_POINT_SHADOWS];
+        for (i, shadow_index) in self.point_shadow_slots() {
+            shadow_indices[i] = Some(shadow_index);
+            let faces = self.lights[i].cube_face_view_proj_uniforms().unwrap();
+            for (face, uniforms) in faces.iter().enumerate() {
+                point_shadow_view_projs[6 * shadow_index + face] =
+                    uniforms.projection * uniforms.view;
+            }
+        }
         LightingUniformsRaw {
             items: std::array::from_fn(|i| {
                 if i < self.lights.len() {
-                    self.lights[i].to_raw(&self.view_frustum)
+                    self.lights[i].to_raw(&self.view_frustum, shadow_indices[i])
                 } else {
                     Default::default()
                 }
@@ -255,6 +465,152 @@ impl UniformData for LightsUniform {
             shadow_bias_factor: self.shadow_bias_factor,
             shadow_blur_half_kernel_size: self.shadow_blur_half_kernel_size,
             ambient_color: self.ambient_color.into(),
+            shadow_view_projs,
+            point_shadow_view_projs,
+            cascade_count: self.cascade_count.clamp(1, MAX_CASCADES) as u32,
+            cascade_blend: self.cascade_blend,
+            environment_intensity: self.environment_intensity,
+            has_environment: self.has_environment as u32,
+            ..Zeroable::zeroed()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        camera::Camera,
+        renderer::{
+            test_scene::{brightness, LitScene, FLOOR_Y},
+            tonemapping::Tonemapper,
+        },
+    };
+
+    fn point_light(position: Vec3) -> Light {
+        Light::from(LightKind::Point {
+            position,
+            radius: 10.0,
+        })
+    }
+
+    #[test]
+    fn test_cube_face_view_projs() {
+        let position = vec3(1.0, 2.0, 3.0);
+        let faces = point_light(position)
+            .cube_face_view_proj_uniforms()
+            .unwrap();
+        // Each face looks straight at the point ahead of it and away from
+        // the one behind it.
+        for (uniforms, (direction, _)) in faces.iter().zip(CUBE_FACES) {
+            let view_proj = uniforms.projection * uniforms.view;
+            let ahead = view_proj.project_point3(position + 2.0 * direction);
+            assert!(ahead.x.abs() < 1e-5 && ahead.y.abs() < 1e-5, "{}", ahead);
+            assert!((0.0..1.0).contains(&ahead.z), "{}", ahead);
+            let behind = view_proj * (position - 2.0 * direction).extend(1.0);
+            assert!(behind.w < 0.0);
+        }
+
+        let spot_light = Light::from(LightKind::Spot {
+            position,
+            direction: Vec3::NEG_Y,
+            fov_degrees: 60.0,
+            reach: 10.0,
+        });
+        assert!(spot_light.cube_face_view_proj_uniforms().is_none());
+    }
+
+    #[test]
+    fn test_cascades() {
+        let frustum = Camera::new(vec3(0.0, 3.0, 6.0), 4.0 / 3.0).frustum();
+        let splits = [0.1, 0.3, 1.0];
+        let light = Light::from(LightKind::Directional {
+            theta: -60.0,
+            phi: 135.0,
+        });
+        let cascades = light.cascade_view_proj_uniforms(&frustum, &splits).unwrap();
+        assert_eq!(cascades.len(), splits.len());
+
+        let mut near = 0.0;
+        let mut previous_width = 0.0;
+        for (uniforms, &far) in cascades.iter().zip(&splits) {
+            let view_proj = uniforms.projection * uniforms.view;
+            // Each cascade covers its slice of the frustum, and the depth of
+            // all of it so casters in front of the slice aren't clipped.
+            let slice = frustum.slice(near, far);
+            assert!(slice
+                .nlt
+                .abs_diff_eq(frustum.nlt.lerp(frustum.flt, near), 1e-4));
+            assert!(slice
+                .frb
+                .abs_diff_eq(frustum.nrb.lerp(frustum.frb, far), 1e-4));
+            for corner in [slice.nlt, slice.nrb, slice.flt, slice.frb] {
+                let p = view_proj.project_point3(corner.truncate());
+                assert!(p.x.abs() <= 1.0 + 1e-4 && p.y.abs() <= 1.0 + 1e-4, "{}", p);
+            }
+            for corner in [frustum.nlt, frustum.frb] {
+                let z = view_proj.project_point3(corner.truncate()).z;
+                assert!((-1e-4..=1.0 + 1e-4).contains(&z), "{}", z);
+            }
+            // Further cascades cover more at a lower resolution.
+            let width = 2.0 / uniforms.projection.x_axis.x;
+            assert!(width > previous_width);
+            previous_width = width;
+            near = far;
         }
+
+        let spot_light = Light::from(LightKind::Spot {
+            position: Vec3::Y,
+            direction: Vec3::NEG_Y,
+            fov_degrees: 60.0,
+            reach: 10.0,
+        });
+        assert!(spot_light
+            .cascade_view_proj_uniforms(&frustum, &splits)
+            .is_none());
+        let uniform = LightsUniform {
+            lights: vec![light, spot_light],
+            view_frustum: frustum,
+            ..Default::default()
+        };
+        let layers: Vec<_> = uniform
+            .shadow_map_views()
+            .iter()
+            .map(|v| (v.light, v.layer))
+            .collect();
+        assert_eq!(layers, vec![(0, 0), (0, 1), (0, 2), (1, 3)]);
+    }
+
+    #[test]
+    fn test_point_shadow_slots() {
+        let mut lights = vec![Light::from(LightKind::Directional {
+            theta: 0.0,
+            phi: 0.0,
+        })];
+        lights.extend((0..MAX_POINT_SHADOWS + 1).map(|i| point_light(Vec3::splat(i as f32))));
+        let uniform = LightsUniform {
+            lights,
+            ..Default::default()
+        };
+        let slots: Vec<_> = uniform.point_shadow_slots().collect();
+        assert_eq!(slots, vec![(1, 0), (2, 1), (3, 2), (4, 3)]);
+    }
+
+    #[test]
+    fn test_point_light_radius() {
+        let point = vec3(-2.8, FLOOR_Y, 2.2);
+        let pixel = |radius: f32| {
+            let mut scene = LitScene::new(Light::from(LightKind::Point {
+                position: vec3(-1.0, 1.5, -2.5),
+                radius,
+            }));
+            scene.render();
+            let color = scene.capture_color(Tonemapper::None);
+            brightness(scene.pixel_at(&color, point))
+        };
+        // The floor point is about 5.7 away from the light, and only gets
+        // the ambient light once that's out of range.
+        let (inside, outside) = (pixel(10.0), pixel(4.0));
+        assert!(inside > outside + 100, "{} {}", inside, outside);
     }
 }
@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use bytemuck::Zeroable;
+use glam::{vec3, Vec3};
+
+use crate::color::Color;
+
+use super::{shaders, Texture, TextureRef, UniformBuffer};
+
+slotmap::new_key_type! {
+    pub struct MaterialRef;
+}
+
+pub type MaterialUniforms = shaders::pbr::types::MaterialUniforms;
+
+/// Metallic/roughness material parameters. Each factor is multiplied with
+/// its texture, empty texture slots use a neutral default (white, or a flat
+/// normal map).
+#[derive(Clone, Debug)]
+pub struct Material {
+    pub base_color: Color,
+    pub metallic: f32,
+    pub roughness: f32,
+    pub emissive: Vec3,
+    /// How much of the occlusion texture is applied, from 0 (none) to 1.
+    pub occlusion_strength: f32,
+    pub normal_scale: f32,
+    pub albedo_texture: Option<TextureRef>,
+    /// Tangent space normals.
+    pub normal_texture: Option<TextureRef>,
+    /// Roughness in the green channel and metallic in the blue one, as in
+    /// glTF.
+    pub metallic_roughness_texture: Option<TextureRef>,
+    pub occlusion_texture: Option<TextureRef>,
+    pub emissive_texture: Option<TextureRef>,
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self {
+            base_color: Color::WHITE,
+            metallic: 0.0,
+            roughness: 0.5,
+            emissive: Vec3::ZERO,
+            occlusion_strength: 1.0,
+            normal_scale: 1.0,
+            albedo_texture: None,
+            normal_texture: None,
+            metallic_roughness_texture: None,
+            occlusion_texture: None,
+            emissive_texture: None,
+        }
+    }
+}
+
+impl Material {
+    pub const TEXTURE_SLOTS: usize = 5;
+
+    pub fn uniforms(&self) -> MaterialUniforms {
+        MaterialUniforms {
+            base_color: self.base_color.into(),
+            emissive: self.emissive,
+            metallic: self.metallic,
+            roughness: self.roughness,
+            occlusion_strength: self.occlusion_strength,
+            normal_scale: self.normal_scale,
+            ..Zeroable::zeroed()
+        }
+    }
+
+    /// Texture slots in the order of their bindings.
+    pub fn textures(&self) -> [Option<TextureRef>; Self::TEXTURE_SLOTS] {
+        [
+            self.albedo_texture,
+            self.normal_texture,
+            self.metallic_roughness_texture,
+            self.occlusion_texture,
+            self.emissive_texture,
+        ]
+    }
+
+    /// Sliders for the scalar factors, the response reports whether any of
+    /// them `changed()`.
+    pub fn debug_ui(&mut self, ui: &mut egui::Ui) -> egui::Response {
+        ui.add(egui::Slider::new(&mut self.metallic, 0.0..=1.0).text("metallic"))
+            | ui.add(egui::Slider::new(&mut self.roughness, 0.0..=1.0).text("roughness"))
+    }
+}
+
+fn parse_floats<const N: usize>(s: &str) -> Option<[f32; N]> {
+    let mut values = s.split_whitespace().map(|v| v.parse().ok());
+    let parsed = std::array::from_fn(|_| values.next().flatten());
+    if parsed.iter().all(Option::is_some) {
+        Some(parsed.map(Option::unwrap))
+    } else {
+        None
+    }
+}
+
+/// Converts the colors of an MTL material, using the PBR extension's `Pm`,
+/// `Pr` and `Ke` parameters when present. Otherwise roughness is derived
+/// from the specular exponent.
+impl From<&tobj::Material> for Material {
+    fn from(m: &tobj::Material) -> Self {
+        let param = |name: &str| m.unknown_param.get(name).map(String::as_str);
+        let [r, g, b] = m.diffuse;
+        Self {
+            base_color: Color::from((r, g, b, 1.0)),
+            metallic: param("Pm").and_then(|v| v.parse().ok()).unwrap_or(0.0),
+            roughness: param("Pr")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or_else(|| (2.0 / (m.shininess.max(0.0) + 2.0)).sqrt()),
+            emissive: param("Ke")
+                .and_then(parse_floats)
+                .map(|[r, g, b]| vec3(r, g, b))
+                .unwrap_or(Vec3::ZERO),
+            ..Default::default()
+        }
+    }
+}
+
+/// A material with its uniforms and bind group. The bind group keeps the
+/// textures it was created with, so replacing one of them doesn't affect
+/// the material until it is replaced too.
+pub struct BoundMaterial {
+    material: Material,
+    uniforms: UniformBuffer<MaterialUniforms>,
+    bind_group: Arc<wgpu::BindGroup>,
+}
+
+impl BoundMaterial {
+    pub fn new(
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        layout: &wgpu::BindGroupLayout,
+        material: Material,
+        textures: [&Texture; Material::TEXTURE_SLOTS],
+    ) -> Self {
+        let mut uniforms = UniformBuffer::new(device, material.uniforms());
+        uniforms.update_with(queue, |_| {});
+        let mut entries = vec![wgpu::BindGroupEntry {
+            binding: 0,
+            resource: uniforms.buffer().as_entire_binding(),
+        }];
+        for (i, texture) in textures.iter().enumerate() {
+            entries.push(wgpu::BindGroupEntry {
+                binding: 2 * i as u32 + 1,
+                resource: wgpu::BindingResource::TextureView(&texture.view),
+            });
+            entries.push(wgpu::BindGroupEntry {
+                binding: 2 * i as u32 + 2,
+                resource: wgpu::BindingResource::Sampler(&texture.sampler),
+            });
+        }
+        let bind_group = Arc::new(device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("material bind group"),
+            layout,
+            entries: &entries,
+        }));
+        Self {
+            material,
+            uniforms,
+            bind_group,
+        }
+    }
+
+    pub fn material(&self) -> &Material {
+        &self.material
+    }
+
+    /// Updates the factors of the material, its textures are left as they
+    /// were.
+    pub fn update_factors(&mut self, queue: &wgpu::Queue, material: &Material) {
+        let uniforms = material.uniforms();
+        self.uniforms.update(queue, uniforms);
+        self.material = Material {
+            albedo_texture: self.material.albedo_texture,
+            normal_texture: self.material.normal_texture,
+            metallic_roughness_texture: self.material.metallic_roughness_texture,
+            occlusion_texture: self.material.occlusion_texture,
+            emissive_texture: self.material.emissive_texture,
+            ..material.clone()
+        };
+    }
+
+    pub fn bind_group(&self) -> &Arc<wgpu::BindGroup> {
+        &self.bind_group
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::{
+        test_scene::{brightness, spot_light, LitScene},
+        tonemapping::Tonemapper,
+    };
+
+    #[test]
+    fn test_from_mtl() {
+        let mut mtl = tobj::Material {
+            diffuse: [0.5, 0.25, 1.0],
+            shininess: 0.0,
+            ..Default::default()
+        };
+        let material = Material::from(&mtl);
+        assert_eq!(material.base_color.r, 0.5);
+        assert_eq!(material.roughness, 1.0);
+        assert_eq!(material.metallic, 0.0);
+
+        mtl.unknown_param.insert("Pm".into(), "0.75".into());
+        mtl.unknown_param.insert("Pr".into(), "0.2".into());
+        mtl.unknown_param.insert("Ke".into(), "1 0.5 0".into());
+        let material = Material::from(&mtl);
+        assert_eq!(material.metallic, 0.75);
+        assert_eq!(material.roughness, 0.2);
+        assert_eq!(material.emissive, vec3(1.0, 0.5, 0.0));
+    }
+
+    #[test]
+    fn test_metallic() {
+        let top = |metallic: f32| {
+            let mut scene = LitScene::new(spot_light());
+            scene.cube_material = Material {
+                base_color: Color::from((1.0, 0.75, 0.3, 1.0)),
+                metallic,
+                roughness: 0.25,
+                ..Default::default()
+            };
+            scene.render();
+            let color = scene.capture_color(Tonemapper::None);
+            scene.pixel_at(&color, vec3(0.0, 1.0, 0.0))
+        };
+        // Metals have no diffuse term, with the light's reflection going
+        // away from the camera the lit top face is about as dark as the
+        // unlit ones. The ambient light it still gets is tinted by the base
+        // color.
+        let (dielectric, metal) = (top(0.0), top(1.0));
+        assert!(
+            brightness(metal) + 100 < brightness(dielectric),
+            "{:?} {:?}",
+            metal,
+            dielectric
+        );
+        assert!(metal[0] > metal[1] && metal[1] > metal[2], "{:?}", metal);
+    }
+}
@@ -7,6 +7,8 @@ use wgpu::{util::DeviceExt, BufferUsages};
 
 use crate::geom::{cube, quad, BasicVertexData, ModelVertexData, VertexData};
 
+use super::{Display, RenderState};
+
 slotmap::new_key_type! {
     pub struct RawMeshRef;
 }
@@ -56,37 +58,192 @@ impl<V> DerefMut for Mesh<V> {
 pub struct UntypedMesh {
     pub vertex_buffer: wgpu::Buffer,
     pub index_buffer: wgpu::Buffer,
+    pub index_format: wgpu::IndexFormat,
     pub num_indices: u32,
 }
 
+impl UntypedMesh {
+    /// Empty mesh with room for `vertex_capacity` vertices and
+    /// `index_capacity` indices, to be filled by `update`.
+    pub fn dynamic<V: VertexData, I: MeshIndex>(
+        device: &wgpu::Device,
+        vertex_capacity: usize,
+        index_capacity: usize,
+    ) -> Self {
+        let create_buffer = |label, usage, size: usize| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size: aligned_size(size).max(wgpu::COPY_BUFFER_ALIGNMENT as usize) as u64,
+                usage: usage | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            })
+        };
+        Self {
+            vertex_buffer: create_buffer(
+                "Dynamic Vertex Buffer",
+                BufferUsages::VERTEX,
+                vertex_capacity * std::mem::size_of::<V>(),
+            ),
+            index_buffer: create_buffer(
+                "Dynamic Index Buffer",
+                BufferUsages::INDEX,
+                index_capacity * std::mem::size_of::<I>(),
+            ),
+            index_format: I::FORMAT,
+            num_indices: 0,
+        }
+    }
+
+    /// Replaces the vertices and indices, writing them in place when they fit.
+    /// Buffers that are too small, or were not created by `dynamic`, are
+    /// reallocated with room to grow. Returns whether that happened.
+    pub fn update<V: VertexData, I: MeshIndex>(
+        &mut self,
+        display: &Display,
+        verts: &[V],
+        indices: &[I],
+    ) -> bool {
+        let vertices_reallocated = write_growing(
+            display,
+            &mut self.vertex_buffer,
+            "Dynamic Vertex Buffer (resized)",
+            BufferUsages::VERTEX,
+            bytemuck::cast_slice(verts),
+        );
+        let indices_reallocated = write_growing(
+            display,
+            &mut self.index_buffer,
+            "Dynamic Index Buffer (resized)",
+            BufferUsages::INDEX,
+            bytemuck::cast_slice(indices),
+        );
+        self.index_format = I::FORMAT;
+        self.num_indices = indices.len() as _;
+        vertices_reallocated || indices_reallocated
+    }
+}
+
+/// Buffer writes need 4 byte alignment.
+fn aligned_size(size: usize) -> usize {
+    size.next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT as usize)
+}
+
+fn write_growing(
+    display: &Display,
+    buffer: &mut wgpu::Buffer,
+    label: &str,
+    usage: BufferUsages,
+    bytes: &[u8],
+) -> bool {
+    let usage = usage | BufferUsages::COPY_DST;
+    let size = aligned_size(bytes.len());
+    let reallocated = buffer.size() < size as u64 || !buffer.usage().contains(usage);
+    if reallocated {
+        *buffer = display.device().create_buffer(&wgpu::BufferDescriptor {
+            label: Some(label),
+            size: (size * 2).max(wgpu::COPY_BUFFER_ALIGNMENT as usize) as u64,
+            usage,
+            mapped_at_creation: false,
+        });
+    }
+    if size == bytes.len() {
+        display.queue().write_buffer(buffer, 0, bytes);
+    } else {
+        let mut padded = bytes.to_vec();
+        padded.resize(size, 0);
+        display.queue().write_buffer(buffer, 0, &padded);
+    }
+    reallocated
+}
+
+/// Element type of an index buffer.
+pub trait MeshIndex: bytemuck::Pod {
+    const FORMAT: wgpu::IndexFormat;
+}
+
+impl MeshIndex for u16 {
+    const FORMAT: wgpu::IndexFormat = wgpu::IndexFormat::Uint16;
+}
+
+impl MeshIndex for u32 {
+    const FORMAT: wgpu::IndexFormat = wgpu::IndexFormat::Uint32;
+}
+
+/// Indices of a mesh, `new` picks the smallest format that can address every
+/// vertex.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MeshIndices {
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl MeshIndices {
+    /// Fails if an index is out of range for `vertex_count` instead of
+    /// truncating it.
+    pub fn new(indices: Vec<u32>, vertex_count: usize) -> anyhow::Result<Self> {
+        Ok(match Self::u32(indices, vertex_count)? {
+            Self::U32(indices) if vertex_count <= u16::MAX as usize + 1 => {
+                Self::U16(indices.into_iter().map(|i| i as u16).collect())
+            }
+            indices => indices,
+        })
+    }
+
+    /// Like `new`, but keeps 32-bit indices however few vertices there are.
+    pub fn u32(indices: Vec<u32>, vertex_count: usize) -> anyhow::Result<Self> {
+        if let Some(index) = indices.iter().find(|&&i| i as usize >= vertex_count) {
+            anyhow::bail!("index {index} is out of range for {vertex_count} vertices");
+        }
+        Ok(Self::U32(indices))
+    }
+
+    pub fn format(&self) -> wgpu::IndexFormat {
+        match self {
+            Self::U16(_) => u16::FORMAT,
+            Self::U32(_) => u32::FORMAT,
+        }
+    }
+}
+
 pub trait LoadMesh {
     type Error: std::fmt::Debug;
 
-    fn load_mesh<V: VertexData>(
+    fn load_mesh<V: VertexData, I: MeshIndex>(
         &self,
         verts: &[V],
-        indices: &[u16],
+        indices: &[I],
     ) -> Result<Mesh<V>, Self::Error>;
 
+    fn load_mesh_with_indices<V: VertexData>(
+        &self,
+        verts: &[V],
+        indices: &MeshIndices,
+    ) -> Result<Mesh<V>, Self::Error> {
+        match indices {
+            MeshIndices::U16(indices) => self.load_mesh(verts, indices),
+            MeshIndices::U32(indices) => self.load_mesh(verts, indices),
+        }
+    }
+
     fn load_quad_mesh(&self) -> Mesh {
         self.load_mesh(
             &quad::verts(0., 0., 1., 1., (0., 0.), (1., 1.)),
-            &quad::INDICES,
+            quad::INDICES,
         )
         .unwrap()
     }
 
     fn load_cube_mesh(&self) -> Mesh<ModelVertexData> {
-        self.load_mesh(&cube::VERTICES, &cube::INDICES).unwrap()
+        self.load_mesh(&cube::VERTICES, cube::INDICES).unwrap()
     }
 }
 
 impl LoadMesh for wgpu::Device {
     type Error = ();
-    fn load_mesh<V: VertexData>(
+    fn load_mesh<V: VertexData, I: MeshIndex>(
         &self,
         verts: &[V],
-        indices: &[u16],
+        indices: &[I],
     ) -> Result<Mesh<V>, Self::Error> {
         Ok(Mesh {
             inner: UntypedMesh {
@@ -100,9 +257,89 @@ impl LoadMesh for wgpu::Device {
                     contents: bytemuck::cast_slice(indices),
                     usage: BufferUsages::INDEX,
                 }),
+                index_format: I::FORMAT,
                 num_indices: indices.len() as _,
             },
             _marker: PhantomData,
         })
     }
 }
+
+/// Geometry rebuilt on the CPU, e.g. every frame for debug shapes or
+/// whenever procedural geometry changes, then uploaded into a mesh of the
+/// `RenderState` that can be drawn like any other.
+pub struct DynamicMesh<V> {
+    pub vertices: Vec<V>,
+    pub indices: Vec<u32>,
+    mesh: MeshRef<V>,
+}
+
+impl<V: VertexData> DynamicMesh<V> {
+    pub fn new(state: &mut RenderState, display: &Display) -> Self {
+        Self {
+            vertices: vec![],
+            indices: vec![],
+            mesh: state.create_dynamic_mesh::<V>(display, 0, 0),
+        }
+    }
+
+    pub fn mesh(&self) -> MeshRef<V> {
+        self.mesh
+    }
+
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+    }
+
+    /// Writes the geometry to the mesh, draws recorded after this use it.
+    pub fn upload(&self, state: &mut RenderState, display: &Display) {
+        state.update_mesh(display, self.mesh, &self.vertices, &self.indices);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Point;
+
+    #[test]
+    fn test_mesh_indices() {
+        let indices = MeshIndices::new(vec![0, 1, 2], 3).unwrap();
+        assert_eq!(indices, MeshIndices::U16(vec![0, 1, 2]));
+        let indices = MeshIndices::new(vec![0, 65535, 65536], 70000).unwrap();
+        assert_eq!(indices.format(), wgpu::IndexFormat::Uint32);
+        assert_eq!(indices, MeshIndices::U32(vec![0, 65535, 65536]));
+        assert!(MeshIndices::new(vec![0, 1, 3], 3).is_err());
+        let indices = MeshIndices::u32(vec![0, 1, 2], 3).unwrap();
+        assert_eq!(indices, MeshIndices::U32(vec![0, 1, 2]));
+        assert!(MeshIndices::u32(vec![0, 1, 3], 3).is_err());
+    }
+
+    #[test]
+    fn test_update_mesh() {
+        let display = pollster::block_on(Display::headless(Point::new(4, 4)));
+        let device = display.device();
+        let verts = quad::verts(0., 0., 1., 1., (0., 0.), (1., 1.));
+        let vertex_size = std::mem::size_of::<BasicVertexData>() as u64;
+
+        let mut mesh = UntypedMesh::dynamic::<BasicVertexData, u16>(device, 4, 6);
+        assert_eq!(mesh.num_indices, 0);
+        assert!(!mesh.update(&display, &verts, quad::INDICES));
+        assert_eq!(mesh.num_indices, 6);
+        // Odd u16 counts get padded.
+        assert!(!mesh.update(&display, &verts[..3], &[0u16, 1, 2]));
+        assert_eq!(mesh.num_indices, 3);
+
+        let more: Vec<_> = verts.iter().cycle().take(8).copied().collect();
+        assert!(mesh.update(&display, &more, &[0u32, 1, 2, 4, 5, 6]));
+        assert_eq!(mesh.index_format, wgpu::IndexFormat::Uint32);
+        assert_eq!(mesh.vertex_buffer.size(), 16 * vertex_size);
+
+        // Static meshes are replaced by dynamic buffers.
+        let mut mesh = device.load_quad_mesh().inner;
+        assert!(mesh.update(&display, &verts, quad::INDICES));
+        assert!(mesh.vertex_buffer.usage().contains(BufferUsages::COPY_DST));
+        assert!(!mesh.update(&display, &verts, quad::INDICES));
+    }
+}
@@ -0,0 +1,199 @@
+//! Mip chain generation by repeatedly blitting each mip level into the next.
+
+use std::collections::HashMap;
+
+use super::shaders;
+
+/// Number of mip levels down to 1x1 for a texture of the given size.
+pub fn mip_level_count(width: u32, height: u32) -> u32 {
+    width.max(height).max(1).ilog2() + 1
+}
+
+/// Fills the mip levels of textures from their first level. Textures need
+/// `RENDER_ATTACHMENT` and `TEXTURE_BINDING` usage and a filterable color
+/// format. Pipelines are created on demand for each format.
+pub struct MipmapGenerator {
+    shader: wgpu::ShaderModule,
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline_layout: wgpu::PipelineLayout,
+    sampler: wgpu::Sampler,
+    pipelines: HashMap<wgpu::TextureFormat, wgpu::RenderPipeline>,
+}
+
+impl MipmapGenerator {
+    pub fn new(device: &wgpu::Device) -> Self {
+        let shader = device.create_shader_module(shaders::blit::DESCRIPTOR);
+        let bind_group_layout =
+            device.create_bind_group_layout(&shaders::blit::globals::group0::layout());
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("mipmap pipeline layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("mipmap sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        Self {
+            shader,
+            bind_group_layout,
+            pipeline_layout,
+            sampler,
+            pipelines: HashMap::default(),
+        }
+    }
+
+    fn create_pipeline(&mut self, device: &wgpu::Device, format: wgpu::TextureFormat) {
+        self.pipelines.entry(format).or_insert_with(|| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("mipmap pipeline"),
+                layout: Some(&self.pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &self.shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &self.shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(format.into())],
+                    compilation_options: Default::default(),
+                }),
+                primitive: Default::default(),
+                depth_stencil: None,
+                multisample: Default::default(),
+                multiview: None,
+                cache: None,
+            })
+        });
+    }
+
+    /// Records the blits filling mip levels 1 and up of every array layer of
+    /// `texture` from its first level.
+    pub fn generate(
+        &mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &wgpu::Texture,
+    ) {
+        let format = texture.format();
+        assert!(
+            !format.has_depth_aspect(),
+            "can't generate mipmaps for depth texture {:?}",
+            format
+        );
+        self.create_pipeline(device, format);
+        let pipeline = &self.pipelines[&format];
+        let view = |mip: u32, layer: u32| {
+            texture.create_view(&wgpu::TextureViewDescriptor {
+                label: Some("mipmap view"),
+                dimension: Some(wgpu::TextureViewDimension::D2),
+                base_mip_level: mip,
+                mip_level_count: Some(1),
+                base_array_layer: layer,
+                array_layer_count: Some(1),
+                ..Default::default()
+            })
+        };
+        for layer in 0..texture.depth_or_array_layers() {
+            for mip in 1..texture.mip_level_count() {
+                let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("mipmap bind group"),
+                    layout: &self.bind_group_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&view(mip - 1, layer)),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&self.sampler),
+                        },
+                    ],
+                });
+                let target = view(mip, layer);
+                let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("mipmap pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &target,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                pass.set_pipeline(pipeline);
+                pass.set_bind_group(0, &bind_group, &[]);
+                pass.draw(0..3, 0..1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geom::Point,
+        renderer::{Display, TextureBuilder},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_generate_mipmaps() {
+        assert_eq!(mip_level_count(1, 1), 1);
+        assert_eq!(mip_level_count(4, 4), 3);
+        assert_eq!(mip_level_count(640, 480), 10);
+
+        // Left half white and right half black, averaging to grey at 1x1.
+        let mut display = pollster::block_on(Display::headless(Point::new(4, 4)));
+        let pixels: Vec<u8> = (0..16)
+            .flat_map(|i| if i % 4 < 2 { [255; 4] } else { [0, 0, 0, 255] })
+            .collect();
+        let texture = TextureBuilder::labeled("mipmapped")
+            .with_format(wgpu::TextureFormat::Rgba8Unorm)
+            .with_usage(
+                wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::COPY_DST
+                    | wgpu::TextureUsages::COPY_SRC,
+            )
+            .with_mipmaps()
+            .from_raw_bytes(display.device(), display.queue(), &pixels, Point::new(4, 4));
+        assert_eq!(texture.texture.mip_level_count(), 3);
+        assert!(texture
+            .texture
+            .usage()
+            .contains(wgpu::TextureUsages::RENDER_ATTACHMENT));
+
+        let last_mip = TextureBuilder::labeled("last mip")
+            .with_format(wgpu::TextureFormat::Rgba8Unorm)
+            .with_usage(wgpu::TextureUsages::COPY_DST | wgpu::TextureUsages::COPY_SRC)
+            .build(display.device(), Point::new(1, 1));
+        let mut encoder = display.command_encoder();
+        encoder.copy_texture_to_texture(
+            wgpu::ImageCopyTexture {
+                mip_level: 2,
+                ..texture.texture.as_image_copy()
+            },
+            last_mip.texture.as_image_copy(),
+            last_mip.texture.size(),
+        );
+        display.queue().submit([encoder.finish()]);
+        let pixel = display
+            .capture_texture(&last_mip)
+            .unwrap()
+            .get_pixel(0, 0)
+            .0;
+        for c in &pixel[..3] {
+            assert!((126..=129).contains(c), "{:?}", pixel);
+        }
+        assert_eq!(pixel[3], 255);
+    }
+}
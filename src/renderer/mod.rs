@@ -1,29 +1,48 @@
+pub mod animation;
+pub mod bloom;
+pub mod capture;
+pub mod cubemap;
 pub mod deferred_lighting;
 pub mod display;
 pub mod egui;
 pub mod forward;
 pub mod geometry;
+pub mod golden;
+pub mod gltf_model;
+pub mod ibl;
 pub mod instance;
+pub mod light_clusters;
 pub mod lighting;
+pub mod material;
 pub mod mesh;
+pub mod mipmaps;
 pub mod model;
 pub mod pipeline;
+pub mod profiler;
 pub mod render_target;
 pub mod shader_type;
 pub mod shaders;
 pub mod shadow_mapping;
+pub mod skinning;
+pub mod skybox;
 pub mod ssao;
 pub mod ssao_from_depth;
 pub mod state;
+pub mod stats;
+pub mod tangents;
+#[cfg(test)]
+mod test_scene;
 pub mod text;
 pub mod texture;
+pub mod tonemapping;
 
 mod renderer;
 
 pub use display::*;
+pub use material::{Material, MaterialRef};
 pub use mesh::MeshRef;
 pub use pipeline::*;
 pub use render_target::*;
 pub use renderer::*;
-pub use state::{RenderPass, RenderState};
+pub use state::{DrawOrder, RenderPass, RenderState};
 pub use texture::*;
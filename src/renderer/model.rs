@@ -1,17 +1,185 @@
-use crate::geom::ModelVertexData;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use glam::Mat4;
+use image::RgbaImage;
+
+use crate::geom::{ModelVertexData, SkinnedVertexData};
 use crate::renderer::mesh::Mesh;
 
-use super::mesh::LoadMesh;
+use super::{
+    animation::{AnimationClip, Skeleton},
+    gltf_model,
+    mesh::{LoadMesh, MeshIndices},
+    tangents::generate_tangents,
+    Material, MaterialRef, MeshRef,
+};
 
 #[derive(Debug)]
 pub struct ModelMesh {
     pub mesh: Mesh<ModelVertexData>,
-    pub material: Option<tobj::Material>,
+    /// Index into `Model::materials`.
+    pub material: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct SkinnedModelMesh {
+    pub mesh: Mesh<SkinnedVertexData>,
+    /// Index into `Model::materials`.
+    pub material: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModelSkin {
+    pub skeleton: Skeleton,
+    /// The animations of the model applied to the joints of this skin, one for
+    /// each animation of the file so indices match between skins.
+    pub animations: Vec<AnimationClip>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextureSource {
+    /// An image file, resolved relative to the model file.
+    File(PathBuf),
+    /// Index into `Model::images`.
+    Embedded(usize),
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MaterialTextures {
+    pub albedo: Option<TextureSource>,
+    pub normal: Option<TextureSource>,
+    pub metallic_roughness: Option<TextureSource>,
+    pub occlusion: Option<TextureSource>,
+    pub emissive: Option<TextureSource>,
+}
+
+impl MaterialTextures {
+    pub fn from_mtl(m: &tobj::Material, base_dir: &Path) -> Self {
+        let resolve = |name: &str| {
+            // Some exporters write windows paths.
+            let path = base_dir.join(name.replace('\\', "/"));
+            (!name.is_empty()).then_some(TextureSource::File(path))
+        };
+        let param = |name: &str| m.unknown_param.get(name).map(String::as_str);
+        Self {
+            albedo: resolve(&m.diffuse_texture),
+            // `norm` is from the PBR extension, the bump and displacement
+            // maps of older files (Sponza's among them) are usually tangent
+            // space normal maps too.
+            normal: param("norm")
+                .or(Some(m.normal_texture.as_str()))
+                .filter(|t| !t.is_empty())
+                .or(param("map_Disp"))
+                .and_then(resolve),
+            emissive: param("map_Ke").and_then(resolve),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ModelMaterial {
+    pub material: Material,
+    pub textures: MaterialTextures,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModelNode {
+    pub name: Option<String>,
+    /// Relative to the parent node.
+    pub transform: Mat4,
+    /// Indices into `Model::meshes`.
+    pub meshes: Vec<usize>,
+    /// Indices into `Model::skinned_meshes`, drawn with `skin`.
+    pub skinned_meshes: Vec<usize>,
+    /// Index into `Model::skins`.
+    pub skin: Option<usize>,
+    /// Indices into `Model::nodes`.
+    pub children: Vec<usize>,
 }
 
 #[derive(Debug)]
 pub struct Model {
     pub meshes: Vec<ModelMesh>,
+    pub skinned_meshes: Vec<SkinnedModelMesh>,
+    pub materials: Vec<ModelMaterial>,
+    /// Images embedded in the model file.
+    pub images: Vec<RgbaImage>,
+    pub nodes: Vec<ModelNode>,
+    /// Indices into `Model::nodes` of the nodes without a parent.
+    pub roots: Vec<usize>,
+    pub skins: Vec<ModelSkin>,
+}
+
+impl Model {
+    /// Model space transform of every node reachable from the roots.
+    fn node_transforms(&self) -> Vec<(usize, Mat4)> {
+        let mut transforms = vec![];
+        let mut stack: Vec<_> = self.roots.iter().map(|&i| (i, Mat4::IDENTITY)).collect();
+        while let Some((index, parent_transform)) = stack.pop() {
+            let node = &self.nodes[index];
+            let transform = parent_transform * node.transform;
+            transforms.push((index, transform));
+            stack.extend(node.children.iter().map(|&child| (child, transform)));
+        }
+        transforms
+    }
+
+    /// Every mesh drawn by the node hierarchy along with its model space
+    /// transform. A mesh used by several nodes is listed once per node.
+    pub fn mesh_instances(&self) -> Vec<(usize, Mat4)> {
+        self.node_transforms()
+            .into_iter()
+            .flat_map(|(index, transform)| {
+                self.nodes[index]
+                    .meshes
+                    .iter()
+                    .map(move |&mesh| (mesh, transform))
+            })
+            .collect()
+    }
+
+    /// Every skinned mesh drawn by the node hierarchy along with its skin.
+    /// Skinned meshes are placed by their joints, the transforms of their
+    /// nodes don't apply.
+    pub fn skinned_mesh_instances(&self) -> Vec<(usize, usize)> {
+        self.node_transforms()
+            .into_iter()
+            .flat_map(|(index, _)| {
+                let node = &self.nodes[index];
+                node.skin
+                    .into_iter()
+                    .flat_map(|skin| node.skinned_meshes.iter().map(move |&mesh| (mesh, skin)))
+            })
+            .collect()
+    }
+}
+
+/// A mesh instance of a model uploaded through `RenderState::prepare_model`.
+#[derive(Debug, Clone, Copy)]
+pub struct PreparedModelMesh {
+    pub mesh: MeshRef<ModelVertexData>,
+    pub material: Option<MaterialRef>,
+    /// Model space transform from the node hierarchy.
+    pub transform: Mat4,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PreparedSkinnedMesh {
+    pub mesh: MeshRef<SkinnedVertexData>,
+    pub material: Option<MaterialRef>,
+    /// Index into `PreparedModel::skins`.
+    pub skin: usize,
+}
+
+/// A model uploaded through `RenderState::prepare_model`, with its mesh
+/// instances flattened out of the node hierarchy.
+#[derive(Debug, Clone)]
+pub struct PreparedModel {
+    pub meshes: Vec<PreparedModelMesh>,
+    pub skinned_meshes: Vec<PreparedSkinnedMesh>,
+    pub skins: Vec<ModelSkin>,
 }
 
 pub trait LoadModel {
@@ -20,69 +188,140 @@ pub trait LoadModel {
     fn load_model(&self, path: impl AsRef<str>) -> Result<Model, Self::Error>;
 }
 
+/// Loads Wavefront OBJ files, and glTF 2.0 files by their `.gltf` or `.glb`
+/// extension.
 impl LoadModel for wgpu::Device {
-    type Error = tobj::LoadError;
+    type Error = anyhow::Error;
 
     fn load_model(&self, path: impl AsRef<str>) -> Result<Model, Self::Error> {
-        let (models, mtls) = tobj::load_obj(path.as_ref(), &tobj::GPU_LOAD_OPTIONS)?;
-        let mtls = mtls?;
-        let mut meshes = vec![];
-        for m in models {
-            let raw_mesh = m.mesh;
-            let material = raw_mesh.material_id.map(|id| mtls[id].clone());
-            let vertices = raw_mesh
-                .positions
-                .iter()
-                .enumerate()
-                .step_by(3)
-                .map(|(i, _)| {
-                    glam::vec4(
-                        raw_mesh.positions[i],
-                        raw_mesh.positions[i + 1],
-                        raw_mesh.positions[i + 2],
-                        1.,
-                    )
-                })
-                .zip(
-                    raw_mesh
-                        .texcoords
-                        .iter()
-                        .enumerate()
-                        .step_by(2)
-                        .map(|(i, _)| glam::vec2(raw_mesh.texcoords[i], raw_mesh.texcoords[i + 1])),
-                )
-                .zip(
-                    raw_mesh
-                        .normals
-                        .iter()
-                        .enumerate()
-                        .step_by(3)
-                        .map(|(i, _)| {
-                            glam::vec3(
-                                raw_mesh.normals[i],
-                                raw_mesh.normals[i + 1],
-                                raw_mesh.normals[i + 2],
-                            )
-                        }),
+        let path = Path::new(path.as_ref());
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("gltf" | "glb") => gltf_model::load_gltf(self, path),
+            _ => load_obj(self, path),
+        }
+        .with_context(|| format!("failed to load model {}", path.display()))
+    }
+}
+
+fn load_obj(device: &wgpu::Device, path: &Path) -> anyhow::Result<Model> {
+    let (models, mtls) = tobj::load_obj(path, &tobj::GPU_LOAD_OPTIONS)?;
+    let base_dir = path.parent().unwrap_or(Path::new(""));
+    let materials = mtls?
+        .iter()
+        .map(|m| ModelMaterial {
+            material: Material::from(m),
+            textures: MaterialTextures::from_mtl(m, base_dir),
+        })
+        .collect();
+    let mut meshes = vec![];
+    for m in models {
+        let raw_mesh = m.mesh;
+        let material = raw_mesh.material_id;
+        let mut vertices = raw_mesh
+            .positions
+            .iter()
+            .enumerate()
+            .step_by(3)
+            .map(|(i, _)| {
+                glam::vec4(
+                    raw_mesh.positions[i],
+                    raw_mesh.positions[i + 1],
+                    raw_mesh.positions[i + 2],
+                    1.,
                 )
-                .map(|((pos, uv), normal)| ModelVertexData {
-                    position: pos.into(),
-                    tex_coords: uv.into(),
-                    normal: normal.into(),
-                    ..Default::default()
-                })
-                .collect::<Vec<_>>();
-            let indices = raw_mesh
-                .indices
-                // rewind faces to Ccw
-                .chunks_exact(3)
-                .map(|x| [x[0] as u16, x[2] as u16, x[1] as u16])
-                .flatten()
-                .collect::<Vec<_>>();
-            let mesh = self.load_mesh(&vertices, &indices).unwrap();
-            meshes.push(ModelMesh { mesh, material })
+            })
+            .zip(
+                raw_mesh
+                    .texcoords
+                    .iter()
+                    .enumerate()
+                    .step_by(2)
+                    .map(|(i, _)| glam::vec2(raw_mesh.texcoords[i], raw_mesh.texcoords[i + 1])),
+            )
+            .zip(
+                raw_mesh
+                    .normals
+                    .iter()
+                    .enumerate()
+                    .step_by(3)
+                    .map(|(i, _)| {
+                        glam::vec3(
+                            raw_mesh.normals[i],
+                            raw_mesh.normals[i + 1],
+                            raw_mesh.normals[i + 2],
+                        )
+                    }),
+            )
+            .map(|((pos, uv), normal)| ModelVertexData {
+                position: pos.into(),
+                tex_coords: uv.into(),
+                normal: normal.into(),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        let mut indices = raw_mesh.indices;
+        if !generate_tangents(&mut vertices, &mut indices) {
+            log::warn!("could not generate tangents for mesh {}", m.name);
         }
+        let indices = indices
+            // rewind faces to Ccw
+            .chunks_exact(3)
+            .flat_map(|x| [x[0], x[2], x[1]])
+            .collect::<Vec<_>>();
+        let indices = MeshIndices::new(indices, vertices.len())
+            .with_context(|| format!("invalid indices in mesh {}", m.name))?;
+        let mesh = device.load_mesh_with_indices(&vertices, &indices).unwrap();
+        meshes.push(ModelMesh { mesh, material })
+    }
+
+    // OBJ has no hierarchy, every mesh gets its own root.
+    let nodes: Vec<_> = (0..meshes.len())
+        .map(|i| ModelNode {
+            name: None,
+            transform: Mat4::IDENTITY,
+            meshes: vec![i],
+            skinned_meshes: vec![],
+            skin: None,
+            children: vec![],
+        })
+        .collect();
+    Ok(Model {
+        roots: (0..nodes.len()).collect(),
+        nodes,
+        meshes,
+        skinned_meshes: vec![],
+        materials,
+        images: vec![],
+        skins: vec![],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sponza_material_textures() {
+        let base_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("res/models/sponza");
+        let (mtls, _) = tobj::load_mtl(base_dir.join("sponza.mtl")).unwrap();
+        let textures: Vec<_> = mtls
+            .iter()
+            .map(|m| MaterialTextures::from_mtl(m, &base_dir))
+            .collect();
 
-        Ok(Model { meshes })
+        let bricks = mtls.iter().position(|m| m.name == "bricks").unwrap();
+        assert_eq!(
+            textures[bricks].albedo,
+            Some(TextureSource::File(
+                base_dir.join("textures/spnza_bricks_a_diff.tga")
+            ))
+        );
+        assert_eq!(
+            textures[bricks].normal,
+            Some(TextureSource::File(
+                base_dir.join("textures/spnza_bricks_a_ddn.tga")
+            ))
+        );
+        assert!(base_dir.join("textures/spnza_bricks_a_diff.tga").exists());
     }
 }
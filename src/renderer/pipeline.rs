@@ -40,6 +40,8 @@ impl<V, I> From<RawPipelineRef> for PipelineRef<V, I> {
 pub struct PipelineBuilder<'a> {
     state: &'a mut RenderState,
     label: Option<&'a str>,
+    vertex_entry_point: &'a str,
+    fragment_entry_point: &'a str,
     color_target_states: Vec<Option<wgpu::ColorTargetState>>,
     extra_bindings: Vec<BindingType>,
     extra_bind_group_layouts: Vec<&'a wgpu::BindGroupLayout>,
@@ -61,6 +63,7 @@ impl<'a> PipelineBuilder<'a> {
     pub const DEFAULT_BINDINGS: [BindingType; 3] = [
         BindingType::Texture {
             format: TextureBuilder::DEFAULT_FORMAT,
+            view_dimension: wgpu::TextureViewDimension::D2,
         }, // material texture
         BindingType::Uniform, // global
         BindingType::Uniform, // view/projection
@@ -70,6 +73,8 @@ impl<'a> PipelineBuilder<'a> {
         Self {
             state,
             label: None,
+            vertex_entry_point: "vs_main",
+            fragment_entry_point: "fs_main",
             color_target_states: vec![Some(wgpu::ColorTargetState {
                 format: TextureBuilder::DEFAULT_RENDER_FORMAT,
                 blend: Some(Self::DEFAULT_BLEND),
@@ -101,6 +106,20 @@ impl<'a> PipelineBuilder<'a> {
         }
     }
 
+    pub fn with_vertex_entry_point(self, vertex_entry_point: &'a str) -> Self {
+        Self {
+            vertex_entry_point,
+            ..self
+        }
+    }
+
+    pub fn with_fragment_entry_point(self, fragment_entry_point: &'a str) -> Self {
+        Self {
+            fragment_entry_point,
+            ..self
+        }
+    }
+
     pub fn with_color_target_states(
         self,
         color_target_states: Vec<Option<wgpu::ColorTargetState>>,
@@ -205,13 +224,13 @@ impl<'a> PipelineBuilder<'a> {
             layout: Some(&layout),
             vertex: wgpu::VertexState {
                 module: shader,
-                entry_point: Some("vs_main"),
+                entry_point: Some(self.vertex_entry_point),
                 buffers: &vertex_buffers,
                 compilation_options: Default::default(),
             },
             fragment: Some(wgpu::FragmentState {
                 module: shader,
-                entry_point: Some("fs_main"),
+                entry_point: Some(self.fragment_entry_point),
                 targets: &self.color_target_states,
                 compilation_options: Default::default(),
             }),
@@ -237,6 +256,7 @@ impl<'a> PipelineBuilder<'a> {
             multiview: None,
             cache: None,
         });
-        self.state.add_pipeline(self.key, pipeline)
+        let binds_material = self.extra_bindings.first() == Some(&BindingType::Material);
+        self.state.add_pipeline(self.key, pipeline, binds_material)
     }
 }
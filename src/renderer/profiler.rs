@@ -0,0 +1,297 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use super::Display;
+
+#[derive(Debug, Clone)]
+pub struct PassTiming {
+    pub name: String,
+    pub duration: Duration,
+}
+
+/// Query set and buffers for the timestamps of a single frame. Several of
+/// these are cycled through, since results are only read back once the GPU
+/// has finished with the frame.
+struct FrameQueries {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    readback_buffer: wgpu::Buffer,
+    pass_names: Vec<String>,
+    frame: usize,
+    /// Set while the readback buffer is being mapped, to `true` once mapped.
+    mapped: Option<Arc<AtomicBool>>,
+}
+
+impl FrameQueries {
+    fn new(device: &wgpu::Device) -> Self {
+        let size = GpuProfiler::MAX_QUERIES as u64 * wgpu::QUERY_SIZE as u64;
+        Self {
+            query_set: device.create_query_set(&wgpu::QuerySetDescriptor {
+                label: Some("gpu_profiler_queries"),
+                ty: wgpu::QueryType::Timestamp,
+                count: GpuProfiler::MAX_QUERIES,
+            }),
+            resolve_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("gpu_profiler_resolve_buffer"),
+                size,
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            }),
+            readback_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("gpu_profiler_readback_buffer"),
+                size,
+                usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+            pass_names: vec![],
+            frame: 0,
+            mapped: None,
+        }
+    }
+
+    fn query_count(&self) -> u32 {
+        self.pass_names.len() as u32 * 2
+    }
+}
+
+/// Measures how long each render pass takes on the GPU using timestamp
+/// queries. Results arrive a few frames late, as reading them back
+/// synchronously would stall the pipeline. Does nothing if the device
+/// doesn't support `TIMESTAMP_QUERY`.
+pub struct GpuProfiler {
+    pub enabled: bool,
+    frames: Vec<FrameQueries>,
+    current: Option<usize>,
+    frame_counter: usize,
+    timestamp_period: f32,
+    timings: Vec<PassTiming>,
+    timings_frame: usize,
+}
+
+impl GpuProfiler {
+    const FRAMES_IN_FLIGHT: usize = 3;
+    const MAX_PASSES_PER_FRAME: u32 = 32;
+    const MAX_QUERIES: u32 = Self::MAX_PASSES_PER_FRAME * 2;
+
+    pub fn new(display: &Display) -> Self {
+        let supported = display
+            .device()
+            .features()
+            .contains(wgpu::Features::TIMESTAMP_QUERY);
+        Self::with_timestamps(display, supported)
+    }
+
+    fn with_timestamps(display: &Display, supported: bool) -> Self {
+        let frames = if supported {
+            (0..Self::FRAMES_IN_FLIGHT)
+                .map(|_| FrameQueries::new(display.device()))
+                .collect()
+        } else {
+            vec![]
+        };
+        Self {
+            enabled: false,
+            frames,
+            current: None,
+            frame_counter: 0,
+            timestamp_period: display.queue().get_timestamp_period(),
+            timings: vec![],
+            timings_frame: 0,
+        }
+    }
+
+    pub fn is_supported(&self) -> bool {
+        !self.frames.is_empty()
+    }
+
+    /// Pass timings of the most recent frame which has been read back.
+    pub fn timings(&self) -> &[PassTiming] {
+        &self.timings
+    }
+
+    /// The frame number `timings` belong to.
+    pub fn timings_frame(&self) -> usize {
+        self.timings_frame
+    }
+
+    /// Reserves queries for a pass, returning the index of the first one.
+    pub(super) fn begin_pass(&mut self, name: &str) -> Option<u32> {
+        if !self.enabled || !self.is_supported() {
+            return None;
+        }
+        let index = match self.current {
+            Some(index) => index,
+            None => {
+                // The slot is still in flight if the GPU is lagging behind,
+                // in which case this frame isn't profiled.
+                let index = self.frame_counter % Self::FRAMES_IN_FLIGHT;
+                if self.frames[index].mapped.is_some() {
+                    return None;
+                }
+                self.frames[index].pass_names.clear();
+                self.frames[index].frame = self.frame_counter;
+                self.current = Some(index);
+                index
+            }
+        };
+        let frame = &mut self.frames[index];
+        if frame.pass_names.len() as u32 >= Self::MAX_PASSES_PER_FRAME {
+            log::warn!("too many render passes to profile, skipping {}", name);
+            return None;
+        }
+        let first_query = frame.query_count();
+        frame.pass_names.push(name.to_owned());
+        Some(first_query)
+    }
+
+    pub(super) fn timestamp_writes(
+        &self,
+        first_query: Option<u32>,
+    ) -> Option<wgpu::RenderPassTimestampWrites<'_>> {
+        Some(wgpu::RenderPassTimestampWrites {
+            query_set: &self.frames[self.current?].query_set,
+            beginning_of_pass_write_index: Some(first_query?),
+            end_of_pass_write_index: Some(first_query? + 1),
+        })
+    }
+
+    /// Resolves this frame's queries and collects the results of earlier
+    /// frames which are ready. Must be called after all of the frame's
+    /// passes have been submitted.
+    pub(super) fn end_frame(&mut self, display: &Display) {
+        if let Some(index) = self.current.take() {
+            let frame = &mut self.frames[index];
+            let query_count = frame.query_count();
+            let mut encoder = display.command_encoder();
+            encoder.resolve_query_set(&frame.query_set, 0..query_count, &frame.resolve_buffer, 0);
+            let size = query_count as u64 * wgpu::QUERY_SIZE as u64;
+            encoder.copy_buffer_to_buffer(
+                &frame.resolve_buffer,
+                0,
+                &frame.readback_buffer,
+                0,
+                size,
+            );
+            display.queue().submit([encoder.finish()]);
+
+            let mapped = Arc::new(AtomicBool::new(false));
+            frame.mapped = Some(mapped.clone());
+            frame
+                .readback_buffer
+                .slice(..size)
+                .map_async(wgpu::MapMode::Read, move |result| {
+                    if let Err(e) = result {
+                        log::error!("failed to read back gpu timestamps: {}", e);
+                    }
+                    mapped.store(true, Ordering::Release);
+                });
+        }
+        self.frame_counter += 1;
+
+        if self.frames.iter().all(|f| f.mapped.is_none()) {
+            return;
+        }
+        display.device().poll(wgpu::Maintain::Poll);
+        let mut ready: Vec<_> = self
+            .frames
+            .iter_mut()
+            .filter(|f| f.mapped.as_ref().is_some_and(|m| m.load(Ordering::Acquire)))
+            .collect();
+        ready.sort_by_key(|f| f.frame);
+        for frame in ready {
+            frame.mapped = None;
+            let size = frame.query_count() as u64 * wgpu::QUERY_SIZE as u64;
+            {
+                let data = frame.readback_buffer.slice(..size).get_mapped_range();
+                let timestamps: &[u64] = bytemuck::cast_slice(&data);
+                self.timings = frame
+                    .pass_names
+                    .iter()
+                    .zip(timestamps.chunks_exact(2))
+                    .map(|(name, t)| PassTiming {
+                        name: name.clone(),
+                        duration: Duration::from_nanos(
+                            (t[1].saturating_sub(t[0]) as f64 * self.timestamp_period as f64)
+                                as u64,
+                        ),
+                    })
+                    .collect();
+                self.timings_frame = frame.frame;
+            }
+            frame.readback_buffer.unmap();
+        }
+    }
+
+    pub fn debug_ui(&mut self, ui: &mut egui::Ui) {
+        if !self.is_supported() {
+            ui.label("GPU timestamps are not supported by this adapter");
+            return;
+        }
+        ui.add(egui::Checkbox::new(&mut self.enabled, "enabled"));
+        if !self.enabled {
+            return;
+        }
+        let ms = |d: Duration| d.as_secs_f32() * 1000.0;
+        egui::Grid::new("gpu_profiler_timings")
+            .num_columns(2)
+            .show(ui, |ui| {
+                for timing in &self.timings {
+                    ui.label(&timing.name);
+                    ui.label(format!("{:.3}ms", ms(timing.duration)));
+                    ui.end_row();
+                }
+                ui.label("total");
+                ui.label(format!(
+                    "{:.3}ms",
+                    ms(self.timings.iter().map(|t| t.duration).sum())
+                ));
+                ui.end_row();
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geom::Point,
+        renderer::{state::ViewProjectionUniforms, RenderState, RenderTarget},
+        transform::Transform2D,
+    };
+
+    #[test]
+    fn test_without_timestamp_queries() {
+        let size = Point::new(4, 4);
+        let (display, mut state) = RenderState::headless(size);
+        state.profiler = GpuProfiler::with_timestamps(&display, false);
+        state.profiler.enabled = true;
+        let framebuffer = state.display_framebuffer(&display).unwrap();
+        let (color, depth) = (framebuffer.color, framebuffer.depth.unwrap());
+
+        // Passes render as usual, without any timings.
+        for _ in 0..=GpuProfiler::FRAMES_IN_FLIGHT {
+            state
+                .render_pass(
+                    &display,
+                    "Profiled Pass",
+                    &[RenderTarget::TextureRef(color)],
+                    Some(RenderTarget::TextureRef(depth)),
+                    &ViewProjectionUniforms::default(),
+                    |r| r.draw_quad(None, Transform2D::default()),
+                )
+                .submit();
+            state.after_frame(&display);
+        }
+        assert!(!state.profiler.is_supported());
+        assert!(state.profiler.timings().is_empty());
+        assert_eq!(
+            state.draw_stats().pass("Profiled Pass").unwrap().draw_calls,
+            1
+        );
+    }
+}
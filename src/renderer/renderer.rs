@@ -2,7 +2,7 @@ use super::instance::InstanceRenderData;
 use super::state::BindingType;
 use super::{MeshRef, PipelineRef, TextureRef};
 use crate::{color::*, geom::*, transform::*};
-use glam::Mat4;
+use glam::{Mat4, Vec3};
 use std::fmt::{Debug, Formatter};
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
@@ -19,6 +19,7 @@ impl<V, I: InstanceData> RenderData<V, I> {
     pub fn for_instance(self, instance: I) -> InstanceRenderData<V, I> {
         InstanceRenderData {
             texture: Some(self.texture),
+            material: None,
             pipeline: self.pipeline,
             mesh: self.mesh,
             instance,
@@ -30,7 +31,19 @@ pub trait VertexLayout {
     fn vertex_layout() -> VertexBufferLayout<'static>;
 }
 
-pub trait InstanceData: Copy + Sized + VertexLayout + bytemuck::Pod {}
+pub trait InstanceData: Copy + Sized + VertexLayout + bytemuck::Pod {
+    /// World space position used to order transparent instances when draws
+    /// are sorted.
+    fn sort_position(&self) -> Vec3 {
+        Vec3::ZERO
+    }
+
+    /// Transparent instances are drawn after opaque ones, back to front,
+    /// when draws are sorted.
+    fn is_transparent(&self) -> bool {
+        false
+    }
+}
 
 impl VertexLayout for () {
     fn vertex_layout() -> VertexBufferLayout<'static> {
@@ -52,7 +65,15 @@ pub struct BasicInstanceData {
     pub transform: Mat4,
 }
 
-impl InstanceData for BasicInstanceData {}
+impl InstanceData for BasicInstanceData {
+    fn sort_position(&self) -> Vec3 {
+        self.transform.w_axis.truncate()
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.tint.a < 1.0
+    }
+}
 
 impl BasicInstanceData {
     const ATTRIBUTES: [VertexAttribute; 7] = vertex_attr_array![
@@ -112,7 +133,15 @@ pub struct InstanceDataWithNormalMatrix {
     pub normal_matrix: Mat4,
 }
 
-impl InstanceData for InstanceDataWithNormalMatrix {}
+impl InstanceData for InstanceDataWithNormalMatrix {
+    fn sort_position(&self) -> Vec3 {
+        self.transform.w_axis.truncate()
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.tint.a < 1.0
+    }
+}
 
 impl InstanceDataWithNormalMatrix {
     const ATTRIBUTES: [VertexAttribute; 11] = vertex_attr_array![
@@ -176,7 +205,7 @@ pub const DEFAULT_TEXTURE_DATA: [u8; 16] = [
     255, 255, 255, 255,
 ];
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct OffscreenFramebuffer {
     pub color: TextureRef,
     pub depth: Option<TextureRef>,
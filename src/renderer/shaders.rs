@@ -79,14 +79,25 @@ pub mod ssao_from_depth {
             pub const NAME: &'static str = "depth_buffer";
             pub const GROUP: u32 = 3u32;
             pub const BINDING: u32 = 0u32;
-        }
-        ///Information about the `depth_buffer_sampler` global variable within this shader module.
-        pub mod depth_buffer_sampler {
-            #[allow(unused)]
-            use super::*;
-            pub const NAME: &'static str = "depth_buffer_sampler";
-            pub const GROUP: u32 = 3u32;
-            pub const BINDING: u32 = 1u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("depth_buffer"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
         }
         ///Information about the `kernel` global variable within this shader module.
         pub mod kernel {
@@ -196,37 +207,6 @@ pub mod ssao_from_depth {
                 }
             }
         }
-        ///Contains the following bindings: depth_buffer, depth_buffer_sampler
-        pub mod group3 {
-            #[allow(unused)]
-            use super::*;
-            pub const GROUP: u32 = 3u32;
-            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
-                ::wgpu::BindGroupLayoutDescriptor {
-                    label: Some("group3"),
-                    entries: &[
-                        ::wgpu::BindGroupLayoutEntry {
-                            binding: 0u32,
-                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
-                            ty: wgpu::BindingType::Texture {
-                                multisampled: false,
-                                view_dimension: ::wgpu::TextureViewDimension::D2,
-                                sample_type: ::wgpu::TextureSampleType::Depth,
-                            },
-                            count: None,
-                        },
-                        ::wgpu::BindGroupLayoutEntry {
-                            binding: 1u32,
-                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
-                            ty: ::wgpu::BindingType::Sampler(
-                                ::wgpu::SamplerBindingType::Filtering,
-                            ),
-                            count: None,
-                        },
-                    ],
-                }
-            }
-        }
     }
     #[allow(unused)]
     ///Information about the constants within the module, exposed as constants and functions.
@@ -250,12 +230,12 @@ pub mod ssao_from_depth {
         pub mod vs_main {
             pub const NAME: &'static str = "vs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct Kernel {\n    items: array<vec4<f32>, 64>,\n    radius: f32,\n    bias: f32,\n    noise_texture_scale: vec2<f32>,\n    aspect_ratio: f32,\n    tan_half_fov: f32,\n    inverse_proj: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nconst KERNEL_SIZE: u32 = 64u;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar depth_buffer: texture_depth_2d;\n@group(3) @binding(1) \nvar depth_buffer_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> kernel: Kernel;\n@group(5) @binding(0) \nvar ssao_noise: texture_2d<f32>;\n@group(5) @binding(1) \nvar ssao_noise_sampler: sampler;\n\nfn reconstructPosition(coords: vec2<f32>) -> vec3<f32> {\n    let x = ((coords.x * 2f) - 1f);\n    let y = (((1f - coords.y) * 2f) - 1f);\n    let z = textureSample(depth_buffer, depth_buffer_sampler, coords);\n    let position_s = vec4<f32>(x, y, z, 1f);\n    let _e20 = kernel.inverse_proj;\n    let position_v = (_e20 * position_s);\n    return (position_v.xyz / vec3(position_v.w));\n}\n\nfn normalFromDepth(center: vec3<f32>, coords_1: vec2<f32>) -> vec3<f32> {\n    var y1_: vec3<f32>;\n    var y2_: vec3<f32>;\n    var x1_: vec3<f32>;\n    var x2_: vec3<f32>;\n\n    let _e6 = global_uniforms.screen_size;\n    let _e9 = reconstructPosition((coords_1 + (vec2<f32>(0f, 1f) / _e6)));\n    let _e15 = global_uniforms.screen_size;\n    let _e18 = reconstructPosition((coords_1 + (vec2<f32>(0f, -1f) / _e15)));\n    y1_ = _e9;\n    y2_ = center;\n    if (abs((_e18.z - center.z)) < abs((_e9.z - center.z))) {\n        y1_ = center;\n        y2_ = _e18;\n    }\n    let _e36 = global_uniforms.screen_size;\n    let _e39 = reconstructPosition((coords_1 + (vec2<f32>(-1f, 0f) / _e36)));\n    let _e45 = global_uniforms.screen_size;\n    let _e48 = reconstructPosition((coords_1 + (vec2<f32>(1f, 0f) / _e45)));\n    x1_ = _e39;\n    x2_ = center;\n    if (abs((_e48.z - center.z)) < abs((_e39.z - center.z))) {\n        x1_ = center;\n        x2_ = _e48;\n    }\n    let _e60 = x2_;\n    let _e61 = x1_;\n    let _e63 = y2_;\n    let _e64 = y1_;\n    return normalize(cross((_e60 - _e61), (_e63 - _e64)));\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    let _e32 = out;\n    return _e32;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct Kernel {\n    items: array<vec4<f32>, 64>,\n    radius: f32,\n    bias: f32,\n    noise_texture_scale: vec2<f32>,\n    aspect_ratio: f32,\n    tan_half_fov: f32,\n    inverse_proj: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nconst KERNEL_SIZE: u32 = 64u;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar depth_buffer: texture_2d<f32>;\n@group(4) @binding(0) \nvar<uniform> kernel: Kernel;\n@group(5) @binding(0) \nvar ssao_noise: texture_2d<f32>;\n@group(5) @binding(1) \nvar ssao_noise_sampler: sampler;\n\nfn load_depth(coords: vec2<f32>) -> f32 {\n    let _e1 = textureDimensions(depth_buffer);\n    let size = vec2<i32>(_e1);\n    let texel = clamp(vec2<i32>((coords * vec2<f32>(size))), vec2(0i), (size - vec2(1i)));\n    let _e15 = textureLoad(depth_buffer, texel, 0i);\n    return _e15.x;\n}\n\nfn reconstructPosition(coords_1: vec2<f32>) -> vec3<f32> {\n    let x = ((coords_1.x * 2f) - 1f);\n    let y = (((1f - coords_1.y) * 2f) - 1f);\n    let _e13 = load_depth(coords_1);\n    let position_s = vec4<f32>(x, y, _e13, 1f);\n    let _e18 = kernel.inverse_proj;\n    let position_v = (_e18 * position_s);\n    return (position_v.xyz / vec3(position_v.w));\n}\n\nfn normalFromDepth(center: vec3<f32>, coords_2: vec2<f32>) -> vec3<f32> {\n    var y1_: vec3<f32>;\n    var y2_: vec3<f32>;\n    var x1_: vec3<f32>;\n    var x2_: vec3<f32>;\n\n    let _e6 = global_uniforms.screen_size;\n    let _e9 = reconstructPosition((coords_2 + (vec2<f32>(0f, 1f) / _e6)));\n    let _e15 = global_uniforms.screen_size;\n    let _e18 = reconstructPosition((coords_2 + (vec2<f32>(0f, -1f) / _e15)));\n    y1_ = _e9;\n    y2_ = center;\n    if (abs((_e18.z - center.z)) < abs((_e9.z - center.z))) {\n        y1_ = center;\n        y2_ = _e18;\n    }\n    let _e36 = global_uniforms.screen_size;\n    let _e39 = reconstructPosition((coords_2 + (vec2<f32>(-1f, 0f) / _e36)));\n    let _e45 = global_uniforms.screen_size;\n    let _e48 = reconstructPosition((coords_2 + (vec2<f32>(1f, 0f) / _e45)));\n    x1_ = _e39;\n    x2_ = center;\n    if (abs((_e48.z - center.z)) < abs((_e39.z - center.z))) {\n        x1_ = center;\n        x2_ = _e48;\n    }\n    let _e60 = x2_;\n    let _e61 = x1_;\n    let _e63 = y2_;\n    let _e64 = y1_;\n    return normalize(cross((_e60 - _e61), (_e63 - _e64)));\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    let _e32 = out;\n    return _e32;\n}\n";
         }
         pub mod fs_main {
             pub const NAME: &'static str = "fs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct Kernel {\n    items: array<vec4<f32>, 64>,\n    radius: f32,\n    bias: f32,\n    noise_texture_scale: vec2<f32>,\n    aspect_ratio: f32,\n    tan_half_fov: f32,\n    inverse_proj: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nconst KERNEL_SIZE: u32 = 64u;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar depth_buffer: texture_depth_2d;\n@group(3) @binding(1) \nvar depth_buffer_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> kernel: Kernel;\n@group(5) @binding(0) \nvar ssao_noise: texture_2d<f32>;\n@group(5) @binding(1) \nvar ssao_noise_sampler: sampler;\n\nfn reconstructPosition(coords: vec2<f32>) -> vec3<f32> {\n    let x = ((coords.x * 2f) - 1f);\n    let y = (((1f - coords.y) * 2f) - 1f);\n    let z = textureSample(depth_buffer, depth_buffer_sampler, coords);\n    let position_s = vec4<f32>(x, y, z, 1f);\n    let _e20 = kernel.inverse_proj;\n    let position_v = (_e20 * position_s);\n    return (position_v.xyz / vec3(position_v.w));\n}\n\nfn normalFromDepth(center: vec3<f32>, coords_1: vec2<f32>) -> vec3<f32> {\n    var y1_: vec3<f32>;\n    var y2_: vec3<f32>;\n    var x1_: vec3<f32>;\n    var x2_: vec3<f32>;\n\n    let _e6 = global_uniforms.screen_size;\n    let _e9 = reconstructPosition((coords_1 + (vec2<f32>(0f, 1f) / _e6)));\n    let _e15 = global_uniforms.screen_size;\n    let _e18 = reconstructPosition((coords_1 + (vec2<f32>(0f, -1f) / _e15)));\n    y1_ = _e9;\n    y2_ = center;\n    if (abs((_e18.z - center.z)) < abs((_e9.z - center.z))) {\n        y1_ = center;\n        y2_ = _e18;\n    }\n    let _e36 = global_uniforms.screen_size;\n    let _e39 = reconstructPosition((coords_1 + (vec2<f32>(-1f, 0f) / _e36)));\n    let _e45 = global_uniforms.screen_size;\n    let _e48 = reconstructPosition((coords_1 + (vec2<f32>(1f, 0f) / _e45)));\n    x1_ = _e39;\n    x2_ = center;\n    if (abs((_e48.z - center.z)) < abs((_e39.z - center.z))) {\n        x1_ = center;\n        x2_ = _e48;\n    }\n    let _e60 = x2_;\n    let _e61 = x1_;\n    let _e63 = y2_;\n    let _e64 = y1_;\n    return normalize(cross((_e60 - _e61), (_e63 - _e64)));\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) f32 {\n    var view_pos: vec3<f32>;\n    var view_space_normal: vec3<f32>;\n    var random_vec: vec3<f32>;\n    var occlusion: f32 = 0f;\n    var i: i32 = 0i;\n    var sample: vec3<f32>;\n    var offset: vec4<f32>;\n    var sample_depth: f32;\n    var range_check: f32;\n\n    let _e4 = reconstructPosition(in.tex_coords);\n    view_pos = _e4;\n    let _e6 = view_pos;\n    let _e8 = normalFromDepth(_e6, in.tex_coords);\n    view_space_normal = _e8;\n    let _e14 = kernel.noise_texture_scale;\n    let _e18 = textureSample(ssao_noise, ssao_noise_sampler, (_e14 * in.tex_coords.xy));\n    random_vec = _e18.xyz;\n    let _e21 = random_vec;\n    let _e22 = view_space_normal;\n    let _e23 = random_vec;\n    let _e24 = view_space_normal;\n    let tangent = normalize((_e21 - (_e22 * dot(_e23, _e24))));\n    let _e29 = view_space_normal;\n    let bitangent = cross(_e29, tangent);\n    let _e31 = view_space_normal;\n    let TBN = mat3x3<f32>(tangent, bitangent, _e31);\n    loop {\n        let _e34 = i;\n        if (_e34 < 64i) {\n        } else {\n            break;\n        }\n        {\n            let _e37 = view_pos;\n            let _e41 = kernel.radius;\n            let _e45 = i;\n            let _e47 = kernel.items[_e45];\n            sample = (_e37.xyz + ((_e41 * TBN) * _e47.xyz));\n            let _e54 = view_proj_uniforms.projection;\n            let _e55 = sample;\n            offset = (_e54 * vec4<f32>(_e55, 1f));\n            let _e62 = offset.w;\n            let _e63 = offset.x;\n            offset.x = (_e63 / _e62);\n            let _e67 = offset.w;\n            let _e68 = offset.y;\n            offset.y = (_e68 / _e67);\n            let _e72 = offset.x;\n            offset.x = ((_e72 * 0.5f) + 0.5f);\n            let _e79 = offset.y;\n            offset.y = ((_e79 * 0.5f) + 0.5f);\n            let _e86 = offset.y;\n            offset.y = (1f - _e86);\n            let _e89 = offset;\n            let _e91 = reconstructPosition(_e89.xy);\n            sample_depth = _e91.z;\n            let _e98 = kernel.radius;\n            let _e100 = view_pos.z;\n            let _e101 = sample_depth;\n            range_check = smoothstep(0f, 1f, (_e98 / abs((_e100 - _e101))));\n            let _e107 = sample_depth;\n            let _e109 = sample.z;\n            let _e112 = kernel.bias;\n            if (_e107 >= (_e109 + _e112)) {\n                let _e116 = range_check;\n                let _e117 = range_check;\n                let _e119 = occlusion;\n                occlusion = (_e119 + (_e116 * _e117));\n            }\n        }\n        continuing {\n            let _e122 = i;\n            i = (_e122 + 1i);\n        }\n    }\n    let _e124 = occlusion;\n    occlusion = (1f - (_e124 / 64f));\n    let _e129 = occlusion;\n    occlusion = pow(_e129, 2f);\n    let _e132 = occlusion;\n    return _e132;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct Kernel {\n    items: array<vec4<f32>, 64>,\n    radius: f32,\n    bias: f32,\n    noise_texture_scale: vec2<f32>,\n    aspect_ratio: f32,\n    tan_half_fov: f32,\n    inverse_proj: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nconst KERNEL_SIZE: u32 = 64u;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar depth_buffer: texture_2d<f32>;\n@group(4) @binding(0) \nvar<uniform> kernel: Kernel;\n@group(5) @binding(0) \nvar ssao_noise: texture_2d<f32>;\n@group(5) @binding(1) \nvar ssao_noise_sampler: sampler;\n\nfn load_depth(coords: vec2<f32>) -> f32 {\n    let _e1 = textureDimensions(depth_buffer);\n    let size = vec2<i32>(_e1);\n    let texel = clamp(vec2<i32>((coords * vec2<f32>(size))), vec2(0i), (size - vec2(1i)));\n    let _e15 = textureLoad(depth_buffer, texel, 0i);\n    return _e15.x;\n}\n\nfn reconstructPosition(coords_1: vec2<f32>) -> vec3<f32> {\n    let x = ((coords_1.x * 2f) - 1f);\n    let y = (((1f - coords_1.y) * 2f) - 1f);\n    let _e13 = load_depth(coords_1);\n    let position_s = vec4<f32>(x, y, _e13, 1f);\n    let _e18 = kernel.inverse_proj;\n    let position_v = (_e18 * position_s);\n    return (position_v.xyz / vec3(position_v.w));\n}\n\nfn normalFromDepth(center: vec3<f32>, coords_2: vec2<f32>) -> vec3<f32> {\n    var y1_: vec3<f32>;\n    var y2_: vec3<f32>;\n    var x1_: vec3<f32>;\n    var x2_: vec3<f32>;\n\n    let _e6 = global_uniforms.screen_size;\n    let _e9 = reconstructPosition((coords_2 + (vec2<f32>(0f, 1f) / _e6)));\n    let _e15 = global_uniforms.screen_size;\n    let _e18 = reconstructPosition((coords_2 + (vec2<f32>(0f, -1f) / _e15)));\n    y1_ = _e9;\n    y2_ = center;\n    if (abs((_e18.z - center.z)) < abs((_e9.z - center.z))) {\n        y1_ = center;\n        y2_ = _e18;\n    }\n    let _e36 = global_uniforms.screen_size;\n    let _e39 = reconstructPosition((coords_2 + (vec2<f32>(-1f, 0f) / _e36)));\n    let _e45 = global_uniforms.screen_size;\n    let _e48 = reconstructPosition((coords_2 + (vec2<f32>(1f, 0f) / _e45)));\n    x1_ = _e39;\n    x2_ = center;\n    if (abs((_e48.z - center.z)) < abs((_e39.z - center.z))) {\n        x1_ = center;\n        x2_ = _e48;\n    }\n    let _e60 = x2_;\n    let _e61 = x1_;\n    let _e63 = y2_;\n    let _e64 = y1_;\n    return normalize(cross((_e60 - _e61), (_e63 - _e64)));\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) f32 {\n    var view_pos: vec3<f32>;\n    var view_space_normal: vec3<f32>;\n    var random_vec: vec3<f32>;\n    var occlusion: f32 = 0f;\n    var i: i32 = 0i;\n    var sample: vec3<f32>;\n    var offset: vec4<f32>;\n    var sample_depth: f32;\n    var range_check: f32;\n\n    let _e4 = reconstructPosition(in.tex_coords);\n    view_pos = _e4;\n    let _e6 = view_pos;\n    let _e8 = normalFromDepth(_e6, in.tex_coords);\n    view_space_normal = _e8;\n    let _e14 = kernel.noise_texture_scale;\n    let _e18 = textureSample(ssao_noise, ssao_noise_sampler, (_e14 * in.tex_coords.xy));\n    random_vec = _e18.xyz;\n    let _e21 = random_vec;\n    let _e22 = view_space_normal;\n    let _e23 = random_vec;\n    let _e24 = view_space_normal;\n    let tangent = normalize((_e21 - (_e22 * dot(_e23, _e24))));\n    let _e29 = view_space_normal;\n    let bitangent = cross(_e29, tangent);\n    let _e31 = view_space_normal;\n    let TBN = mat3x3<f32>(tangent, bitangent, _e31);\n    loop {\n        let _e34 = i;\n        if (_e34 < 64i) {\n        } else {\n            break;\n        }\n        {\n            let _e37 = view_pos;\n            let _e41 = kernel.radius;\n            let _e45 = i;\n            let _e47 = kernel.items[_e45];\n            sample = (_e37.xyz + ((_e41 * TBN) * _e47.xyz));\n            let _e54 = view_proj_uniforms.projection;\n            let _e55 = sample;\n            offset = (_e54 * vec4<f32>(_e55, 1f));\n            let _e62 = offset.w;\n            let _e63 = offset.x;\n            offset.x = (_e63 / _e62);\n            let _e67 = offset.w;\n            let _e68 = offset.y;\n            offset.y = (_e68 / _e67);\n            let _e72 = offset.x;\n            offset.x = ((_e72 * 0.5f) + 0.5f);\n            let _e79 = offset.y;\n            offset.y = ((_e79 * 0.5f) + 0.5f);\n            let _e86 = offset.y;\n            offset.y = (1f - _e86);\n            let _e89 = offset;\n            let _e91 = reconstructPosition(_e89.xy);\n            sample_depth = _e91.z;\n            let _e98 = kernel.radius;\n            let _e100 = view_pos.z;\n            let _e101 = sample_depth;\n            range_check = smoothstep(0f, 1f, (_e98 / abs((_e100 - _e101))));\n            let _e107 = sample_depth;\n            let _e109 = sample.z;\n            let _e112 = kernel.bias;\n            if (_e107 >= (_e109 + _e112)) {\n                let _e116 = range_check;\n                let _e117 = range_check;\n                let _e119 = occlusion;\n                occlusion = (_e119 + (_e116 * _e117));\n            }\n        }\n        continuing {\n            let _e122 = i;\n            i = (_e122 + 1i);\n        }\n    }\n    let _e124 = occlusion;\n    occlusion = (1f - (_e124 / 64f));\n    let _e129 = occlusion;\n    occlusion = pow(_e129, 2f);\n    let _e132 = occlusion;\n    return _e132;\n}\n";
         }
     }
     #[allow(unused)]
@@ -278,7 +258,7 @@ pub mod ssao_from_depth {
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct Kernel {\n    items: array<vec4<f32>, 64>,\n    radius: f32,\n    bias: f32,\n    noise_texture_scale: vec2<f32>,\n    aspect_ratio: f32,\n    tan_half_fov: f32,\n    inverse_proj: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nconst KERNEL_SIZE: u32 = 64u;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar depth_buffer: texture_depth_2d;\n@group(3) @binding(1) \nvar depth_buffer_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> kernel: Kernel;\n@group(5) @binding(0) \nvar ssao_noise: texture_2d<f32>;\n@group(5) @binding(1) \nvar ssao_noise_sampler: sampler;\n\nfn reconstructPosition(coords: vec2<f32>) -> vec3<f32> {\n    let x = ((coords.x * 2f) - 1f);\n    let y = (((1f - coords.y) * 2f) - 1f);\n    let z = textureSample(depth_buffer, depth_buffer_sampler, coords);\n    let position_s = vec4<f32>(x, y, z, 1f);\n    let _e20 = kernel.inverse_proj;\n    let position_v = (_e20 * position_s);\n    return (position_v.xyz / vec3(position_v.w));\n}\n\nfn normalFromDepth(center: vec3<f32>, coords_1: vec2<f32>) -> vec3<f32> {\n    var y1_: vec3<f32>;\n    var y2_: vec3<f32>;\n    var x1_: vec3<f32>;\n    var x2_: vec3<f32>;\n\n    let _e6 = global_uniforms.screen_size;\n    let _e9 = reconstructPosition((coords_1 + (vec2<f32>(0f, 1f) / _e6)));\n    let _e15 = global_uniforms.screen_size;\n    let _e18 = reconstructPosition((coords_1 + (vec2<f32>(0f, -1f) / _e15)));\n    y1_ = _e9;\n    y2_ = center;\n    if (abs((_e18.z - center.z)) < abs((_e9.z - center.z))) {\n        y1_ = center;\n        y2_ = _e18;\n    }\n    let _e36 = global_uniforms.screen_size;\n    let _e39 = reconstructPosition((coords_1 + (vec2<f32>(-1f, 0f) / _e36)));\n    let _e45 = global_uniforms.screen_size;\n    let _e48 = reconstructPosition((coords_1 + (vec2<f32>(1f, 0f) / _e45)));\n    x1_ = _e39;\n    x2_ = center;\n    if (abs((_e48.z - center.z)) < abs((_e39.z - center.z))) {\n        x1_ = center;\n        x2_ = _e48;\n    }\n    let _e60 = x2_;\n    let _e61 = x1_;\n    let _e63 = y2_;\n    let _e64 = y1_;\n    return normalize(cross((_e60 - _e61), (_e63 - _e64)));\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    let _e32 = out;\n    return _e32;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) f32 {\n    var view_pos: vec3<f32>;\n    var view_space_normal: vec3<f32>;\n    var random_vec: vec3<f32>;\n    var occlusion: f32 = 0f;\n    var i: i32 = 0i;\n    var sample: vec3<f32>;\n    var offset: vec4<f32>;\n    var sample_depth: f32;\n    var range_check: f32;\n\n    let _e4 = reconstructPosition(in.tex_coords);\n    view_pos = _e4;\n    let _e6 = view_pos;\n    let _e8 = normalFromDepth(_e6, in.tex_coords);\n    view_space_normal = _e8;\n    let _e14 = kernel.noise_texture_scale;\n    let _e18 = textureSample(ssao_noise, ssao_noise_sampler, (_e14 * in.tex_coords.xy));\n    random_vec = _e18.xyz;\n    let _e21 = random_vec;\n    let _e22 = view_space_normal;\n    let _e23 = random_vec;\n    let _e24 = view_space_normal;\n    let tangent = normalize((_e21 - (_e22 * dot(_e23, _e24))));\n    let _e29 = view_space_normal;\n    let bitangent = cross(_e29, tangent);\n    let _e31 = view_space_normal;\n    let TBN = mat3x3<f32>(tangent, bitangent, _e31);\n    loop {\n        let _e34 = i;\n        if (_e34 < 64i) {\n        } else {\n            break;\n        }\n        {\n            let _e37 = view_pos;\n            let _e41 = kernel.radius;\n            let _e45 = i;\n            let _e47 = kernel.items[_e45];\n            sample = (_e37.xyz + ((_e41 * TBN) * _e47.xyz));\n            let _e54 = view_proj_uniforms.projection;\n            let _e55 = sample;\n            offset = (_e54 * vec4<f32>(_e55, 1f));\n            let _e62 = offset.w;\n            let _e63 = offset.x;\n            offset.x = (_e63 / _e62);\n            let _e67 = offset.w;\n            let _e68 = offset.y;\n            offset.y = (_e68 / _e67);\n            let _e72 = offset.x;\n            offset.x = ((_e72 * 0.5f) + 0.5f);\n            let _e79 = offset.y;\n            offset.y = ((_e79 * 0.5f) + 0.5f);\n            let _e86 = offset.y;\n            offset.y = (1f - _e86);\n            let _e89 = offset;\n            let _e91 = reconstructPosition(_e89.xy);\n            sample_depth = _e91.z;\n            let _e98 = kernel.radius;\n            let _e100 = view_pos.z;\n            let _e101 = sample_depth;\n            range_check = smoothstep(0f, 1f, (_e98 / abs((_e100 - _e101))));\n            let _e107 = sample_depth;\n            let _e109 = sample.z;\n            let _e112 = kernel.bias;\n            if (_e107 >= (_e109 + _e112)) {\n                let _e116 = range_check;\n                let _e117 = range_check;\n                let _e119 = occlusion;\n                occlusion = (_e119 + (_e116 * _e117));\n            }\n        }\n        continuing {\n            let _e122 = i;\n            i = (_e122 + 1i);\n        }\n    }\n    let _e124 = occlusion;\n    occlusion = (1f - (_e124 / 64f));\n    let _e129 = occlusion;\n    occlusion = pow(_e129, 2f);\n    let _e132 = occlusion;\n    return _e132;\n}\n";
+    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct Kernel {\n    items: array<vec4<f32>, 64>,\n    radius: f32,\n    bias: f32,\n    noise_texture_scale: vec2<f32>,\n    aspect_ratio: f32,\n    tan_half_fov: f32,\n    inverse_proj: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nconst KERNEL_SIZE: u32 = 64u;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar depth_buffer: texture_2d<f32>;\n@group(4) @binding(0) \nvar<uniform> kernel: Kernel;\n@group(5) @binding(0) \nvar ssao_noise: texture_2d<f32>;\n@group(5) @binding(1) \nvar ssao_noise_sampler: sampler;\n\nfn load_depth(coords: vec2<f32>) -> f32 {\n    let _e1 = textureDimensions(depth_buffer);\n    let size = vec2<i32>(_e1);\n    let texel = clamp(vec2<i32>((coords * vec2<f32>(size))), vec2(0i), (size - vec2(1i)));\n    let _e15 = textureLoad(depth_buffer, texel, 0i);\n    return _e15.x;\n}\n\nfn reconstructPosition(coords_1: vec2<f32>) -> vec3<f32> {\n    let x = ((coords_1.x * 2f) - 1f);\n    let y = (((1f - coords_1.y) * 2f) - 1f);\n    let _e13 = load_depth(coords_1);\n    let position_s = vec4<f32>(x, y, _e13, 1f);\n    let _e18 = kernel.inverse_proj;\n    let position_v = (_e18 * position_s);\n    return (position_v.xyz / vec3(position_v.w));\n}\n\nfn normalFromDepth(center: vec3<f32>, coords_2: vec2<f32>) -> vec3<f32> {\n    var y1_: vec3<f32>;\n    var y2_: vec3<f32>;\n    var x1_: vec3<f32>;\n    var x2_: vec3<f32>;\n\n    let _e6 = global_uniforms.screen_size;\n    let _e9 = reconstructPosition((coords_2 + (vec2<f32>(0f, 1f) / _e6)));\n    let _e15 = global_uniforms.screen_size;\n    let _e18 = reconstructPosition((coords_2 + (vec2<f32>(0f, -1f) / _e15)));\n    y1_ = _e9;\n    y2_ = center;\n    if (abs((_e18.z - center.z)) < abs((_e9.z - center.z))) {\n        y1_ = center;\n        y2_ = _e18;\n    }\n    let _e36 = global_uniforms.screen_size;\n    let _e39 = reconstructPosition((coords_2 + (vec2<f32>(-1f, 0f) / _e36)));\n    let _e45 = global_uniforms.screen_size;\n    let _e48 = reconstructPosition((coords_2 + (vec2<f32>(1f, 0f) / _e45)));\n    x1_ = _e39;\n    x2_ = center;\n    if (abs((_e48.z - center.z)) < abs((_e39.z - center.z))) {\n        x1_ = center;\n        x2_ = _e48;\n    }\n    let _e60 = x2_;\n    let _e61 = x1_;\n    let _e63 = y2_;\n    let _e64 = y1_;\n    return normalize(cross((_e60 - _e61), (_e63 - _e64)));\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    let _e32 = out;\n    return _e32;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) f32 {\n    var view_pos: vec3<f32>;\n    var view_space_normal: vec3<f32>;\n    var random_vec: vec3<f32>;\n    var occlusion: f32 = 0f;\n    var i: i32 = 0i;\n    var sample: vec3<f32>;\n    var offset: vec4<f32>;\n    var sample_depth: f32;\n    var range_check: f32;\n\n    let _e4 = reconstructPosition(in.tex_coords);\n    view_pos = _e4;\n    let _e6 = view_pos;\n    let _e8 = normalFromDepth(_e6, in.tex_coords);\n    view_space_normal = _e8;\n    let _e14 = kernel.noise_texture_scale;\n    let _e18 = textureSample(ssao_noise, ssao_noise_sampler, (_e14 * in.tex_coords.xy));\n    random_vec = _e18.xyz;\n    let _e21 = random_vec;\n    let _e22 = view_space_normal;\n    let _e23 = random_vec;\n    let _e24 = view_space_normal;\n    let tangent = normalize((_e21 - (_e22 * dot(_e23, _e24))));\n    let _e29 = view_space_normal;\n    let bitangent = cross(_e29, tangent);\n    let _e31 = view_space_normal;\n    let TBN = mat3x3<f32>(tangent, bitangent, _e31);\n    loop {\n        let _e34 = i;\n        if (_e34 < 64i) {\n        } else {\n            break;\n        }\n        {\n            let _e37 = view_pos;\n            let _e41 = kernel.radius;\n            let _e45 = i;\n            let _e47 = kernel.items[_e45];\n            sample = (_e37.xyz + ((_e41 * TBN) * _e47.xyz));\n            let _e54 = view_proj_uniforms.projection;\n            let _e55 = sample;\n            offset = (_e54 * vec4<f32>(_e55, 1f));\n            let _e62 = offset.w;\n            let _e63 = offset.x;\n            offset.x = (_e63 / _e62);\n            let _e67 = offset.w;\n            let _e68 = offset.y;\n            offset.y = (_e68 / _e67);\n            let _e72 = offset.x;\n            offset.x = ((_e72 * 0.5f) + 0.5f);\n            let _e79 = offset.y;\n            offset.y = ((_e79 * 0.5f) + 0.5f);\n            let _e86 = offset.y;\n            offset.y = (1f - _e86);\n            let _e89 = offset;\n            let _e91 = reconstructPosition(_e89.xy);\n            sample_depth = _e91.z;\n            let _e98 = kernel.radius;\n            let _e100 = view_pos.z;\n            let _e101 = sample_depth;\n            range_check = smoothstep(0f, 1f, (_e98 / abs((_e100 - _e101))));\n            let _e107 = sample_depth;\n            let _e109 = sample.z;\n            let _e112 = kernel.bias;\n            if (_e107 >= (_e109 + _e112)) {\n                let _e116 = range_check;\n                let _e117 = range_check;\n                let _e119 = occlusion;\n                occlusion = (_e119 + (_e116 * _e117));\n            }\n        }\n        continuing {\n            let _e122 = i;\n            i = (_e122 + 1i);\n        }\n    }\n    let _e124 = occlusion;\n    occlusion = (1f - (_e124 / 64f));\n    let _e129 = occlusion;\n    occlusion = pow(_e129, 2f);\n    let _e132 = occlusion;\n    return _e132;\n}\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("ssao_from_depth"),
@@ -405,12 +385,12 @@ pub mod picking {
         pub mod vs_main {
             pub const NAME: &'static str = "vs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) model_1_: vec4<f32>,\n    @location(4) model_2_: vec4<f32>,\n    @location(5) model_3_: vec4<f32>,\n    @location(6) model_4_: vec4<f32>,\n    @location(7) @interpolate(flat) id_color: vec2<u32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(1) world_pos: vec4<f32>,\n    @location(2) @interpolate(flat) id_color: vec2<u32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let model = (model_transform * vertex.position);\n    let _e11 = view_proj_uniforms.view;\n    let model_view = (_e11 * model);\n    let _e17 = view_proj_uniforms.projection;\n    out.clip_position = (_e17 * model_view);\n    out.world_pos = model;\n    out.id_color = instance.id_color;\n    let _e22 = out;\n    return _e22;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(4) model_1_: vec4<f32>,\n    @location(5) model_2_: vec4<f32>,\n    @location(6) model_3_: vec4<f32>,\n    @location(7) model_4_: vec4<f32>,\n    @location(8) @interpolate(flat) id_color: vec2<u32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(1) world_pos: vec4<f32>,\n    @location(2) @interpolate(flat) id_color: vec2<u32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let model = (model_transform * vertex.position);\n    let _e11 = view_proj_uniforms.view;\n    let model_view = (_e11 * model);\n    let _e17 = view_proj_uniforms.projection;\n    out.clip_position = (_e17 * model_view);\n    out.world_pos = model;\n    out.id_color = instance.id_color;\n    let _e22 = out;\n    return _e22;\n}\n";
         }
         pub mod fs_main {
             pub const NAME: &'static str = "fs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) model_1_: vec4<f32>,\n    @location(4) model_2_: vec4<f32>,\n    @location(5) model_3_: vec4<f32>,\n    @location(6) model_4_: vec4<f32>,\n    @location(7) @interpolate(flat) id_color: vec2<u32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(1) world_pos: vec4<f32>,\n    @location(2) @interpolate(flat) id_color: vec2<u32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) @interpolate(flat) vec4<u32> {\n    return vec4<u32>((in.id_color.x & 255u), ((in.id_color.x >> 8u) & 255u), ((in.id_color.x >> 16u) & 255u), (in.id_color.x >> 24u));\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(4) model_1_: vec4<f32>,\n    @location(5) model_2_: vec4<f32>,\n    @location(6) model_3_: vec4<f32>,\n    @location(7) model_4_: vec4<f32>,\n    @location(8) @interpolate(flat) id_color: vec2<u32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(1) world_pos: vec4<f32>,\n    @location(2) @interpolate(flat) id_color: vec2<u32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) @interpolate(flat) vec4<u32> {\n    return vec4<u32>((in.id_color.x & 255u), ((in.id_color.x >> 8u) & 255u), ((in.id_color.x >> 16u) & 255u), (in.id_color.x >> 24u));\n}\n";
         }
     }
     #[allow(unused)]
@@ -419,7 +399,7 @@ pub mod picking {
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) model_1_: vec4<f32>,\n    @location(4) model_2_: vec4<f32>,\n    @location(5) model_3_: vec4<f32>,\n    @location(6) model_4_: vec4<f32>,\n    @location(7) @interpolate(flat) id_color: vec2<u32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(1) world_pos: vec4<f32>,\n    @location(2) @interpolate(flat) id_color: vec2<u32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let model = (model_transform * vertex.position);\n    let _e11 = view_proj_uniforms.view;\n    let model_view = (_e11 * model);\n    let _e17 = view_proj_uniforms.projection;\n    out.clip_position = (_e17 * model_view);\n    out.world_pos = model;\n    out.id_color = instance.id_color;\n    let _e22 = out;\n    return _e22;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) @interpolate(flat) vec4<u32> {\n    return vec4<u32>((in.id_color.x & 255u), ((in.id_color.x >> 8u) & 255u), ((in.id_color.x >> 16u) & 255u), (in.id_color.x >> 24u));\n}\n";
+    pub const SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(4) model_1_: vec4<f32>,\n    @location(5) model_2_: vec4<f32>,\n    @location(6) model_3_: vec4<f32>,\n    @location(7) model_4_: vec4<f32>,\n    @location(8) @interpolate(flat) id_color: vec2<u32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(1) world_pos: vec4<f32>,\n    @location(2) @interpolate(flat) id_color: vec2<u32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let model = (model_transform * vertex.position);\n    let _e11 = view_proj_uniforms.view;\n    let model_view = (_e11 * model);\n    let _e17 = view_proj_uniforms.projection;\n    out.clip_position = (_e17 * model_view);\n    out.world_pos = model;\n    out.id_color = instance.id_color;\n    let _e22 = out;\n    return _e22;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) @interpolate(flat) vec4<u32> {\n    return vec4<u32>((in.id_color.x & 255u), ((in.id_color.x >> 8u) & 255u), ((in.id_color.x >> 16u) & 255u), (in.id_color.x >> 24u));\n}\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("picking"),
@@ -740,12 +720,12 @@ pub mod deferred_lighting {
         pub mod vs_main {
             pub const NAME: &'static str = "vs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(2) tint_color: vec4<f32>,\n}\n\nstruct Light {\n    position: vec4<f32>,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n}\n\nconst AMBIENT_LIGHT_FACTOR: vec3<f32> = vec3<f32>(0.5f, 0.5f, 0.5f);\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n@group(3) @binding(0) \nvar g_position: texture_2d<f32>;\n@group(3) @binding(1) \nvar g_position_sampler: sampler;\n@group(3) @binding(2) \nvar g_normal: texture_2d<f32>;\n@group(3) @binding(3) \nvar g_normal_sampler: sampler;\n@group(3) @binding(4) \nvar g_albedo_spec: texture_2d<f32>;\n@group(3) @binding(5) \nvar g_albedo_spec_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    out.tint_color = instance.tint;\n    let _e34 = out;\n    return _e34;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(2) tint_color: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n    shadow_index: i32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n    shadow_view_projs: array<mat4x4<f32>, 8>,\n    point_shadow_view_projs: array<mat4x4<f32>, 24>,\n    cascade_count: u32,\n    cascade_blend: f32,\n    environment_intensity: f32,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\nconst AMBIENT_LIGHT_FACTOR: vec3<f32> = vec3<f32>(0.5f, 0.5f, 0.5f);\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n@group(3) @binding(0) \nvar g_position: texture_2d<f32>;\n@group(3) @binding(1) \nvar g_position_sampler: sampler;\n@group(3) @binding(2) \nvar g_normal: texture_2d<f32>;\n@group(3) @binding(3) \nvar g_normal_sampler: sampler;\n@group(3) @binding(4) \nvar g_albedo_spec: texture_2d<f32>;\n@group(3) @binding(5) \nvar g_albedo_spec_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n\nfn fresnel_schlickX_naga_oil_mod_XOBRHEX(cos_theta: f32, f0_: vec3<f32>) -> vec3<f32> {\n    return (f0_ + ((vec3(1f) - f0_) * pow(clamp((1f - cos_theta), 0f, 1f), 5f)));\n}\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v: f32, n_dot_l: f32, roughness_1: f32) -> f32 {\n    let k = (((roughness_1 + 1f) * (roughness_1 + 1f)) / 8f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\nfn cook_torranceX_naga_oil_mod_XOBRHEX(n: vec3<f32>, v: vec3<f32>, l: vec3<f32>, albedo: vec3<f32>, metallic: f32, roughness_2: f32, radiance: vec3<f32>) -> vec3<f32> {\n    let h = normalize((v + l));\n    let n_dot_l_1 = max(dot(n, l), 0f);\n    let n_dot_v_1 = max(dot(n, v), 0.0001f);\n    let f0_1 = mix(vec3(0.04f), albedo, metallic);\n    let _e19 = fresnel_schlickX_naga_oil_mod_XOBRHEX(max(dot(h, v), 0f), f0_1);\n    let _e24 = distribution_ggxX_naga_oil_mod_XOBRHEX(max(dot(n, h), 0f), roughness_2);\n    let _e25 = geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v_1, n_dot_l_1, roughness_2);\n    let specular = (((_e24 * _e25) * _e19) / vec3(max(((4f * n_dot_v_1) * n_dot_l_1), 0.0001f)));\n    let k_d = ((vec3(1f) - _e19) * (1f - metallic));\n    return (((((k_d * albedo) / vec3(3.1415927f)) + specular) * radiance) * n_dot_l_1);\n}\n\nfn point_attenuation(distance: f32, radius: f32) -> f32 {\n    let falloff = clamp((1f - pow((distance / radius), 2f)), 0f, 1f);\n    return (falloff * falloff);\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    out.tint_color = instance.tint;\n    let _e34 = out;\n    return _e34;\n}\n";
         }
         pub mod fs_main {
             pub const NAME: &'static str = "fs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(2) tint_color: vec4<f32>,\n}\n\nstruct Light {\n    position: vec4<f32>,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n}\n\nconst AMBIENT_LIGHT_FACTOR: vec3<f32> = vec3<f32>(0.5f, 0.5f, 0.5f);\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n@group(3) @binding(0) \nvar g_position: texture_2d<f32>;\n@group(3) @binding(1) \nvar g_position_sampler: sampler;\n@group(3) @binding(2) \nvar g_normal: texture_2d<f32>;\n@group(3) @binding(3) \nvar g_normal_sampler: sampler;\n@group(3) @binding(4) \nvar g_albedo_spec: texture_2d<f32>;\n@group(3) @binding(5) \nvar g_albedo_spec_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    var specular: vec4<f32> = vec4<f32>(0f, 0f, 0f, 0f);\n    var diffuse: vec4<f32> = vec4<f32>(0f, 0f, 0f, 0f);\n    var total_light: vec4<f32>;\n    var i: u32 = 0u;\n    var shadow: f32;\n    var specular_factor: f32;\n\n    let view_pos = textureSample(g_position, g_position_sampler, in.tex_coords);\n    let view_space_normal = textureSample(g_normal, g_normal_sampler, in.tex_coords);\n    let _e16 = textureSample(g_albedo_spec, g_albedo_spec_sampler, in.tex_coords);\n    let albedo_spec = (in.tint_color * _e16);\n    let _e21 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    total_light = vec4(clamp(_e21.x, 0f, 1f));\n    loop {\n        let _e29 = i;\n        let _e32 = lights.count;\n        if (_e29 < _e32) {\n        } else {\n            break;\n        }\n        {\n            let _e36 = i;\n            let light_color = lights.items[_e36].color;\n            let _e42 = i;\n            let light_pos_w = lights.items[_e42].position;\n            let view_dir_v = normalize(view_pos);\n            let _e49 = view_proj_uniforms.view;\n            let light_pos_v = (_e49 * light_pos_w);\n            let light_dir_v = normalize((light_pos_v - view_pos).xyz);\n            let _e56 = i;\n            let _e59 = lights.items[_e56].view_proj;\n            let _e62 = view_proj_uniforms.inverse_view;\n            let shadow_pos = ((_e59 * _e62) * view_pos);\n            shadow = 1f;\n            let half_dir_v = normalize((view_dir_v.xyz + light_dir_v));\n            let reflect_dir_v = reflect(light_dir_v, view_space_normal.xyz);\n            specular_factor = clamp(dot(view_space_normal.xyz, half_dir_v), 0f, 1f);\n            let _e78 = specular_factor;\n            specular_factor = pow(_e78, 32f);\n            let _e81 = specular_factor;\n            if (_e81 > 0f) {\n                let _e85 = shadow;\n                let _e87 = specular_factor;\n                let _e90 = total_light;\n                total_light = (_e90 + (((_e85 * 0.6f) * _e87) * light_color));\n            }\n            let d = clamp(dot(view_space_normal.xyz, light_dir_v), 0f, 1f);\n            let _e97 = shadow;\n            let _e100 = total_light;\n            total_light = (_e100 + ((_e97 * d) * light_color));\n        }\n        continuing {\n            let _e103 = i;\n            i = (_e103 + 1u);\n        }\n    }\n    let _e106 = total_light;\n    return vec4<f32>((albedo_spec.xyz * _e106.xyz), albedo_spec.w);\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(2) tint_color: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n    shadow_index: i32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n    shadow_view_projs: array<mat4x4<f32>, 8>,\n    point_shadow_view_projs: array<mat4x4<f32>, 24>,\n    cascade_count: u32,\n    cascade_blend: f32,\n    environment_intensity: f32,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\nconst AMBIENT_LIGHT_FACTOR: vec3<f32> = vec3<f32>(0.5f, 0.5f, 0.5f);\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n@group(3) @binding(0) \nvar g_position: texture_2d<f32>;\n@group(3) @binding(1) \nvar g_position_sampler: sampler;\n@group(3) @binding(2) \nvar g_normal: texture_2d<f32>;\n@group(3) @binding(3) \nvar g_normal_sampler: sampler;\n@group(3) @binding(4) \nvar g_albedo_spec: texture_2d<f32>;\n@group(3) @binding(5) \nvar g_albedo_spec_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n\nfn fresnel_schlickX_naga_oil_mod_XOBRHEX(cos_theta: f32, f0_: vec3<f32>) -> vec3<f32> {\n    return (f0_ + ((vec3(1f) - f0_) * pow(clamp((1f - cos_theta), 0f, 1f), 5f)));\n}\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v: f32, n_dot_l: f32, roughness_1: f32) -> f32 {\n    let k = (((roughness_1 + 1f) * (roughness_1 + 1f)) / 8f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\nfn cook_torranceX_naga_oil_mod_XOBRHEX(n: vec3<f32>, v: vec3<f32>, l: vec3<f32>, albedo: vec3<f32>, metallic: f32, roughness_2: f32, radiance: vec3<f32>) -> vec3<f32> {\n    let h = normalize((v + l));\n    let n_dot_l_1 = max(dot(n, l), 0f);\n    let n_dot_v_1 = max(dot(n, v), 0.0001f);\n    let f0_1 = mix(vec3(0.04f), albedo, metallic);\n    let _e19 = fresnel_schlickX_naga_oil_mod_XOBRHEX(max(dot(h, v), 0f), f0_1);\n    let _e24 = distribution_ggxX_naga_oil_mod_XOBRHEX(max(dot(n, h), 0f), roughness_2);\n    let _e25 = geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v_1, n_dot_l_1, roughness_2);\n    let specular = (((_e24 * _e25) * _e19) / vec3(max(((4f * n_dot_v_1) * n_dot_l_1), 0.0001f)));\n    let k_d = ((vec3(1f) - _e19) * (1f - metallic));\n    return (((((k_d * albedo) / vec3(3.1415927f)) + specular) * radiance) * n_dot_l_1);\n}\n\nfn point_attenuation(distance: f32, radius: f32) -> f32 {\n    let falloff = clamp((1f - pow((distance / radius), 2f)), 0f, 1f);\n    return (falloff * falloff);\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    var total_light: vec3<f32>;\n    var i: u32 = 0u;\n    var light_color: vec4<f32>;\n    var shadow: f32;\n\n    let position_metallic = textureSample(g_position, g_position_sampler, in.tex_coords);\n    let normal_roughness = textureSample(g_normal, g_normal_sampler, in.tex_coords);\n    let view_pos = vec4<f32>(position_metallic.xyz, 1f);\n    let view_space_normal = vec4<f32>(normal_roughness.xyz, 0f);\n    let metallic_1 = position_metallic.w;\n    let roughness_3 = normal_roughness.w;\n    let _e22 = textureSample(g_albedo_spec, g_albedo_spec_sampler, in.tex_coords);\n    let albedo_spec = (in.tint_color * _e22);\n    let _e27 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let ao = clamp(_e27.x, 0f, 1f);\n    let n_1 = normalize(view_space_normal.xyz);\n    let v_1 = normalize(-(view_pos.xyz));\n    total_light = ((ao * AMBIENT_LIGHT_FACTOR) * albedo_spec.xyz);\n    loop {\n        let _e43 = i;\n        let _e46 = lights.count;\n        if (_e43 < _e46) {\n        } else {\n            break;\n        }\n        {\n            let _e50 = i;\n            let _e53 = lights.items[_e50].color;\n            light_color = _e53;\n            let _e57 = i;\n            let _e60 = lights.items[_e57].position;\n            let light_pos_w = vec4<f32>(_e60, 1f);\n            let _e65 = view_proj_uniforms.view;\n            let light_pos_v = (_e65 * light_pos_w);\n            let light_dir_v = normalize((light_pos_v - view_pos).xyz);\n            let _e72 = i;\n            let _e75 = lights.items[_e72].kind;\n            if (_e75 == 2u) {\n                let _e83 = i;\n                let _e86 = lights.items[_e83].reach;\n                let _e87 = point_attenuation(distance(light_pos_v.xyz, view_pos.xyz), _e86);\n                let _e88 = light_color;\n                light_color = (_e88 * _e87);\n            }\n            let _e92 = i;\n            let _e95 = lights.items[_e92].view_proj;\n            let _e98 = view_proj_uniforms.inverse_view;\n            let shadow_pos = ((_e95 * _e98) * view_pos);\n            shadow = 1f;\n            let _e103 = shadow;\n            let _e104 = light_color;\n            let _e108 = light_color.w;\n            let radiance_1 = (((_e103 * _e104.xyz) * _e108) * PIX_naga_oil_mod_XOBRHEX);\n            let _e113 = cook_torranceX_naga_oil_mod_XOBRHEX(n_1, v_1, light_dir_v, albedo_spec.xyz, metallic_1, roughness_3, radiance_1);\n            let _e114 = total_light;\n            total_light = (_e114 + _e113);\n        }\n        continuing {\n            let _e117 = i;\n            i = (_e117 + 1u);\n        }\n    }\n    let _e119 = total_light;\n    return vec4<f32>(_e119, albedo_spec.w);\n}\n";
         }
     }
     #[allow(unused)]
@@ -755,9 +735,15 @@ pub mod deferred_lighting {
         #[repr(C)]
         #[derive(Debug, PartialEq, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
         pub struct Light {
-            pub position: glam::f32::Vec4,
+            pub direction: glam::f32::Vec3,
+            pub kind: u32,
             pub color: glam::f32::Vec4,
             pub view_proj: glam::f32::Mat4,
+            pub position: glam::f32::Vec3,
+            pub radius: f32,
+            pub reach: f32,
+            pub shadow_index: i32,
+            pub _pad: [u8; 8u32 as usize],
         }
         #[allow(unused, non_camel_case_types)]
         #[repr(C)]
@@ -765,13 +751,22 @@ pub mod deferred_lighting {
         pub struct LightsUniform {
             pub items: [Light; 8u32 as usize],
             pub count: u32,
-            pub _pad: [u8; 12u32 as usize],
+            pub shadow_bias_minimum: f32,
+            pub shadow_bias_factor: f32,
+            pub shadow_blur_half_kernel_size: i32,
+            pub ambient_color: glam::f32::Vec4,
+            pub shadow_view_projs: [glam::f32::Mat4; 8u32 as usize],
+            pub point_shadow_view_projs: [glam::f32::Mat4; 24u32 as usize],
+            pub cascade_count: u32,
+            pub cascade_blend: f32,
+            pub environment_intensity: f32,
+            pub _pad: [u8; 4u32 as usize],
         }
     }
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(2) tint_color: vec4<f32>,\n}\n\nstruct Light {\n    position: vec4<f32>,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n}\n\nconst AMBIENT_LIGHT_FACTOR: vec3<f32> = vec3<f32>(0.5f, 0.5f, 0.5f);\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n@group(3) @binding(0) \nvar g_position: texture_2d<f32>;\n@group(3) @binding(1) \nvar g_position_sampler: sampler;\n@group(3) @binding(2) \nvar g_normal: texture_2d<f32>;\n@group(3) @binding(3) \nvar g_normal_sampler: sampler;\n@group(3) @binding(4) \nvar g_albedo_spec: texture_2d<f32>;\n@group(3) @binding(5) \nvar g_albedo_spec_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    out.tint_color = instance.tint;\n    let _e34 = out;\n    return _e34;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    var specular: vec4<f32> = vec4<f32>(0f, 0f, 0f, 0f);\n    var diffuse: vec4<f32> = vec4<f32>(0f, 0f, 0f, 0f);\n    var total_light: vec4<f32>;\n    var i: u32 = 0u;\n    var shadow: f32;\n    var specular_factor: f32;\n\n    let view_pos = textureSample(g_position, g_position_sampler, in.tex_coords);\n    let view_space_normal = textureSample(g_normal, g_normal_sampler, in.tex_coords);\n    let _e16 = textureSample(g_albedo_spec, g_albedo_spec_sampler, in.tex_coords);\n    let albedo_spec = (in.tint_color * _e16);\n    let _e21 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    total_light = vec4(clamp(_e21.x, 0f, 1f));\n    loop {\n        let _e29 = i;\n        let _e32 = lights.count;\n        if (_e29 < _e32) {\n        } else {\n            break;\n        }\n        {\n            let _e36 = i;\n            let light_color = lights.items[_e36].color;\n            let _e42 = i;\n            let light_pos_w = lights.items[_e42].position;\n            let view_dir_v = normalize(view_pos);\n            let _e49 = view_proj_uniforms.view;\n            let light_pos_v = (_e49 * light_pos_w);\n            let light_dir_v = normalize((light_pos_v - view_pos).xyz);\n            let _e56 = i;\n            let _e59 = lights.items[_e56].view_proj;\n            let _e62 = view_proj_uniforms.inverse_view;\n            let shadow_pos = ((_e59 * _e62) * view_pos);\n            shadow = 1f;\n            let half_dir_v = normalize((view_dir_v.xyz + light_dir_v));\n            let reflect_dir_v = reflect(light_dir_v, view_space_normal.xyz);\n            specular_factor = clamp(dot(view_space_normal.xyz, half_dir_v), 0f, 1f);\n            let _e78 = specular_factor;\n            specular_factor = pow(_e78, 32f);\n            let _e81 = specular_factor;\n            if (_e81 > 0f) {\n                let _e85 = shadow;\n                let _e87 = specular_factor;\n                let _e90 = total_light;\n                total_light = (_e90 + (((_e85 * 0.6f) * _e87) * light_color));\n            }\n            let d = clamp(dot(view_space_normal.xyz, light_dir_v), 0f, 1f);\n            let _e97 = shadow;\n            let _e100 = total_light;\n            total_light = (_e100 + ((_e97 * d) * light_color));\n        }\n        continuing {\n            let _e103 = i;\n            i = (_e103 + 1u);\n        }\n    }\n    let _e106 = total_light;\n    return vec4<f32>((albedo_spec.xyz * _e106.xyz), albedo_spec.w);\n}\n";
+    pub const SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(2) tint_color: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n    shadow_index: i32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n    shadow_view_projs: array<mat4x4<f32>, 8>,\n    point_shadow_view_projs: array<mat4x4<f32>, 24>,\n    cascade_count: u32,\n    cascade_blend: f32,\n    environment_intensity: f32,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\nconst AMBIENT_LIGHT_FACTOR: vec3<f32> = vec3<f32>(0.5f, 0.5f, 0.5f);\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniforms;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniforms;\n@group(3) @binding(0) \nvar g_position: texture_2d<f32>;\n@group(3) @binding(1) \nvar g_position_sampler: sampler;\n@group(3) @binding(2) \nvar g_normal: texture_2d<f32>;\n@group(3) @binding(3) \nvar g_normal_sampler: sampler;\n@group(3) @binding(4) \nvar g_albedo_spec: texture_2d<f32>;\n@group(3) @binding(5) \nvar g_albedo_spec_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n\nfn fresnel_schlickX_naga_oil_mod_XOBRHEX(cos_theta: f32, f0_: vec3<f32>) -> vec3<f32> {\n    return (f0_ + ((vec3(1f) - f0_) * pow(clamp((1f - cos_theta), 0f, 1f), 5f)));\n}\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v: f32, n_dot_l: f32, roughness_1: f32) -> f32 {\n    let k = (((roughness_1 + 1f) * (roughness_1 + 1f)) / 8f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\nfn cook_torranceX_naga_oil_mod_XOBRHEX(n: vec3<f32>, v: vec3<f32>, l: vec3<f32>, albedo: vec3<f32>, metallic: f32, roughness_2: f32, radiance: vec3<f32>) -> vec3<f32> {\n    let h = normalize((v + l));\n    let n_dot_l_1 = max(dot(n, l), 0f);\n    let n_dot_v_1 = max(dot(n, v), 0.0001f);\n    let f0_1 = mix(vec3(0.04f), albedo, metallic);\n    let _e19 = fresnel_schlickX_naga_oil_mod_XOBRHEX(max(dot(h, v), 0f), f0_1);\n    let _e24 = distribution_ggxX_naga_oil_mod_XOBRHEX(max(dot(n, h), 0f), roughness_2);\n    let _e25 = geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v_1, n_dot_l_1, roughness_2);\n    let specular = (((_e24 * _e25) * _e19) / vec3(max(((4f * n_dot_v_1) * n_dot_l_1), 0.0001f)));\n    let k_d = ((vec3(1f) - _e19) * (1f - metallic));\n    return (((((k_d * albedo) / vec3(3.1415927f)) + specular) * radiance) * n_dot_l_1);\n}\n\nfn point_attenuation(distance: f32, radius: f32) -> f32 {\n    let falloff = clamp((1f - pow((distance / radius), 2f)), 0f, 1f);\n    return (falloff * falloff);\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    out.tint_color = instance.tint;\n    let _e34 = out;\n    return _e34;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    var total_light: vec3<f32>;\n    var i: u32 = 0u;\n    var light_color: vec4<f32>;\n    var shadow: f32;\n\n    let position_metallic = textureSample(g_position, g_position_sampler, in.tex_coords);\n    let normal_roughness = textureSample(g_normal, g_normal_sampler, in.tex_coords);\n    let view_pos = vec4<f32>(position_metallic.xyz, 1f);\n    let view_space_normal = vec4<f32>(normal_roughness.xyz, 0f);\n    let metallic_1 = position_metallic.w;\n    let roughness_3 = normal_roughness.w;\n    let _e22 = textureSample(g_albedo_spec, g_albedo_spec_sampler, in.tex_coords);\n    let albedo_spec = (in.tint_color * _e22);\n    let _e27 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let ao = clamp(_e27.x, 0f, 1f);\n    let n_1 = normalize(view_space_normal.xyz);\n    let v_1 = normalize(-(view_pos.xyz));\n    total_light = ((ao * AMBIENT_LIGHT_FACTOR) * albedo_spec.xyz);\n    loop {\n        let _e43 = i;\n        let _e46 = lights.count;\n        if (_e43 < _e46) {\n        } else {\n            break;\n        }\n        {\n            let _e50 = i;\n            let _e53 = lights.items[_e50].color;\n            light_color = _e53;\n            let _e57 = i;\n            let _e60 = lights.items[_e57].position;\n            let light_pos_w = vec4<f32>(_e60, 1f);\n            let _e65 = view_proj_uniforms.view;\n            let light_pos_v = (_e65 * light_pos_w);\n            let light_dir_v = normalize((light_pos_v - view_pos).xyz);\n            let _e72 = i;\n            let _e75 = lights.items[_e72].kind;\n            if (_e75 == 2u) {\n                let _e83 = i;\n                let _e86 = lights.items[_e83].reach;\n                let _e87 = point_attenuation(distance(light_pos_v.xyz, view_pos.xyz), _e86);\n                let _e88 = light_color;\n                light_color = (_e88 * _e87);\n            }\n            let _e92 = i;\n            let _e95 = lights.items[_e92].view_proj;\n            let _e98 = view_proj_uniforms.inverse_view;\n            let shadow_pos = ((_e95 * _e98) * view_pos);\n            shadow = 1f;\n            let _e103 = shadow;\n            let _e104 = light_color;\n            let _e108 = light_color.w;\n            let radiance_1 = (((_e103 * _e104.xyz) * _e108) * PIX_naga_oil_mod_XOBRHEX);\n            let _e113 = cook_torranceX_naga_oil_mod_XOBRHEX(n_1, v_1, light_dir_v, albedo_spec.xyz, metallic_1, roughness_3, radiance_1);\n            let _e114 = total_light;\n            total_light = (_e114 + _e113);\n        }\n        continuing {\n            let _e117 = i;\n            i = (_e117 + 1u);\n        }\n    }\n    let _e119 = total_light;\n    return vec4<f32>(_e119, albedo_spec.w);\n}\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("deferred_lighting"),
@@ -852,6 +847,33 @@ pub mod depth_only {
                 }
             }
         }
+        ///Information about the `joint_matrices` global variable within this shader module.
+        pub mod joint_matrices {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "joint_matrices";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("joint_matrices"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Storage {
+                                    read_only: true,
+                                },
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
         ///Contains the following bindings: t_diffuse, s_diffuse
         pub mod group0 {
             #[allow(unused)]
@@ -900,12 +922,17 @@ pub mod depth_only {
         pub mod vs_main {
             pub const NAME: &'static str = "vs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1x: vec4<f32>,\n    @location(7) model_2x: vec4<f32>,\n    @location(8) model_3x: vec4<f32>,\n    @location(9) model_4x: vec4<f32>,\n    @location(10) normal_1x: vec4<f32>,\n    @location(11) normal_2x: vec4<f32>,\n    @location(12) normal_3x: vec4<f32>,\n    @location(13) normal_4x: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n\n@vertex \nfn vs_main(vertex: VertexInputX_naga_oil_mod_XNFXHA5LUOMX, instance: InstanceInputX_naga_oil_mod_XNFXHA5LUOMX) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n    let normal_matrix = mat4x4<f32>(instance.normal_1x, instance.normal_2x, instance.normal_3x, instance.normal_4x);\n    let _e13 = view_proj_uniforms.view;\n    let model_view = (_e13 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e22 = view_proj_uniforms.projection;\n    out.clip_position = (_e22 * model_view_pos);\n    let _e24 = out;\n    return _e24;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1x: vec4<f32>,\n    @location(8) model_2x: vec4<f32>,\n    @location(9) model_3x: vec4<f32>,\n    @location(10) model_4x: vec4<f32>,\n    @location(11) normal_1x: vec4<f32>,\n    @location(12) normal_2x: vec4<f32>,\n    @location(13) normal_3x: vec4<f32>,\n    @location(14) normal_4x: vec4<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_1: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance_1.model_1x, instance_1.model_2x, instance_1.model_3x, instance_1.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@vertex \nfn vs_main(vertex: VertexInputX_naga_oil_mod_XNFXHA5LUOMX, instance: InstanceInputX_naga_oil_mod_XNFXHA5LUOMX) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n    let normal_matrix = mat4x4<f32>(instance.normal_1x, instance.normal_2x, instance.normal_3x, instance.normal_4x);\n    let _e13 = view_proj_uniforms.view;\n    let model_view = (_e13 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e22 = view_proj_uniforms.projection;\n    out.clip_position = (_e22 * model_view_pos);\n    let _e24 = out;\n    return _e24;\n}\n";
+        }
+        pub mod vs_skinned {
+            pub const NAME: &'static str = "vs_skinned";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1x: vec4<f32>,\n    @location(8) model_2x: vec4<f32>,\n    @location(9) model_3x: vec4<f32>,\n    @location(10) model_4x: vec4<f32>,\n    @location(11) normal_1x: vec4<f32>,\n    @location(12) normal_2x: vec4<f32>,\n    @location(13) normal_3x: vec4<f32>,\n    @location(14) normal_4x: vec4<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_1: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance_1.model_1x, instance_1.model_2x, instance_1.model_3x, instance_1.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@vertex \nfn vs_skinned(vertex: SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> VertexOutput {\n    var out: VertexOutput;\n\n    let joints = (vertex.joints + vec4(instance.joint_offset));\n    let _e6 = instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance);\n    let _e10 = joint_matrices[joints.x];\n    let _e14 = joint_matrices[joints.y];\n    let _e18 = joint_matrices[joints.z];\n    let _e22 = joint_matrices[joints.w];\n    let _e24 = blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(_e10, _e14, _e18, _e22, vertex.weights);\n    let model_transform = (_e6 * _e24);\n    let _e28 = view_proj_uniforms.view;\n    let model_view_pos = ((_e28 * model_transform) * vertex.position);\n    let _e36 = view_proj_uniforms.projection;\n    out.clip_position = (_e36 * model_view_pos);\n    let _e38 = out;\n    return _e38;\n}\n";
         }
         pub mod fs_main {
             pub const NAME: &'static str = "fs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1x: vec4<f32>,\n    @location(7) model_2x: vec4<f32>,\n    @location(8) model_3x: vec4<f32>,\n    @location(9) model_4x: vec4<f32>,\n    @location(10) normal_1x: vec4<f32>,\n    @location(11) normal_2x: vec4<f32>,\n    @location(12) normal_3x: vec4<f32>,\n    @location(13) normal_4x: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n\n@fragment \nfn fs_main(in: VertexOutput) {\n    return;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1x: vec4<f32>,\n    @location(8) model_2x: vec4<f32>,\n    @location(9) model_3x: vec4<f32>,\n    @location(10) model_4x: vec4<f32>,\n    @location(11) normal_1x: vec4<f32>,\n    @location(12) normal_2x: vec4<f32>,\n    @location(13) normal_3x: vec4<f32>,\n    @location(14) normal_4x: vec4<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@fragment \nfn fs_main(in: VertexOutput) {\n    return;\n}\n";
         }
     }
     #[allow(unused)]
@@ -914,7 +941,7 @@ pub mod depth_only {
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1x: vec4<f32>,\n    @location(7) model_2x: vec4<f32>,\n    @location(8) model_3x: vec4<f32>,\n    @location(9) model_4x: vec4<f32>,\n    @location(10) normal_1x: vec4<f32>,\n    @location(11) normal_2x: vec4<f32>,\n    @location(12) normal_3x: vec4<f32>,\n    @location(13) normal_4x: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n\n@vertex \nfn vs_main(vertex: VertexInputX_naga_oil_mod_XNFXHA5LUOMX, instance: InstanceInputX_naga_oil_mod_XNFXHA5LUOMX) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n    let normal_matrix = mat4x4<f32>(instance.normal_1x, instance.normal_2x, instance.normal_3x, instance.normal_4x);\n    let _e13 = view_proj_uniforms.view;\n    let model_view = (_e13 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e22 = view_proj_uniforms.projection;\n    out.clip_position = (_e22 * model_view_pos);\n    let _e24 = out;\n    return _e24;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) {\n    return;\n}\n";
+    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1x: vec4<f32>,\n    @location(8) model_2x: vec4<f32>,\n    @location(9) model_3x: vec4<f32>,\n    @location(10) model_4x: vec4<f32>,\n    @location(11) normal_1x: vec4<f32>,\n    @location(12) normal_2x: vec4<f32>,\n    @location(13) normal_3x: vec4<f32>,\n    @location(14) normal_4x: vec4<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_2: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance_2.model_1x, instance_2.model_2x, instance_2.model_3x, instance_2.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@vertex \nfn vs_main(vertex: VertexInputX_naga_oil_mod_XNFXHA5LUOMX, instance: InstanceInputX_naga_oil_mod_XNFXHA5LUOMX) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n    let normal_matrix = mat4x4<f32>(instance.normal_1x, instance.normal_2x, instance.normal_3x, instance.normal_4x);\n    let _e13 = view_proj_uniforms.view;\n    let model_view = (_e13 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e22 = view_proj_uniforms.projection;\n    out.clip_position = (_e22 * model_view_pos);\n    let _e24 = out;\n    return _e24;\n}\n\n@vertex \nfn vs_skinned(vertex_1: SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance_1: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> VertexOutput {\n    var out_1: VertexOutput;\n\n    let joints = (vertex_1.joints + vec4(instance_1.joint_offset));\n    let _e6 = instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_1);\n    let _e10 = joint_matrices[joints.x];\n    let _e14 = joint_matrices[joints.y];\n    let _e18 = joint_matrices[joints.z];\n    let _e22 = joint_matrices[joints.w];\n    let _e24 = blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(_e10, _e14, _e18, _e22, vertex_1.weights);\n    let model_transform_1 = (_e6 * _e24);\n    let _e28 = view_proj_uniforms.view;\n    let model_view_pos_1 = ((_e28 * model_transform_1) * vertex_1.position);\n    let _e36 = view_proj_uniforms.projection;\n    out_1.clip_position = (_e36 * model_view_pos_1);\n    let _e38 = out_1;\n    return _e38;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) {\n    return;\n}\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("depth_only"),
@@ -995,6 +1022,95 @@ pub mod geometry {
                 }
             }
         }
+        ///Information about the `material` global variable within this shader module.
+        pub mod material {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "material";
+            pub type Ty = super::super::super::pbr::types::MaterialUniforms;
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `albedo_texture` global variable within this shader module.
+        pub mod albedo_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "albedo_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Information about the `albedo_sampler` global variable within this shader module.
+        pub mod albedo_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "albedo_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 2u32;
+        }
+        ///Information about the `normal_texture` global variable within this shader module.
+        pub mod normal_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "normal_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 3u32;
+        }
+        ///Information about the `normal_sampler` global variable within this shader module.
+        pub mod normal_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "normal_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 4u32;
+        }
+        ///Information about the `metallic_roughness_texture` global variable within this shader module.
+        pub mod metallic_roughness_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "metallic_roughness_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 5u32;
+        }
+        ///Information about the `metallic_roughness_sampler` global variable within this shader module.
+        pub mod metallic_roughness_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "metallic_roughness_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 6u32;
+        }
+        ///Information about the `occlusion_texture` global variable within this shader module.
+        pub mod occlusion_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "occlusion_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 7u32;
+        }
+        ///Information about the `occlusion_sampler` global variable within this shader module.
+        pub mod occlusion_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "occlusion_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 8u32;
+        }
+        ///Information about the `emissive_texture` global variable within this shader module.
+        pub mod emissive_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "emissive_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 9u32;
+        }
+        ///Information about the `emissive_sampler` global variable within this shader module.
+        pub mod emissive_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "emissive_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 10u32;
+        }
         ///Contains the following bindings: t_diffuse, s_diffuse
         pub mod group0 {
             #[allow(unused)]
@@ -1028,6 +1144,129 @@ pub mod geometry {
                 }
             }
         }
+        ///Contains the following bindings: material, albedo_texture, albedo_sampler, normal_texture, normal_sampler, metallic_roughness_texture, metallic_roughness_sampler, occlusion_texture, occlusion_sampler, emissive_texture, emissive_sampler
+        pub mod group3 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 3u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group3"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 2u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 3u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 4u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 5u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 6u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 7u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 8u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 9u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 10u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
     }
     #[allow(unused)]
     ///Information about the constants within the module, exposed as constants and functions.
@@ -1043,12 +1282,12 @@ pub mod geometry {
         pub mod vs_main {
             pub const NAME: &'static str = "vs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1_: vec4<f32>,\n    @location(7) model_2_: vec4<f32>,\n    @location(8) model_3_: vec4<f32>,\n    @location(9) model_4_: vec4<f32>,\n    @location(10) normal_1_: vec4<f32>,\n    @location(11) normal_2_: vec4<f32>,\n    @location(12) normal_3_: vec4<f32>,\n    @location(13) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec4<f32>,\n    @location(3) tint_color: vec4<f32>,\n}\n\nstruct FragmentOutput {\n    @location(0) g_position: vec4<f32>,\n    @location(1) g_normal: vec4<f32>,\n    @location(2) g_albedo_spec: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let normal_matrix = mat4x4<f32>(instance.normal_1_, instance.normal_2_, instance.normal_3_, instance.normal_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    let _e21 = view_proj_uniforms.view;\n    let model_view = (_e21 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e28 = view_proj_uniforms.projection;\n    out.clip_position = (_e28 * model_view_pos);\n    out.view_space_normal = (normal_matrix * vec4<f32>(vertex.normal, 1f));\n    out.view_pos = model_view_pos;\n    out.tint_color = instance.tint;\n    let _e38 = out;\n    return _e38;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct MaterialUniformsX_naga_oil_mod_XOBRHEX {\n    base_color: vec4<f32>,\n    emissive: vec3<f32>,\n    metallic: f32,\n    roughness: f32,\n    occlusion_strength: f32,\n    normal_scale: f32,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1_: vec4<f32>,\n    @location(8) model_2_: vec4<f32>,\n    @location(9) model_3_: vec4<f32>,\n    @location(10) model_4_: vec4<f32>,\n    @location(11) normal_1_: vec4<f32>,\n    @location(12) normal_2_: vec4<f32>,\n    @location(13) normal_3_: vec4<f32>,\n    @location(14) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec4<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) view_space_tangent: vec4<f32>,\n}\n\nstruct FragmentOutput {\n    @location(0) g_position: vec4<f32>,\n    @location(1) g_normal: vec4<f32>,\n    @location(2) g_albedo_spec: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<uniform> material: MaterialUniformsX_naga_oil_mod_XOBRHEX;\n@group(3) @binding(1) \nvar albedo_texture: texture_2d<f32>;\n@group(3) @binding(2) \nvar albedo_sampler: sampler;\n@group(3) @binding(3) \nvar normal_texture: texture_2d<f32>;\n@group(3) @binding(4) \nvar normal_sampler: sampler;\n@group(3) @binding(5) \nvar metallic_roughness_texture: texture_2d<f32>;\n@group(3) @binding(6) \nvar metallic_roughness_sampler: sampler;\n@group(3) @binding(7) \nvar occlusion_texture: texture_2d<f32>;\n@group(3) @binding(8) \nvar occlusion_sampler: sampler;\n@group(3) @binding(9) \nvar emissive_texture: texture_2d<f32>;\n@group(3) @binding(10) \nvar emissive_sampler: sampler;\n\nfn perturb_normalX_naga_oil_mod_XOBRHEX(n: vec3<f32>, pos: vec3<f32>, uv: vec2<f32>, map_normal: vec3<f32>, scale: f32) -> vec3<f32> {\n    let dp1_ = dpdx(pos);\n    let dp2_ = dpdy(pos);\n    let duv1_ = dpdx(uv);\n    let duv2_ = dpdy(uv);\n    let dp2_perp = cross(dp2_, n);\n    let dp1_perp = cross(n, dp1_);\n    let t = ((dp2_perp * duv1_.x) + (dp1_perp * duv2_.x));\n    let b = ((dp2_perp * duv1_.y) + (dp1_perp * duv2_.y));\n    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 0.000000000001f));\n    let tbn = mat3x3<f32>((t * inv_max), (b * inv_max), n);\n    return normalize((tbn * vec3<f32>((map_normal.xy * scale), map_normal.z)));\n}\n\nfn tangent_space_normalX_naga_oil_mod_XOBRHEX(n_1: vec3<f32>, tangent: vec4<f32>, pos_1: vec3<f32>, uv_1: vec2<f32>, map_normal_1: vec3<f32>, scale_1: f32) -> vec3<f32> {\n    let _e5 = perturb_normalX_naga_oil_mod_XOBRHEX(n_1, pos_1, uv_1, map_normal_1, scale_1);\n    if (dot(tangent.xyz, tangent.xyz) < 0.000000000001f) {\n        return _e5;\n    }\n    let t_1 = normalize((tangent.xyz - (n_1 * dot(n_1, tangent.xyz))));\n    let b_1 = (sign(tangent.w) * cross(n_1, t_1));\n    let tbn_1 = mat3x3<f32>(t_1, b_1, n_1);\n    return normalize((tbn_1 * vec3<f32>((map_normal_1.xy * scale_1), map_normal_1.z)));\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let normal_matrix = mat4x4<f32>(instance.normal_1_, instance.normal_2_, instance.normal_3_, instance.normal_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    let _e21 = view_proj_uniforms.view;\n    let model_view = (_e21 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e28 = view_proj_uniforms.projection;\n    out.clip_position = (_e28 * model_view_pos);\n    out.view_space_normal = (normal_matrix * vec4<f32>(vertex.normal, 1f));\n    out.view_space_tangent = vec4<f32>((model_view * vec4<f32>(vertex.tangent.xyz, 0f)).xyz, vertex.tangent.w);\n    out.view_pos = model_view_pos;\n    out.tint_color = instance.tint;\n    let _e48 = out;\n    return _e48;\n}\n";
         }
         pub mod fs_main {
             pub const NAME: &'static str = "fs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1_: vec4<f32>,\n    @location(7) model_2_: vec4<f32>,\n    @location(8) model_3_: vec4<f32>,\n    @location(9) model_4_: vec4<f32>,\n    @location(10) normal_1_: vec4<f32>,\n    @location(11) normal_2_: vec4<f32>,\n    @location(12) normal_3_: vec4<f32>,\n    @location(13) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec4<f32>,\n    @location(3) tint_color: vec4<f32>,\n}\n\nstruct FragmentOutput {\n    @location(0) g_position: vec4<f32>,\n    @location(1) g_normal: vec4<f32>,\n    @location(2) g_albedo_spec: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n\n@fragment \nfn fs_main(in: VertexOutput) -> FragmentOutput {\n    var out: FragmentOutput;\n\n    out.g_position = in.view_pos;\n    out.g_normal = normalize(in.view_space_normal);\n    let _e12 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    out.g_albedo_spec = (in.tint_color * _e12);\n    let _e14 = out;\n    return _e14;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct MaterialUniformsX_naga_oil_mod_XOBRHEX {\n    base_color: vec4<f32>,\n    emissive: vec3<f32>,\n    metallic: f32,\n    roughness: f32,\n    occlusion_strength: f32,\n    normal_scale: f32,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1_: vec4<f32>,\n    @location(8) model_2_: vec4<f32>,\n    @location(9) model_3_: vec4<f32>,\n    @location(10) model_4_: vec4<f32>,\n    @location(11) normal_1_: vec4<f32>,\n    @location(12) normal_2_: vec4<f32>,\n    @location(13) normal_3_: vec4<f32>,\n    @location(14) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec4<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) view_space_tangent: vec4<f32>,\n}\n\nstruct FragmentOutput {\n    @location(0) g_position: vec4<f32>,\n    @location(1) g_normal: vec4<f32>,\n    @location(2) g_albedo_spec: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<uniform> material: MaterialUniformsX_naga_oil_mod_XOBRHEX;\n@group(3) @binding(1) \nvar albedo_texture: texture_2d<f32>;\n@group(3) @binding(2) \nvar albedo_sampler: sampler;\n@group(3) @binding(3) \nvar normal_texture: texture_2d<f32>;\n@group(3) @binding(4) \nvar normal_sampler: sampler;\n@group(3) @binding(5) \nvar metallic_roughness_texture: texture_2d<f32>;\n@group(3) @binding(6) \nvar metallic_roughness_sampler: sampler;\n@group(3) @binding(7) \nvar occlusion_texture: texture_2d<f32>;\n@group(3) @binding(8) \nvar occlusion_sampler: sampler;\n@group(3) @binding(9) \nvar emissive_texture: texture_2d<f32>;\n@group(3) @binding(10) \nvar emissive_sampler: sampler;\n\nfn perturb_normalX_naga_oil_mod_XOBRHEX(n: vec3<f32>, pos: vec3<f32>, uv: vec2<f32>, map_normal: vec3<f32>, scale: f32) -> vec3<f32> {\n    let dp1_ = dpdx(pos);\n    let dp2_ = dpdy(pos);\n    let duv1_ = dpdx(uv);\n    let duv2_ = dpdy(uv);\n    let dp2_perp = cross(dp2_, n);\n    let dp1_perp = cross(n, dp1_);\n    let t = ((dp2_perp * duv1_.x) + (dp1_perp * duv2_.x));\n    let b = ((dp2_perp * duv1_.y) + (dp1_perp * duv2_.y));\n    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 0.000000000001f));\n    let tbn = mat3x3<f32>((t * inv_max), (b * inv_max), n);\n    return normalize((tbn * vec3<f32>((map_normal.xy * scale), map_normal.z)));\n}\n\nfn tangent_space_normalX_naga_oil_mod_XOBRHEX(n_1: vec3<f32>, tangent: vec4<f32>, pos_1: vec3<f32>, uv_1: vec2<f32>, map_normal_1: vec3<f32>, scale_1: f32) -> vec3<f32> {\n    let _e5 = perturb_normalX_naga_oil_mod_XOBRHEX(n_1, pos_1, uv_1, map_normal_1, scale_1);\n    if (dot(tangent.xyz, tangent.xyz) < 0.000000000001f) {\n        return _e5;\n    }\n    let t_1 = normalize((tangent.xyz - (n_1 * dot(n_1, tangent.xyz))));\n    let b_1 = (sign(tangent.w) * cross(n_1, t_1));\n    let tbn_1 = mat3x3<f32>(t_1, b_1, n_1);\n    return normalize((tbn_1 * vec3<f32>((map_normal_1.xy * scale_1), map_normal_1.z)));\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> FragmentOutput {\n    var out: FragmentOutput;\n\n    let metallic_roughness = textureSample(metallic_roughness_texture, metallic_roughness_sampler, in.tex_coords);\n    let _e7 = material.metallic;\n    let metallic = clamp((_e7 * metallic_roughness.z), 0f, 1f);\n    let _e15 = material.roughness;\n    let roughness = clamp((_e15 * metallic_roughness.y), 0.04f, 1f);\n    out.g_position = vec4<f32>(in.view_pos.xyz, metallic);\n    let _e29 = textureSample(normal_texture, normal_sampler, in.tex_coords);\n    let map_normal_2 = ((_e29.xyz * 2f) - vec3(1f));\n    let _e45 = material.normal_scale;\n    let _e46 = tangent_space_normalX_naga_oil_mod_XOBRHEX(normalize(in.view_space_normal.xyz), in.view_space_tangent, in.view_pos.xyz, in.tex_coords, map_normal_2, _e45);\n    out.g_normal = vec4<f32>(_e46, roughness);\n    let _e54 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let _e58 = material.base_color;\n    let _e63 = textureSample(albedo_texture, albedo_sampler, in.tex_coords);\n    out.g_albedo_spec = (((in.tint_color * _e54) * _e58) * _e63);\n    let _e65 = out;\n    return _e65;\n}\n";
         }
     }
     #[allow(unused)]
@@ -1057,7 +1296,7 @@ pub mod geometry {
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1_: vec4<f32>,\n    @location(7) model_2_: vec4<f32>,\n    @location(8) model_3_: vec4<f32>,\n    @location(9) model_4_: vec4<f32>,\n    @location(10) normal_1_: vec4<f32>,\n    @location(11) normal_2_: vec4<f32>,\n    @location(12) normal_3_: vec4<f32>,\n    @location(13) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec4<f32>,\n    @location(3) tint_color: vec4<f32>,\n}\n\nstruct FragmentOutput {\n    @location(0) g_position: vec4<f32>,\n    @location(1) g_normal: vec4<f32>,\n    @location(2) g_albedo_spec: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let normal_matrix = mat4x4<f32>(instance.normal_1_, instance.normal_2_, instance.normal_3_, instance.normal_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    let _e21 = view_proj_uniforms.view;\n    let model_view = (_e21 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e28 = view_proj_uniforms.projection;\n    out.clip_position = (_e28 * model_view_pos);\n    out.view_space_normal = (normal_matrix * vec4<f32>(vertex.normal, 1f));\n    out.view_pos = model_view_pos;\n    out.tint_color = instance.tint;\n    let _e38 = out;\n    return _e38;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> FragmentOutput {\n    var out_1: FragmentOutput;\n\n    out_1.g_position = in.view_pos;\n    out_1.g_normal = normalize(in.view_space_normal);\n    let _e12 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    out_1.g_albedo_spec = (in.tint_color * _e12);\n    let _e14 = out_1;\n    return _e14;\n}\n";
+    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct MaterialUniformsX_naga_oil_mod_XOBRHEX {\n    base_color: vec4<f32>,\n    emissive: vec3<f32>,\n    metallic: f32,\n    roughness: f32,\n    occlusion_strength: f32,\n    normal_scale: f32,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1_: vec4<f32>,\n    @location(8) model_2_: vec4<f32>,\n    @location(9) model_3_: vec4<f32>,\n    @location(10) model_4_: vec4<f32>,\n    @location(11) normal_1_: vec4<f32>,\n    @location(12) normal_2_: vec4<f32>,\n    @location(13) normal_3_: vec4<f32>,\n    @location(14) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec4<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) view_space_tangent: vec4<f32>,\n}\n\nstruct FragmentOutput {\n    @location(0) g_position: vec4<f32>,\n    @location(1) g_normal: vec4<f32>,\n    @location(2) g_albedo_spec: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<uniform> material: MaterialUniformsX_naga_oil_mod_XOBRHEX;\n@group(3) @binding(1) \nvar albedo_texture: texture_2d<f32>;\n@group(3) @binding(2) \nvar albedo_sampler: sampler;\n@group(3) @binding(3) \nvar normal_texture: texture_2d<f32>;\n@group(3) @binding(4) \nvar normal_sampler: sampler;\n@group(3) @binding(5) \nvar metallic_roughness_texture: texture_2d<f32>;\n@group(3) @binding(6) \nvar metallic_roughness_sampler: sampler;\n@group(3) @binding(7) \nvar occlusion_texture: texture_2d<f32>;\n@group(3) @binding(8) \nvar occlusion_sampler: sampler;\n@group(3) @binding(9) \nvar emissive_texture: texture_2d<f32>;\n@group(3) @binding(10) \nvar emissive_sampler: sampler;\n\nfn perturb_normalX_naga_oil_mod_XOBRHEX(n: vec3<f32>, pos: vec3<f32>, uv: vec2<f32>, map_normal: vec3<f32>, scale: f32) -> vec3<f32> {\n    let dp1_ = dpdx(pos);\n    let dp2_ = dpdy(pos);\n    let duv1_ = dpdx(uv);\n    let duv2_ = dpdy(uv);\n    let dp2_perp = cross(dp2_, n);\n    let dp1_perp = cross(n, dp1_);\n    let t = ((dp2_perp * duv1_.x) + (dp1_perp * duv2_.x));\n    let b = ((dp2_perp * duv1_.y) + (dp1_perp * duv2_.y));\n    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 0.000000000001f));\n    let tbn = mat3x3<f32>((t * inv_max), (b * inv_max), n);\n    return normalize((tbn * vec3<f32>((map_normal.xy * scale), map_normal.z)));\n}\n\nfn tangent_space_normalX_naga_oil_mod_XOBRHEX(n_1: vec3<f32>, tangent: vec4<f32>, pos_1: vec3<f32>, uv_1: vec2<f32>, map_normal_1: vec3<f32>, scale_1: f32) -> vec3<f32> {\n    let _e5 = perturb_normalX_naga_oil_mod_XOBRHEX(n_1, pos_1, uv_1, map_normal_1, scale_1);\n    if (dot(tangent.xyz, tangent.xyz) < 0.000000000001f) {\n        return _e5;\n    }\n    let t_1 = normalize((tangent.xyz - (n_1 * dot(n_1, tangent.xyz))));\n    let b_1 = (sign(tangent.w) * cross(n_1, t_1));\n    let tbn_1 = mat3x3<f32>(t_1, b_1, n_1);\n    return normalize((tbn_1 * vec3<f32>((map_normal_1.xy * scale_1), map_normal_1.z)));\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let normal_matrix = mat4x4<f32>(instance.normal_1_, instance.normal_2_, instance.normal_3_, instance.normal_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    let _e21 = view_proj_uniforms.view;\n    let model_view = (_e21 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e28 = view_proj_uniforms.projection;\n    out.clip_position = (_e28 * model_view_pos);\n    out.view_space_normal = (normal_matrix * vec4<f32>(vertex.normal, 1f));\n    out.view_space_tangent = vec4<f32>((model_view * vec4<f32>(vertex.tangent.xyz, 0f)).xyz, vertex.tangent.w);\n    out.view_pos = model_view_pos;\n    out.tint_color = instance.tint;\n    let _e48 = out;\n    return _e48;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> FragmentOutput {\n    var out_1: FragmentOutput;\n\n    let metallic_roughness = textureSample(metallic_roughness_texture, metallic_roughness_sampler, in.tex_coords);\n    let _e7 = material.metallic;\n    let metallic = clamp((_e7 * metallic_roughness.z), 0f, 1f);\n    let _e15 = material.roughness;\n    let roughness = clamp((_e15 * metallic_roughness.y), 0.04f, 1f);\n    out_1.g_position = vec4<f32>(in.view_pos.xyz, metallic);\n    let _e29 = textureSample(normal_texture, normal_sampler, in.tex_coords);\n    let map_normal_2 = ((_e29.xyz * 2f) - vec3(1f));\n    let _e45 = material.normal_scale;\n    let _e46 = tangent_space_normalX_naga_oil_mod_XOBRHEX(normalize(in.view_space_normal.xyz), in.view_space_tangent, in.view_pos.xyz, in.tex_coords, map_normal_2, _e45);\n    out_1.g_normal = vec4<f32>(_e46, roughness);\n    let _e54 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let _e58 = material.base_color;\n    let _e63 = textureSample(albedo_texture, albedo_sampler, in.tex_coords);\n    out_1.g_albedo_spec = (((in.tint_color * _e54) * _e58) * _e63);\n    let _e65 = out_1;\n    return _e65;\n}\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("geometry"),
@@ -1443,26 +1682,32 @@ pub mod inputs {
         #[derive(Debug, PartialEq, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
         pub struct VertexInput {
             pub position: glam::f32::Vec4,
+            pub tangent: glam::f32::Vec4,
             pub tex_coords: glam::f32::Vec2,
             pub normal: glam::f32::Vec3,
         }
         impl VertexInput {
-            pub const VERTEX_ATTRIBUTES: [::wgpu::VertexAttribute; 3usize] = [
+            pub const VERTEX_ATTRIBUTES: [::wgpu::VertexAttribute; 4usize] = [
                 ::wgpu::VertexAttribute {
                     format: ::wgpu::VertexFormat::Float32x4,
                     offset: 0u64,
                     shader_location: 0u32,
                 },
                 ::wgpu::VertexAttribute {
-                    format: ::wgpu::VertexFormat::Float32x2,
+                    format: ::wgpu::VertexFormat::Float32x4,
                     offset: 16u64,
                     shader_location: 1u32,
                 },
                 ::wgpu::VertexAttribute {
-                    format: ::wgpu::VertexFormat::Float32x3,
-                    offset: 24u64,
+                    format: ::wgpu::VertexFormat::Float32x2,
+                    offset: 32u64,
                     shader_location: 2u32,
                 },
+                ::wgpu::VertexAttribute {
+                    format: ::wgpu::VertexFormat::Float32x3,
+                    offset: 40u64,
+                    shader_location: 3u32,
+                },
             ];
             pub fn vertex_buffer_layout() -> ::wgpu::VertexBufferLayout<'static> {
                 ::wgpu::VertexBufferLayout {
@@ -1558,7 +1803,7 @@ pub mod inputs {
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1x: vec4<f32>,\n    @location(7) model_2x: vec4<f32>,\n    @location(8) model_3x: vec4<f32>,\n    @location(9) model_4x: vec4<f32>,\n    @location(10) normal_1x: vec4<f32>,\n    @location(11) normal_2x: vec4<f32>,\n    @location(12) normal_3x: vec4<f32>,\n    @location(13) normal_4x: vec4<f32>,\n}\n\n";
+    pub const SOURCE: &'static str = "struct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1x: vec4<f32>,\n    @location(8) model_2x: vec4<f32>,\n    @location(9) model_3x: vec4<f32>,\n    @location(10) model_4x: vec4<f32>,\n    @location(11) normal_1x: vec4<f32>,\n    @location(12) normal_2x: vec4<f32>,\n    @location(13) normal_3x: vec4<f32>,\n    @location(14) normal_4x: vec4<f32>,\n}\n\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("inputs"),
@@ -1586,7 +1831,7 @@ pub mod global {
         pub mod main {
             pub const NAME: &'static str = "main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexData {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\n@fragment \nfn main() {\n    return;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexData {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct SkinnedVertexData {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\n@fragment \nfn main() {\n    return;\n}\n";
         }
     }
     #[allow(unused)]
@@ -1615,26 +1860,84 @@ pub mod global {
         #[derive(Debug, PartialEq, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
         pub struct ModelVertexData {
             pub position: glam::f32::Vec4,
+            pub tangent: glam::f32::Vec4,
             pub tex_coords: glam::f32::Vec2,
             pub normal: glam::f32::Vec3,
         }
         impl ModelVertexData {
-            pub const VERTEX_ATTRIBUTES: [::wgpu::VertexAttribute; 3usize] = [
+            pub const VERTEX_ATTRIBUTES: [::wgpu::VertexAttribute; 4usize] = [
                 ::wgpu::VertexAttribute {
                     format: ::wgpu::VertexFormat::Float32x4,
                     offset: 0u64,
                     shader_location: 0u32,
                 },
                 ::wgpu::VertexAttribute {
-                    format: ::wgpu::VertexFormat::Float32x2,
+                    format: ::wgpu::VertexFormat::Float32x4,
                     offset: 16u64,
                     shader_location: 1u32,
                 },
+                ::wgpu::VertexAttribute {
+                    format: ::wgpu::VertexFormat::Float32x2,
+                    offset: 32u64,
+                    shader_location: 2u32,
+                },
                 ::wgpu::VertexAttribute {
                     format: ::wgpu::VertexFormat::Float32x3,
-                    offset: 24u64,
+                    offset: 40u64,
+                    shader_location: 3u32,
+                },
+            ];
+            pub fn vertex_buffer_layout() -> ::wgpu::VertexBufferLayout<'static> {
+                ::wgpu::VertexBufferLayout {
+                    array_stride: ::std::mem::size_of::<Self>() as ::wgpu::BufferAddress,
+                    step_mode: ::wgpu::VertexStepMode::Vertex,
+                    attributes: &Self::VERTEX_ATTRIBUTES,
+                }
+            }
+        }
+        #[allow(unused, non_camel_case_types)]
+        #[repr(C)]
+        #[derive(Debug, PartialEq, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+        pub struct SkinnedVertexData {
+            pub position: glam::f32::Vec4,
+            pub tangent: glam::f32::Vec4,
+            pub weights: glam::f32::Vec4,
+            pub joints: glam::u32::UVec4,
+            pub tex_coords: glam::f32::Vec2,
+            pub normal: glam::f32::Vec3,
+        }
+        impl SkinnedVertexData {
+            pub const VERTEX_ATTRIBUTES: [::wgpu::VertexAttribute; 6usize] = [
+                ::wgpu::VertexAttribute {
+                    format: ::wgpu::VertexFormat::Float32x4,
+                    offset: 0u64,
+                    shader_location: 0u32,
+                },
+                ::wgpu::VertexAttribute {
+                    format: ::wgpu::VertexFormat::Float32x4,
+                    offset: 16u64,
+                    shader_location: 1u32,
+                },
+                ::wgpu::VertexAttribute {
+                    format: ::wgpu::VertexFormat::Float32x4,
+                    offset: 32u64,
                     shader_location: 2u32,
                 },
+                ::wgpu::VertexAttribute {
+                    format: ::wgpu::VertexFormat::Uint32x4,
+                    offset: 48u64,
+                    shader_location: 3u32,
+                },
+                ::wgpu::VertexAttribute {
+                    format: ::wgpu::VertexFormat::Float32x2,
+                    offset: 64u64,
+                    shader_location: 4u32,
+                },
+                ::wgpu::VertexAttribute {
+                    format: ::wgpu::VertexFormat::Float32x3,
+                    offset: 72u64,
+                    shader_location: 5u32,
+                },
             ];
             pub fn vertex_buffer_layout() -> ::wgpu::VertexBufferLayout<'static> {
                 ::wgpu::VertexBufferLayout {
@@ -1648,7 +1951,7 @@ pub mod global {
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexData {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\n@fragment \nfn main() {\n    return;\n}\n";
+    pub const SOURCE: &'static str = "struct GlobalUniforms {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniforms {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexData {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct SkinnedVertexData {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\n@fragment \nfn main() {\n    return;\n}\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("global"),
@@ -1729,6 +2032,33 @@ pub mod shadow_map {
                 }
             }
         }
+        ///Information about the `joint_matrices` global variable within this shader module.
+        pub mod joint_matrices {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "joint_matrices";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("joint_matrices"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Storage {
+                                    read_only: true,
+                                },
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
         ///Contains the following bindings: t_diffuse, s_diffuse
         pub mod group0 {
             #[allow(unused)]
@@ -1777,12 +2107,17 @@ pub mod shadow_map {
         pub mod vs_main {
             pub const NAME: &'static str = "vs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1x: vec4<f32>,\n    @location(7) model_2x: vec4<f32>,\n    @location(8) model_3x: vec4<f32>,\n    @location(9) model_4x: vec4<f32>,\n    @location(10) normal_1x: vec4<f32>,\n    @location(11) normal_2x: vec4<f32>,\n    @location(12) normal_3x: vec4<f32>,\n    @location(13) normal_4x: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n\n@vertex \nfn vs_main(vertex: VertexInputX_naga_oil_mod_XNFXHA5LUOMX, instance: InstanceInputX_naga_oil_mod_XNFXHA5LUOMX) -> @builtin(position) vec4<f32> {\n    let model_transform = mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n    let model = (model_transform * vertex.position);\n    let _e11 = view_proj_uniforms.view;\n    let model_view = (_e11 * model);\n    let _e15 = view_proj_uniforms.projection;\n    return (_e15 * model_view);\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1x: vec4<f32>,\n    @location(8) model_2x: vec4<f32>,\n    @location(9) model_3x: vec4<f32>,\n    @location(10) model_4x: vec4<f32>,\n    @location(11) normal_1x: vec4<f32>,\n    @location(12) normal_2x: vec4<f32>,\n    @location(13) normal_3x: vec4<f32>,\n    @location(14) normal_4x: vec4<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_1: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance_1.model_1x, instance_1.model_2x, instance_1.model_3x, instance_1.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@vertex \nfn vs_main(vertex: VertexInputX_naga_oil_mod_XNFXHA5LUOMX, instance: InstanceInputX_naga_oil_mod_XNFXHA5LUOMX) -> @builtin(position) vec4<f32> {\n    let model_transform = mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n    let model = (model_transform * vertex.position);\n    let _e11 = view_proj_uniforms.view;\n    let model_view = (_e11 * model);\n    let _e15 = view_proj_uniforms.projection;\n    return (_e15 * model_view);\n}\n";
+        }
+        pub mod vs_skinned {
+            pub const NAME: &'static str = "vs_skinned";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1x: vec4<f32>,\n    @location(8) model_2x: vec4<f32>,\n    @location(9) model_3x: vec4<f32>,\n    @location(10) model_4x: vec4<f32>,\n    @location(11) normal_1x: vec4<f32>,\n    @location(12) normal_2x: vec4<f32>,\n    @location(13) normal_3x: vec4<f32>,\n    @location(14) normal_4x: vec4<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_1: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance_1.model_1x, instance_1.model_2x, instance_1.model_3x, instance_1.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@vertex \nfn vs_skinned(vertex: SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> @builtin(position) vec4<f32> {\n    let joints = (vertex.joints + vec4(instance.joint_offset));\n    let _e6 = instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance);\n    let _e10 = joint_matrices[joints.x];\n    let _e14 = joint_matrices[joints.y];\n    let _e18 = joint_matrices[joints.z];\n    let _e22 = joint_matrices[joints.w];\n    let _e24 = blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(_e10, _e14, _e18, _e22, vertex.weights);\n    let model_transform = (_e6 * _e24);\n    let _e28 = view_proj_uniforms.view;\n    let model_view = (_e28 * model_transform);\n    let _e32 = view_proj_uniforms.projection;\n    return ((_e32 * model_view) * vertex.position);\n}\n";
         }
         pub mod fs_main {
             pub const NAME: &'static str = "fs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1x: vec4<f32>,\n    @location(7) model_2x: vec4<f32>,\n    @location(8) model_3x: vec4<f32>,\n    @location(9) model_4x: vec4<f32>,\n    @location(10) normal_1x: vec4<f32>,\n    @location(11) normal_2x: vec4<f32>,\n    @location(12) normal_3x: vec4<f32>,\n    @location(13) normal_4x: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n\n@fragment \nfn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {\n    return vec4<f32>(position.z, position.z, position.z, 1f);\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1x: vec4<f32>,\n    @location(8) model_2x: vec4<f32>,\n    @location(9) model_3x: vec4<f32>,\n    @location(10) model_4x: vec4<f32>,\n    @location(11) normal_1x: vec4<f32>,\n    @location(12) normal_2x: vec4<f32>,\n    @location(13) normal_3x: vec4<f32>,\n    @location(14) normal_4x: vec4<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@fragment \nfn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {\n    return vec4<f32>(position.z, position.z, position.z, 1f);\n}\n";
         }
     }
     #[allow(unused)]
@@ -1791,7 +2126,7 @@ pub mod shadow_map {
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1x: vec4<f32>,\n    @location(7) model_2x: vec4<f32>,\n    @location(8) model_3x: vec4<f32>,\n    @location(9) model_4x: vec4<f32>,\n    @location(10) normal_1x: vec4<f32>,\n    @location(11) normal_2x: vec4<f32>,\n    @location(12) normal_3x: vec4<f32>,\n    @location(13) normal_4x: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n\n@vertex \nfn vs_main(vertex: VertexInputX_naga_oil_mod_XNFXHA5LUOMX, instance: InstanceInputX_naga_oil_mod_XNFXHA5LUOMX) -> @builtin(position) vec4<f32> {\n    let model_transform = mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n    let model = (model_transform * vertex.position);\n    let _e11 = view_proj_uniforms.view;\n    let model_view = (_e11 * model);\n    let _e15 = view_proj_uniforms.projection;\n    return (_e15 * model_view);\n}\n\n@fragment \nfn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {\n    return vec4<f32>(position.z, position.z, position.z, 1f);\n}\n";
+    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct VertexInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct InstanceInputX_naga_oil_mod_XNFXHA5LUOMX {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1x: vec4<f32>,\n    @location(8) model_2x: vec4<f32>,\n    @location(9) model_3x: vec4<f32>,\n    @location(10) model_4x: vec4<f32>,\n    @location(11) normal_1x: vec4<f32>,\n    @location(12) normal_2x: vec4<f32>,\n    @location(13) normal_3x: vec4<f32>,\n    @location(14) normal_4x: vec4<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_2: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance_2.model_1x, instance_2.model_2x, instance_2.model_3x, instance_2.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@vertex \nfn vs_main(vertex: VertexInputX_naga_oil_mod_XNFXHA5LUOMX, instance: InstanceInputX_naga_oil_mod_XNFXHA5LUOMX) -> @builtin(position) vec4<f32> {\n    let model_transform = mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n    let model = (model_transform * vertex.position);\n    let _e11 = view_proj_uniforms.view;\n    let model_view = (_e11 * model);\n    let _e15 = view_proj_uniforms.projection;\n    return (_e15 * model_view);\n}\n\n@vertex \nfn vs_skinned(vertex_1: SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance_1: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> @builtin(position) vec4<f32> {\n    let joints = (vertex_1.joints + vec4(instance_1.joint_offset));\n    let _e6 = instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_1);\n    let _e10 = joint_matrices[joints.x];\n    let _e14 = joint_matrices[joints.y];\n    let _e18 = joint_matrices[joints.z];\n    let _e22 = joint_matrices[joints.w];\n    let _e24 = blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(_e10, _e14, _e18, _e22, vertex_1.weights);\n    let model_transform_1 = (_e6 * _e24);\n    let _e28 = view_proj_uniforms.view;\n    let model_view_1 = (_e28 * model_transform_1);\n    let _e32 = view_proj_uniforms.projection;\n    return ((_e32 * model_view_1) * vertex_1.position);\n}\n\n@fragment \nfn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {\n    return vec4<f32>(position.z, position.z, position.z, 1f);\n}\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("shadow_map"),
@@ -2187,23 +2522,9 @@ pub mod ssao_blur {
             pub const NAME: &'static str = "depth_buffer";
             pub const GROUP: u32 = 3u32;
             pub const BINDING: u32 = 0u32;
-        }
-        ///Information about the `depth_buffer_sampler` global variable within this shader module.
-        pub mod depth_buffer_sampler {
-            #[allow(unused)]
-            use super::*;
-            pub const NAME: &'static str = "depth_buffer_sampler";
-            pub const GROUP: u32 = 3u32;
-            pub const BINDING: u32 = 1u32;
-        }
-        ///Contains the following bindings: t_diffuse, s_diffuse
-        pub mod group0 {
-            #[allow(unused)]
-            use super::*;
-            pub const GROUP: u32 = 0u32;
             pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
                 ::wgpu::BindGroupLayoutDescriptor {
-                    label: Some("group0"),
+                    label: Some("depth_buffer"),
                     entries: &[
                         ::wgpu::BindGroupLayoutEntry {
                             binding: 0u32,
@@ -2217,26 +2538,18 @@ pub mod ssao_blur {
                             },
                             count: None,
                         },
-                        ::wgpu::BindGroupLayoutEntry {
-                            binding: 1u32,
-                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
-                            ty: ::wgpu::BindingType::Sampler(
-                                ::wgpu::SamplerBindingType::Filtering,
-                            ),
-                            count: None,
-                        },
                     ],
                 }
             }
         }
-        ///Contains the following bindings: depth_buffer, depth_buffer_sampler
-        pub mod group3 {
+        ///Contains the following bindings: t_diffuse, s_diffuse
+        pub mod group0 {
             #[allow(unused)]
             use super::*;
-            pub const GROUP: u32 = 3u32;
+            pub const GROUP: u32 = 0u32;
             pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
                 ::wgpu::BindGroupLayoutDescriptor {
-                    label: Some("group3"),
+                    label: Some("group0"),
                     entries: &[
                         ::wgpu::BindGroupLayoutEntry {
                             binding: 0u32,
@@ -2244,7 +2557,9 @@ pub mod ssao_blur {
                             ty: wgpu::BindingType::Texture {
                                 multisampled: false,
                                 view_dimension: ::wgpu::TextureViewDimension::D2,
-                                sample_type: ::wgpu::TextureSampleType::Depth,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
                             },
                             count: None,
                         },
@@ -2275,12 +2590,12 @@ pub mod ssao_blur {
         pub mod vs_main {
             pub const NAME: &'static str = "vs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) tint_color: vec4<f32>,\n}\n\nstruct BlurUniforms {\n    half_kernel_size: i32,\n    sharpness: f32,\n    step: vec2<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(4) @binding(0) \nvar<uniform> blur_settings: BlurUniforms;\n@group(3) @binding(0) \nvar depth_buffer: texture_depth_2d;\n@group(3) @binding(1) \nvar depth_buffer_sampler: sampler;\n\nfn blur_weight(radius: f32, center_depth: f32, sample_depth: f32) -> f32 {\n    let _e2 = blur_settings.half_kernel_size;\n    let blur_sigma = ((f32(_e2) + 1f) * 0.5f);\n    let blur_falloff = (1f / ((2f * blur_sigma) * blur_sigma));\n    let _e18 = blur_settings.sharpness;\n    let depth_diff = ((sample_depth - center_depth) * _e18);\n    let weight = exp2((((-(radius) * radius) * blur_falloff) - (depth_diff * depth_diff)));\n    return weight;\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    out.tint_color = instance.tint;\n    let _e34 = out;\n    return _e34;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) tint_color: vec4<f32>,\n}\n\nstruct BlurUniforms {\n    half_kernel_size: i32,\n    sharpness: f32,\n    step: vec2<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(4) @binding(0) \nvar<uniform> blur_settings: BlurUniforms;\n@group(3) @binding(0) \nvar depth_buffer: texture_2d<f32>;\n\nfn load_depth(coords: vec2<f32>) -> f32 {\n    let _e1 = textureDimensions(depth_buffer);\n    let size = vec2<i32>(_e1);\n    let texel = clamp(vec2<i32>((coords * vec2<f32>(size))), vec2(0i), (size - vec2(1i)));\n    let _e15 = textureLoad(depth_buffer, texel, 0i);\n    return _e15.x;\n}\n\nfn blur_weight(radius: f32, center_depth: f32, sample_depth: f32) -> f32 {\n    let _e2 = blur_settings.half_kernel_size;\n    let blur_sigma = ((f32(_e2) + 1f) * 0.5f);\n    let blur_falloff = (1f / ((2f * blur_sigma) * blur_sigma));\n    let _e18 = blur_settings.sharpness;\n    let depth_diff = ((sample_depth - center_depth) * _e18);\n    let weight = exp2((((-(radius) * radius) * blur_falloff) - (depth_diff * depth_diff)));\n    return weight;\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    out.tint_color = instance.tint;\n    let _e34 = out;\n    return _e34;\n}\n";
         }
         pub mod fs_main {
             pub const NAME: &'static str = "fs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) tint_color: vec4<f32>,\n}\n\nstruct BlurUniforms {\n    half_kernel_size: i32,\n    sharpness: f32,\n    step: vec2<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(4) @binding(0) \nvar<uniform> blur_settings: BlurUniforms;\n@group(3) @binding(0) \nvar depth_buffer: texture_depth_2d;\n@group(3) @binding(1) \nvar depth_buffer_sampler: sampler;\n\nfn blur_weight(radius: f32, center_depth_1: f32, sample_depth: f32) -> f32 {\n    let _e2 = blur_settings.half_kernel_size;\n    let blur_sigma = ((f32(_e2) + 1f) * 0.5f);\n    let blur_falloff = (1f / ((2f * blur_sigma) * blur_sigma));\n    let _e18 = blur_settings.sharpness;\n    let depth_diff = ((sample_depth - center_depth_1) * _e18);\n    let weight_1 = exp2((((-(radius) * radius) * blur_falloff) - (depth_diff * depth_diff)));\n    return weight_1;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) f32 {\n    var result: f32;\n    var center_depth: f32;\n    var weight: f32 = 1f;\n    var i: i32 = 1i;\n    var i_1: i32 = 1i;\n\n    let _e7 = textureDimensions(t_diffuse, 0i);\n    let texelSize = (vec2<f32>(1f, 1f) / vec2<f32>(_e7));\n    let _e14 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    result = _e14.x;\n    let _e20 = textureSample(depth_buffer, depth_buffer_sampler, in.tex_coords);\n    center_depth = _e20;\n    loop {\n        let _e23 = i;\n        let _e26 = blur_settings.half_kernel_size;\n        if (_e23 <= _e26) {\n        } else {\n            break;\n        }\n        {\n            let _e28 = i;\n            let r = f32(_e28);\n            let _e33 = blur_settings.step;\n            let uv = (in.tex_coords + (r * _e33));\n            let _e38 = textureSample(t_diffuse, s_diffuse, uv);\n            let sample_color = _e38.x;\n            let sample_depth_1 = textureSample(depth_buffer, depth_buffer_sampler, in.tex_coords);\n            let _e44 = center_depth;\n            let _e45 = blur_weight(r, _e44, sample_depth_1);\n            let _e47 = weight;\n            weight = (_e47 + _e45);\n            let _e50 = result;\n            result = (_e50 + (sample_color * _e45));\n        }\n        continuing {\n            let _e53 = i;\n            i = (_e53 + 1i);\n        }\n    }\n    loop {\n        let _e56 = i_1;\n        let _e59 = blur_settings.half_kernel_size;\n        if (_e56 <= _e59) {\n        } else {\n            break;\n        }\n        {\n            let _e61 = i_1;\n            let r_1 = f32(_e61);\n            let _e66 = blur_settings.step;\n            let uv_1 = (in.tex_coords - (r_1 * _e66));\n            let _e71 = textureSample(t_diffuse, s_diffuse, uv_1);\n            let sample_color_1 = _e71.x;\n            let sample_depth_2 = textureSample(depth_buffer, depth_buffer_sampler, in.tex_coords);\n            let _e77 = center_depth;\n            let _e78 = blur_weight(r_1, _e77, sample_depth_2);\n            let _e79 = weight;\n            weight = (_e79 + _e78);\n            let _e82 = result;\n            result = (_e82 + (sample_color_1 * _e78));\n        }\n        continuing {\n            let _e85 = i_1;\n            i_1 = (_e85 + 1i);\n        }\n    }\n    let _e87 = weight;\n    let _e88 = result;\n    result = (_e88 / _e87);\n    let _e90 = result;\n    return _e90;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) tint_color: vec4<f32>,\n}\n\nstruct BlurUniforms {\n    half_kernel_size: i32,\n    sharpness: f32,\n    step: vec2<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(4) @binding(0) \nvar<uniform> blur_settings: BlurUniforms;\n@group(3) @binding(0) \nvar depth_buffer: texture_2d<f32>;\n\nfn load_depth(coords: vec2<f32>) -> f32 {\n    let _e1 = textureDimensions(depth_buffer);\n    let size = vec2<i32>(_e1);\n    let texel = clamp(vec2<i32>((coords * vec2<f32>(size))), vec2(0i), (size - vec2(1i)));\n    let _e15 = textureLoad(depth_buffer, texel, 0i);\n    return _e15.x;\n}\n\nfn blur_weight(radius: f32, center_depth_1: f32, sample_depth: f32) -> f32 {\n    let _e2 = blur_settings.half_kernel_size;\n    let blur_sigma = ((f32(_e2) + 1f) * 0.5f);\n    let blur_falloff = (1f / ((2f * blur_sigma) * blur_sigma));\n    let _e18 = blur_settings.sharpness;\n    let depth_diff = ((sample_depth - center_depth_1) * _e18);\n    let weight_1 = exp2((((-(radius) * radius) * blur_falloff) - (depth_diff * depth_diff)));\n    return weight_1;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) f32 {\n    var result: f32;\n    var center_depth: f32;\n    var weight: f32 = 1f;\n    var i: i32 = 1i;\n    var i_1: i32 = 1i;\n\n    let _e7 = textureDimensions(t_diffuse, 0i);\n    let texelSize = (vec2<f32>(1f, 1f) / vec2<f32>(_e7));\n    let _e14 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    result = _e14.x;\n    let _e18 = load_depth(in.tex_coords);\n    center_depth = _e18;\n    loop {\n        let _e21 = i;\n        let _e24 = blur_settings.half_kernel_size;\n        if (_e21 <= _e24) {\n        } else {\n            break;\n        }\n        {\n            let _e26 = i;\n            let r = f32(_e26);\n            let _e31 = blur_settings.step;\n            let uv = (in.tex_coords + (r * _e31));\n            let _e36 = textureSample(t_diffuse, s_diffuse, uv);\n            let sample_color = _e36.x;\n            let _e39 = load_depth(in.tex_coords);\n            let _e40 = center_depth;\n            let _e41 = blur_weight(r, _e40, _e39);\n            let _e43 = weight;\n            weight = (_e43 + _e41);\n            let _e46 = result;\n            result = (_e46 + (sample_color * _e41));\n        }\n        continuing {\n            let _e49 = i;\n            i = (_e49 + 1i);\n        }\n    }\n    loop {\n        let _e52 = i_1;\n        let _e55 = blur_settings.half_kernel_size;\n        if (_e52 <= _e55) {\n        } else {\n            break;\n        }\n        {\n            let _e57 = i_1;\n            let r_1 = f32(_e57);\n            let _e62 = blur_settings.step;\n            let uv_1 = (in.tex_coords - (r_1 * _e62));\n            let _e67 = textureSample(t_diffuse, s_diffuse, uv_1);\n            let sample_color_1 = _e67.x;\n            let _e70 = load_depth(in.tex_coords);\n            let _e71 = center_depth;\n            let _e72 = blur_weight(r_1, _e71, _e70);\n            let _e73 = weight;\n            weight = (_e73 + _e72);\n            let _e76 = result;\n            result = (_e76 + (sample_color_1 * _e72));\n        }\n        continuing {\n            let _e79 = i_1;\n            i_1 = (_e79 + 1i);\n        }\n    }\n    let _e81 = weight;\n    let _e82 = result;\n    result = (_e82 / _e81);\n    let _e84 = result;\n    return _e84;\n}\n";
         }
     }
     #[allow(unused)]
@@ -2298,7 +2613,7 @@ pub mod ssao_blur {
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) tint_color: vec4<f32>,\n}\n\nstruct BlurUniforms {\n    half_kernel_size: i32,\n    sharpness: f32,\n    step: vec2<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(4) @binding(0) \nvar<uniform> blur_settings: BlurUniforms;\n@group(3) @binding(0) \nvar depth_buffer: texture_depth_2d;\n@group(3) @binding(1) \nvar depth_buffer_sampler: sampler;\n\nfn blur_weight(radius: f32, center_depth_1: f32, sample_depth: f32) -> f32 {\n    let _e2 = blur_settings.half_kernel_size;\n    let blur_sigma = ((f32(_e2) + 1f) * 0.5f);\n    let blur_falloff = (1f / ((2f * blur_sigma) * blur_sigma));\n    let _e18 = blur_settings.sharpness;\n    let depth_diff = ((sample_depth - center_depth_1) * _e18);\n    let weight_1 = exp2((((-(radius) * radius) * blur_falloff) - (depth_diff * depth_diff)));\n    return weight_1;\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    out.tint_color = instance.tint;\n    let _e34 = out;\n    return _e34;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) f32 {\n    var result: f32;\n    var center_depth: f32;\n    var weight: f32 = 1f;\n    var i: i32 = 1i;\n    var i_1: i32 = 1i;\n\n    let _e7 = textureDimensions(t_diffuse, 0i);\n    let texelSize = (vec2<f32>(1f, 1f) / vec2<f32>(_e7));\n    let _e14 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    result = _e14.x;\n    let _e20 = textureSample(depth_buffer, depth_buffer_sampler, in.tex_coords);\n    center_depth = _e20;\n    loop {\n        let _e23 = i;\n        let _e26 = blur_settings.half_kernel_size;\n        if (_e23 <= _e26) {\n        } else {\n            break;\n        }\n        {\n            let _e28 = i;\n            let r = f32(_e28);\n            let _e33 = blur_settings.step;\n            let uv = (in.tex_coords + (r * _e33));\n            let _e38 = textureSample(t_diffuse, s_diffuse, uv);\n            let sample_color = _e38.x;\n            let sample_depth_1 = textureSample(depth_buffer, depth_buffer_sampler, in.tex_coords);\n            let _e44 = center_depth;\n            let _e45 = blur_weight(r, _e44, sample_depth_1);\n            let _e47 = weight;\n            weight = (_e47 + _e45);\n            let _e50 = result;\n            result = (_e50 + (sample_color * _e45));\n        }\n        continuing {\n            let _e53 = i;\n            i = (_e53 + 1i);\n        }\n    }\n    loop {\n        let _e56 = i_1;\n        let _e59 = blur_settings.half_kernel_size;\n        if (_e56 <= _e59) {\n        } else {\n            break;\n        }\n        {\n            let _e61 = i_1;\n            let r_1 = f32(_e61);\n            let _e66 = blur_settings.step;\n            let uv_1 = (in.tex_coords - (r_1 * _e66));\n            let _e71 = textureSample(t_diffuse, s_diffuse, uv_1);\n            let sample_color_1 = _e71.x;\n            let sample_depth_2 = textureSample(depth_buffer, depth_buffer_sampler, in.tex_coords);\n            let _e77 = center_depth;\n            let _e78 = blur_weight(r_1, _e77, sample_depth_2);\n            let _e79 = weight;\n            weight = (_e79 + _e78);\n            let _e82 = result;\n            result = (_e82 + (sample_color_1 * _e78));\n        }\n        continuing {\n            let _e85 = i_1;\n            i_1 = (_e85 + 1i);\n        }\n    }\n    let _e87 = weight;\n    let _e88 = result;\n    result = (_e88 / _e87);\n    let _e90 = result;\n    return _e90;\n}\n";
+    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) tint_color: vec4<f32>,\n}\n\nstruct BlurUniforms {\n    half_kernel_size: i32,\n    sharpness: f32,\n    step: vec2<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(4) @binding(0) \nvar<uniform> blur_settings: BlurUniforms;\n@group(3) @binding(0) \nvar depth_buffer: texture_2d<f32>;\n\nfn load_depth(coords: vec2<f32>) -> f32 {\n    let _e1 = textureDimensions(depth_buffer);\n    let size = vec2<i32>(_e1);\n    let texel = clamp(vec2<i32>((coords * vec2<f32>(size))), vec2(0i), (size - vec2(1i)));\n    let _e15 = textureLoad(depth_buffer, texel, 0i);\n    return _e15.x;\n}\n\nfn blur_weight(radius: f32, center_depth_1: f32, sample_depth: f32) -> f32 {\n    let _e2 = blur_settings.half_kernel_size;\n    let blur_sigma = ((f32(_e2) + 1f) * 0.5f);\n    let blur_falloff = (1f / ((2f * blur_sigma) * blur_sigma));\n    let _e18 = blur_settings.sharpness;\n    let depth_diff = ((sample_depth - center_depth_1) * _e18);\n    let weight_1 = exp2((((-(radius) * radius) * blur_falloff) - (depth_diff * depth_diff)));\n    return weight_1;\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e18 = model.x;\n    model.x = ((_e18 * 2f) - 1f);\n    let _e25 = model.y;\n    model.y = ((_e25 * 2f) - 1f);\n    let model_view = model;\n    out.clip_position = model_view;\n    out.tint_color = instance.tint;\n    let _e34 = out;\n    return _e34;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) f32 {\n    var result: f32;\n    var center_depth: f32;\n    var weight: f32 = 1f;\n    var i: i32 = 1i;\n    var i_1: i32 = 1i;\n\n    let _e7 = textureDimensions(t_diffuse, 0i);\n    let texelSize = (vec2<f32>(1f, 1f) / vec2<f32>(_e7));\n    let _e14 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    result = _e14.x;\n    let _e18 = load_depth(in.tex_coords);\n    center_depth = _e18;\n    loop {\n        let _e21 = i;\n        let _e24 = blur_settings.half_kernel_size;\n        if (_e21 <= _e24) {\n        } else {\n            break;\n        }\n        {\n            let _e26 = i;\n            let r = f32(_e26);\n            let _e31 = blur_settings.step;\n            let uv = (in.tex_coords + (r * _e31));\n            let _e36 = textureSample(t_diffuse, s_diffuse, uv);\n            let sample_color = _e36.x;\n            let _e39 = load_depth(in.tex_coords);\n            let _e40 = center_depth;\n            let _e41 = blur_weight(r, _e40, _e39);\n            let _e43 = weight;\n            weight = (_e43 + _e41);\n            let _e46 = result;\n            result = (_e46 + (sample_color * _e41));\n        }\n        continuing {\n            let _e49 = i;\n            i = (_e49 + 1i);\n        }\n    }\n    loop {\n        let _e52 = i_1;\n        let _e55 = blur_settings.half_kernel_size;\n        if (_e52 <= _e55) {\n        } else {\n            break;\n        }\n        {\n            let _e57 = i_1;\n            let r_1 = f32(_e57);\n            let _e62 = blur_settings.step;\n            let uv_1 = (in.tex_coords - (r_1 * _e62));\n            let _e67 = textureSample(t_diffuse, s_diffuse, uv_1);\n            let sample_color_1 = _e67.x;\n            let _e70 = load_depth(in.tex_coords);\n            let _e71 = center_depth;\n            let _e72 = blur_weight(r_1, _e71, _e70);\n            let _e73 = weight;\n            weight = (_e73 + _e72);\n            let _e76 = result;\n            result = (_e76 + (sample_color_1 * _e72));\n        }\n        continuing {\n            let _e79 = i_1;\n            i_1 = (_e79 + 1i);\n        }\n    }\n    let _e81 = weight;\n    let _e82 = result;\n    result = (_e82 / _e81);\n    let _e84 = result;\n    return _e84;\n}\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("ssao_blur"),
@@ -2379,13 +2694,129 @@ pub mod forward {
                 }
             }
         }
+        ///Information about the `joint_matrices` global variable within this shader module.
+        pub mod joint_matrices {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "joint_matrices";
+            pub const GROUP: u32 = 7u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("joint_matrices"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Storage {
+                                    read_only: true,
+                                },
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Information about the `material` global variable within this shader module.
+        pub mod material {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "material";
+            pub type Ty = super::super::super::pbr::types::MaterialUniforms;
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `albedo_texture` global variable within this shader module.
+        pub mod albedo_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "albedo_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Information about the `albedo_sampler` global variable within this shader module.
+        pub mod albedo_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "albedo_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 2u32;
+        }
+        ///Information about the `normal_texture` global variable within this shader module.
+        pub mod normal_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "normal_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 3u32;
+        }
+        ///Information about the `normal_sampler` global variable within this shader module.
+        pub mod normal_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "normal_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 4u32;
+        }
+        ///Information about the `metallic_roughness_texture` global variable within this shader module.
+        pub mod metallic_roughness_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "metallic_roughness_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 5u32;
+        }
+        ///Information about the `metallic_roughness_sampler` global variable within this shader module.
+        pub mod metallic_roughness_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "metallic_roughness_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 6u32;
+        }
+        ///Information about the `occlusion_texture` global variable within this shader module.
+        pub mod occlusion_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "occlusion_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 7u32;
+        }
+        ///Information about the `occlusion_sampler` global variable within this shader module.
+        pub mod occlusion_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "occlusion_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 8u32;
+        }
+        ///Information about the `emissive_texture` global variable within this shader module.
+        pub mod emissive_texture {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "emissive_texture";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 9u32;
+        }
+        ///Information about the `emissive_sampler` global variable within this shader module.
+        pub mod emissive_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "emissive_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 10u32;
+        }
         ///Information about the `lights` global variable within this shader module.
         pub mod lights {
             #[allow(unused)]
             use super::*;
             pub const NAME: &'static str = "lights";
             pub type Ty = LightsUniform;
-            pub const GROUP: u32 = 3u32;
+            pub const GROUP: u32 = 4u32;
             pub const BINDING: u32 = 0u32;
         }
         ///Information about the `shadow_map` global variable within this shader module.
@@ -2393,7 +2824,7 @@ pub mod forward {
             #[allow(unused)]
             use super::*;
             pub const NAME: &'static str = "shadow_map";
-            pub const GROUP: u32 = 3u32;
+            pub const GROUP: u32 = 4u32;
             pub const BINDING: u32 = 1u32;
         }
         ///Information about the `shadow_map_sampler` global variable within this shader module.
@@ -2401,15 +2832,55 @@ pub mod forward {
             #[allow(unused)]
             use super::*;
             pub const NAME: &'static str = "shadow_map_sampler";
-            pub const GROUP: u32 = 3u32;
+            pub const GROUP: u32 = 4u32;
             pub const BINDING: u32 = 2u32;
         }
+        ///Information about the `point_shadow_map` global variable within this shader module.
+        pub mod point_shadow_map {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "point_shadow_map";
+            pub const GROUP: u32 = 4u32;
+            pub const BINDING: u32 = 3u32;
+        }
+        ///Information about the `irradiance_map` global variable within this shader module.
+        pub mod irradiance_map {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "irradiance_map";
+            pub const GROUP: u32 = 4u32;
+            pub const BINDING: u32 = 4u32;
+        }
+        ///Information about the `prefiltered_map` global variable within this shader module.
+        pub mod prefiltered_map {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "prefiltered_map";
+            pub const GROUP: u32 = 4u32;
+            pub const BINDING: u32 = 5u32;
+        }
+        ///Information about the `brdf_lut` global variable within this shader module.
+        pub mod brdf_lut {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "brdf_lut";
+            pub const GROUP: u32 = 4u32;
+            pub const BINDING: u32 = 6u32;
+        }
+        ///Information about the `environment_sampler` global variable within this shader module.
+        pub mod environment_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "environment_sampler";
+            pub const GROUP: u32 = 4u32;
+            pub const BINDING: u32 = 7u32;
+        }
         ///Information about the `occlusion_map` global variable within this shader module.
         pub mod occlusion_map {
             #[allow(unused)]
             use super::*;
             pub const NAME: &'static str = "occlusion_map";
-            pub const GROUP: u32 = 4u32;
+            pub const GROUP: u32 = 5u32;
             pub const BINDING: u32 = 0u32;
         }
         ///Information about the `occlusion_map_sampler` global variable within this shader module.
@@ -2417,10 +2888,43 @@ pub mod forward {
             #[allow(unused)]
             use super::*;
             pub const NAME: &'static str = "occlusion_map_sampler";
-            pub const GROUP: u32 = 4u32;
+            pub const GROUP: u32 = 5u32;
             pub const BINDING: u32 = 1u32;
         }
-        ///Contains the following bindings: lights, shadow_map, shadow_map_sampler
+        ///Information about the `clusters_uniform` global variable within this shader module.
+        pub mod clusters_uniform {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "clusters_uniform";
+            pub type Ty = ClusterUniforms;
+            pub const GROUP: u32 = 6u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `cluster_lights` global variable within this shader module.
+        pub mod cluster_lights {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "cluster_lights";
+            pub const GROUP: u32 = 6u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Information about the `clusters` global variable within this shader module.
+        pub mod clusters {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "clusters";
+            pub const GROUP: u32 = 6u32;
+            pub const BINDING: u32 = 2u32;
+        }
+        ///Information about the `cluster_light_indices` global variable within this shader module.
+        pub mod cluster_light_indices {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "cluster_light_indices";
+            pub const GROUP: u32 = 6u32;
+            pub const BINDING: u32 = 3u32;
+        }
+        ///Contains the following bindings: material, albedo_texture, albedo_sampler, normal_texture, normal_sampler, metallic_roughness_texture, metallic_roughness_sampler, occlusion_texture, occlusion_sampler, emissive_texture, emissive_sampler
         pub mod group3 {
             #[allow(unused)]
             use super::*;
@@ -2444,8 +2948,10 @@ pub mod forward {
                             visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
                             ty: wgpu::BindingType::Texture {
                                 multisampled: false,
-                                view_dimension: ::wgpu::TextureViewDimension::D2Array,
-                                sample_type: ::wgpu::TextureSampleType::Depth,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
                             },
                             count: None,
                         },
@@ -2453,25 +2959,12 @@ pub mod forward {
                             binding: 2u32,
                             visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
                             ty: ::wgpu::BindingType::Sampler(
-                                ::wgpu::SamplerBindingType::Comparison,
+                                ::wgpu::SamplerBindingType::Filtering,
                             ),
                             count: None,
                         },
-                    ],
-                }
-            }
-        }
-        ///Contains the following bindings: t_diffuse, s_diffuse
-        pub mod group0 {
-            #[allow(unused)]
-            use super::*;
-            pub const GROUP: u32 = 0u32;
-            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
-                ::wgpu::BindGroupLayoutDescriptor {
-                    label: Some("group0"),
-                    entries: &[
                         ::wgpu::BindGroupLayoutEntry {
-                            binding: 0u32,
+                            binding: 3u32,
                             visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
                             ty: wgpu::BindingType::Texture {
                                 multisampled: false,
@@ -2483,7 +2976,67 @@ pub mod forward {
                             count: None,
                         },
                         ::wgpu::BindGroupLayoutEntry {
-                            binding: 1u32,
+                            binding: 4u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 5u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 6u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 7u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 8u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 9u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 10u32,
                             visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
                             ty: ::wgpu::BindingType::Sampler(
                                 ::wgpu::SamplerBindingType::Filtering,
@@ -2494,7 +3047,40 @@ pub mod forward {
                 }
             }
         }
-        ///Contains the following bindings: occlusion_map, occlusion_map_sampler
+        ///Contains the following bindings: t_diffuse, s_diffuse
+        pub mod group0 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group0"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Contains the following bindings: lights, shadow_map, shadow_map_sampler, point_shadow_map, irradiance_map, prefiltered_map, brdf_lut, environment_sampler
         pub mod group4 {
             #[allow(unused)]
             use super::*;
@@ -2502,6 +3088,101 @@ pub mod forward {
             pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
                 ::wgpu::BindGroupLayoutDescriptor {
                     label: Some("group4"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2Array,
+                                sample_type: ::wgpu::TextureSampleType::Depth,
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 2u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Comparison,
+                            ),
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 3u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2Array,
+                                sample_type: ::wgpu::TextureSampleType::Depth,
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 4u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::Cube,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 5u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::Cube,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 6u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 7u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Contains the following bindings: occlusion_map, occlusion_map_sampler
+        pub mod group5 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 5u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group5"),
                     entries: &[
                         ::wgpu::BindGroupLayoutEntry {
                             binding: 0u32,
@@ -2527,6 +3208,65 @@ pub mod forward {
                 }
             }
         }
+        ///Contains the following bindings: clusters_uniform, cluster_lights, clusters, cluster_light_indices
+        pub mod group6 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 6u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group6"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Storage {
+                                    read_only: true,
+                                },
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 2u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Storage {
+                                    read_only: true,
+                                },
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 3u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Storage {
+                                    read_only: true,
+                                },
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
     }
     #[allow(unused)]
     ///Information about the constants within the module, exposed as constants and functions.
@@ -2542,12 +3282,17 @@ pub mod forward {
         pub mod vs_main {
             pub const NAME: &'static str = "vs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1_: vec4<f32>,\n    @location(7) model_2_: vec4<f32>,\n    @location(8) model_3_: vec4<f32>,\n    @location(9) model_4_: vec4<f32>,\n    @location(10) normal_1_: vec4<f32>,\n    @location(11) normal_2_: vec4<f32>,\n    @location(12) normal_3_: vec4<f32>,\n    @location(13) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec3<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) world_pos: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(3) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(3) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n@group(4) @binding(0) \nvar occlusion_map: texture_2d<f32>;\n@group(4) @binding(1) \nvar occlusion_map_sampler: sampler;\n\n@vertex \nfn vs_main(vertex: ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let normal_matrix = mat4x4<f32>(instance.normal_1_, instance.normal_2_, instance.normal_3_, instance.normal_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    let _e21 = view_proj_uniforms.view;\n    let model_view = (_e21 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e28 = view_proj_uniforms.projection;\n    out.clip_position = (_e28 * model_view_pos);\n    out.view_space_normal = (normal_matrix * vec4<f32>(vertex.normal, 1f)).xyz;\n    out.view_pos = model_view_pos;\n    out.tint_color = instance.tint;\n    out.world_pos = (model_transform * vertex.position);\n    let _e42 = out;\n    return _e42;\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nstruct MaterialUniformsX_naga_oil_mod_XOBRHEX {\n    base_color: vec4<f32>,\n    emissive: vec3<f32>,\n    metallic: f32,\n    roughness: f32,\n    occlusion_strength: f32,\n    normal_scale: f32,\n}\n\nstruct InstanceInput {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1_: vec4<f32>,\n    @location(8) model_2_: vec4<f32>,\n    @location(9) model_3_: vec4<f32>,\n    @location(10) model_4_: vec4<f32>,\n    @location(11) normal_1_: vec4<f32>,\n    @location(12) normal_2_: vec4<f32>,\n    @location(13) normal_3_: vec4<f32>,\n    @location(14) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec3<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) world_pos: vec4<f32>,\n    @location(5) view_space_tangent: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n    shadow_index: i32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n    shadow_view_projs: array<mat4x4<f32>, 8>,\n    point_shadow_view_projs: array<mat4x4<f32>, 24>,\n    cascade_count: u32,\n    cascade_blend: f32,\n    environment_intensity: f32,\n    has_environment: u32,\n}\n\nstruct ClusterUniforms {\n    grid_size: vec3<u32>,\n    z_near: f32,\n    z_far: f32,\n    show_heatmap: u32,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(7) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n@group(3) @binding(0) \nvar<uniform> material: MaterialUniformsX_naga_oil_mod_XOBRHEX;\n@group(3) @binding(1) \nvar albedo_texture: texture_2d<f32>;\n@group(3) @binding(2) \nvar albedo_sampler: sampler;\n@group(3) @binding(3) \nvar normal_texture: texture_2d<f32>;\n@group(3) @binding(4) \nvar normal_sampler: sampler;\n@group(3) @binding(5) \nvar metallic_roughness_texture: texture_2d<f32>;\n@group(3) @binding(6) \nvar metallic_roughness_sampler: sampler;\n@group(3) @binding(7) \nvar occlusion_texture: texture_2d<f32>;\n@group(3) @binding(8) \nvar occlusion_sampler: sampler;\n@group(3) @binding(9) \nvar emissive_texture: texture_2d<f32>;\n@group(3) @binding(10) \nvar emissive_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n@group(4) @binding(3) \nvar point_shadow_map: texture_depth_2d_array;\n@group(4) @binding(4) \nvar irradiance_map: texture_cube<f32>;\n@group(4) @binding(5) \nvar prefiltered_map: texture_cube<f32>;\n@group(4) @binding(6) \nvar brdf_lut: texture_2d<f32>;\n@group(4) @binding(7) \nvar environment_sampler: sampler;\n@group(5) @binding(0) \nvar occlusion_map: texture_2d<f32>;\n@group(5) @binding(1) \nvar occlusion_map_sampler: sampler;\n@group(6) @binding(0) \nvar<uniform> clusters_uniform: ClusterUniforms;\n@group(6) @binding(1) \nvar<storage> cluster_lights: array<Light>;\n@group(6) @binding(2) \nvar<storage> clusters: array<vec2<u32>>;\n@group(6) @binding(3) \nvar<storage> cluster_light_indices: array<u32>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_1: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance_1.model_1x, instance_1.model_2x, instance_1.model_3x, instance_1.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\nfn fresnel_schlickX_naga_oil_mod_XOBRHEX(cos_theta: f32, f0_: vec3<f32>) -> vec3<f32> {\n    return (f0_ + ((vec3(1f) - f0_) * pow(clamp((1f - cos_theta), 0f, 1f), 5f)));\n}\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v: f32, n_dot_l: f32, roughness_1: f32) -> f32 {\n    let k = (((roughness_1 + 1f) * (roughness_1 + 1f)) / 8f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\nfn cook_torranceX_naga_oil_mod_XOBRHEX(n: vec3<f32>, v: vec3<f32>, l: vec3<f32>, albedo: vec3<f32>, metallic: f32, roughness_2: f32, radiance: vec3<f32>) -> vec3<f32> {\n    let h = normalize((v + l));\n    let n_dot_l_1 = max(dot(n, l), 0f);\n    let n_dot_v_2 = max(dot(n, v), 0.0001f);\n    let f0_2 = mix(vec3(0.04f), albedo, metallic);\n    let _e19 = fresnel_schlickX_naga_oil_mod_XOBRHEX(max(dot(h, v), 0f), f0_2);\n    let _e24 = distribution_ggxX_naga_oil_mod_XOBRHEX(max(dot(n, h), 0f), roughness_2);\n    let _e25 = geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v_2, n_dot_l_1, roughness_2);\n    let specular = (((_e24 * _e25) * _e19) / vec3(max(((4f * n_dot_v_2) * n_dot_l_1), 0.0001f)));\n    let k_d = ((vec3(1f) - _e19) * (1f - metallic));\n    return (((((k_d * albedo) / vec3(3.1415927f)) + specular) * radiance) * n_dot_l_1);\n}\n\nfn fresnel_schlick_roughnessX_naga_oil_mod_XOBRHEX(cos_theta_1: f32, f0_1: vec3<f32>, roughness_3: f32) -> vec3<f32> {\n    return (f0_1 + ((max(vec3((1f - roughness_3)), f0_1) - f0_1) * pow(clamp((1f - cos_theta_1), 0f, 1f), 5f)));\n}\n\nfn environment_lightingX_naga_oil_mod_XOBRHEX(n_dot_v_1: f32, albedo_1: vec3<f32>, metallic_1: f32, roughness_4: f32, irradiance: vec3<f32>, prefiltered: vec3<f32>, brdf: vec2<f32>) -> vec3<f32> {\n    let f0_3 = mix(vec3(0.04f), albedo_1, metallic_1);\n    let _e7 = fresnel_schlick_roughnessX_naga_oil_mod_XOBRHEX(n_dot_v_1, f0_3, roughness_4);\n    let k_d_1 = ((vec3(1f) - _e7) * (1f - metallic_1));\n    return (((k_d_1 * irradiance) * albedo_1) + (prefiltered * ((f0_3 * brdf.x) + vec3(brdf.y))));\n}\n\nfn perturb_normalX_naga_oil_mod_XOBRHEX(n_1: vec3<f32>, pos: vec3<f32>, uv: vec2<f32>, map_normal: vec3<f32>, scale: f32) -> vec3<f32> {\n    let dp1_ = dpdx(pos);\n    let dp2_ = dpdy(pos);\n    let duv1_ = dpdx(uv);\n    let duv2_ = dpdy(uv);\n    let dp2_perp = cross(dp2_, n_1);\n    let dp1_perp = cross(n_1, dp1_);\n    let t = ((dp2_perp * duv1_.x) + (dp1_perp * duv2_.x));\n    let b = ((dp2_perp * duv1_.y) + (dp1_perp * duv2_.y));\n    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 0.000000000001f));\n    let tbn = mat3x3<f32>((t * inv_max), (b * inv_max), n_1);\n    return normalize((tbn * vec3<f32>((map_normal.xy * scale), map_normal.z)));\n}\n\nfn tangent_space_normalX_naga_oil_mod_XOBRHEX(n_2: vec3<f32>, tangent: vec4<f32>, pos_1: vec3<f32>, uv_1: vec2<f32>, map_normal_1: vec3<f32>, scale_1: f32) -> vec3<f32> {\n    let _e5 = perturb_normalX_naga_oil_mod_XOBRHEX(n_2, pos_1, uv_1, map_normal_1, scale_1);\n    if (dot(tangent.xyz, tangent.xyz) < 0.000000000001f) {\n        return _e5;\n    }\n    let t_1 = normalize((tangent.xyz - (n_2 * dot(n_2, tangent.xyz))));\n    let b_1 = (sign(tangent.w) * cross(n_2, t_1));\n    let tbn_1 = mat3x3<f32>(t_1, b_1, n_2);\n    return normalize((tbn_1 * vec3<f32>((map_normal_1.xy * scale_1), map_normal_1.z)));\n}\n\nfn point_attenuation(distance: f32, radius: f32) -> f32 {\n    let falloff = clamp((1f - pow((distance / radius), 2f)), 0f, 1f);\n    return (falloff * falloff);\n}\n\nfn cube_face(dir: vec3<f32>) -> i32 {\n    let a_1 = abs(dir);\n    if ((a_1.x >= a_1.y) && (a_1.x >= a_1.z)) {\n        return select(1i, 0i, (dir.x > 0f));\n    }\n    if (a_1.y >= a_1.z) {\n        return select(3i, 2i, (dir.y > 0f));\n    }\n    return select(5i, 4i, (dir.z > 0f));\n}\n\nfn shadow_occlusion(map: texture_depth_2d_array, layer: i32, shadow_pos: vec4<f32>, bias: f32) -> f32 {\n    var occlusion: f32 = 0f;\n    var weight: f32 = 0f;\n    var x: i32;\n    var y: i32;\n\n    let _e2 = textureDimensions(map);\n    let texelSize = (1f / f32(_e2.x));\n    const flip_correction = vec2<f32>(0.5f, -0.5f);\n    let proj_correction = (1f / shadow_pos.w);\n    let ShadowCoord = (((shadow_pos.xy * flip_correction) * proj_correction) + vec2<f32>(0.5f, 0.5f));\n    let _e23 = lights.shadow_blur_half_kernel_size;\n    x = -(_e23);\n    loop {\n        let _e26 = x;\n        let _e29 = lights.shadow_blur_half_kernel_size;\n        if (_e26 <= _e29) {\n        } else {\n            break;\n        }\n        {\n            let _e33 = lights.shadow_blur_half_kernel_size;\n            y = -(_e33);\n            loop {\n                let _e36 = y;\n                let _e39 = lights.shadow_blur_half_kernel_size;\n                if (_e36 <= _e39) {\n                } else {\n                    break;\n                }\n                {\n                    let _e45 = x;\n                    let _e47 = y;\n                    let _e56 = textureSampleCompareLevel(map, shadow_map_sampler, (ShadowCoord.xy + (vec2<f32>(f32(_e45), f32(_e47)) * texelSize)), layer, ((shadow_pos.z - bias) / shadow_pos.w));\n                    let _e60 = occlusion;\n                    occlusion = (_e60 + (1f - _e56));\n                    let _e64 = weight;\n                    weight = (_e64 + 1f);\n                }\n                continuing {\n                    let _e67 = y;\n                    y = (_e67 + 1i);\n                }\n            }\n        }\n        continuing {\n            let _e70 = x;\n            x = (_e70 + 1i);\n        }\n    }\n    let _e72 = occlusion;\n    let _e73 = weight;\n    return (_e72 / _e73);\n}\n\nfn cascaded_shadow_occlusion(first_layer: i32, world_pos: vec4<f32>, bias_1: f32) -> f32 {\n    var c: i32 = 0i;\n\n    loop {\n        let _e2 = c;\n        let _e5 = lights.cascade_count;\n        if (_e2 < i32(_e5)) {\n        } else {\n            break;\n        }\n        {\n            let _e9 = c;\n            let layer_1 = (first_layer + _e9);\n            let _e15 = lights.shadow_view_projs[layer_1];\n            let shadow_pos_1 = (_e15 * world_pos);\n            let uv_2 = ((shadow_pos_1.xy * vec2<f32>(0.5f, -0.5f)) + vec2<f32>(0.5f, 0.5f));\n            let edge = min(min(uv_2.x, (1f - uv_2.x)), min(uv_2.y, (1f - uv_2.y)));\n            if (edge < 0f) {\n                continue;\n            }\n            let _e41 = shadow_occlusion(shadow_map, layer_1, shadow_pos_1, bias_1);\n            let _e44 = lights.cascade_blend;\n            let blend = (1f - (edge / max((0.5f * _e44), 0.0001f)));\n            let _e54 = c;\n            let _e59 = lights.cascade_count;\n            if ((blend <= 0f) || ((_e54 + 1i) >= i32(_e59))) {\n                return _e41;\n            }\n            let _e68 = lights.shadow_view_projs[(layer_1 + 1i)];\n            let next_shadow_pos = (_e68 * world_pos);\n            let _e73 = shadow_occlusion(shadow_map, (layer_1 + 1i), next_shadow_pos, bias_1);\n            return mix(_e41, _e73, blend);\n        }\n        continuing {\n            let _e76 = c;\n            c = (_e76 + 1i);\n        }\n    }\n    return 0f;\n}\n\nfn cluster_index(frag_coord: vec2<f32>, view_depth: f32) -> u32 {\n    let grid = clusters_uniform.grid_size;\n    let _e6 = global_uniforms.screen_size;\n    let tile = vec2<u32>(clamp(((frag_coord / _e6) * vec2<f32>(grid.xy)), vec2(0f), vec2<f32>((grid.xy - vec2(1u)))));\n    let _e23 = clusters_uniform.z_near;\n    let _e27 = clusters_uniform.z_near;\n    let _e32 = clusters_uniform.z_far;\n    let _e35 = clusters_uniform.z_near;\n    let depth_ratio = (log((max(view_depth, _e23) / _e27)) / log((_e32 / _e35)));\n    let slice = u32(clamp((depth_ratio * f32(grid.z)), 0f, f32((grid.z - 1u))));\n    return (tile.x + (grid.x * (tile.y + (grid.y * slice))));\n}\n\nfn light_attenuation(light: Light, world_pos_1: vec3<f32>) -> f32 {\n    let light_to_fragment = (world_pos_1 - light.position);\n    if (light.kind == 2u) {\n        let _e9 = point_attenuation(length(light_to_fragment), light.reach);\n        return _e9;\n    } else {\n        if (light.kind == 1u) {\n            let light_dist_sqr = dot(light_to_fragment, light_to_fragment);\n            let spot_factor = dot(normalize(light_to_fragment), light.direction);\n            let reach_sqr = pow(light.reach, 2f);\n            if ((spot_factor > light.radius) && (light_dist_sqr < reach_sqr)) {\n                return (1f - (((1f - spot_factor) * 1f) / (1f - light.radius)));\n            }\n            return 0f;\n        }\n    }\n    return 1f;\n}\n\nfn shade(light_1: Light, visibility: f32, world_pos_2: vec3<f32>, view_pos: vec3<f32>, n_3: vec3<f32>, albedo_2: vec3<f32>, metallic_2: f32, roughness_5: f32) -> vec3<f32> {\n    var l_1: vec3<f32>;\n\n    let _e3 = view_proj_uniforms.view;\n    l_1 = normalize((_e3 * vec4<f32>(light_1.position, 0f)).xyz);\n    if (light_1.kind == 2u) {\n        let _e17 = view_proj_uniforms.view;\n        l_1 = normalize((_e17 * vec4<f32>((light_1.position - world_pos_2), 0f)).xyz);\n    }\n    let v_1 = normalize(-(view_pos));\n    let radiance_1 = (((light_1.color.xyz * light_1.color.w) * PIX_naga_oil_mod_XOBRHEX) * visibility);\n    let _e37 = l_1;\n    let _e42 = cook_torranceX_naga_oil_mod_XOBRHEX(n_3, v_1, _e37, albedo_2, metallic_2, roughness_5, radiance_1);\n    return _e42;\n}\n\n@vertex \nfn vs_main(vertex: ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let normal_matrix = mat4x4<f32>(instance.normal_1_, instance.normal_2_, instance.normal_3_, instance.normal_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    let _e21 = view_proj_uniforms.view;\n    let model_view = (_e21 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e28 = view_proj_uniforms.projection;\n    out.clip_position = (_e28 * model_view_pos);\n    out.view_space_normal = (normal_matrix * vec4<f32>(vertex.normal, 1f)).xyz;\n    out.view_space_tangent = vec4<f32>((model_view * vec4<f32>(vertex.tangent.xyz, 0f)).xyz, vertex.tangent.w);\n    out.view_pos = model_view_pos;\n    out.tint_color = instance.tint;\n    out.world_pos = (model_transform * vertex.position);\n    let _e52 = out;\n    return _e52;\n}\n";
+        }
+        pub mod vs_skinned {
+            pub const NAME: &'static str = "vs_skinned";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nstruct MaterialUniformsX_naga_oil_mod_XOBRHEX {\n    base_color: vec4<f32>,\n    emissive: vec3<f32>,\n    metallic: f32,\n    roughness: f32,\n    occlusion_strength: f32,\n    normal_scale: f32,\n}\n\nstruct InstanceInput {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1_: vec4<f32>,\n    @location(8) model_2_: vec4<f32>,\n    @location(9) model_3_: vec4<f32>,\n    @location(10) model_4_: vec4<f32>,\n    @location(11) normal_1_: vec4<f32>,\n    @location(12) normal_2_: vec4<f32>,\n    @location(13) normal_3_: vec4<f32>,\n    @location(14) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec3<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) world_pos: vec4<f32>,\n    @location(5) view_space_tangent: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n    shadow_index: i32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n    shadow_view_projs: array<mat4x4<f32>, 8>,\n    point_shadow_view_projs: array<mat4x4<f32>, 24>,\n    cascade_count: u32,\n    cascade_blend: f32,\n    environment_intensity: f32,\n    has_environment: u32,\n}\n\nstruct ClusterUniforms {\n    grid_size: vec3<u32>,\n    z_near: f32,\n    z_far: f32,\n    show_heatmap: u32,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(7) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n@group(3) @binding(0) \nvar<uniform> material: MaterialUniformsX_naga_oil_mod_XOBRHEX;\n@group(3) @binding(1) \nvar albedo_texture: texture_2d<f32>;\n@group(3) @binding(2) \nvar albedo_sampler: sampler;\n@group(3) @binding(3) \nvar normal_texture: texture_2d<f32>;\n@group(3) @binding(4) \nvar normal_sampler: sampler;\n@group(3) @binding(5) \nvar metallic_roughness_texture: texture_2d<f32>;\n@group(3) @binding(6) \nvar metallic_roughness_sampler: sampler;\n@group(3) @binding(7) \nvar occlusion_texture: texture_2d<f32>;\n@group(3) @binding(8) \nvar occlusion_sampler: sampler;\n@group(3) @binding(9) \nvar emissive_texture: texture_2d<f32>;\n@group(3) @binding(10) \nvar emissive_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n@group(4) @binding(3) \nvar point_shadow_map: texture_depth_2d_array;\n@group(4) @binding(4) \nvar irradiance_map: texture_cube<f32>;\n@group(4) @binding(5) \nvar prefiltered_map: texture_cube<f32>;\n@group(4) @binding(6) \nvar brdf_lut: texture_2d<f32>;\n@group(4) @binding(7) \nvar environment_sampler: sampler;\n@group(5) @binding(0) \nvar occlusion_map: texture_2d<f32>;\n@group(5) @binding(1) \nvar occlusion_map_sampler: sampler;\n@group(6) @binding(0) \nvar<uniform> clusters_uniform: ClusterUniforms;\n@group(6) @binding(1) \nvar<storage> cluster_lights: array<Light>;\n@group(6) @binding(2) \nvar<storage> clusters: array<vec2<u32>>;\n@group(6) @binding(3) \nvar<storage> cluster_light_indices: array<u32>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_1: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance_1.model_1x, instance_1.model_2x, instance_1.model_3x, instance_1.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\nfn fresnel_schlickX_naga_oil_mod_XOBRHEX(cos_theta: f32, f0_: vec3<f32>) -> vec3<f32> {\n    return (f0_ + ((vec3(1f) - f0_) * pow(clamp((1f - cos_theta), 0f, 1f), 5f)));\n}\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v: f32, n_dot_l: f32, roughness_1: f32) -> f32 {\n    let k = (((roughness_1 + 1f) * (roughness_1 + 1f)) / 8f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\nfn cook_torranceX_naga_oil_mod_XOBRHEX(n: vec3<f32>, v: vec3<f32>, l: vec3<f32>, albedo: vec3<f32>, metallic: f32, roughness_2: f32, radiance: vec3<f32>) -> vec3<f32> {\n    let h = normalize((v + l));\n    let n_dot_l_1 = max(dot(n, l), 0f);\n    let n_dot_v_2 = max(dot(n, v), 0.0001f);\n    let f0_2 = mix(vec3(0.04f), albedo, metallic);\n    let _e19 = fresnel_schlickX_naga_oil_mod_XOBRHEX(max(dot(h, v), 0f), f0_2);\n    let _e24 = distribution_ggxX_naga_oil_mod_XOBRHEX(max(dot(n, h), 0f), roughness_2);\n    let _e25 = geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v_2, n_dot_l_1, roughness_2);\n    let specular = (((_e24 * _e25) * _e19) / vec3(max(((4f * n_dot_v_2) * n_dot_l_1), 0.0001f)));\n    let k_d = ((vec3(1f) - _e19) * (1f - metallic));\n    return (((((k_d * albedo) / vec3(3.1415927f)) + specular) * radiance) * n_dot_l_1);\n}\n\nfn fresnel_schlick_roughnessX_naga_oil_mod_XOBRHEX(cos_theta_1: f32, f0_1: vec3<f32>, roughness_3: f32) -> vec3<f32> {\n    return (f0_1 + ((max(vec3((1f - roughness_3)), f0_1) - f0_1) * pow(clamp((1f - cos_theta_1), 0f, 1f), 5f)));\n}\n\nfn environment_lightingX_naga_oil_mod_XOBRHEX(n_dot_v_1: f32, albedo_1: vec3<f32>, metallic_1: f32, roughness_4: f32, irradiance: vec3<f32>, prefiltered: vec3<f32>, brdf: vec2<f32>) -> vec3<f32> {\n    let f0_3 = mix(vec3(0.04f), albedo_1, metallic_1);\n    let _e7 = fresnel_schlick_roughnessX_naga_oil_mod_XOBRHEX(n_dot_v_1, f0_3, roughness_4);\n    let k_d_1 = ((vec3(1f) - _e7) * (1f - metallic_1));\n    return (((k_d_1 * irradiance) * albedo_1) + (prefiltered * ((f0_3 * brdf.x) + vec3(brdf.y))));\n}\n\nfn perturb_normalX_naga_oil_mod_XOBRHEX(n_1: vec3<f32>, pos: vec3<f32>, uv: vec2<f32>, map_normal: vec3<f32>, scale: f32) -> vec3<f32> {\n    let dp1_ = dpdx(pos);\n    let dp2_ = dpdy(pos);\n    let duv1_ = dpdx(uv);\n    let duv2_ = dpdy(uv);\n    let dp2_perp = cross(dp2_, n_1);\n    let dp1_perp = cross(n_1, dp1_);\n    let t = ((dp2_perp * duv1_.x) + (dp1_perp * duv2_.x));\n    let b = ((dp2_perp * duv1_.y) + (dp1_perp * duv2_.y));\n    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 0.000000000001f));\n    let tbn = mat3x3<f32>((t * inv_max), (b * inv_max), n_1);\n    return normalize((tbn * vec3<f32>((map_normal.xy * scale), map_normal.z)));\n}\n\nfn tangent_space_normalX_naga_oil_mod_XOBRHEX(n_2: vec3<f32>, tangent: vec4<f32>, pos_1: vec3<f32>, uv_1: vec2<f32>, map_normal_1: vec3<f32>, scale_1: f32) -> vec3<f32> {\n    let _e5 = perturb_normalX_naga_oil_mod_XOBRHEX(n_2, pos_1, uv_1, map_normal_1, scale_1);\n    if (dot(tangent.xyz, tangent.xyz) < 0.000000000001f) {\n        return _e5;\n    }\n    let t_1 = normalize((tangent.xyz - (n_2 * dot(n_2, tangent.xyz))));\n    let b_1 = (sign(tangent.w) * cross(n_2, t_1));\n    let tbn_1 = mat3x3<f32>(t_1, b_1, n_2);\n    return normalize((tbn_1 * vec3<f32>((map_normal_1.xy * scale_1), map_normal_1.z)));\n}\n\nfn point_attenuation(distance: f32, radius: f32) -> f32 {\n    let falloff = clamp((1f - pow((distance / radius), 2f)), 0f, 1f);\n    return (falloff * falloff);\n}\n\nfn cube_face(dir: vec3<f32>) -> i32 {\n    let a_1 = abs(dir);\n    if ((a_1.x >= a_1.y) && (a_1.x >= a_1.z)) {\n        return select(1i, 0i, (dir.x > 0f));\n    }\n    if (a_1.y >= a_1.z) {\n        return select(3i, 2i, (dir.y > 0f));\n    }\n    return select(5i, 4i, (dir.z > 0f));\n}\n\nfn shadow_occlusion(map: texture_depth_2d_array, layer: i32, shadow_pos: vec4<f32>, bias: f32) -> f32 {\n    var occlusion: f32 = 0f;\n    var weight: f32 = 0f;\n    var x: i32;\n    var y: i32;\n\n    let _e2 = textureDimensions(map);\n    let texelSize = (1f / f32(_e2.x));\n    const flip_correction = vec2<f32>(0.5f, -0.5f);\n    let proj_correction = (1f / shadow_pos.w);\n    let ShadowCoord = (((shadow_pos.xy * flip_correction) * proj_correction) + vec2<f32>(0.5f, 0.5f));\n    let _e23 = lights.shadow_blur_half_kernel_size;\n    x = -(_e23);\n    loop {\n        let _e26 = x;\n        let _e29 = lights.shadow_blur_half_kernel_size;\n        if (_e26 <= _e29) {\n        } else {\n            break;\n        }\n        {\n            let _e33 = lights.shadow_blur_half_kernel_size;\n            y = -(_e33);\n            loop {\n                let _e36 = y;\n                let _e39 = lights.shadow_blur_half_kernel_size;\n                if (_e36 <= _e39) {\n                } else {\n                    break;\n                }\n                {\n                    let _e45 = x;\n                    let _e47 = y;\n                    let _e56 = textureSampleCompareLevel(map, shadow_map_sampler, (ShadowCoord.xy + (vec2<f32>(f32(_e45), f32(_e47)) * texelSize)), layer, ((shadow_pos.z - bias) / shadow_pos.w));\n                    let _e60 = occlusion;\n                    occlusion = (_e60 + (1f - _e56));\n                    let _e64 = weight;\n                    weight = (_e64 + 1f);\n                }\n                continuing {\n                    let _e67 = y;\n                    y = (_e67 + 1i);\n                }\n            }\n        }\n        continuing {\n            let _e70 = x;\n            x = (_e70 + 1i);\n        }\n    }\n    let _e72 = occlusion;\n    let _e73 = weight;\n    return (_e72 / _e73);\n}\n\nfn cascaded_shadow_occlusion(first_layer: i32, world_pos: vec4<f32>, bias_1: f32) -> f32 {\n    var c: i32 = 0i;\n\n    loop {\n        let _e2 = c;\n        let _e5 = lights.cascade_count;\n        if (_e2 < i32(_e5)) {\n        } else {\n            break;\n        }\n        {\n            let _e9 = c;\n            let layer_1 = (first_layer + _e9);\n            let _e15 = lights.shadow_view_projs[layer_1];\n            let shadow_pos_1 = (_e15 * world_pos);\n            let uv_2 = ((shadow_pos_1.xy * vec2<f32>(0.5f, -0.5f)) + vec2<f32>(0.5f, 0.5f));\n            let edge = min(min(uv_2.x, (1f - uv_2.x)), min(uv_2.y, (1f - uv_2.y)));\n            if (edge < 0f) {\n                continue;\n            }\n            let _e41 = shadow_occlusion(shadow_map, layer_1, shadow_pos_1, bias_1);\n            let _e44 = lights.cascade_blend;\n            let blend = (1f - (edge / max((0.5f * _e44), 0.0001f)));\n            let _e54 = c;\n            let _e59 = lights.cascade_count;\n            if ((blend <= 0f) || ((_e54 + 1i) >= i32(_e59))) {\n                return _e41;\n            }\n            let _e68 = lights.shadow_view_projs[(layer_1 + 1i)];\n            let next_shadow_pos = (_e68 * world_pos);\n            let _e73 = shadow_occlusion(shadow_map, (layer_1 + 1i), next_shadow_pos, bias_1);\n            return mix(_e41, _e73, blend);\n        }\n        continuing {\n            let _e76 = c;\n            c = (_e76 + 1i);\n        }\n    }\n    return 0f;\n}\n\nfn cluster_index(frag_coord: vec2<f32>, view_depth: f32) -> u32 {\n    let grid = clusters_uniform.grid_size;\n    let _e6 = global_uniforms.screen_size;\n    let tile = vec2<u32>(clamp(((frag_coord / _e6) * vec2<f32>(grid.xy)), vec2(0f), vec2<f32>((grid.xy - vec2(1u)))));\n    let _e23 = clusters_uniform.z_near;\n    let _e27 = clusters_uniform.z_near;\n    let _e32 = clusters_uniform.z_far;\n    let _e35 = clusters_uniform.z_near;\n    let depth_ratio = (log((max(view_depth, _e23) / _e27)) / log((_e32 / _e35)));\n    let slice = u32(clamp((depth_ratio * f32(grid.z)), 0f, f32((grid.z - 1u))));\n    return (tile.x + (grid.x * (tile.y + (grid.y * slice))));\n}\n\nfn light_attenuation(light: Light, world_pos_1: vec3<f32>) -> f32 {\n    let light_to_fragment = (world_pos_1 - light.position);\n    if (light.kind == 2u) {\n        let _e9 = point_attenuation(length(light_to_fragment), light.reach);\n        return _e9;\n    } else {\n        if (light.kind == 1u) {\n            let light_dist_sqr = dot(light_to_fragment, light_to_fragment);\n            let spot_factor = dot(normalize(light_to_fragment), light.direction);\n            let reach_sqr = pow(light.reach, 2f);\n            if ((spot_factor > light.radius) && (light_dist_sqr < reach_sqr)) {\n                return (1f - (((1f - spot_factor) * 1f) / (1f - light.radius)));\n            }\n            return 0f;\n        }\n    }\n    return 1f;\n}\n\nfn shade(light_1: Light, visibility: f32, world_pos_2: vec3<f32>, view_pos: vec3<f32>, n_3: vec3<f32>, albedo_2: vec3<f32>, metallic_2: f32, roughness_5: f32) -> vec3<f32> {\n    var l_1: vec3<f32>;\n\n    let _e3 = view_proj_uniforms.view;\n    l_1 = normalize((_e3 * vec4<f32>(light_1.position, 0f)).xyz);\n    if (light_1.kind == 2u) {\n        let _e17 = view_proj_uniforms.view;\n        l_1 = normalize((_e17 * vec4<f32>((light_1.position - world_pos_2), 0f)).xyz);\n    }\n    let v_1 = normalize(-(view_pos));\n    let radiance_1 = (((light_1.color.xyz * light_1.color.w) * PIX_naga_oil_mod_XOBRHEX) * visibility);\n    let _e37 = l_1;\n    let _e42 = cook_torranceX_naga_oil_mod_XOBRHEX(n_3, v_1, _e37, albedo_2, metallic_2, roughness_5, radiance_1);\n    return _e42;\n}\n\n@vertex \nfn vs_skinned(vertex: SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> VertexOutput {\n    var out: VertexOutput;\n\n    let joints = (vertex.joints + vec4(instance.joint_offset));\n    let _e6 = instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance);\n    let _e10 = joint_matrices[joints.x];\n    let _e14 = joint_matrices[joints.y];\n    let _e18 = joint_matrices[joints.z];\n    let _e22 = joint_matrices[joints.w];\n    let _e24 = blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(_e10, _e14, _e18, _e22, vertex.weights);\n    let model_transform = (_e6 * _e24);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    let _e35 = view_proj_uniforms.view;\n    let model_view = (_e35 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e42 = view_proj_uniforms.projection;\n    out.clip_position = (_e42 * model_view_pos);\n    out.view_space_normal = (model_view * vec4<f32>(vertex.normal, 0f)).xyz;\n    out.view_space_tangent = vec4<f32>((model_view * vec4<f32>(vertex.tangent.xyz, 0f)).xyz, vertex.tangent.w);\n    out.view_pos = model_view_pos;\n    out.tint_color = instance.tint;\n    out.world_pos = (model_transform * vertex.position);\n    let _e66 = out;\n    return _e66;\n}\n";
         }
         pub mod fs_main {
             pub const NAME: &'static str = "fs_main";
             ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
-            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1_: vec4<f32>,\n    @location(7) model_2_: vec4<f32>,\n    @location(8) model_3_: vec4<f32>,\n    @location(9) model_4_: vec4<f32>,\n    @location(10) normal_1_: vec4<f32>,\n    @location(11) normal_2_: vec4<f32>,\n    @location(12) normal_3_: vec4<f32>,\n    @location(13) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec3<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) world_pos: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(3) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(3) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n@group(4) @binding(0) \nvar occlusion_map: texture_2d<f32>;\n@group(4) @binding(1) \nvar occlusion_map_sampler: sampler;\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    var total_light: vec3<f32> = vec3<f32>(0f, 0f, 0f);\n    var i: u32 = 0u;\n    var visibility: f32;\n    var bias: f32;\n    var occlusion: f32;\n    var weight: f32;\n    var x: i32;\n    var y: i32;\n\n    let view_pos = in.view_pos;\n    let view_space_normal = in.view_space_normal;\n    let _e10 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let albedo_spec = (in.tint_color * _e10);\n    let _e13 = textureDimensions(shadow_map);\n    let texelSize = (1f / f32(_e13.x));\n    let _e20 = lights.ambient_color;\n    let MaterialAmbientColor = _e20.xyz;\n    let MaterialDiffuseColor = albedo_spec.xyz;\n    const MaterialSpecularColor = vec3<f32>(0.4f, 0.4f, 0.4f);\n    let n = normalize(in.view_space_normal);\n    let _e35 = global_uniforms.screen_size;\n    let _e37 = textureSample(occlusion_map, occlusion_map_sampler, (in.clip_position.xy / _e35));\n    let ao = _e37.x;\n    let _e42 = total_light;\n    total_light = (_e42 + ((ao * MaterialAmbientColor) * MaterialDiffuseColor));\n    loop {\n        let _e45 = i;\n        let _e48 = lights.count;\n        if (_e45 < _e48) {\n        } else {\n            break;\n        }\n        {\n            visibility = 1f;\n            let _e54 = i;\n            let _e57 = lights.items[_e54].kind;\n            if (_e57 == 1u) {\n                let _e64 = i;\n                let _e67 = lights.items[_e64].position;\n                let light_to_fragment = (in.world_pos.xyz - _e67);\n                let light_dist_sqr = dot(light_to_fragment, light_to_fragment);\n                let _e73 = i;\n                let _e76 = lights.items[_e73].direction;\n                let spot_factor = dot(normalize(light_to_fragment), _e76);\n                let _e80 = i;\n                let _e83 = lights.items[_e80].reach;\n                let reach_sqr = pow(_e83, 2f);\n                let _e88 = i;\n                let _e91 = lights.items[_e88].radius;\n                if ((spot_factor > _e91) && (light_dist_sqr < reach_sqr)) {\n                    let _e101 = i;\n                    let _e104 = lights.items[_e101].radius;\n                    visibility = (1f - (((1f - spot_factor) * 1f) / (1f - _e104)));\n                } else {\n                    visibility = 0f;\n                }\n            }\n            let _e113 = i;\n            let _e116 = lights.items[_e113].color;\n            let LightColor = _e116.xyz;\n            let _e120 = i;\n            let LightPower = lights.items[_e120].color.w;\n            let _e127 = view_proj_uniforms.view;\n            let _e130 = i;\n            let _e133 = lights.items[_e130].position;\n            let l = normalize((_e127 * vec4<f32>(_e133, 0f)).xyz);\n            let cosTheta = clamp(dot(n, l), 0f, 1f);\n            let E = normalize(-(in.view_pos.xyz));\n            let R = reflect(-(l), n);\n            let cosAlpha = clamp(dot(E, R), 0f, 1f);\n            bias = 0f;\n            let _e157 = i;\n            let _e160 = lights.items[_e157].view_proj;\n            let shadow_pos = (_e160 * in.world_pos);\n            const flip_correction = vec2<f32>(0.5f, -0.5f);\n            let proj_correction = (1f / shadow_pos.w);\n            let ShadowCoord = (((shadow_pos.xy * flip_correction) * proj_correction) + vec2<f32>(0.5f, 0.5f));\n            occlusion = 0f;\n            weight = 0f;\n            let _e182 = lights.shadow_blur_half_kernel_size;\n            x = -(_e182);\n            loop {\n                let _e185 = x;\n                let _e188 = lights.shadow_blur_half_kernel_size;\n                if (_e185 <= _e188) {\n                } else {\n                    break;\n                }\n                {\n                    let _e192 = lights.shadow_blur_half_kernel_size;\n                    y = -(_e192);\n                    loop {\n                        let _e195 = y;\n                        let _e198 = lights.shadow_blur_half_kernel_size;\n                        if (_e195 <= _e198) {\n                        } else {\n                            break;\n                        }\n                        {\n                            let _e203 = x;\n                            let _e205 = y;\n                            let _e210 = i;\n                            let _e212 = bias;\n                            let _e216 = textureSampleCompare(shadow_map, shadow_map_sampler, (ShadowCoord.xy + (vec2<f32>(f32(_e203), f32(_e205)) * texelSize)), _e210, ((shadow_pos.z - _e212) / shadow_pos.w));\n                            let _e219 = occlusion;\n                            occlusion = (_e219 + (1f - _e216));\n                            let _e222 = weight;\n                            weight = (_e222 + 1f);\n                        }\n                        continuing {\n                            let _e225 = y;\n                            y = (_e225 + 1i);\n                        }\n                    }\n                }\n                continuing {\n                    let _e228 = x;\n                    x = (_e228 + 1i);\n                }\n            }\n            let _e230 = weight;\n            let _e231 = occlusion;\n            occlusion = (_e231 / _e230);\n            let _e233 = visibility;\n            let _e234 = occlusion;\n            visibility = clamp((_e233 - _e234), 0f, 1f);\n            let _e239 = visibility;\n            let _e244 = visibility;\n            let _e252 = total_light;\n            total_light = (_e252 + (((((_e239 * MaterialDiffuseColor) * LightColor) * LightPower) * cosTheta) + ((((_e244 * MaterialSpecularColor) * LightColor) * LightPower) * pow(cosAlpha, 5f))));\n        }\n        continuing {\n            let _e255 = i;\n            i = (_e255 + 1u);\n        }\n    }\n    let _e257 = total_light;\n    return vec4<f32>(_e257.xyz, albedo_spec.w);\n}\n";
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nstruct MaterialUniformsX_naga_oil_mod_XOBRHEX {\n    base_color: vec4<f32>,\n    emissive: vec3<f32>,\n    metallic: f32,\n    roughness: f32,\n    occlusion_strength: f32,\n    normal_scale: f32,\n}\n\nstruct InstanceInput {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1_: vec4<f32>,\n    @location(8) model_2_: vec4<f32>,\n    @location(9) model_3_: vec4<f32>,\n    @location(10) model_4_: vec4<f32>,\n    @location(11) normal_1_: vec4<f32>,\n    @location(12) normal_2_: vec4<f32>,\n    @location(13) normal_3_: vec4<f32>,\n    @location(14) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec3<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) world_pos: vec4<f32>,\n    @location(5) view_space_tangent: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n    shadow_index: i32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n    shadow_view_projs: array<mat4x4<f32>, 8>,\n    point_shadow_view_projs: array<mat4x4<f32>, 24>,\n    cascade_count: u32,\n    cascade_blend: f32,\n    environment_intensity: f32,\n    has_environment: u32,\n}\n\nstruct ClusterUniforms {\n    grid_size: vec3<u32>,\n    z_near: f32,\n    z_far: f32,\n    show_heatmap: u32,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(7) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n@group(3) @binding(0) \nvar<uniform> material: MaterialUniformsX_naga_oil_mod_XOBRHEX;\n@group(3) @binding(1) \nvar albedo_texture: texture_2d<f32>;\n@group(3) @binding(2) \nvar albedo_sampler: sampler;\n@group(3) @binding(3) \nvar normal_texture: texture_2d<f32>;\n@group(3) @binding(4) \nvar normal_sampler: sampler;\n@group(3) @binding(5) \nvar metallic_roughness_texture: texture_2d<f32>;\n@group(3) @binding(6) \nvar metallic_roughness_sampler: sampler;\n@group(3) @binding(7) \nvar occlusion_texture: texture_2d<f32>;\n@group(3) @binding(8) \nvar occlusion_sampler: sampler;\n@group(3) @binding(9) \nvar emissive_texture: texture_2d<f32>;\n@group(3) @binding(10) \nvar emissive_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n@group(4) @binding(3) \nvar point_shadow_map: texture_depth_2d_array;\n@group(4) @binding(4) \nvar irradiance_map: texture_cube<f32>;\n@group(4) @binding(5) \nvar prefiltered_map: texture_cube<f32>;\n@group(4) @binding(6) \nvar brdf_lut: texture_2d<f32>;\n@group(4) @binding(7) \nvar environment_sampler: sampler;\n@group(5) @binding(0) \nvar occlusion_map: texture_2d<f32>;\n@group(5) @binding(1) \nvar occlusion_map_sampler: sampler;\n@group(6) @binding(0) \nvar<uniform> clusters_uniform: ClusterUniforms;\n@group(6) @binding(1) \nvar<storage> cluster_lights: array<Light>;\n@group(6) @binding(2) \nvar<storage> clusters: array<vec2<u32>>;\n@group(6) @binding(3) \nvar<storage> cluster_light_indices: array<u32>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\nfn fresnel_schlickX_naga_oil_mod_XOBRHEX(cos_theta: f32, f0_: vec3<f32>) -> vec3<f32> {\n    return (f0_ + ((vec3(1f) - f0_) * pow(clamp((1f - cos_theta), 0f, 1f), 5f)));\n}\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v: f32, n_dot_l: f32, roughness_1: f32) -> f32 {\n    let k = (((roughness_1 + 1f) * (roughness_1 + 1f)) / 8f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\nfn cook_torranceX_naga_oil_mod_XOBRHEX(n: vec3<f32>, v: vec3<f32>, l: vec3<f32>, albedo: vec3<f32>, metallic: f32, roughness_2: f32, radiance: vec3<f32>) -> vec3<f32> {\n    let h = normalize((v + l));\n    let n_dot_l_1 = max(dot(n, l), 0f);\n    let n_dot_v_2 = max(dot(n, v), 0.0001f);\n    let f0_2 = mix(vec3(0.04f), albedo, metallic);\n    let _e19 = fresnel_schlickX_naga_oil_mod_XOBRHEX(max(dot(h, v), 0f), f0_2);\n    let _e24 = distribution_ggxX_naga_oil_mod_XOBRHEX(max(dot(n, h), 0f), roughness_2);\n    let _e25 = geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v_2, n_dot_l_1, roughness_2);\n    let specular = (((_e24 * _e25) * _e19) / vec3(max(((4f * n_dot_v_2) * n_dot_l_1), 0.0001f)));\n    let k_d = ((vec3(1f) - _e19) * (1f - metallic));\n    return (((((k_d * albedo) / vec3(3.1415927f)) + specular) * radiance) * n_dot_l_1);\n}\n\nfn fresnel_schlick_roughnessX_naga_oil_mod_XOBRHEX(cos_theta_1: f32, f0_1: vec3<f32>, roughness_3: f32) -> vec3<f32> {\n    return (f0_1 + ((max(vec3((1f - roughness_3)), f0_1) - f0_1) * pow(clamp((1f - cos_theta_1), 0f, 1f), 5f)));\n}\n\nfn environment_lightingX_naga_oil_mod_XOBRHEX(n_dot_v_1: f32, albedo_1: vec3<f32>, metallic_1: f32, roughness_4: f32, irradiance: vec3<f32>, prefiltered: vec3<f32>, brdf: vec2<f32>) -> vec3<f32> {\n    let f0_3 = mix(vec3(0.04f), albedo_1, metallic_1);\n    let _e7 = fresnel_schlick_roughnessX_naga_oil_mod_XOBRHEX(n_dot_v_1, f0_3, roughness_4);\n    let k_d_1 = ((vec3(1f) - _e7) * (1f - metallic_1));\n    return (((k_d_1 * irradiance) * albedo_1) + (prefiltered * ((f0_3 * brdf.x) + vec3(brdf.y))));\n}\n\nfn perturb_normalX_naga_oil_mod_XOBRHEX(n_1: vec3<f32>, pos: vec3<f32>, uv: vec2<f32>, map_normal: vec3<f32>, scale: f32) -> vec3<f32> {\n    let dp1_ = dpdx(pos);\n    let dp2_ = dpdy(pos);\n    let duv1_ = dpdx(uv);\n    let duv2_ = dpdy(uv);\n    let dp2_perp = cross(dp2_, n_1);\n    let dp1_perp = cross(n_1, dp1_);\n    let t = ((dp2_perp * duv1_.x) + (dp1_perp * duv2_.x));\n    let b = ((dp2_perp * duv1_.y) + (dp1_perp * duv2_.y));\n    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 0.000000000001f));\n    let tbn = mat3x3<f32>((t * inv_max), (b * inv_max), n_1);\n    return normalize((tbn * vec3<f32>((map_normal.xy * scale), map_normal.z)));\n}\n\nfn tangent_space_normalX_naga_oil_mod_XOBRHEX(n_2: vec3<f32>, tangent: vec4<f32>, pos_1: vec3<f32>, uv_1: vec2<f32>, map_normal_1: vec3<f32>, scale_1: f32) -> vec3<f32> {\n    let _e5 = perturb_normalX_naga_oil_mod_XOBRHEX(n_2, pos_1, uv_1, map_normal_1, scale_1);\n    if (dot(tangent.xyz, tangent.xyz) < 0.000000000001f) {\n        return _e5;\n    }\n    let t_1 = normalize((tangent.xyz - (n_2 * dot(n_2, tangent.xyz))));\n    let b_1 = (sign(tangent.w) * cross(n_2, t_1));\n    let tbn_1 = mat3x3<f32>(t_1, b_1, n_2);\n    return normalize((tbn_1 * vec3<f32>((map_normal_1.xy * scale_1), map_normal_1.z)));\n}\n\nfn point_attenuation(distance: f32, radius: f32) -> f32 {\n    let falloff = clamp((1f - pow((distance / radius), 2f)), 0f, 1f);\n    return (falloff * falloff);\n}\n\nfn cube_face(dir: vec3<f32>) -> i32 {\n    let a_1 = abs(dir);\n    if ((a_1.x >= a_1.y) && (a_1.x >= a_1.z)) {\n        return select(1i, 0i, (dir.x > 0f));\n    }\n    if (a_1.y >= a_1.z) {\n        return select(3i, 2i, (dir.y > 0f));\n    }\n    return select(5i, 4i, (dir.z > 0f));\n}\n\nfn shadow_occlusion(map: texture_depth_2d_array, layer: i32, shadow_pos: vec4<f32>, bias_1: f32) -> f32 {\n    var occlusion_1: f32 = 0f;\n    var weight: f32 = 0f;\n    var x: i32;\n    var y: i32;\n\n    let _e2 = textureDimensions(map);\n    let texelSize = (1f / f32(_e2.x));\n    const flip_correction = vec2<f32>(0.5f, -0.5f);\n    let proj_correction = (1f / shadow_pos.w);\n    let ShadowCoord = (((shadow_pos.xy * flip_correction) * proj_correction) + vec2<f32>(0.5f, 0.5f));\n    let _e23 = lights.shadow_blur_half_kernel_size;\n    x = -(_e23);\n    loop {\n        let _e26 = x;\n        let _e29 = lights.shadow_blur_half_kernel_size;\n        if (_e26 <= _e29) {\n        } else {\n            break;\n        }\n        {\n            let _e33 = lights.shadow_blur_half_kernel_size;\n            y = -(_e33);\n            loop {\n                let _e36 = y;\n                let _e39 = lights.shadow_blur_half_kernel_size;\n                if (_e36 <= _e39) {\n                } else {\n                    break;\n                }\n                {\n                    let _e45 = x;\n                    let _e47 = y;\n                    let _e56 = textureSampleCompareLevel(map, shadow_map_sampler, (ShadowCoord.xy + (vec2<f32>(f32(_e45), f32(_e47)) * texelSize)), layer, ((shadow_pos.z - bias_1) / shadow_pos.w));\n                    let _e60 = occlusion_1;\n                    occlusion_1 = (_e60 + (1f - _e56));\n                    let _e64 = weight;\n                    weight = (_e64 + 1f);\n                }\n                continuing {\n                    let _e67 = y;\n                    y = (_e67 + 1i);\n                }\n            }\n        }\n        continuing {\n            let _e70 = x;\n            x = (_e70 + 1i);\n        }\n    }\n    let _e72 = occlusion_1;\n    let _e73 = weight;\n    return (_e72 / _e73);\n}\n\nfn cascaded_shadow_occlusion(first_layer: i32, world_pos: vec4<f32>, bias_2: f32) -> f32 {\n    var c: i32 = 0i;\n\n    loop {\n        let _e2 = c;\n        let _e5 = lights.cascade_count;\n        if (_e2 < i32(_e5)) {\n        } else {\n            break;\n        }\n        {\n            let _e9 = c;\n            let layer_1 = (first_layer + _e9);\n            let _e15 = lights.shadow_view_projs[layer_1];\n            let shadow_pos_1 = (_e15 * world_pos);\n            let uv_2 = ((shadow_pos_1.xy * vec2<f32>(0.5f, -0.5f)) + vec2<f32>(0.5f, 0.5f));\n            let edge = min(min(uv_2.x, (1f - uv_2.x)), min(uv_2.y, (1f - uv_2.y)));\n            if (edge < 0f) {\n                continue;\n            }\n            let _e41 = shadow_occlusion(shadow_map, layer_1, shadow_pos_1, bias_2);\n            let _e44 = lights.cascade_blend;\n            let blend = (1f - (edge / max((0.5f * _e44), 0.0001f)));\n            let _e54 = c;\n            let _e59 = lights.cascade_count;\n            if ((blend <= 0f) || ((_e54 + 1i) >= i32(_e59))) {\n                return _e41;\n            }\n            let _e68 = lights.shadow_view_projs[(layer_1 + 1i)];\n            let next_shadow_pos = (_e68 * world_pos);\n            let _e73 = shadow_occlusion(shadow_map, (layer_1 + 1i), next_shadow_pos, bias_2);\n            return mix(_e41, _e73, blend);\n        }\n        continuing {\n            let _e76 = c;\n            c = (_e76 + 1i);\n        }\n    }\n    return 0f;\n}\n\nfn cluster_index(frag_coord: vec2<f32>, view_depth: f32) -> u32 {\n    let grid = clusters_uniform.grid_size;\n    let _e6 = global_uniforms.screen_size;\n    let tile = vec2<u32>(clamp(((frag_coord / _e6) * vec2<f32>(grid.xy)), vec2(0f), vec2<f32>((grid.xy - vec2(1u)))));\n    let _e23 = clusters_uniform.z_near;\n    let _e27 = clusters_uniform.z_near;\n    let _e32 = clusters_uniform.z_far;\n    let _e35 = clusters_uniform.z_near;\n    let depth_ratio = (log((max(view_depth, _e23) / _e27)) / log((_e32 / _e35)));\n    let slice = u32(clamp((depth_ratio * f32(grid.z)), 0f, f32((grid.z - 1u))));\n    return (tile.x + (grid.x * (tile.y + (grid.y * slice))));\n}\n\nfn light_attenuation(light: Light, world_pos_1: vec3<f32>) -> f32 {\n    let light_to_fragment = (world_pos_1 - light.position);\n    if (light.kind == 2u) {\n        let _e9 = point_attenuation(length(light_to_fragment), light.reach);\n        return _e9;\n    } else {\n        if (light.kind == 1u) {\n            let light_dist_sqr = dot(light_to_fragment, light_to_fragment);\n            let spot_factor = dot(normalize(light_to_fragment), light.direction);\n            let reach_sqr = pow(light.reach, 2f);\n            if ((spot_factor > light.radius) && (light_dist_sqr < reach_sqr)) {\n                return (1f - (((1f - spot_factor) * 1f) / (1f - light.radius)));\n            }\n            return 0f;\n        }\n    }\n    return 1f;\n}\n\nfn shade(light_1: Light, visibility: f32, world_pos_2: vec3<f32>, view_pos: vec3<f32>, n_3: vec3<f32>, albedo_2: vec3<f32>, metallic_2: f32, roughness_5: f32) -> vec3<f32> {\n    var l_1: vec3<f32>;\n\n    let _e3 = view_proj_uniforms.view;\n    l_1 = normalize((_e3 * vec4<f32>(light_1.position, 0f)).xyz);\n    if (light_1.kind == 2u) {\n        let _e17 = view_proj_uniforms.view;\n        l_1 = normalize((_e17 * vec4<f32>((light_1.position - world_pos_2), 0f)).xyz);\n    }\n    let v_1 = normalize(-(view_pos));\n    let radiance_1 = (((light_1.color.xyz * light_1.color.w) * PIX_naga_oil_mod_XOBRHEX) * visibility);\n    let _e37 = l_1;\n    let _e42 = cook_torranceX_naga_oil_mod_XOBRHEX(n_3, v_1, _e37, albedo_2, metallic_2, roughness_5, radiance_1);\n    return _e42;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    var ambient: vec3<f32>;\n    var total_light: vec3<f32>;\n    var i: u32 = 0u;\n    var bias: f32;\n    var occlusion: f32;\n    var i_1: u32 = 0u;\n\n    let _e6 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let _e10 = material.base_color;\n    let _e15 = textureSample(albedo_texture, albedo_sampler, in.tex_coords);\n    let albedo_3 = (((in.tint_color * _e6) * _e10) * _e15);\n    let metallic_roughness = textureSample(metallic_roughness_texture, metallic_roughness_sampler, in.tex_coords);\n    let _e23 = material.metallic;\n    let metallic_3 = clamp((_e23 * metallic_roughness.z), 0f, 1f);\n    let _e31 = material.roughness;\n    let roughness_6 = clamp((_e31 * metallic_roughness.y), 0.04f, 1f);\n    let _e40 = textureSample(occlusion_texture, occlusion_sampler, in.tex_coords);\n    let _e45 = material.occlusion_strength;\n    let material_occlusion = mix(1f, _e40.x, _e45);\n    let _e49 = material.emissive;\n    let _e53 = textureSample(emissive_texture, emissive_sampler, in.tex_coords);\n    let emissive = (_e49 * _e53.xyz);\n    let _e59 = textureSample(normal_texture, normal_sampler, in.tex_coords);\n    let map_normal_2 = ((_e59.xyz * 2f) - vec3(1f));\n    let _e74 = material.normal_scale;\n    let _e75 = tangent_space_normalX_naga_oil_mod_XOBRHEX(normalize(in.view_space_normal), in.view_space_tangent, in.view_pos.xyz, in.tex_coords, map_normal_2, _e74);\n    let _e82 = global_uniforms.screen_size;\n    let _e84 = textureSample(occlusion_map, occlusion_map_sampler, (in.clip_position.xy / _e82));\n    let ao = _e84.x;\n    let _e88 = lights.ambient_color;\n    ambient = (_e88.xyz * albedo_3.xyz);\n    let _e95 = lights.has_environment;\n    if (_e95 != 0u) {\n        let v_2 = normalize(-(in.view_pos.xyz));\n        let n_dot_v_3 = max(dot(_e75, v_2), 0.0001f);\n        let _e107 = view_proj_uniforms.inverse_view;\n        let world_n = (_e107 * vec4<f32>(_e75, 0f)).xyz;\n        let _e114 = view_proj_uniforms.inverse_view;\n        let world_r = (_e114 * vec4<f32>(reflect(-(v_2), _e75), 0f)).xyz;\n        let _e122 = textureNumLevels(prefiltered_map);\n        let max_lod = f32((_e122 - 1u));\n        let _e128 = lights.environment_intensity;\n        let _e132 = textureSample(irradiance_map, environment_sampler, world_n);\n        let _e137 = textureSampleLevel(prefiltered_map, environment_sampler, world_r, (roughness_6 * max_lod));\n        let _e142 = textureSample(brdf_lut, environment_sampler, vec2<f32>(n_dot_v_3, roughness_6));\n        let _e144 = environment_lightingX_naga_oil_mod_XOBRHEX(n_dot_v_3, albedo_3.xyz, metallic_3, roughness_6, _e132.xyz, _e137.xyz, _e142.xy);\n        ambient = (_e128 * _e144);\n    }\n    let _e147 = ambient;\n    total_light = (((ao * material_occlusion) * _e147) + emissive);\n    loop {\n        let _e152 = i;\n        let _e155 = lights.count;\n        if (_e152 < _e155) {\n        } else {\n            break;\n        }\n        {\n            let _e159 = i;\n            let light_2 = lights.items[_e159];\n            let light_to_fragment_1 = (in.world_pos.xyz - light_2.position);\n            bias = 0f;\n            occlusion = 0f;\n            if (light_2.kind == 2u) {\n                if (light_2.shadow_index >= 0i) {\n                    let _e179 = cube_face(light_to_fragment_1);\n                    let layer_2 = ((light_2.shadow_index * 6i) + _e179);\n                    let _e184 = lights.point_shadow_view_projs[layer_2];\n                    let shadow_pos_2 = (_e184 * in.world_pos);\n                    let _e187 = bias;\n                    let _e189 = shadow_occlusion(point_shadow_map, layer_2, shadow_pos_2, _e187);\n                    occlusion = _e189;\n                }\n            } else {\n                if (light_2.shadow_index >= 0i) {\n                    if (light_2.kind == 0u) {\n                        let _e198 = bias;\n                        let _e199 = cascaded_shadow_occlusion(light_2.shadow_index, in.world_pos, _e198);\n                        occlusion = _e199;\n                    } else {\n                        let shadow_pos_3 = (light_2.view_proj * in.world_pos);\n                        let _e204 = bias;\n                        let _e206 = shadow_occlusion(shadow_map, light_2.shadow_index, shadow_pos_3, _e204);\n                        occlusion = _e206;\n                    }\n                }\n            }\n            let _e209 = light_attenuation(light_2, in.world_pos.xyz);\n            let _e210 = occlusion;\n            let visibility_1 = clamp((_e209 - _e210), 0f, 1f);\n            let _e220 = shade(light_2, visibility_1, in.world_pos.xyz, in.view_pos.xyz, _e75, albedo_3.xyz, metallic_3, roughness_6);\n            let _e221 = total_light;\n            total_light = (_e221 + _e220);\n        }\n        continuing {\n            let _e224 = i;\n            i = (_e224 + 1u);\n        }\n    }\n    let _e231 = cluster_index(in.clip_position.xy, -(in.view_pos.z));\n    let cluster = clusters[_e231];\n    loop {\n        let _e236 = i_1;\n        if (_e236 < cluster.y) {\n        } else {\n            break;\n        }\n        {\n            let _e242 = i_1;\n            let _e245 = cluster_light_indices[(cluster.x + _e242)];\n            let light_3 = cluster_lights[_e245];\n            let _e250 = light_attenuation(light_3, in.world_pos.xyz);\n            let _e256 = shade(light_3, _e250, in.world_pos.xyz, in.view_pos.xyz, _e75, albedo_3.xyz, metallic_3, roughness_6);\n            let _e257 = total_light;\n            total_light = (_e257 + _e256);\n        }\n        continuing {\n            let _e260 = i_1;\n            i_1 = (_e260 + 1u);\n        }\n    }\n    let _e264 = clusters_uniform.show_heatmap;\n    if ((_e264 != 0u) && (cluster.y > 0u)) {\n        let heat = clamp((f32(cluster.y) / 16f), 0f, 1f);\n        let _e278 = total_light;\n        total_light = mix(_e278, vec3<f32>(heat, (1f - heat), 0f), 0.5f);\n    }\n    let _e285 = total_light;\n    return vec4<f32>(_e285, albedo_3.w);\n}\n";
         }
     }
     #[allow(unused)]
@@ -2564,7 +3309,8 @@ pub mod forward {
             pub position: glam::f32::Vec3,
             pub radius: f32,
             pub reach: f32,
-            pub _pad: [u8; 12u32 as usize],
+            pub shadow_index: i32,
+            pub _pad: [u8; 8u32 as usize],
         }
         #[allow(unused, non_camel_case_types)]
         #[repr(C)]
@@ -2576,15 +3322,1040 @@ pub mod forward {
             pub shadow_bias_factor: f32,
             pub shadow_blur_half_kernel_size: i32,
             pub ambient_color: glam::f32::Vec4,
+            pub shadow_view_projs: [glam::f32::Mat4; 8u32 as usize],
+            pub point_shadow_view_projs: [glam::f32::Mat4; 24u32 as usize],
+            pub cascade_count: u32,
+            pub cascade_blend: f32,
+            pub environment_intensity: f32,
+            pub has_environment: u32,
+        }
+        #[allow(unused, non_camel_case_types)]
+        #[repr(C)]
+        #[derive(Debug, PartialEq, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+        pub struct ClusterUniforms {
+            pub grid_size: glam::u32::UVec3,
+            pub z_near: f32,
+            pub z_far: f32,
+            pub show_heatmap: u32,
+            pub _pad: [u8; 8u32 as usize],
         }
     }
     #[allow(unused)]
     use types::*;
     ///The sourcecode for the shader, as a constant string.
-    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n    @location(2) normal: vec3<f32>,\n}\n\nstruct InstanceInput {\n    @location(3) uv_scale: vec2<f32>,\n    @location(4) uv_offset: vec2<f32>,\n    @location(5) tint: vec4<f32>,\n    @location(6) model_1_: vec4<f32>,\n    @location(7) model_2_: vec4<f32>,\n    @location(8) model_3_: vec4<f32>,\n    @location(9) model_4_: vec4<f32>,\n    @location(10) normal_1_: vec4<f32>,\n    @location(11) normal_2_: vec4<f32>,\n    @location(12) normal_3_: vec4<f32>,\n    @location(13) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec3<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) world_pos: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(3) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(3) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n@group(4) @binding(0) \nvar occlusion_map: texture_2d<f32>;\n@group(4) @binding(1) \nvar occlusion_map_sampler: sampler;\n\n@vertex \nfn vs_main(vertex: ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let normal_matrix = mat4x4<f32>(instance.normal_1_, instance.normal_2_, instance.normal_3_, instance.normal_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    let _e21 = view_proj_uniforms.view;\n    let model_view = (_e21 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e28 = view_proj_uniforms.projection;\n    out.clip_position = (_e28 * model_view_pos);\n    out.view_space_normal = (normal_matrix * vec4<f32>(vertex.normal, 1f)).xyz;\n    out.view_pos = model_view_pos;\n    out.tint_color = instance.tint;\n    out.world_pos = (model_transform * vertex.position);\n    let _e42 = out;\n    return _e42;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    var total_light: vec3<f32> = vec3<f32>(0f, 0f, 0f);\n    var i: u32 = 0u;\n    var visibility: f32;\n    var bias: f32;\n    var occlusion: f32;\n    var weight: f32;\n    var x: i32;\n    var y: i32;\n\n    let view_pos = in.view_pos;\n    let view_space_normal = in.view_space_normal;\n    let _e10 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let albedo_spec = (in.tint_color * _e10);\n    let _e13 = textureDimensions(shadow_map);\n    let texelSize = (1f / f32(_e13.x));\n    let _e20 = lights.ambient_color;\n    let MaterialAmbientColor = _e20.xyz;\n    let MaterialDiffuseColor = albedo_spec.xyz;\n    const MaterialSpecularColor = vec3<f32>(0.4f, 0.4f, 0.4f);\n    let n = normalize(in.view_space_normal);\n    let _e35 = global_uniforms.screen_size;\n    let _e37 = textureSample(occlusion_map, occlusion_map_sampler, (in.clip_position.xy / _e35));\n    let ao = _e37.x;\n    let _e42 = total_light;\n    total_light = (_e42 + ((ao * MaterialAmbientColor) * MaterialDiffuseColor));\n    loop {\n        let _e45 = i;\n        let _e48 = lights.count;\n        if (_e45 < _e48) {\n        } else {\n            break;\n        }\n        {\n            visibility = 1f;\n            let _e54 = i;\n            let _e57 = lights.items[_e54].kind;\n            if (_e57 == 1u) {\n                let _e64 = i;\n                let _e67 = lights.items[_e64].position;\n                let light_to_fragment = (in.world_pos.xyz - _e67);\n                let light_dist_sqr = dot(light_to_fragment, light_to_fragment);\n                let _e73 = i;\n                let _e76 = lights.items[_e73].direction;\n                let spot_factor = dot(normalize(light_to_fragment), _e76);\n                let _e80 = i;\n                let _e83 = lights.items[_e80].reach;\n                let reach_sqr = pow(_e83, 2f);\n                let _e88 = i;\n                let _e91 = lights.items[_e88].radius;\n                if ((spot_factor > _e91) && (light_dist_sqr < reach_sqr)) {\n                    let _e101 = i;\n                    let _e104 = lights.items[_e101].radius;\n                    visibility = (1f - (((1f - spot_factor) * 1f) / (1f - _e104)));\n                } else {\n                    visibility = 0f;\n                }\n            }\n            let _e113 = i;\n            let _e116 = lights.items[_e113].color;\n            let LightColor = _e116.xyz;\n            let _e120 = i;\n            let LightPower = lights.items[_e120].color.w;\n            let _e127 = view_proj_uniforms.view;\n            let _e130 = i;\n            let _e133 = lights.items[_e130].position;\n            let l = normalize((_e127 * vec4<f32>(_e133, 0f)).xyz);\n            let cosTheta = clamp(dot(n, l), 0f, 1f);\n            let E = normalize(-(in.view_pos.xyz));\n            let R = reflect(-(l), n);\n            let cosAlpha = clamp(dot(E, R), 0f, 1f);\n            bias = 0f;\n            let _e157 = i;\n            let _e160 = lights.items[_e157].view_proj;\n            let shadow_pos = (_e160 * in.world_pos);\n            const flip_correction = vec2<f32>(0.5f, -0.5f);\n            let proj_correction = (1f / shadow_pos.w);\n            let ShadowCoord = (((shadow_pos.xy * flip_correction) * proj_correction) + vec2<f32>(0.5f, 0.5f));\n            occlusion = 0f;\n            weight = 0f;\n            let _e182 = lights.shadow_blur_half_kernel_size;\n            x = -(_e182);\n            loop {\n                let _e185 = x;\n                let _e188 = lights.shadow_blur_half_kernel_size;\n                if (_e185 <= _e188) {\n                } else {\n                    break;\n                }\n                {\n                    let _e192 = lights.shadow_blur_half_kernel_size;\n                    y = -(_e192);\n                    loop {\n                        let _e195 = y;\n                        let _e198 = lights.shadow_blur_half_kernel_size;\n                        if (_e195 <= _e198) {\n                        } else {\n                            break;\n                        }\n                        {\n                            let _e203 = x;\n                            let _e205 = y;\n                            let _e210 = i;\n                            let _e212 = bias;\n                            let _e216 = textureSampleCompare(shadow_map, shadow_map_sampler, (ShadowCoord.xy + (vec2<f32>(f32(_e203), f32(_e205)) * texelSize)), _e210, ((shadow_pos.z - _e212) / shadow_pos.w));\n                            let _e219 = occlusion;\n                            occlusion = (_e219 + (1f - _e216));\n                            let _e222 = weight;\n                            weight = (_e222 + 1f);\n                        }\n                        continuing {\n                            let _e225 = y;\n                            y = (_e225 + 1i);\n                        }\n                    }\n                }\n                continuing {\n                    let _e228 = x;\n                    x = (_e228 + 1i);\n                }\n            }\n            let _e230 = weight;\n            let _e231 = occlusion;\n            occlusion = (_e231 / _e230);\n            let _e233 = visibility;\n            let _e234 = occlusion;\n            visibility = clamp((_e233 - _e234), 0f, 1f);\n            let _e239 = visibility;\n            let _e244 = visibility;\n            let _e252 = total_light;\n            total_light = (_e252 + (((((_e239 * MaterialDiffuseColor) * LightColor) * LightPower) * cosTheta) + ((((_e244 * MaterialSpecularColor) * LightColor) * LightPower) * pow(cosAlpha, 5f))));\n        }\n        continuing {\n            let _e255 = i;\n            i = (_e255 + 1u);\n        }\n    }\n    let _e257 = total_light;\n    return vec4<f32>(_e257.xyz, albedo_spec.w);\n}\n";
+    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) tex_coords: vec2<f32>,\n    @location(3) normal: vec3<f32>,\n}\n\nstruct SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX {\n    @location(0) position: vec4<f32>,\n    @location(1) tangent: vec4<f32>,\n    @location(2) weights: vec4<f32>,\n    @location(3) @interpolate(flat) joints: vec4<u32>,\n    @location(4) tex_coords: vec2<f32>,\n    @location(5) normal: vec3<f32>,\n}\n\nstruct SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nstruct MaterialUniformsX_naga_oil_mod_XOBRHEX {\n    base_color: vec4<f32>,\n    emissive: vec3<f32>,\n    metallic: f32,\n    roughness: f32,\n    occlusion_strength: f32,\n    normal_scale: f32,\n}\n\nstruct InstanceInput {\n    @location(4) uv_scale: vec2<f32>,\n    @location(5) uv_offset: vec2<f32>,\n    @location(6) tint: vec4<f32>,\n    @location(7) model_1_: vec4<f32>,\n    @location(8) model_2_: vec4<f32>,\n    @location(9) model_3_: vec4<f32>,\n    @location(10) model_4_: vec4<f32>,\n    @location(11) normal_1_: vec4<f32>,\n    @location(12) normal_2_: vec4<f32>,\n    @location(13) normal_3_: vec4<f32>,\n    @location(14) normal_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n    @location(1) view_pos: vec4<f32>,\n    @location(2) view_space_normal: vec3<f32>,\n    @location(3) tint_color: vec4<f32>,\n    @location(4) world_pos: vec4<f32>,\n    @location(5) view_space_tangent: vec4<f32>,\n}\n\nstruct Light {\n    direction: vec3<f32>,\n    kind: u32,\n    color: vec4<f32>,\n    view_proj: mat4x4<f32>,\n    position: vec3<f32>,\n    radius: f32,\n    reach: f32,\n    shadow_index: i32,\n}\n\nstruct LightsUniform {\n    items: array<Light, 8>,\n    count: u32,\n    shadow_bias_minimum: f32,\n    shadow_bias_factor: f32,\n    shadow_blur_half_kernel_size: i32,\n    ambient_color: vec4<f32>,\n    shadow_view_projs: array<mat4x4<f32>, 8>,\n    point_shadow_view_projs: array<mat4x4<f32>, 24>,\n    cascade_count: u32,\n    cascade_blend: f32,\n    environment_intensity: f32,\n    has_environment: u32,\n}\n\nstruct ClusterUniforms {\n    grid_size: vec3<u32>,\n    z_near: f32,\n    z_far: f32,\n    show_heatmap: u32,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(7) @binding(0) \nvar<storage> joint_matrices: array<mat4x4<f32>>;\n@group(3) @binding(0) \nvar<uniform> material: MaterialUniformsX_naga_oil_mod_XOBRHEX;\n@group(3) @binding(1) \nvar albedo_texture: texture_2d<f32>;\n@group(3) @binding(2) \nvar albedo_sampler: sampler;\n@group(3) @binding(3) \nvar normal_texture: texture_2d<f32>;\n@group(3) @binding(4) \nvar normal_sampler: sampler;\n@group(3) @binding(5) \nvar metallic_roughness_texture: texture_2d<f32>;\n@group(3) @binding(6) \nvar metallic_roughness_sampler: sampler;\n@group(3) @binding(7) \nvar occlusion_texture: texture_2d<f32>;\n@group(3) @binding(8) \nvar occlusion_sampler: sampler;\n@group(3) @binding(9) \nvar emissive_texture: texture_2d<f32>;\n@group(3) @binding(10) \nvar emissive_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> lights: LightsUniform;\n@group(4) @binding(1) \nvar shadow_map: texture_depth_2d_array;\n@group(4) @binding(2) \nvar shadow_map_sampler: sampler_comparison;\n@group(4) @binding(3) \nvar point_shadow_map: texture_depth_2d_array;\n@group(4) @binding(4) \nvar irradiance_map: texture_cube<f32>;\n@group(4) @binding(5) \nvar prefiltered_map: texture_cube<f32>;\n@group(4) @binding(6) \nvar brdf_lut: texture_2d<f32>;\n@group(4) @binding(7) \nvar environment_sampler: sampler;\n@group(5) @binding(0) \nvar occlusion_map: texture_2d<f32>;\n@group(5) @binding(1) \nvar occlusion_map_sampler: sampler;\n@group(6) @binding(0) \nvar<uniform> clusters_uniform: ClusterUniforms;\n@group(6) @binding(1) \nvar<storage> cluster_lights: array<Light>;\n@group(6) @binding(2) \nvar<storage> clusters: array<vec2<u32>>;\n@group(6) @binding(3) \nvar<storage> cluster_light_indices: array<u32>;\n\nfn instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_2: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> mat4x4<f32> {\n    return mat4x4<f32>(instance_2.model_1x, instance_2.model_2x, instance_2.model_3x, instance_2.model_4x);\n}\n\nfn blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\nfn fresnel_schlickX_naga_oil_mod_XOBRHEX(cos_theta: f32, f0_: vec3<f32>) -> vec3<f32> {\n    return (f0_ + ((vec3(1f) - f0_) * pow(clamp((1f - cos_theta), 0f, 1f), 5f)));\n}\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v: f32, n_dot_l: f32, roughness_1: f32) -> f32 {\n    let k = (((roughness_1 + 1f) * (roughness_1 + 1f)) / 8f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\nfn cook_torranceX_naga_oil_mod_XOBRHEX(n: vec3<f32>, v: vec3<f32>, l: vec3<f32>, albedo: vec3<f32>, metallic: f32, roughness_2: f32, radiance: vec3<f32>) -> vec3<f32> {\n    let h = normalize((v + l));\n    let n_dot_l_1 = max(dot(n, l), 0f);\n    let n_dot_v_2 = max(dot(n, v), 0.0001f);\n    let f0_2 = mix(vec3(0.04f), albedo, metallic);\n    let _e19 = fresnel_schlickX_naga_oil_mod_XOBRHEX(max(dot(h, v), 0f), f0_2);\n    let _e24 = distribution_ggxX_naga_oil_mod_XOBRHEX(max(dot(n, h), 0f), roughness_2);\n    let _e25 = geometry_smithX_naga_oil_mod_XOBRHEX(n_dot_v_2, n_dot_l_1, roughness_2);\n    let specular = (((_e24 * _e25) * _e19) / vec3(max(((4f * n_dot_v_2) * n_dot_l_1), 0.0001f)));\n    let k_d = ((vec3(1f) - _e19) * (1f - metallic));\n    return (((((k_d * albedo) / vec3(3.1415927f)) + specular) * radiance) * n_dot_l_1);\n}\n\nfn fresnel_schlick_roughnessX_naga_oil_mod_XOBRHEX(cos_theta_1: f32, f0_1: vec3<f32>, roughness_3: f32) -> vec3<f32> {\n    return (f0_1 + ((max(vec3((1f - roughness_3)), f0_1) - f0_1) * pow(clamp((1f - cos_theta_1), 0f, 1f), 5f)));\n}\n\nfn environment_lightingX_naga_oil_mod_XOBRHEX(n_dot_v_1: f32, albedo_1: vec3<f32>, metallic_1: f32, roughness_4: f32, irradiance: vec3<f32>, prefiltered: vec3<f32>, brdf: vec2<f32>) -> vec3<f32> {\n    let f0_3 = mix(vec3(0.04f), albedo_1, metallic_1);\n    let _e7 = fresnel_schlick_roughnessX_naga_oil_mod_XOBRHEX(n_dot_v_1, f0_3, roughness_4);\n    let k_d_1 = ((vec3(1f) - _e7) * (1f - metallic_1));\n    return (((k_d_1 * irradiance) * albedo_1) + (prefiltered * ((f0_3 * brdf.x) + vec3(brdf.y))));\n}\n\nfn perturb_normalX_naga_oil_mod_XOBRHEX(n_1: vec3<f32>, pos: vec3<f32>, uv: vec2<f32>, map_normal: vec3<f32>, scale: f32) -> vec3<f32> {\n    let dp1_ = dpdx(pos);\n    let dp2_ = dpdy(pos);\n    let duv1_ = dpdx(uv);\n    let duv2_ = dpdy(uv);\n    let dp2_perp = cross(dp2_, n_1);\n    let dp1_perp = cross(n_1, dp1_);\n    let t = ((dp2_perp * duv1_.x) + (dp1_perp * duv2_.x));\n    let b = ((dp2_perp * duv1_.y) + (dp1_perp * duv2_.y));\n    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 0.000000000001f));\n    let tbn = mat3x3<f32>((t * inv_max), (b * inv_max), n_1);\n    return normalize((tbn * vec3<f32>((map_normal.xy * scale), map_normal.z)));\n}\n\nfn tangent_space_normalX_naga_oil_mod_XOBRHEX(n_2: vec3<f32>, tangent: vec4<f32>, pos_1: vec3<f32>, uv_1: vec2<f32>, map_normal_1: vec3<f32>, scale_1: f32) -> vec3<f32> {\n    let _e5 = perturb_normalX_naga_oil_mod_XOBRHEX(n_2, pos_1, uv_1, map_normal_1, scale_1);\n    if (dot(tangent.xyz, tangent.xyz) < 0.000000000001f) {\n        return _e5;\n    }\n    let t_1 = normalize((tangent.xyz - (n_2 * dot(n_2, tangent.xyz))));\n    let b_1 = (sign(tangent.w) * cross(n_2, t_1));\n    let tbn_1 = mat3x3<f32>(t_1, b_1, n_2);\n    return normalize((tbn_1 * vec3<f32>((map_normal_1.xy * scale_1), map_normal_1.z)));\n}\n\nfn point_attenuation(distance: f32, radius: f32) -> f32 {\n    let falloff = clamp((1f - pow((distance / radius), 2f)), 0f, 1f);\n    return (falloff * falloff);\n}\n\nfn cube_face(dir: vec3<f32>) -> i32 {\n    let a_1 = abs(dir);\n    if ((a_1.x >= a_1.y) && (a_1.x >= a_1.z)) {\n        return select(1i, 0i, (dir.x > 0f));\n    }\n    if (a_1.y >= a_1.z) {\n        return select(3i, 2i, (dir.y > 0f));\n    }\n    return select(5i, 4i, (dir.z > 0f));\n}\n\nfn shadow_occlusion(map: texture_depth_2d_array, layer: i32, shadow_pos: vec4<f32>, bias_1: f32) -> f32 {\n    var occlusion_1: f32 = 0f;\n    var weight: f32 = 0f;\n    var x: i32;\n    var y: i32;\n\n    let _e2 = textureDimensions(map);\n    let texelSize = (1f / f32(_e2.x));\n    const flip_correction = vec2<f32>(0.5f, -0.5f);\n    let proj_correction = (1f / shadow_pos.w);\n    let ShadowCoord = (((shadow_pos.xy * flip_correction) * proj_correction) + vec2<f32>(0.5f, 0.5f));\n    let _e23 = lights.shadow_blur_half_kernel_size;\n    x = -(_e23);\n    loop {\n        let _e26 = x;\n        let _e29 = lights.shadow_blur_half_kernel_size;\n        if (_e26 <= _e29) {\n        } else {\n            break;\n        }\n        {\n            let _e33 = lights.shadow_blur_half_kernel_size;\n            y = -(_e33);\n            loop {\n                let _e36 = y;\n                let _e39 = lights.shadow_blur_half_kernel_size;\n                if (_e36 <= _e39) {\n                } else {\n                    break;\n                }\n                {\n                    let _e45 = x;\n                    let _e47 = y;\n                    let _e56 = textureSampleCompareLevel(map, shadow_map_sampler, (ShadowCoord.xy + (vec2<f32>(f32(_e45), f32(_e47)) * texelSize)), layer, ((shadow_pos.z - bias_1) / shadow_pos.w));\n                    let _e60 = occlusion_1;\n                    occlusion_1 = (_e60 + (1f - _e56));\n                    let _e64 = weight;\n                    weight = (_e64 + 1f);\n                }\n                continuing {\n                    let _e67 = y;\n                    y = (_e67 + 1i);\n                }\n            }\n        }\n        continuing {\n            let _e70 = x;\n            x = (_e70 + 1i);\n        }\n    }\n    let _e72 = occlusion_1;\n    let _e73 = weight;\n    return (_e72 / _e73);\n}\n\nfn cascaded_shadow_occlusion(first_layer: i32, world_pos: vec4<f32>, bias_2: f32) -> f32 {\n    var c: i32 = 0i;\n\n    loop {\n        let _e2 = c;\n        let _e5 = lights.cascade_count;\n        if (_e2 < i32(_e5)) {\n        } else {\n            break;\n        }\n        {\n            let _e9 = c;\n            let layer_1 = (first_layer + _e9);\n            let _e15 = lights.shadow_view_projs[layer_1];\n            let shadow_pos_1 = (_e15 * world_pos);\n            let uv_2 = ((shadow_pos_1.xy * vec2<f32>(0.5f, -0.5f)) + vec2<f32>(0.5f, 0.5f));\n            let edge = min(min(uv_2.x, (1f - uv_2.x)), min(uv_2.y, (1f - uv_2.y)));\n            if (edge < 0f) {\n                continue;\n            }\n            let _e41 = shadow_occlusion(shadow_map, layer_1, shadow_pos_1, bias_2);\n            let _e44 = lights.cascade_blend;\n            let blend = (1f - (edge / max((0.5f * _e44), 0.0001f)));\n            let _e54 = c;\n            let _e59 = lights.cascade_count;\n            if ((blend <= 0f) || ((_e54 + 1i) >= i32(_e59))) {\n                return _e41;\n            }\n            let _e68 = lights.shadow_view_projs[(layer_1 + 1i)];\n            let next_shadow_pos = (_e68 * world_pos);\n            let _e73 = shadow_occlusion(shadow_map, (layer_1 + 1i), next_shadow_pos, bias_2);\n            return mix(_e41, _e73, blend);\n        }\n        continuing {\n            let _e76 = c;\n            c = (_e76 + 1i);\n        }\n    }\n    return 0f;\n}\n\nfn cluster_index(frag_coord: vec2<f32>, view_depth: f32) -> u32 {\n    let grid = clusters_uniform.grid_size;\n    let _e6 = global_uniforms.screen_size;\n    let tile = vec2<u32>(clamp(((frag_coord / _e6) * vec2<f32>(grid.xy)), vec2(0f), vec2<f32>((grid.xy - vec2(1u)))));\n    let _e23 = clusters_uniform.z_near;\n    let _e27 = clusters_uniform.z_near;\n    let _e32 = clusters_uniform.z_far;\n    let _e35 = clusters_uniform.z_near;\n    let depth_ratio = (log((max(view_depth, _e23) / _e27)) / log((_e32 / _e35)));\n    let slice = u32(clamp((depth_ratio * f32(grid.z)), 0f, f32((grid.z - 1u))));\n    return (tile.x + (grid.x * (tile.y + (grid.y * slice))));\n}\n\nfn light_attenuation(light: Light, world_pos_1: vec3<f32>) -> f32 {\n    let light_to_fragment = (world_pos_1 - light.position);\n    if (light.kind == 2u) {\n        let _e9 = point_attenuation(length(light_to_fragment), light.reach);\n        return _e9;\n    } else {\n        if (light.kind == 1u) {\n            let light_dist_sqr = dot(light_to_fragment, light_to_fragment);\n            let spot_factor = dot(normalize(light_to_fragment), light.direction);\n            let reach_sqr = pow(light.reach, 2f);\n            if ((spot_factor > light.radius) && (light_dist_sqr < reach_sqr)) {\n                return (1f - (((1f - spot_factor) * 1f) / (1f - light.radius)));\n            }\n            return 0f;\n        }\n    }\n    return 1f;\n}\n\nfn shade(light_1: Light, visibility: f32, world_pos_2: vec3<f32>, view_pos: vec3<f32>, n_3: vec3<f32>, albedo_2: vec3<f32>, metallic_2: f32, roughness_5: f32) -> vec3<f32> {\n    var l_1: vec3<f32>;\n\n    let _e3 = view_proj_uniforms.view;\n    l_1 = normalize((_e3 * vec4<f32>(light_1.position, 0f)).xyz);\n    if (light_1.kind == 2u) {\n        let _e17 = view_proj_uniforms.view;\n        l_1 = normalize((_e17 * vec4<f32>((light_1.position - world_pos_2), 0f)).xyz);\n    }\n    let v_1 = normalize(-(view_pos));\n    let radiance_1 = (((light_1.color.xyz * light_1.color.w) * PIX_naga_oil_mod_XOBRHEX) * visibility);\n    let _e37 = l_1;\n    let _e42 = cook_torranceX_naga_oil_mod_XOBRHEX(n_3, v_1, _e37, albedo_2, metallic_2, roughness_5, radiance_1);\n    return _e42;\n}\n\n@vertex \nfn vs_main(vertex: ModelVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let model_transform = mat4x4<f32>(instance.model_1_, instance.model_2_, instance.model_3_, instance.model_4_);\n    let normal_matrix = mat4x4<f32>(instance.normal_1_, instance.normal_2_, instance.normal_3_, instance.normal_4_);\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    let _e21 = view_proj_uniforms.view;\n    let model_view = (_e21 * model_transform);\n    let model_view_pos = (model_view * vertex.position);\n    let _e28 = view_proj_uniforms.projection;\n    out.clip_position = (_e28 * model_view_pos);\n    out.view_space_normal = (normal_matrix * vec4<f32>(vertex.normal, 1f)).xyz;\n    out.view_space_tangent = vec4<f32>((model_view * vec4<f32>(vertex.tangent.xyz, 0f)).xyz, vertex.tangent.w);\n    out.view_pos = model_view_pos;\n    out.tint_color = instance.tint;\n    out.world_pos = (model_transform * vertex.position);\n    let _e52 = out;\n    return _e52;\n}\n\n@vertex \nfn vs_skinned(vertex_1: SkinnedVertexDataX_naga_oil_mod_XM5WG6YTBNQX, instance_1: SkinnedInstanceInputX_naga_oil_mod_XONVWS3TONFXGOX) -> VertexOutput {\n    var out_1: VertexOutput;\n\n    let joints = (vertex_1.joints + vec4(instance_1.joint_offset));\n    let _e6 = instance_transformX_naga_oil_mod_XONVWS3TONFXGOX(instance_1);\n    let _e10 = joint_matrices[joints.x];\n    let _e14 = joint_matrices[joints.y];\n    let _e18 = joint_matrices[joints.z];\n    let _e22 = joint_matrices[joints.w];\n    let _e24 = blend_jointsX_naga_oil_mod_XONVWS3TONFXGOX(_e10, _e14, _e18, _e22, vertex_1.weights);\n    let model_transform_1 = (_e6 * _e24);\n    out_1.tex_coords = (instance_1.uv_offset + (instance_1.uv_scale * vertex_1.tex_coords));\n    let _e35 = view_proj_uniforms.view;\n    let model_view_1 = (_e35 * model_transform_1);\n    let model_view_pos_1 = (model_view_1 * vertex_1.position);\n    let _e42 = view_proj_uniforms.projection;\n    out_1.clip_position = (_e42 * model_view_pos_1);\n    out_1.view_space_normal = (model_view_1 * vec4<f32>(vertex_1.normal, 0f)).xyz;\n    out_1.view_space_tangent = vec4<f32>((model_view_1 * vec4<f32>(vertex_1.tangent.xyz, 0f)).xyz, vertex_1.tangent.w);\n    out_1.view_pos = model_view_pos_1;\n    out_1.tint_color = instance_1.tint;\n    out_1.world_pos = (model_transform_1 * vertex_1.position);\n    let _e66 = out_1;\n    return _e66;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    var ambient: vec3<f32>;\n    var total_light: vec3<f32>;\n    var i: u32 = 0u;\n    var bias: f32;\n    var occlusion: f32;\n    var i_1: u32 = 0u;\n\n    let _e6 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let _e10 = material.base_color;\n    let _e15 = textureSample(albedo_texture, albedo_sampler, in.tex_coords);\n    let albedo_3 = (((in.tint_color * _e6) * _e10) * _e15);\n    let metallic_roughness = textureSample(metallic_roughness_texture, metallic_roughness_sampler, in.tex_coords);\n    let _e23 = material.metallic;\n    let metallic_3 = clamp((_e23 * metallic_roughness.z), 0f, 1f);\n    let _e31 = material.roughness;\n    let roughness_6 = clamp((_e31 * metallic_roughness.y), 0.04f, 1f);\n    let _e40 = textureSample(occlusion_texture, occlusion_sampler, in.tex_coords);\n    let _e45 = material.occlusion_strength;\n    let material_occlusion = mix(1f, _e40.x, _e45);\n    let _e49 = material.emissive;\n    let _e53 = textureSample(emissive_texture, emissive_sampler, in.tex_coords);\n    let emissive = (_e49 * _e53.xyz);\n    let _e59 = textureSample(normal_texture, normal_sampler, in.tex_coords);\n    let map_normal_2 = ((_e59.xyz * 2f) - vec3(1f));\n    let _e74 = material.normal_scale;\n    let _e75 = tangent_space_normalX_naga_oil_mod_XOBRHEX(normalize(in.view_space_normal), in.view_space_tangent, in.view_pos.xyz, in.tex_coords, map_normal_2, _e74);\n    let _e82 = global_uniforms.screen_size;\n    let _e84 = textureSample(occlusion_map, occlusion_map_sampler, (in.clip_position.xy / _e82));\n    let ao = _e84.x;\n    let _e88 = lights.ambient_color;\n    ambient = (_e88.xyz * albedo_3.xyz);\n    let _e95 = lights.has_environment;\n    if (_e95 != 0u) {\n        let v_2 = normalize(-(in.view_pos.xyz));\n        let n_dot_v_3 = max(dot(_e75, v_2), 0.0001f);\n        let _e107 = view_proj_uniforms.inverse_view;\n        let world_n = (_e107 * vec4<f32>(_e75, 0f)).xyz;\n        let _e114 = view_proj_uniforms.inverse_view;\n        let world_r = (_e114 * vec4<f32>(reflect(-(v_2), _e75), 0f)).xyz;\n        let _e122 = textureNumLevels(prefiltered_map);\n        let max_lod = f32((_e122 - 1u));\n        let _e128 = lights.environment_intensity;\n        let _e132 = textureSample(irradiance_map, environment_sampler, world_n);\n        let _e137 = textureSampleLevel(prefiltered_map, environment_sampler, world_r, (roughness_6 * max_lod));\n        let _e142 = textureSample(brdf_lut, environment_sampler, vec2<f32>(n_dot_v_3, roughness_6));\n        let _e144 = environment_lightingX_naga_oil_mod_XOBRHEX(n_dot_v_3, albedo_3.xyz, metallic_3, roughness_6, _e132.xyz, _e137.xyz, _e142.xy);\n        ambient = (_e128 * _e144);\n    }\n    let _e147 = ambient;\n    total_light = (((ao * material_occlusion) * _e147) + emissive);\n    loop {\n        let _e152 = i;\n        let _e155 = lights.count;\n        if (_e152 < _e155) {\n        } else {\n            break;\n        }\n        {\n            let _e159 = i;\n            let light_2 = lights.items[_e159];\n            let light_to_fragment_1 = (in.world_pos.xyz - light_2.position);\n            bias = 0f;\n            occlusion = 0f;\n            if (light_2.kind == 2u) {\n                if (light_2.shadow_index >= 0i) {\n                    let _e179 = cube_face(light_to_fragment_1);\n                    let layer_2 = ((light_2.shadow_index * 6i) + _e179);\n                    let _e184 = lights.point_shadow_view_projs[layer_2];\n                    let shadow_pos_2 = (_e184 * in.world_pos);\n                    let _e187 = bias;\n                    let _e189 = shadow_occlusion(point_shadow_map, layer_2, shadow_pos_2, _e187);\n                    occlusion = _e189;\n                }\n            } else {\n                if (light_2.shadow_index >= 0i) {\n                    if (light_2.kind == 0u) {\n                        let _e198 = bias;\n                        let _e199 = cascaded_shadow_occlusion(light_2.shadow_index, in.world_pos, _e198);\n                        occlusion = _e199;\n                    } else {\n                        let shadow_pos_3 = (light_2.view_proj * in.world_pos);\n                        let _e204 = bias;\n                        let _e206 = shadow_occlusion(shadow_map, light_2.shadow_index, shadow_pos_3, _e204);\n                        occlusion = _e206;\n                    }\n                }\n            }\n            let _e209 = light_attenuation(light_2, in.world_pos.xyz);\n            let _e210 = occlusion;\n            let visibility_1 = clamp((_e209 - _e210), 0f, 1f);\n            let _e220 = shade(light_2, visibility_1, in.world_pos.xyz, in.view_pos.xyz, _e75, albedo_3.xyz, metallic_3, roughness_6);\n            let _e221 = total_light;\n            total_light = (_e221 + _e220);\n        }\n        continuing {\n            let _e224 = i;\n            i = (_e224 + 1u);\n        }\n    }\n    let _e231 = cluster_index(in.clip_position.xy, -(in.view_pos.z));\n    let cluster = clusters[_e231];\n    loop {\n        let _e236 = i_1;\n        if (_e236 < cluster.y) {\n        } else {\n            break;\n        }\n        {\n            let _e242 = i_1;\n            let _e245 = cluster_light_indices[(cluster.x + _e242)];\n            let light_3 = cluster_lights[_e245];\n            let _e250 = light_attenuation(light_3, in.world_pos.xyz);\n            let _e256 = shade(light_3, _e250, in.world_pos.xyz, in.view_pos.xyz, _e75, albedo_3.xyz, metallic_3, roughness_6);\n            let _e257 = total_light;\n            total_light = (_e257 + _e256);\n        }\n        continuing {\n            let _e260 = i_1;\n            i_1 = (_e260 + 1u);\n        }\n    }\n    let _e264 = clusters_uniform.show_heatmap;\n    if ((_e264 != 0u) && (cluster.y > 0u)) {\n        let heat = clamp((f32(cluster.y) / 16f), 0f, 1f);\n        let _e278 = total_light;\n        total_light = mix(_e278, vec3<f32>(heat, (1f - heat), 0f), 0.5f);\n    }\n    let _e285 = total_light;\n    return vec4<f32>(_e285, albedo_3.w);\n}\n";
     ///Shader module descriptor.
     pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
         label: Some("forward"),
         source: ::wgpu::ShaderSource::Wgsl(::std::borrow::Cow::Borrowed(SOURCE)),
     };
 }
+pub mod pbr {
+    #[allow(unused)]
+    ///Information about the globals within the module, exposed as constants and functions.
+    pub mod globals {
+        #[allow(unused)]
+        use super::*;
+    }
+    #[allow(unused)]
+    ///Information about the constants within the module, exposed as constants and functions.
+    pub mod constants {
+        #[allow(unused)]
+        use super::*;
+        #[allow(non_snake_case)]
+        ///Information about the `PI` constant variable within this shader module.
+        pub mod PI {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "PI";
+            pub const VALUE: f32 = 3.1415927f32;
+        }
+    }
+    #[allow(unused)]
+    ///Information about the entry points within the module, exposed as constants and functions.
+    pub mod entry_points {
+        #[allow(unused)]
+        use super::*;
+        pub mod main {
+            pub const NAME: &'static str = "main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct MaterialUniforms {\n    base_color: vec4<f32>,\n    emissive: vec3<f32>,\n    metallic: f32,\n    roughness: f32,\n    occlusion_strength: f32,\n    normal_scale: f32,\n}\n\nconst PI: f32 = 3.1415927f;\n\nfn distribution_ggx(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PI * d) * d));\n}\n\nfn geometry_smith(n_dot_v: f32, n_dot_l: f32, roughness_1: f32) -> f32 {\n    let k = (((roughness_1 + 1f) * (roughness_1 + 1f)) / 8f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\nfn fresnel_schlick(cos_theta: f32, f0_: vec3<f32>) -> vec3<f32> {\n    return (f0_ + ((vec3(1f) - f0_) * pow(clamp((1f - cos_theta), 0f, 1f), 5f)));\n}\n\nfn cook_torrance(n: vec3<f32>, v: vec3<f32>, l: vec3<f32>, albedo: vec3<f32>, metallic: f32, roughness_2: f32, radiance: vec3<f32>) -> vec3<f32> {\n    let h = normalize((v + l));\n    let n_dot_l_1 = max(dot(n, l), 0f);\n    let n_dot_v_2 = max(dot(n, v), 0.0001f);\n    let f0_2 = mix(vec3(0.04f), albedo, metallic);\n    let _e19 = fresnel_schlick(max(dot(h, v), 0f), f0_2);\n    let _e24 = distribution_ggx(max(dot(n, h), 0f), roughness_2);\n    let _e25 = geometry_smith(n_dot_v_2, n_dot_l_1, roughness_2);\n    let specular = (((_e24 * _e25) * _e19) / vec3(max(((4f * n_dot_v_2) * n_dot_l_1), 0.0001f)));\n    let k_d = ((vec3(1f) - _e19) * (1f - metallic));\n    return (((((k_d * albedo) / vec3(3.1415927f)) + specular) * radiance) * n_dot_l_1);\n}\n\nfn fresnel_schlick_roughness(cos_theta_1: f32, f0_1: vec3<f32>, roughness_3: f32) -> vec3<f32> {\n    return (f0_1 + ((max(vec3((1f - roughness_3)), f0_1) - f0_1) * pow(clamp((1f - cos_theta_1), 0f, 1f), 5f)));\n}\n\nfn environment_lighting(n_dot_v_1: f32, albedo_1: vec3<f32>, metallic_1: f32, roughness_4: f32, irradiance: vec3<f32>, prefiltered: vec3<f32>, brdf: vec2<f32>) -> vec3<f32> {\n    let f0_3 = mix(vec3(0.04f), albedo_1, metallic_1);\n    let _e7 = fresnel_schlick_roughness(n_dot_v_1, f0_3, roughness_4);\n    let k_d_1 = ((vec3(1f) - _e7) * (1f - metallic_1));\n    return (((k_d_1 * irradiance) * albedo_1) + (prefiltered * ((f0_3 * brdf.x) + vec3(brdf.y))));\n}\n\nfn perturb_normal(n_1: vec3<f32>, pos: vec3<f32>, uv: vec2<f32>, map_normal: vec3<f32>, scale: f32) -> vec3<f32> {\n    let dp1_ = dpdx(pos);\n    let dp2_ = dpdy(pos);\n    let duv1_ = dpdx(uv);\n    let duv2_ = dpdy(uv);\n    let dp2_perp = cross(dp2_, n_1);\n    let dp1_perp = cross(n_1, dp1_);\n    let t = ((dp2_perp * duv1_.x) + (dp1_perp * duv2_.x));\n    let b = ((dp2_perp * duv1_.y) + (dp1_perp * duv2_.y));\n    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 0.000000000001f));\n    let tbn = mat3x3<f32>((t * inv_max), (b * inv_max), n_1);\n    return normalize((tbn * vec3<f32>((map_normal.xy * scale), map_normal.z)));\n}\n\nfn tangent_space_normal(n_2: vec3<f32>, tangent: vec4<f32>, pos_1: vec3<f32>, uv_1: vec2<f32>, map_normal_1: vec3<f32>, scale_1: f32) -> vec3<f32> {\n    let _e5 = perturb_normal(n_2, pos_1, uv_1, map_normal_1, scale_1);\n    if (dot(tangent.xyz, tangent.xyz) < 0.000000000001f) {\n        return _e5;\n    }\n    let t_1 = normalize((tangent.xyz - (n_2 * dot(n_2, tangent.xyz))));\n    let b_1 = (sign(tangent.w) * cross(n_2, t_1));\n    let tbn_1 = mat3x3<f32>(t_1, b_1, n_2);\n    return normalize((tbn_1 * vec3<f32>((map_normal_1.xy * scale_1), map_normal_1.z)));\n}\n\n@fragment \nfn main() {\n    return;\n}\n";
+        }
+    }
+    #[allow(unused)]
+    ///Equivalent Rust definitions of the types defined in this module.
+    pub mod types {
+        #[allow(unused, non_camel_case_types)]
+        #[repr(C)]
+        #[derive(Debug, PartialEq, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+        pub struct MaterialUniforms {
+            pub base_color: glam::f32::Vec4,
+            pub emissive: glam::f32::Vec3,
+            pub metallic: f32,
+            pub roughness: f32,
+            pub occlusion_strength: f32,
+            pub normal_scale: f32,
+            pub _pad: [u8; 4u32 as usize],
+        }
+    }
+    #[allow(unused)]
+    use types::*;
+    ///The sourcecode for the shader, as a constant string.
+    pub const SOURCE: &'static str = "struct MaterialUniforms {\n    base_color: vec4<f32>,\n    emissive: vec3<f32>,\n    metallic: f32,\n    roughness: f32,\n    occlusion_strength: f32,\n    normal_scale: f32,\n}\n\nconst PI: f32 = 3.1415927f;\n\nfn distribution_ggx(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PI * d) * d));\n}\n\nfn geometry_smith(n_dot_v: f32, n_dot_l: f32, roughness_1: f32) -> f32 {\n    let k = (((roughness_1 + 1f) * (roughness_1 + 1f)) / 8f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\nfn fresnel_schlick(cos_theta: f32, f0_: vec3<f32>) -> vec3<f32> {\n    return (f0_ + ((vec3(1f) - f0_) * pow(clamp((1f - cos_theta), 0f, 1f), 5f)));\n}\n\nfn cook_torrance(n: vec3<f32>, v: vec3<f32>, l: vec3<f32>, albedo: vec3<f32>, metallic: f32, roughness_2: f32, radiance: vec3<f32>) -> vec3<f32> {\n    let h = normalize((v + l));\n    let n_dot_l_1 = max(dot(n, l), 0f);\n    let n_dot_v_2 = max(dot(n, v), 0.0001f);\n    let f0_2 = mix(vec3(0.04f), albedo, metallic);\n    let _e19 = fresnel_schlick(max(dot(h, v), 0f), f0_2);\n    let _e24 = distribution_ggx(max(dot(n, h), 0f), roughness_2);\n    let _e25 = geometry_smith(n_dot_v_2, n_dot_l_1, roughness_2);\n    let specular = (((_e24 * _e25) * _e19) / vec3(max(((4f * n_dot_v_2) * n_dot_l_1), 0.0001f)));\n    let k_d = ((vec3(1f) - _e19) * (1f - metallic));\n    return (((((k_d * albedo) / vec3(3.1415927f)) + specular) * radiance) * n_dot_l_1);\n}\n\nfn fresnel_schlick_roughness(cos_theta_1: f32, f0_1: vec3<f32>, roughness_3: f32) -> vec3<f32> {\n    return (f0_1 + ((max(vec3((1f - roughness_3)), f0_1) - f0_1) * pow(clamp((1f - cos_theta_1), 0f, 1f), 5f)));\n}\n\nfn environment_lighting(n_dot_v_1: f32, albedo_1: vec3<f32>, metallic_1: f32, roughness_4: f32, irradiance: vec3<f32>, prefiltered: vec3<f32>, brdf: vec2<f32>) -> vec3<f32> {\n    let f0_3 = mix(vec3(0.04f), albedo_1, metallic_1);\n    let _e7 = fresnel_schlick_roughness(n_dot_v_1, f0_3, roughness_4);\n    let k_d_1 = ((vec3(1f) - _e7) * (1f - metallic_1));\n    return (((k_d_1 * irradiance) * albedo_1) + (prefiltered * ((f0_3 * brdf.x) + vec3(brdf.y))));\n}\n\nfn perturb_normal(n_1: vec3<f32>, pos: vec3<f32>, uv: vec2<f32>, map_normal: vec3<f32>, scale: f32) -> vec3<f32> {\n    let dp1_ = dpdx(pos);\n    let dp2_ = dpdy(pos);\n    let duv1_ = dpdx(uv);\n    let duv2_ = dpdy(uv);\n    let dp2_perp = cross(dp2_, n_1);\n    let dp1_perp = cross(n_1, dp1_);\n    let t = ((dp2_perp * duv1_.x) + (dp1_perp * duv2_.x));\n    let b = ((dp2_perp * duv1_.y) + (dp1_perp * duv2_.y));\n    let inv_max = inverseSqrt(max(max(dot(t, t), dot(b, b)), 0.000000000001f));\n    let tbn = mat3x3<f32>((t * inv_max), (b * inv_max), n_1);\n    return normalize((tbn * vec3<f32>((map_normal.xy * scale), map_normal.z)));\n}\n\nfn tangent_space_normal(n_2: vec3<f32>, tangent: vec4<f32>, pos_1: vec3<f32>, uv_1: vec2<f32>, map_normal_1: vec3<f32>, scale_1: f32) -> vec3<f32> {\n    let _e5 = perturb_normal(n_2, pos_1, uv_1, map_normal_1, scale_1);\n    if (dot(tangent.xyz, tangent.xyz) < 0.000000000001f) {\n        return _e5;\n    }\n    let t_1 = normalize((tangent.xyz - (n_2 * dot(n_2, tangent.xyz))));\n    let b_1 = (sign(tangent.w) * cross(n_2, t_1));\n    let tbn_1 = mat3x3<f32>(t_1, b_1, n_2);\n    return normalize((tbn_1 * vec3<f32>((map_normal_1.xy * scale_1), map_normal_1.z)));\n}\n\n@fragment \nfn main() {\n    return;\n}\n";
+    ///Shader module descriptor.
+    pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
+        label: Some("pbr"),
+        source: ::wgpu::ShaderSource::Wgsl(::std::borrow::Cow::Borrowed(SOURCE)),
+    };
+}
+pub mod skinning {
+    #[allow(unused)]
+    ///Information about the globals within the module, exposed as constants and functions.
+    pub mod globals {
+        #[allow(unused)]
+        use super::*;
+    }
+    #[allow(unused)]
+    ///Information about the constants within the module, exposed as constants and functions.
+    pub mod constants {
+        #[allow(unused)]
+        use super::*;
+    }
+    #[allow(unused)]
+    ///Information about the entry points within the module, exposed as constants and functions.
+    pub mod entry_points {
+        #[allow(unused)]
+        use super::*;
+        pub mod main {
+            pub const NAME: &'static str = "main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct SkinnedInstanceInput {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nfn instance_transform(instance: SkinnedInstanceInput) -> mat4x4<f32> {\n    return mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n}\n\nfn blend_joints(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@fragment \nfn main() {\n    return;\n}\n";
+        }
+    }
+    #[allow(unused)]
+    ///Equivalent Rust definitions of the types defined in this module.
+    pub mod types {}
+    #[allow(unused)]
+    use types::*;
+    ///The sourcecode for the shader, as a constant string.
+    pub const SOURCE: &'static str = "struct SkinnedInstanceInput {\n    @location(6) uv_scale: vec2<f32>,\n    @location(7) uv_offset: vec2<f32>,\n    @location(8) tint: vec4<f32>,\n    @location(9) model_1x: vec4<f32>,\n    @location(10) model_2x: vec4<f32>,\n    @location(11) model_3x: vec4<f32>,\n    @location(12) model_4x: vec4<f32>,\n    @location(13) @interpolate(flat) joint_offset: u32,\n}\n\nfn instance_transform(instance: SkinnedInstanceInput) -> mat4x4<f32> {\n    return mat4x4<f32>(instance.model_1x, instance.model_2x, instance.model_3x, instance.model_4x);\n}\n\nfn blend_joints(joint_0_: mat4x4<f32>, joint_1_: mat4x4<f32>, joint_2_: mat4x4<f32>, joint_3_: mat4x4<f32>, weights: vec4<f32>) -> mat4x4<f32> {\n    return ((((joint_0_ * weights.x) + (joint_1_ * weights.y)) + (joint_2_ * weights.z)) + (joint_3_ * weights.w));\n}\n\n@fragment \nfn main() {\n    return;\n}\n";
+    ///Shader module descriptor.
+    pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
+        label: Some("skinning"),
+        source: ::wgpu::ShaderSource::Wgsl(::std::borrow::Cow::Borrowed(SOURCE)),
+    };
+}
+pub mod blit {
+    #[allow(unused)]
+    ///Information about the globals within the module, exposed as constants and functions.
+    pub mod globals {
+        #[allow(unused)]
+        use super::*;
+        ///Information about the `t_source` global variable within this shader module.
+        pub mod t_source {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "t_source";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `s_source` global variable within this shader module.
+        pub mod s_source {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "s_source";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Contains the following bindings: t_source, s_source
+        pub mod group0 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group0"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+    }
+    #[allow(unused)]
+    ///Information about the constants within the module, exposed as constants and functions.
+    pub mod constants {
+        #[allow(unused)]
+        use super::*;
+    }
+    #[allow(unused)]
+    ///Information about the entry points within the module, exposed as constants and functions.
+    pub mod entry_points {
+        #[allow(unused)]
+        use super::*;
+        pub mod vs_main {
+            pub const NAME: &'static str = "vs_main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\n@group(0) @binding(0) \nvar t_source: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_source: sampler;\n\n@vertex \nfn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {\n    var out: VertexOutput;\n\n    let uv = vec2<f32>(f32(((index << 1u) & 2u)), f32((index & 2u)));\n    out.clip_position = vec4<f32>(((uv.x * 2f) - 1f), (1f - (uv.y * 2f)), 0f, 1f);\n    out.tex_coords = uv;\n    let _e26 = out;\n    return _e26;\n}\n";
+        }
+        pub mod fs_main {
+            pub const NAME: &'static str = "fs_main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\n@group(0) @binding(0) \nvar t_source: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_source: sampler;\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    let _e4 = textureSample(t_source, s_source, in.tex_coords);\n    return _e4;\n}\n";
+        }
+    }
+    #[allow(unused)]
+    ///Equivalent Rust definitions of the types defined in this module.
+    pub mod types {}
+    #[allow(unused)]
+    use types::*;
+    ///The sourcecode for the shader, as a constant string.
+    pub const SOURCE: &'static str = "struct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\n@group(0) @binding(0) \nvar t_source: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_source: sampler;\n\n@vertex \nfn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {\n    var out: VertexOutput;\n\n    let uv = vec2<f32>(f32(((index << 1u) & 2u)), f32((index & 2u)));\n    out.clip_position = vec4<f32>(((uv.x * 2f) - 1f), (1f - (uv.y * 2f)), 0f, 1f);\n    out.tex_coords = uv;\n    let _e26 = out;\n    return _e26;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    let _e4 = textureSample(t_source, s_source, in.tex_coords);\n    return _e4;\n}\n";
+    ///Shader module descriptor.
+    pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
+        label: Some("blit"),
+        source: ::wgpu::ShaderSource::Wgsl(::std::borrow::Cow::Borrowed(SOURCE)),
+    };
+}
+pub mod skybox {
+    #[allow(unused)]
+    ///Information about the globals within the module, exposed as constants and functions.
+    pub mod globals {
+        #[allow(unused)]
+        use super::*;
+        ///Information about the `t_diffuse` global variable within this shader module.
+        pub mod t_diffuse {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "t_diffuse";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `s_diffuse` global variable within this shader module.
+        pub mod s_diffuse {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "s_diffuse";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Information about the `global_uniforms` global variable within this shader module.
+        pub mod global_uniforms {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "global_uniforms";
+            pub type Ty = super::super::super::global::types::GlobalUniforms;
+            pub const GROUP: u32 = 1u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("global_uniforms"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Information about the `view_proj_uniforms` global variable within this shader module.
+        pub mod view_proj_uniforms {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "view_proj_uniforms";
+            pub type Ty = super::super::super::global::types::ViewProjectionUniforms;
+            pub const GROUP: u32 = 2u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("view_proj_uniforms"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Information about the `t_skybox` global variable within this shader module.
+        pub mod t_skybox {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "t_skybox";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `s_skybox` global variable within this shader module.
+        pub mod s_skybox {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "s_skybox";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Contains the following bindings: t_diffuse, s_diffuse
+        pub mod group0 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group0"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Contains the following bindings: t_skybox, s_skybox
+        pub mod group3 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 3u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group3"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::Cube,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+    }
+    #[allow(unused)]
+    ///Information about the constants within the module, exposed as constants and functions.
+    pub mod constants {
+        #[allow(unused)]
+        use super::*;
+    }
+    #[allow(unused)]
+    ///Information about the entry points within the module, exposed as constants and functions.
+    pub mod entry_points {
+        #[allow(unused)]
+        use super::*;
+        pub mod vs_main {
+            pub const NAME: &'static str = "vs_main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) direction: vec3<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar t_skybox: texture_cube<f32>;\n@group(3) @binding(1) \nvar s_skybox: sampler;\n\n@vertex \nfn vs_main(vertex: VertexInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let view = view_proj_uniforms.view;\n    let rotation = mat3x3<f32>(view[0].xyz, view[1].xyz, view[2].xyz);\n    let _e13 = view_proj_uniforms.projection;\n    let clip_position = (_e13 * vec4<f32>((rotation * vertex.position.xyz), 1f));\n    out.clip_position = clip_position.xyww;\n    out.direction = vertex.position.xyz;\n    let _e26 = out;\n    return _e26;\n}\n";
+        }
+        pub mod fs_main {
+            pub const NAME: &'static str = "fs_main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) direction: vec3<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar t_skybox: texture_cube<f32>;\n@group(3) @binding(1) \nvar s_skybox: sampler;\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    let _e4 = textureSample(t_skybox, s_skybox, in.direction);\n    return vec4<f32>(_e4.xyz, 1f);\n}\n";
+        }
+    }
+    #[allow(unused)]
+    ///Equivalent Rust definitions of the types defined in this module.
+    pub mod types {}
+    #[allow(unused)]
+    use types::*;
+    ///The sourcecode for the shader, as a constant string.
+    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) direction: vec3<f32>,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar t_skybox: texture_cube<f32>;\n@group(3) @binding(1) \nvar s_skybox: sampler;\n\n@vertex \nfn vs_main(vertex: VertexInput) -> VertexOutput {\n    var out: VertexOutput;\n\n    let view = view_proj_uniforms.view;\n    let rotation = mat3x3<f32>(view[0].xyz, view[1].xyz, view[2].xyz);\n    let _e13 = view_proj_uniforms.projection;\n    let clip_position = (_e13 * vec4<f32>((rotation * vertex.position.xyz), 1f));\n    out.clip_position = clip_position.xyww;\n    out.direction = vertex.position.xyz;\n    let _e26 = out;\n    return _e26;\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    let _e4 = textureSample(t_skybox, s_skybox, in.direction);\n    return vec4<f32>(_e4.xyz, 1f);\n}\n";
+    ///Shader module descriptor.
+    pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
+        label: Some("skybox"),
+        source: ::wgpu::ShaderSource::Wgsl(::std::borrow::Cow::Borrowed(SOURCE)),
+    };
+}
+pub mod ibl {
+    #[allow(unused)]
+    ///Information about the globals within the module, exposed as constants and functions.
+    pub mod globals {
+        #[allow(unused)]
+        use super::*;
+        ///Information about the `environment` global variable within this shader module.
+        pub mod environment {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "environment";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `environment_sampler` global variable within this shader module.
+        pub mod environment_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "environment_sampler";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Information about the `params` global variable within this shader module.
+        pub mod params {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "params";
+            pub type Ty = FilterUniforms;
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 2u32;
+        }
+        ///Contains the following bindings: environment, environment_sampler, params
+        pub mod group0 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group0"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::Cube,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 2u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+    }
+    #[allow(unused)]
+    ///Information about the constants within the module, exposed as constants and functions.
+    pub mod constants {
+        #[allow(unused)]
+        use super::*;
+        #[allow(non_snake_case)]
+        ///Information about the `BRDF_LUT_SAMPLES` constant variable within this shader module.
+        pub mod BRDF_LUT_SAMPLES {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "BRDF_LUT_SAMPLES";
+            pub const VALUE: u32 = 256u32;
+        }
+    }
+    #[allow(unused)]
+    ///Information about the entry points within the module, exposed as constants and functions.
+    pub mod entry_points {
+        #[allow(unused)]
+        use super::*;
+        pub mod vs_main {
+            pub const NAME: &'static str = "vs_main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct FilterUniforms {\n    face: u32,\n    roughness: f32,\n    sample_count: u32,\n    environment_size: f32,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) uv: vec2<f32>,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\nconst BRDF_LUT_SAMPLES: u32 = 256u;\n\n@group(0) @binding(0) \nvar environment: texture_cube<f32>;\n@group(0) @binding(1) \nvar environment_sampler: sampler;\n@group(0) @binding(2) \nvar<uniform> params: FilterUniforms;\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn face_direction(face: u32, uv: vec2<f32>) -> vec3<f32> {\n    let u = ((uv.x * 2f) - 1f);\n    let v = ((uv.y * 2f) - 1f);\n    switch face {\n        case 0u: {\n            return normalize(vec3<f32>(1f, -(v), -(u)));\n        }\n        case 1u: {\n            return normalize(vec3<f32>(-1f, -(v), u));\n        }\n        case 2u: {\n            return normalize(vec3<f32>(u, 1f, v));\n        }\n        case 3u: {\n            return normalize(vec3<f32>(u, -1f, -(v)));\n        }\n        case 4u: {\n            return normalize(vec3<f32>(u, -(v), 1f));\n        }\n        default: {\n            return normalize(vec3<f32>(-(u), -(v), -1f));\n        }\n    }\n}\n\nfn hammersley(i: u32, n: u32) -> vec2<f32> {\n    return vec2<f32>((f32(i) / f32(n)), (f32(reverseBits(i)) * 0.00000000023283064f));\n}\n\nfn tangent_frame(n_1: vec3<f32>) -> mat3x3<f32> {\n    let up = select(vec3<f32>(0f, 0f, 1f), vec3<f32>(1f, 0f, 0f), (abs(n_1.z) > 0.999f));\n    let t = normalize(cross(up, n_1));\n    return mat3x3<f32>(t, cross(n_1, t), n_1);\n}\n\nfn importance_sample_ggx(xi: vec2<f32>, roughness_1: f32) -> vec3<f32> {\n    let a_1 = (roughness_1 * roughness_1);\n    let phi = (6.2831855f * xi.x);\n    let cos_theta = sqrt(((1f - xi.y) / (1f + (((a_1 * a_1) - 1f) * xi.y))));\n    let sin_theta = sqrt((1f - (cos_theta * cos_theta)));\n    return vec3<f32>((cos(phi) * sin_theta), (sin(phi) * sin_theta), cos_theta);\n}\n\nfn sample_lod(pdf: f32) -> f32 {\n    let _e2 = params.environment_size;\n    let _e8 = params.environment_size;\n    let texel = (12.566371f / ((6f * _e2) * _e8));\n    let _e14 = params.sample_count;\n    let sample = (1f / ((f32(_e14) * pdf) + 0.0001f));\n    return max(((0.5f * log2((sample / texel))) + 1f), 0f);\n}\n\nfn geometry_smith_ibl(n_dot_v: f32, n_dot_l: f32, roughness_2: f32) -> f32 {\n    let k = ((roughness_2 * roughness_2) / 2f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\n@vertex \nfn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {\n    var out: VertexOutput;\n\n    let uv_1 = vec2<f32>(f32(((index << 1u) & 2u)), f32((index & 2u)));\n    out.clip_position = vec4<f32>(((uv_1.x * 2f) - 1f), (1f - (uv_1.y * 2f)), 0f, 1f);\n    out.uv = uv_1;\n    let _e26 = out;\n    return _e26;\n}\n";
+        }
+        pub mod fs_irradiance {
+            pub const NAME: &'static str = "fs_irradiance";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct FilterUniforms {\n    face: u32,\n    roughness: f32,\n    sample_count: u32,\n    environment_size: f32,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) uv: vec2<f32>,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\nconst BRDF_LUT_SAMPLES: u32 = 256u;\n\n@group(0) @binding(0) \nvar environment: texture_cube<f32>;\n@group(0) @binding(1) \nvar environment_sampler: sampler;\n@group(0) @binding(2) \nvar<uniform> params: FilterUniforms;\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn face_direction(face: u32, uv: vec2<f32>) -> vec3<f32> {\n    let u = ((uv.x * 2f) - 1f);\n    let v = ((uv.y * 2f) - 1f);\n    switch face {\n        case 0u: {\n            return normalize(vec3<f32>(1f, -(v), -(u)));\n        }\n        case 1u: {\n            return normalize(vec3<f32>(-1f, -(v), u));\n        }\n        case 2u: {\n            return normalize(vec3<f32>(u, 1f, v));\n        }\n        case 3u: {\n            return normalize(vec3<f32>(u, -1f, -(v)));\n        }\n        case 4u: {\n            return normalize(vec3<f32>(u, -(v), 1f));\n        }\n        default: {\n            return normalize(vec3<f32>(-(u), -(v), -1f));\n        }\n    }\n}\n\nfn hammersley(i_1: u32, n: u32) -> vec2<f32> {\n    return vec2<f32>((f32(i_1) / f32(n)), (f32(reverseBits(i_1)) * 0.00000000023283064f));\n}\n\nfn tangent_frame(n_1: vec3<f32>) -> mat3x3<f32> {\n    let up = select(vec3<f32>(0f, 0f, 1f), vec3<f32>(1f, 0f, 0f), (abs(n_1.z) > 0.999f));\n    let t = normalize(cross(up, n_1));\n    return mat3x3<f32>(t, cross(n_1, t), n_1);\n}\n\nfn importance_sample_ggx(xi: vec2<f32>, roughness_1: f32) -> vec3<f32> {\n    let a_1 = (roughness_1 * roughness_1);\n    let phi = (6.2831855f * xi.x);\n    let cos_theta = sqrt(((1f - xi.y) / (1f + (((a_1 * a_1) - 1f) * xi.y))));\n    let sin_theta = sqrt((1f - (cos_theta * cos_theta)));\n    return vec3<f32>((cos(phi) * sin_theta), (sin(phi) * sin_theta), cos_theta);\n}\n\nfn sample_lod(pdf: f32) -> f32 {\n    let _e2 = params.environment_size;\n    let _e8 = params.environment_size;\n    let texel = (12.566371f / ((6f * _e2) * _e8));\n    let _e14 = params.sample_count;\n    let sample = (1f / ((f32(_e14) * pdf) + 0.0001f));\n    return max(((0.5f * log2((sample / texel))) + 1f), 0f);\n}\n\nfn geometry_smith_ibl(n_dot_v: f32, n_dot_l: f32, roughness_2: f32) -> f32 {\n    let k = ((roughness_2 * roughness_2) / 2f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\n@fragment \nfn fs_irradiance(in: VertexOutput) -> @location(0) vec4<f32> {\n    var irradiance: vec3<f32> = vec3(0f);\n    var i: u32 = 0u;\n\n    let _e6 = params.face;\n    let _e8 = face_direction(_e6, in.uv);\n    let _e9 = tangent_frame(_e8);\n    loop {\n        let _e11 = i;\n        let _e14 = params.sample_count;\n        if (_e11 < _e14) {\n        } else {\n            break;\n        }\n        {\n            let _e16 = i;\n            let _e19 = params.sample_count;\n            let _e20 = hammersley(_e16, _e19);\n            let phi_1 = (6.2831855f * _e20.x);\n            let cos_theta_1 = sqrt((1f - _e20.y));\n            let sin_theta_1 = sqrt(_e20.y);\n            let l = (_e9 * vec3<f32>((cos(phi_1) * sin_theta_1), (sin(phi_1) * sin_theta_1), cos_theta_1));\n            let _e38 = sample_lod((cos_theta_1 / PIX_naga_oil_mod_XOBRHEX));\n            let _e42 = textureSampleLevel(environment, environment_sampler, l, _e38);\n            let _e44 = irradiance;\n            irradiance = (_e44 + _e42.xyz);\n        }\n        continuing {\n            let _e47 = i;\n            i = (_e47 + 1u);\n        }\n    }\n    let _e49 = irradiance;\n    let _e52 = params.sample_count;\n    return vec4<f32>((_e49 / vec3(f32(_e52))), 1f);\n}\n";
+        }
+        pub mod fs_prefilter {
+            pub const NAME: &'static str = "fs_prefilter";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct FilterUniforms {\n    face: u32,\n    roughness: f32,\n    sample_count: u32,\n    environment_size: f32,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) uv: vec2<f32>,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\nconst BRDF_LUT_SAMPLES: u32 = 256u;\n\n@group(0) @binding(0) \nvar environment: texture_cube<f32>;\n@group(0) @binding(1) \nvar environment_sampler: sampler;\n@group(0) @binding(2) \nvar<uniform> params: FilterUniforms;\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn face_direction(face: u32, uv: vec2<f32>) -> vec3<f32> {\n    let u = ((uv.x * 2f) - 1f);\n    let v = ((uv.y * 2f) - 1f);\n    switch face {\n        case 0u: {\n            return normalize(vec3<f32>(1f, -(v), -(u)));\n        }\n        case 1u: {\n            return normalize(vec3<f32>(-1f, -(v), u));\n        }\n        case 2u: {\n            return normalize(vec3<f32>(u, 1f, v));\n        }\n        case 3u: {\n            return normalize(vec3<f32>(u, -1f, -(v)));\n        }\n        case 4u: {\n            return normalize(vec3<f32>(u, -(v), 1f));\n        }\n        default: {\n            return normalize(vec3<f32>(-(u), -(v), -1f));\n        }\n    }\n}\n\nfn hammersley(i_1: u32, n: u32) -> vec2<f32> {\n    return vec2<f32>((f32(i_1) / f32(n)), (f32(reverseBits(i_1)) * 0.00000000023283064f));\n}\n\nfn tangent_frame(n_1: vec3<f32>) -> mat3x3<f32> {\n    let up = select(vec3<f32>(0f, 0f, 1f), vec3<f32>(1f, 0f, 0f), (abs(n_1.z) > 0.999f));\n    let t = normalize(cross(up, n_1));\n    return mat3x3<f32>(t, cross(n_1, t), n_1);\n}\n\nfn importance_sample_ggx(xi: vec2<f32>, roughness_1: f32) -> vec3<f32> {\n    let a_1 = (roughness_1 * roughness_1);\n    let phi = (6.2831855f * xi.x);\n    let cos_theta = sqrt(((1f - xi.y) / (1f + (((a_1 * a_1) - 1f) * xi.y))));\n    let sin_theta = sqrt((1f - (cos_theta * cos_theta)));\n    return vec3<f32>((cos(phi) * sin_theta), (sin(phi) * sin_theta), cos_theta);\n}\n\nfn sample_lod(pdf: f32) -> f32 {\n    let _e2 = params.environment_size;\n    let _e8 = params.environment_size;\n    let texel = (12.566371f / ((6f * _e2) * _e8));\n    let _e14 = params.sample_count;\n    let sample = (1f / ((f32(_e14) * pdf) + 0.0001f));\n    return max(((0.5f * log2((sample / texel))) + 1f), 0f);\n}\n\nfn geometry_smith_ibl(n_dot_v: f32, n_dot_l: f32, roughness_2: f32) -> f32 {\n    let k = ((roughness_2 * roughness_2) / 2f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\n@fragment \nfn fs_prefilter(in: VertexOutput) -> @location(0) vec4<f32> {\n    var color: vec3<f32> = vec3(0f);\n    var weight: f32 = 0f;\n    var i: u32 = 0u;\n\n    let _e6 = params.face;\n    let _e8 = face_direction(_e6, in.uv);\n    let _e11 = params.roughness;\n    if (_e11 <= 0f) {\n        let _e17 = textureSampleLevel(environment, environment_sampler, _e8, 0f);\n        return vec4<f32>(_e17.xyz, 1f);\n    }\n    let _e21 = tangent_frame(_e8);\n    loop {\n        let _e23 = i;\n        let _e26 = params.sample_count;\n        if (_e23 < _e26) {\n        } else {\n            break;\n        }\n        {\n            let _e28 = i;\n            let _e31 = params.sample_count;\n            let _e32 = hammersley(_e28, _e31);\n            let _e35 = params.roughness;\n            let _e36 = importance_sample_ggx(_e32, _e35);\n            let h = (_e21 * _e36);\n            let l = (((2f * dot(_e8, h)) * h) - _e8);\n            let n_dot_l_1 = dot(_e8, l);\n            if (n_dot_l_1 > 0f) {\n                let _e51 = params.roughness;\n                let _e52 = distribution_ggxX_naga_oil_mod_XOBRHEX(max(dot(_e8, h), 0f), _e51);\n                let pdf_1 = (_e52 / 4f);\n                let _e55 = sample_lod(pdf_1);\n                let _e59 = textureSampleLevel(environment, environment_sampler, l, _e55);\n                let _e62 = color;\n                color = (_e62 + (_e59.xyz * n_dot_l_1));\n                let _e65 = weight;\n                weight = (_e65 + n_dot_l_1);\n            }\n        }\n        continuing {\n            let _e68 = i;\n            i = (_e68 + 1u);\n        }\n    }\n    let _e70 = color;\n    let _e71 = weight;\n    return vec4<f32>((_e70 / vec3(max(_e71, 0.0001f))), 1f);\n}\n";
+        }
+        pub mod fs_brdf_lut {
+            pub const NAME: &'static str = "fs_brdf_lut";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct FilterUniforms {\n    face: u32,\n    roughness: f32,\n    sample_count: u32,\n    environment_size: f32,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) uv: vec2<f32>,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\nconst BRDF_LUT_SAMPLES: u32 = 256u;\n\n@group(0) @binding(0) \nvar environment: texture_cube<f32>;\n@group(0) @binding(1) \nvar environment_sampler: sampler;\n@group(0) @binding(2) \nvar<uniform> params: FilterUniforms;\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn face_direction(face: u32, uv: vec2<f32>) -> vec3<f32> {\n    let u = ((uv.x * 2f) - 1f);\n    let v = ((uv.y * 2f) - 1f);\n    switch face {\n        case 0u: {\n            return normalize(vec3<f32>(1f, -(v), -(u)));\n        }\n        case 1u: {\n            return normalize(vec3<f32>(-1f, -(v), u));\n        }\n        case 2u: {\n            return normalize(vec3<f32>(u, 1f, v));\n        }\n        case 3u: {\n            return normalize(vec3<f32>(u, -1f, -(v)));\n        }\n        case 4u: {\n            return normalize(vec3<f32>(u, -(v), 1f));\n        }\n        default: {\n            return normalize(vec3<f32>(-(u), -(v), -1f));\n        }\n    }\n}\n\nfn hammersley(i_1: u32, n: u32) -> vec2<f32> {\n    return vec2<f32>((f32(i_1) / f32(n)), (f32(reverseBits(i_1)) * 0.00000000023283064f));\n}\n\nfn tangent_frame(n_1: vec3<f32>) -> mat3x3<f32> {\n    let up = select(vec3<f32>(0f, 0f, 1f), vec3<f32>(1f, 0f, 0f), (abs(n_1.z) > 0.999f));\n    let t = normalize(cross(up, n_1));\n    return mat3x3<f32>(t, cross(n_1, t), n_1);\n}\n\nfn importance_sample_ggx(xi: vec2<f32>, roughness_1: f32) -> vec3<f32> {\n    let a_1 = (roughness_1 * roughness_1);\n    let phi = (6.2831855f * xi.x);\n    let cos_theta = sqrt(((1f - xi.y) / (1f + (((a_1 * a_1) - 1f) * xi.y))));\n    let sin_theta = sqrt((1f - (cos_theta * cos_theta)));\n    return vec3<f32>((cos(phi) * sin_theta), (sin(phi) * sin_theta), cos_theta);\n}\n\nfn sample_lod(pdf: f32) -> f32 {\n    let _e2 = params.environment_size;\n    let _e8 = params.environment_size;\n    let texel = (12.566371f / ((6f * _e2) * _e8));\n    let _e14 = params.sample_count;\n    let sample = (1f / ((f32(_e14) * pdf) + 0.0001f));\n    return max(((0.5f * log2((sample / texel))) + 1f), 0f);\n}\n\nfn geometry_smith_ibl(n_dot_v: f32, n_dot_l: f32, roughness_2: f32) -> f32 {\n    let k = ((roughness_2 * roughness_2) / 2f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\n@fragment \nfn fs_brdf_lut(in: VertexOutput) -> @location(0) vec4<f32> {\n    var scale: f32 = 0f;\n    var bias: f32 = 0f;\n    var i: u32 = 0u;\n\n    let n_dot_v_1 = max(in.uv.x, 0.001f);\n    let roughness_3 = in.uv.y;\n    let v_1 = vec3<f32>(sqrt((1f - (n_dot_v_1 * n_dot_v_1))), 0f, n_dot_v_1);\n    loop {\n        let _e16 = i;\n        if (_e16 < BRDF_LUT_SAMPLES) {\n        } else {\n            break;\n        }\n        {\n            let _e19 = i;\n            let _e21 = hammersley(_e19, BRDF_LUT_SAMPLES);\n            let _e22 = importance_sample_ggx(_e21, roughness_3);\n            let l = (((2f * dot(v_1, _e22)) * _e22) - v_1);\n            let n_dot_l_1 = max(l.z, 0f);\n            let n_dot_h_1 = max(_e22.z, 0f);\n            let v_dot_h = max(dot(v_1, _e22), 0f);\n            if (n_dot_l_1 > 0f) {\n                let _e39 = geometry_smith_ibl(n_dot_v_1, n_dot_l_1, roughness_3);\n                let g_vis = ((_e39 * v_dot_h) / max((n_dot_h_1 * n_dot_v_1), 0.0001f));\n                let fc = pow((1f - v_dot_h), 5f);\n                let _e53 = scale;\n                scale = (_e53 + ((1f - fc) * g_vis));\n                let _e57 = bias;\n                bias = (_e57 + (fc * g_vis));\n            }\n        }\n        continuing {\n            let _e60 = i;\n            i = (_e60 + 1u);\n        }\n    }\n    let _e62 = scale;\n    let _e63 = bias;\n    return (vec4<f32>(_e62, _e63, 0f, 1f) / vec4<f32>(256f, 256f, 1f, 1f));\n}\n";
+        }
+    }
+    #[allow(unused)]
+    ///Equivalent Rust definitions of the types defined in this module.
+    pub mod types {
+        #[allow(unused, non_camel_case_types)]
+        #[repr(C)]
+        #[derive(Debug, PartialEq, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+        pub struct FilterUniforms {
+            pub face: u32,
+            pub roughness: f32,
+            pub sample_count: u32,
+            pub environment_size: f32,
+        }
+    }
+    #[allow(unused)]
+    use types::*;
+    ///The sourcecode for the shader, as a constant string.
+    pub const SOURCE: &'static str = "struct FilterUniforms {\n    face: u32,\n    roughness: f32,\n    sample_count: u32,\n    environment_size: f32,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) uv: vec2<f32>,\n}\n\nconst PIX_naga_oil_mod_XOBRHEX: f32 = 3.1415927f;\nconst BRDF_LUT_SAMPLES: u32 = 256u;\n\n@group(0) @binding(0) \nvar environment: texture_cube<f32>;\n@group(0) @binding(1) \nvar environment_sampler: sampler;\n@group(0) @binding(2) \nvar<uniform> params: FilterUniforms;\n\nfn distribution_ggxX_naga_oil_mod_XOBRHEX(n_dot_h: f32, roughness: f32) -> f32 {\n    let a = (roughness * roughness);\n    let a2_ = (a * a);\n    let d = (((n_dot_h * n_dot_h) * (a2_ - 1f)) + 1f);\n    return (a2_ / ((PIX_naga_oil_mod_XOBRHEX * d) * d));\n}\n\nfn face_direction(face: u32, uv: vec2<f32>) -> vec3<f32> {\n    let u = ((uv.x * 2f) - 1f);\n    let v = ((uv.y * 2f) - 1f);\n    switch face {\n        case 0u: {\n            return normalize(vec3<f32>(1f, -(v), -(u)));\n        }\n        case 1u: {\n            return normalize(vec3<f32>(-1f, -(v), u));\n        }\n        case 2u: {\n            return normalize(vec3<f32>(u, 1f, v));\n        }\n        case 3u: {\n            return normalize(vec3<f32>(u, -1f, -(v)));\n        }\n        case 4u: {\n            return normalize(vec3<f32>(u, -(v), 1f));\n        }\n        default: {\n            return normalize(vec3<f32>(-(u), -(v), -1f));\n        }\n    }\n}\n\nfn hammersley(i_3: u32, n: u32) -> vec2<f32> {\n    return vec2<f32>((f32(i_3) / f32(n)), (f32(reverseBits(i_3)) * 0.00000000023283064f));\n}\n\nfn tangent_frame(n_1: vec3<f32>) -> mat3x3<f32> {\n    let up = select(vec3<f32>(0f, 0f, 1f), vec3<f32>(1f, 0f, 0f), (abs(n_1.z) > 0.999f));\n    let t = normalize(cross(up, n_1));\n    return mat3x3<f32>(t, cross(n_1, t), n_1);\n}\n\nfn importance_sample_ggx(xi: vec2<f32>, roughness_1: f32) -> vec3<f32> {\n    let a_1 = (roughness_1 * roughness_1);\n    let phi = (6.2831855f * xi.x);\n    let cos_theta = sqrt(((1f - xi.y) / (1f + (((a_1 * a_1) - 1f) * xi.y))));\n    let sin_theta = sqrt((1f - (cos_theta * cos_theta)));\n    return vec3<f32>((cos(phi) * sin_theta), (sin(phi) * sin_theta), cos_theta);\n}\n\nfn sample_lod(pdf: f32) -> f32 {\n    let _e2 = params.environment_size;\n    let _e8 = params.environment_size;\n    let texel = (12.566371f / ((6f * _e2) * _e8));\n    let _e14 = params.sample_count;\n    let sample = (1f / ((f32(_e14) * pdf) + 0.0001f));\n    return max(((0.5f * log2((sample / texel))) + 1f), 0f);\n}\n\nfn geometry_smith_ibl(n_dot_v: f32, n_dot_l: f32, roughness_2: f32) -> f32 {\n    let k = ((roughness_2 * roughness_2) / 2f);\n    let g_v = (n_dot_v / ((n_dot_v * (1f - k)) + k));\n    let g_l = (n_dot_l / ((n_dot_l * (1f - k)) + k));\n    return (g_v * g_l);\n}\n\n@vertex \nfn vs_main(@builtin(vertex_index) index: u32) -> VertexOutput {\n    var out: VertexOutput;\n\n    let uv_1 = vec2<f32>(f32(((index << 1u) & 2u)), f32((index & 2u)));\n    out.clip_position = vec4<f32>(((uv_1.x * 2f) - 1f), (1f - (uv_1.y * 2f)), 0f, 1f);\n    out.uv = uv_1;\n    let _e26 = out;\n    return _e26;\n}\n\n@fragment \nfn fs_irradiance(in: VertexOutput) -> @location(0) vec4<f32> {\n    var irradiance: vec3<f32> = vec3(0f);\n    var i: u32 = 0u;\n\n    let _e6 = params.face;\n    let _e8 = face_direction(_e6, in.uv);\n    let _e9 = tangent_frame(_e8);\n    loop {\n        let _e11 = i;\n        let _e14 = params.sample_count;\n        if (_e11 < _e14) {\n        } else {\n            break;\n        }\n        {\n            let _e16 = i;\n            let _e19 = params.sample_count;\n            let _e20 = hammersley(_e16, _e19);\n            let phi_1 = (6.2831855f * _e20.x);\n            let cos_theta_1 = sqrt((1f - _e20.y));\n            let sin_theta_1 = sqrt(_e20.y);\n            let l = (_e9 * vec3<f32>((cos(phi_1) * sin_theta_1), (sin(phi_1) * sin_theta_1), cos_theta_1));\n            let _e38 = sample_lod((cos_theta_1 / PIX_naga_oil_mod_XOBRHEX));\n            let _e42 = textureSampleLevel(environment, environment_sampler, l, _e38);\n            let _e44 = irradiance;\n            irradiance = (_e44 + _e42.xyz);\n        }\n        continuing {\n            let _e47 = i;\n            i = (_e47 + 1u);\n        }\n    }\n    let _e49 = irradiance;\n    let _e52 = params.sample_count;\n    return vec4<f32>((_e49 / vec3(f32(_e52))), 1f);\n}\n\n@fragment \nfn fs_prefilter(in_1: VertexOutput) -> @location(0) vec4<f32> {\n    var color: vec3<f32> = vec3(0f);\n    var weight: f32 = 0f;\n    var i_1: u32 = 0u;\n\n    let _e6 = params.face;\n    let _e8 = face_direction(_e6, in_1.uv);\n    let _e11 = params.roughness;\n    if (_e11 <= 0f) {\n        let _e17 = textureSampleLevel(environment, environment_sampler, _e8, 0f);\n        return vec4<f32>(_e17.xyz, 1f);\n    }\n    let _e21 = tangent_frame(_e8);\n    loop {\n        let _e23 = i_1;\n        let _e26 = params.sample_count;\n        if (_e23 < _e26) {\n        } else {\n            break;\n        }\n        {\n            let _e28 = i_1;\n            let _e31 = params.sample_count;\n            let _e32 = hammersley(_e28, _e31);\n            let _e35 = params.roughness;\n            let _e36 = importance_sample_ggx(_e32, _e35);\n            let h = (_e21 * _e36);\n            let l_1 = (((2f * dot(_e8, h)) * h) - _e8);\n            let n_dot_l_1 = dot(_e8, l_1);\n            if (n_dot_l_1 > 0f) {\n                let _e51 = params.roughness;\n                let _e52 = distribution_ggxX_naga_oil_mod_XOBRHEX(max(dot(_e8, h), 0f), _e51);\n                let pdf_1 = (_e52 / 4f);\n                let _e55 = sample_lod(pdf_1);\n                let _e59 = textureSampleLevel(environment, environment_sampler, l_1, _e55);\n                let _e62 = color;\n                color = (_e62 + (_e59.xyz * n_dot_l_1));\n                let _e65 = weight;\n                weight = (_e65 + n_dot_l_1);\n            }\n        }\n        continuing {\n            let _e68 = i_1;\n            i_1 = (_e68 + 1u);\n        }\n    }\n    let _e70 = color;\n    let _e71 = weight;\n    return vec4<f32>((_e70 / vec3(max(_e71, 0.0001f))), 1f);\n}\n\n@fragment \nfn fs_brdf_lut(in_2: VertexOutput) -> @location(0) vec4<f32> {\n    var scale: f32 = 0f;\n    var bias: f32 = 0f;\n    var i_2: u32 = 0u;\n\n    let n_dot_v_1 = max(in_2.uv.x, 0.001f);\n    let roughness_3 = in_2.uv.y;\n    let v_1 = vec3<f32>(sqrt((1f - (n_dot_v_1 * n_dot_v_1))), 0f, n_dot_v_1);\n    loop {\n        let _e16 = i_2;\n        if (_e16 < BRDF_LUT_SAMPLES) {\n        } else {\n            break;\n        }\n        {\n            let _e19 = i_2;\n            let _e21 = hammersley(_e19, BRDF_LUT_SAMPLES);\n            let _e22 = importance_sample_ggx(_e21, roughness_3);\n            let l_2 = (((2f * dot(v_1, _e22)) * _e22) - v_1);\n            let n_dot_l_2 = max(l_2.z, 0f);\n            let n_dot_h_1 = max(_e22.z, 0f);\n            let v_dot_h = max(dot(v_1, _e22), 0f);\n            if (n_dot_l_2 > 0f) {\n                let _e39 = geometry_smith_ibl(n_dot_v_1, n_dot_l_2, roughness_3);\n                let g_vis = ((_e39 * v_dot_h) / max((n_dot_h_1 * n_dot_v_1), 0.0001f));\n                let fc = pow((1f - v_dot_h), 5f);\n                let _e53 = scale;\n                scale = (_e53 + ((1f - fc) * g_vis));\n                let _e57 = bias;\n                bias = (_e57 + (fc * g_vis));\n            }\n        }\n        continuing {\n            let _e60 = i_2;\n            i_2 = (_e60 + 1u);\n        }\n    }\n    let _e62 = scale;\n    let _e63 = bias;\n    return (vec4<f32>(_e62, _e63, 0f, 1f) / vec4<f32>(256f, 256f, 1f, 1f));\n}\n";
+    ///Shader module descriptor.
+    pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
+        label: Some("ibl"),
+        source: ::wgpu::ShaderSource::Wgsl(::std::borrow::Cow::Borrowed(SOURCE)),
+    };
+}
+pub mod tonemapping {
+    #[allow(unused)]
+    ///Information about the globals within the module, exposed as constants and functions.
+    pub mod globals {
+        #[allow(unused)]
+        use super::*;
+        ///Information about the `t_diffuse` global variable within this shader module.
+        pub mod t_diffuse {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "t_diffuse";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `s_diffuse` global variable within this shader module.
+        pub mod s_diffuse {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "s_diffuse";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Information about the `global_uniforms` global variable within this shader module.
+        pub mod global_uniforms {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "global_uniforms";
+            pub type Ty = super::super::super::global::types::GlobalUniforms;
+            pub const GROUP: u32 = 1u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("global_uniforms"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Information about the `view_proj_uniforms` global variable within this shader module.
+        pub mod view_proj_uniforms {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "view_proj_uniforms";
+            pub type Ty = super::super::super::global::types::ViewProjectionUniforms;
+            pub const GROUP: u32 = 2u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("view_proj_uniforms"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Information about the `adapted_luminance` global variable within this shader module.
+        pub mod adapted_luminance {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "adapted_luminance";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `adapted_luminance_sampler` global variable within this shader module.
+        pub mod adapted_luminance_sampler {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "adapted_luminance_sampler";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Information about the `params` global variable within this shader module.
+        pub mod params {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "params";
+            pub type Ty = TonemappingUniforms;
+            pub const GROUP: u32 = 4u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("params"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Contains the following bindings: t_diffuse, s_diffuse
+        pub mod group0 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group0"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Contains the following bindings: adapted_luminance, adapted_luminance_sampler
+        pub mod group3 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 3u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group3"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+    }
+    #[allow(unused)]
+    ///Information about the constants within the module, exposed as constants and functions.
+    pub mod constants {
+        #[allow(unused)]
+        use super::*;
+        #[allow(non_snake_case)]
+        ///Information about the `KEY_VALUE` constant variable within this shader module.
+        pub mod KEY_VALUE {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "KEY_VALUE";
+            pub const VALUE: f32 = 0.18f32;
+        }
+    }
+    #[allow(unused)]
+    ///Information about the entry points within the module, exposed as constants and functions.
+    pub mod entry_points {
+        #[allow(unused)]
+        use super::*;
+        pub mod vs_main {
+            pub const NAME: &'static str = "vs_main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct TonemappingUniforms {\n    tonemapper: u32,\n    auto_exposure: u32,\n    exposure: f32,\n    adaptation: f32,\n    min_luminance: f32,\n    max_luminance: f32,\n    luminance_level: f32,\n}\n\nconst KEY_VALUE: f32 = 0.18f;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar adapted_luminance: texture_2d<f32>;\n@group(3) @binding(1) \nvar adapted_luminance_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> params: TonemappingUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn tonemap_aces(color_1: vec3<f32>) -> vec3<f32> {\n    const input = mat3x3<f32>(vec3<f32>(0.59719f, 0.076f, 0.0284f), vec3<f32>(0.35458f, 0.90834f, 0.13383f), vec3<f32>(0.04823f, 0.01566f, 0.83777f));\n    const output = mat3x3<f32>(vec3<f32>(1.60475f, -0.10208f, -0.00327f), vec3<f32>(-0.53108f, 1.10813f, -0.07276f), vec3<f32>(-0.07367f, -0.00605f, 1.07602f));\n    let v_1 = (input * color_1);\n    let a = ((v_1 * (v_1 + vec3(0.0245786f))) - vec3(0.000090537f));\n    let b = ((v_1 * ((0.983729f * v_1) + vec3(0.432951f))) + vec3(0.238081f));\n    return clamp((output * (a / b)), vec3(0f), vec3(1f));\n}\n\nfn tonemap_reinhard(color_2: vec3<f32>) -> vec3<f32> {\n    let _e1 = luminance(color_2);\n    return (color_2 / vec3((1f + _e1)));\n}\n\nfn agx_contrast(x: vec3<f32>) -> vec3<f32> {\n    let x2_ = (x * x);\n    let x4_ = (x2_ * x2_);\n    return ((((((((15.5f * x4_) * x2_) - ((40.14f * x4_) * x)) + (31.96f * x4_)) - ((6.868f * x2_) * x)) + (0.4298f * x2_)) + (0.1191f * x)) - vec3(0.00232f));\n}\n\nfn tonemap_agx(color_3: vec3<f32>) -> vec3<f32> {\n    var v: vec3<f32>;\n\n    const inset = mat3x3<f32>(vec3<f32>(0.84247905f, 0.042328242f, 0.042375654f), vec3<f32>(0.0784336f, 0.87846863f, 0.0784336f), vec3<f32>(0.079223745f, 0.07916613f, 0.879143f));\n    const outset = mat3x3<f32>(vec3<f32>(1.196879f, -0.052896854f, -0.052971635f), vec3<f32>(-0.09802088f, 1.1519032f, -0.09804345f), vec3<f32>(-0.09902974f, -0.098961174f, 1.1510737f));\n    v = (inset * color_3);\n    let _e29 = v;\n    v = clamp(log2(max(_e29, vec3(0.0000000001f))), vec3(-12.47393f), vec3(4.026069f));\n    let _e39 = v;\n    v = ((_e39 - vec3(-12.47393f)) / vec3((4.026069f - -12.47393f)));\n    let _e45 = v;\n    let _e46 = agx_contrast(_e45);\n    v = (outset * _e46);\n    let _e48 = v;\n    return pow(max(_e48, vec3(0f)), vec3(2.2f));\n}\n\nfn tonemap(color_4: vec3<f32>) -> vec3<f32> {\n    let _e2 = params.tonemapper;\n    switch _e2 {\n        case 1u: {\n            let _e4 = tonemap_aces(color_4);\n            return _e4;\n        }\n        case 2u: {\n            let _e5 = tonemap_reinhard(color_4);\n            return _e5;\n        }\n        case 3u: {\n            let _e6 = tonemap_agx(color_4);\n            return _e6;\n        }\n        default: {\n            return clamp(color_4, vec3(0f), vec3(1f));\n        }\n    }\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e13 = model.x;\n    model.x = ((_e13 * 2f) - 1f);\n    let _e20 = model.y;\n    model.y = ((_e20 * 2f) - 1f);\n    let _e26 = model;\n    out.clip_position = _e26;\n    let _e27 = out;\n    return _e27;\n}\n";
+        }
+        pub mod fs_luminance {
+            pub const NAME: &'static str = "fs_luminance";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct TonemappingUniforms {\n    tonemapper: u32,\n    auto_exposure: u32,\n    exposure: f32,\n    adaptation: f32,\n    min_luminance: f32,\n    max_luminance: f32,\n    luminance_level: f32,\n}\n\nconst KEY_VALUE: f32 = 0.18f;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar adapted_luminance: texture_2d<f32>;\n@group(3) @binding(1) \nvar adapted_luminance_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> params: TonemappingUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn tonemap_aces(color_1: vec3<f32>) -> vec3<f32> {\n    const input = mat3x3<f32>(vec3<f32>(0.59719f, 0.076f, 0.0284f), vec3<f32>(0.35458f, 0.90834f, 0.13383f), vec3<f32>(0.04823f, 0.01566f, 0.83777f));\n    const output = mat3x3<f32>(vec3<f32>(1.60475f, -0.10208f, -0.00327f), vec3<f32>(-0.53108f, 1.10813f, -0.07276f), vec3<f32>(-0.07367f, -0.00605f, 1.07602f));\n    let v_1 = (input * color_1);\n    let a = ((v_1 * (v_1 + vec3(0.0245786f))) - vec3(0.000090537f));\n    let b = ((v_1 * ((0.983729f * v_1) + vec3(0.432951f))) + vec3(0.238081f));\n    return clamp((output * (a / b)), vec3(0f), vec3(1f));\n}\n\nfn tonemap_reinhard(color_2: vec3<f32>) -> vec3<f32> {\n    let _e1 = luminance(color_2);\n    return (color_2 / vec3((1f + _e1)));\n}\n\nfn agx_contrast(x: vec3<f32>) -> vec3<f32> {\n    let x2_ = (x * x);\n    let x4_ = (x2_ * x2_);\n    return ((((((((15.5f * x4_) * x2_) - ((40.14f * x4_) * x)) + (31.96f * x4_)) - ((6.868f * x2_) * x)) + (0.4298f * x2_)) + (0.1191f * x)) - vec3(0.00232f));\n}\n\nfn tonemap_agx(color_3: vec3<f32>) -> vec3<f32> {\n    var v: vec3<f32>;\n\n    const inset = mat3x3<f32>(vec3<f32>(0.84247905f, 0.042328242f, 0.042375654f), vec3<f32>(0.0784336f, 0.87846863f, 0.0784336f), vec3<f32>(0.079223745f, 0.07916613f, 0.879143f));\n    const outset = mat3x3<f32>(vec3<f32>(1.196879f, -0.052896854f, -0.052971635f), vec3<f32>(-0.09802088f, 1.1519032f, -0.09804345f), vec3<f32>(-0.09902974f, -0.098961174f, 1.1510737f));\n    v = (inset * color_3);\n    let _e29 = v;\n    v = clamp(log2(max(_e29, vec3(0.0000000001f))), vec3(-12.47393f), vec3(4.026069f));\n    let _e39 = v;\n    v = ((_e39 - vec3(-12.47393f)) / vec3((4.026069f - -12.47393f)));\n    let _e45 = v;\n    let _e46 = agx_contrast(_e45);\n    v = (outset * _e46);\n    let _e48 = v;\n    return pow(max(_e48, vec3(0f)), vec3(2.2f));\n}\n\nfn tonemap(color_4: vec3<f32>) -> vec3<f32> {\n    let _e2 = params.tonemapper;\n    switch _e2 {\n        case 1u: {\n            let _e4 = tonemap_aces(color_4);\n            return _e4;\n        }\n        case 2u: {\n            let _e5 = tonemap_reinhard(color_4);\n            return _e5;\n        }\n        case 3u: {\n            let _e6 = tonemap_agx(color_4);\n            return _e6;\n        }\n        default: {\n            return clamp(color_4, vec3(0f), vec3(1f));\n        }\n    }\n}\n\n@fragment \nfn fs_luminance(in: VertexOutput) -> @location(0) f32 {\n    let _e4 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let color_5 = _e4.xyz;\n    let _e6 = luminance(color_5);\n    return log2(max(_e6, 0.0001f));\n}\n";
+        }
+        pub mod fs_adapt {
+            pub const NAME: &'static str = "fs_adapt";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct TonemappingUniforms {\n    tonemapper: u32,\n    auto_exposure: u32,\n    exposure: f32,\n    adaptation: f32,\n    min_luminance: f32,\n    max_luminance: f32,\n    luminance_level: f32,\n}\n\nconst KEY_VALUE: f32 = 0.18f;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar adapted_luminance: texture_2d<f32>;\n@group(3) @binding(1) \nvar adapted_luminance_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> params: TonemappingUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn tonemap_aces(color_1: vec3<f32>) -> vec3<f32> {\n    const input = mat3x3<f32>(vec3<f32>(0.59719f, 0.076f, 0.0284f), vec3<f32>(0.35458f, 0.90834f, 0.13383f), vec3<f32>(0.04823f, 0.01566f, 0.83777f));\n    const output = mat3x3<f32>(vec3<f32>(1.60475f, -0.10208f, -0.00327f), vec3<f32>(-0.53108f, 1.10813f, -0.07276f), vec3<f32>(-0.07367f, -0.00605f, 1.07602f));\n    let v_1 = (input * color_1);\n    let a = ((v_1 * (v_1 + vec3(0.0245786f))) - vec3(0.000090537f));\n    let b = ((v_1 * ((0.983729f * v_1) + vec3(0.432951f))) + vec3(0.238081f));\n    return clamp((output * (a / b)), vec3(0f), vec3(1f));\n}\n\nfn tonemap_reinhard(color_2: vec3<f32>) -> vec3<f32> {\n    let _e1 = luminance(color_2);\n    return (color_2 / vec3((1f + _e1)));\n}\n\nfn agx_contrast(x: vec3<f32>) -> vec3<f32> {\n    let x2_ = (x * x);\n    let x4_ = (x2_ * x2_);\n    return ((((((((15.5f * x4_) * x2_) - ((40.14f * x4_) * x)) + (31.96f * x4_)) - ((6.868f * x2_) * x)) + (0.4298f * x2_)) + (0.1191f * x)) - vec3(0.00232f));\n}\n\nfn tonemap_agx(color_3: vec3<f32>) -> vec3<f32> {\n    var v: vec3<f32>;\n\n    const inset = mat3x3<f32>(vec3<f32>(0.84247905f, 0.042328242f, 0.042375654f), vec3<f32>(0.0784336f, 0.87846863f, 0.0784336f), vec3<f32>(0.079223745f, 0.07916613f, 0.879143f));\n    const outset = mat3x3<f32>(vec3<f32>(1.196879f, -0.052896854f, -0.052971635f), vec3<f32>(-0.09802088f, 1.1519032f, -0.09804345f), vec3<f32>(-0.09902974f, -0.098961174f, 1.1510737f));\n    v = (inset * color_3);\n    let _e29 = v;\n    v = clamp(log2(max(_e29, vec3(0.0000000001f))), vec3(-12.47393f), vec3(4.026069f));\n    let _e39 = v;\n    v = ((_e39 - vec3(-12.47393f)) / vec3((4.026069f - -12.47393f)));\n    let _e45 = v;\n    let _e46 = agx_contrast(_e45);\n    v = (outset * _e46);\n    let _e48 = v;\n    return pow(max(_e48, vec3(0f)), vec3(2.2f));\n}\n\nfn tonemap(color_4: vec3<f32>) -> vec3<f32> {\n    let _e2 = params.tonemapper;\n    switch _e2 {\n        case 1u: {\n            let _e4 = tonemap_aces(color_4);\n            return _e4;\n        }\n        case 2u: {\n            let _e5 = tonemap_reinhard(color_4);\n            return _e5;\n        }\n        case 3u: {\n            let _e6 = tonemap_agx(color_4);\n            return _e6;\n        }\n        default: {\n            return clamp(color_4, vec3(0f), vec3(1f));\n        }\n    }\n}\n\n@fragment \nfn fs_adapt(in: VertexOutput) -> @location(0) f32 {\n    let _e6 = params.luminance_level;\n    let _e7 = textureSampleLevel(t_diffuse, s_diffuse, vec2(0.5f), _e6);\n    let average = exp2(_e7.x);\n    let _e14 = textureSample(adapted_luminance, adapted_luminance_sampler, vec2(0.5f));\n    let previous = _e14.x;\n    let _e18 = params.adaptation;\n    return mix(previous, average, _e18);\n}\n";
+        }
+        pub mod fs_main {
+            pub const NAME: &'static str = "fs_main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct TonemappingUniforms {\n    tonemapper: u32,\n    auto_exposure: u32,\n    exposure: f32,\n    adaptation: f32,\n    min_luminance: f32,\n    max_luminance: f32,\n    luminance_level: f32,\n}\n\nconst KEY_VALUE: f32 = 0.18f;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar adapted_luminance: texture_2d<f32>;\n@group(3) @binding(1) \nvar adapted_luminance_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> params: TonemappingUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn tonemap_aces(color_1: vec3<f32>) -> vec3<f32> {\n    const input = mat3x3<f32>(vec3<f32>(0.59719f, 0.076f, 0.0284f), vec3<f32>(0.35458f, 0.90834f, 0.13383f), vec3<f32>(0.04823f, 0.01566f, 0.83777f));\n    const output = mat3x3<f32>(vec3<f32>(1.60475f, -0.10208f, -0.00327f), vec3<f32>(-0.53108f, 1.10813f, -0.07276f), vec3<f32>(-0.07367f, -0.00605f, 1.07602f));\n    let v_1 = (input * color_1);\n    let a = ((v_1 * (v_1 + vec3(0.0245786f))) - vec3(0.000090537f));\n    let b = ((v_1 * ((0.983729f * v_1) + vec3(0.432951f))) + vec3(0.238081f));\n    return clamp((output * (a / b)), vec3(0f), vec3(1f));\n}\n\nfn tonemap_reinhard(color_2: vec3<f32>) -> vec3<f32> {\n    let _e1 = luminance(color_2);\n    return (color_2 / vec3((1f + _e1)));\n}\n\nfn agx_contrast(x: vec3<f32>) -> vec3<f32> {\n    let x2_ = (x * x);\n    let x4_ = (x2_ * x2_);\n    return ((((((((15.5f * x4_) * x2_) - ((40.14f * x4_) * x)) + (31.96f * x4_)) - ((6.868f * x2_) * x)) + (0.4298f * x2_)) + (0.1191f * x)) - vec3(0.00232f));\n}\n\nfn tonemap_agx(color_3: vec3<f32>) -> vec3<f32> {\n    var v: vec3<f32>;\n\n    const inset = mat3x3<f32>(vec3<f32>(0.84247905f, 0.042328242f, 0.042375654f), vec3<f32>(0.0784336f, 0.87846863f, 0.0784336f), vec3<f32>(0.079223745f, 0.07916613f, 0.879143f));\n    const outset = mat3x3<f32>(vec3<f32>(1.196879f, -0.052896854f, -0.052971635f), vec3<f32>(-0.09802088f, 1.1519032f, -0.09804345f), vec3<f32>(-0.09902974f, -0.098961174f, 1.1510737f));\n    v = (inset * color_3);\n    let _e29 = v;\n    v = clamp(log2(max(_e29, vec3(0.0000000001f))), vec3(-12.47393f), vec3(4.026069f));\n    let _e39 = v;\n    v = ((_e39 - vec3(-12.47393f)) / vec3((4.026069f - -12.47393f)));\n    let _e45 = v;\n    let _e46 = agx_contrast(_e45);\n    v = (outset * _e46);\n    let _e48 = v;\n    return pow(max(_e48, vec3(0f)), vec3(2.2f));\n}\n\nfn tonemap(color_4: vec3<f32>) -> vec3<f32> {\n    let _e2 = params.tonemapper;\n    switch _e2 {\n        case 1u: {\n            let _e4 = tonemap_aces(color_4);\n            return _e4;\n        }\n        case 2u: {\n            let _e5 = tonemap_reinhard(color_4);\n            return _e5;\n        }\n        case 3u: {\n            let _e6 = tonemap_agx(color_4);\n            return _e6;\n        }\n        default: {\n            return clamp(color_4, vec3(0f), vec3(1f));\n        }\n    }\n}\n\n@fragment \nfn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {\n    var exposure: f32;\n\n    let _e4 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let color_5 = _e4.xyz;\n    let _e10 = textureSample(adapted_luminance, adapted_luminance_sampler, vec2(0.5f));\n    let adapted = _e10.x;\n    let _e14 = params.exposure;\n    exposure = exp2(_e14);\n    let _e19 = params.auto_exposure;\n    if (_e19 != 0u) {\n        let _e24 = params.min_luminance;\n        let _e28 = params.max_luminance;\n        let _e31 = exposure;\n        exposure = (_e31 * (KEY_VALUE / clamp(adapted, _e24, _e28)));\n    }\n    let _e33 = exposure;\n    let _e35 = tonemap((color_5 * _e33));\n    return vec4<f32>(_e35, 1f);\n}\n";
+        }
+    }
+    #[allow(unused)]
+    ///Equivalent Rust definitions of the types defined in this module.
+    pub mod types {
+        #[allow(unused, non_camel_case_types)]
+        #[repr(C)]
+        #[derive(Debug, PartialEq, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+        pub struct TonemappingUniforms {
+            pub tonemapper: u32,
+            pub auto_exposure: u32,
+            pub exposure: f32,
+            pub adaptation: f32,
+            pub min_luminance: f32,
+            pub max_luminance: f32,
+            pub luminance_level: f32,
+        }
+    }
+    #[allow(unused)]
+    use types::*;
+    ///The sourcecode for the shader, as a constant string.
+    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct TonemappingUniforms {\n    tonemapper: u32,\n    auto_exposure: u32,\n    exposure: f32,\n    adaptation: f32,\n    min_luminance: f32,\n    max_luminance: f32,\n    luminance_level: f32,\n}\n\nconst KEY_VALUE: f32 = 0.18f;\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar adapted_luminance: texture_2d<f32>;\n@group(3) @binding(1) \nvar adapted_luminance_sampler: sampler;\n@group(4) @binding(0) \nvar<uniform> params: TonemappingUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn tonemap_aces(color_1: vec3<f32>) -> vec3<f32> {\n    const input = mat3x3<f32>(vec3<f32>(0.59719f, 0.076f, 0.0284f), vec3<f32>(0.35458f, 0.90834f, 0.13383f), vec3<f32>(0.04823f, 0.01566f, 0.83777f));\n    const output = mat3x3<f32>(vec3<f32>(1.60475f, -0.10208f, -0.00327f), vec3<f32>(-0.53108f, 1.10813f, -0.07276f), vec3<f32>(-0.07367f, -0.00605f, 1.07602f));\n    let v_1 = (input * color_1);\n    let a = ((v_1 * (v_1 + vec3(0.0245786f))) - vec3(0.000090537f));\n    let b = ((v_1 * ((0.983729f * v_1) + vec3(0.432951f))) + vec3(0.238081f));\n    return clamp((output * (a / b)), vec3(0f), vec3(1f));\n}\n\nfn tonemap_reinhard(color_2: vec3<f32>) -> vec3<f32> {\n    let _e1 = luminance(color_2);\n    return (color_2 / vec3((1f + _e1)));\n}\n\nfn agx_contrast(x: vec3<f32>) -> vec3<f32> {\n    let x2_ = (x * x);\n    let x4_ = (x2_ * x2_);\n    return ((((((((15.5f * x4_) * x2_) - ((40.14f * x4_) * x)) + (31.96f * x4_)) - ((6.868f * x2_) * x)) + (0.4298f * x2_)) + (0.1191f * x)) - vec3(0.00232f));\n}\n\nfn tonemap_agx(color_3: vec3<f32>) -> vec3<f32> {\n    var v: vec3<f32>;\n\n    const inset = mat3x3<f32>(vec3<f32>(0.84247905f, 0.042328242f, 0.042375654f), vec3<f32>(0.0784336f, 0.87846863f, 0.0784336f), vec3<f32>(0.079223745f, 0.07916613f, 0.879143f));\n    const outset = mat3x3<f32>(vec3<f32>(1.196879f, -0.052896854f, -0.052971635f), vec3<f32>(-0.09802088f, 1.1519032f, -0.09804345f), vec3<f32>(-0.09902974f, -0.098961174f, 1.1510737f));\n    v = (inset * color_3);\n    let _e29 = v;\n    v = clamp(log2(max(_e29, vec3(0.0000000001f))), vec3(-12.47393f), vec3(4.026069f));\n    let _e39 = v;\n    v = ((_e39 - vec3(-12.47393f)) / vec3((4.026069f - -12.47393f)));\n    let _e45 = v;\n    let _e46 = agx_contrast(_e45);\n    v = (outset * _e46);\n    let _e48 = v;\n    return pow(max(_e48, vec3(0f)), vec3(2.2f));\n}\n\nfn tonemap(color_4: vec3<f32>) -> vec3<f32> {\n    let _e2 = params.tonemapper;\n    switch _e2 {\n        case 1u: {\n            let _e4 = tonemap_aces(color_4);\n            return _e4;\n        }\n        case 2u: {\n            let _e5 = tonemap_reinhard(color_4);\n            return _e5;\n        }\n        case 3u: {\n            let _e6 = tonemap_agx(color_4);\n            return _e6;\n        }\n        default: {\n            return clamp(color_4, vec3(0f), vec3(1f));\n        }\n    }\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e13 = model.x;\n    model.x = ((_e13 * 2f) - 1f);\n    let _e20 = model.y;\n    model.y = ((_e20 * 2f) - 1f);\n    let _e26 = model;\n    out.clip_position = _e26;\n    let _e27 = out;\n    return _e27;\n}\n\n@fragment \nfn fs_luminance(in: VertexOutput) -> @location(0) f32 {\n    let _e4 = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let color_5 = _e4.xyz;\n    let _e6 = luminance(color_5);\n    return log2(max(_e6, 0.0001f));\n}\n\n@fragment \nfn fs_adapt(in_1: VertexOutput) -> @location(0) f32 {\n    let _e6 = params.luminance_level;\n    let _e7 = textureSampleLevel(t_diffuse, s_diffuse, vec2(0.5f), _e6);\n    let average = exp2(_e7.x);\n    let _e14 = textureSample(adapted_luminance, adapted_luminance_sampler, vec2(0.5f));\n    let previous = _e14.x;\n    let _e18 = params.adaptation;\n    return mix(previous, average, _e18);\n}\n\n@fragment \nfn fs_main(in_2: VertexOutput) -> @location(0) vec4<f32> {\n    var exposure: f32;\n\n    let _e4 = textureSample(t_diffuse, s_diffuse, in_2.tex_coords);\n    let color_6 = _e4.xyz;\n    let _e10 = textureSample(adapted_luminance, adapted_luminance_sampler, vec2(0.5f));\n    let adapted = _e10.x;\n    let _e14 = params.exposure;\n    exposure = exp2(_e14);\n    let _e19 = params.auto_exposure;\n    if (_e19 != 0u) {\n        let _e24 = params.min_luminance;\n        let _e28 = params.max_luminance;\n        let _e31 = exposure;\n        exposure = (_e31 * (KEY_VALUE / clamp(adapted, _e24, _e28)));\n    }\n    let _e33 = exposure;\n    let _e35 = tonemap((color_6 * _e33));\n    return vec4<f32>(_e35, 1f);\n}\n";
+    ///Shader module descriptor.
+    pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
+        label: Some("tonemapping"),
+        source: ::wgpu::ShaderSource::Wgsl(::std::borrow::Cow::Borrowed(SOURCE)),
+    };
+}
+pub mod bloom {
+    #[allow(unused)]
+    ///Information about the globals within the module, exposed as constants and functions.
+    pub mod globals {
+        #[allow(unused)]
+        use super::*;
+        ///Information about the `t_diffuse` global variable within this shader module.
+        pub mod t_diffuse {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "t_diffuse";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `s_diffuse` global variable within this shader module.
+        pub mod s_diffuse {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "s_diffuse";
+            pub const GROUP: u32 = 0u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Information about the `global_uniforms` global variable within this shader module.
+        pub mod global_uniforms {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "global_uniforms";
+            pub type Ty = super::super::super::global::types::GlobalUniforms;
+            pub const GROUP: u32 = 1u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("global_uniforms"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Information about the `view_proj_uniforms` global variable within this shader module.
+        pub mod view_proj_uniforms {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "view_proj_uniforms";
+            pub type Ty = super::super::super::global::types::ViewProjectionUniforms;
+            pub const GROUP: u32 = 2u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("view_proj_uniforms"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Information about the `t_bloom` global variable within this shader module.
+        pub mod t_bloom {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "t_bloom";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 0u32;
+        }
+        ///Information about the `s_bloom` global variable within this shader module.
+        pub mod s_bloom {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "s_bloom";
+            pub const GROUP: u32 = 3u32;
+            pub const BINDING: u32 = 1u32;
+        }
+        ///Information about the `bloom` global variable within this shader module.
+        pub mod bloom {
+            #[allow(unused)]
+            use super::*;
+            pub const NAME: &'static str = "bloom";
+            pub type Ty = BloomUniforms;
+            pub const GROUP: u32 = 4u32;
+            pub const BINDING: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("bloom"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Buffer {
+                                ty: ::wgpu::BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Contains the following bindings: t_diffuse, s_diffuse
+        pub mod group0 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 0u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group0"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+        ///Contains the following bindings: t_bloom, s_bloom
+        pub mod group3 {
+            #[allow(unused)]
+            use super::*;
+            pub const GROUP: u32 = 3u32;
+            pub fn layout() -> ::wgpu::BindGroupLayoutDescriptor<'static> {
+                ::wgpu::BindGroupLayoutDescriptor {
+                    label: Some("group3"),
+                    entries: &[
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 0u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: wgpu::BindingType::Texture {
+                                multisampled: false,
+                                view_dimension: ::wgpu::TextureViewDimension::D2,
+                                sample_type: ::wgpu::TextureSampleType::Float {
+                                    filterable: true,
+                                },
+                            },
+                            count: None,
+                        },
+                        ::wgpu::BindGroupLayoutEntry {
+                            binding: 1u32,
+                            visibility: ::wgpu::ShaderStages::VERTEX_FRAGMENT,
+                            ty: ::wgpu::BindingType::Sampler(
+                                ::wgpu::SamplerBindingType::Filtering,
+                            ),
+                            count: None,
+                        },
+                    ],
+                }
+            }
+        }
+    }
+    #[allow(unused)]
+    ///Information about the constants within the module, exposed as constants and functions.
+    pub mod constants {
+        #[allow(unused)]
+        use super::*;
+    }
+    #[allow(unused)]
+    ///Information about the entry points within the module, exposed as constants and functions.
+    pub mod entry_points {
+        #[allow(unused)]
+        use super::*;
+        pub mod vs_main {
+            pub const NAME: &'static str = "vs_main";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct BloomUniforms {\n    threshold: f32,\n    knee: f32,\n    intensity: f32,\n    filter_radius: f32,\n    level_count: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar t_bloom: texture_2d<f32>;\n@group(3) @binding(1) \nvar s_bloom: sampler;\n@group(4) @binding(0) \nvar<uniform> bloom: BloomUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn downsample(uv: vec2<f32>, karis: bool) -> vec3<f32> {\n    var result: vec3<f32> = vec3(0f);\n    var total_weight: f32 = 0f;\n    var n: i32 = 0i;\n    var weight: f32;\n\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let texel = (vec2(1f) / vec2<f32>(_e5));\n    let _e18 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 2f))));\n    let a = _e18.xyz;\n    let _e27 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, 2f))));\n    let b = _e27.xyz;\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 2f))));\n    let c = _e36.xyz;\n    let _e45 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 0f))));\n    let d = _e45.xyz;\n    let _e49 = textureSample(t_diffuse, s_diffuse, uv);\n    let e = _e49.xyz;\n    let _e58 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 0f))));\n    let f = _e58.xyz;\n    let _e67 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, -2f))));\n    let g = _e67.xyz;\n    let _e76 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, -2f))));\n    let h = _e76.xyz;\n    let _e85 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, -2f))));\n    let i = _e85.xyz;\n    let _e94 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, 1f))));\n    let j = _e94.xyz;\n    let _e103 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, 1f))));\n    let k = _e103.xyz;\n    let _e112 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, -1f))));\n    let l = _e112.xyz;\n    let _e121 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, -1f))));\n    let m = _e121.xyz;\n    let boxes = array<vec3<f32>, 5>(((((j + k) + l) + m) * 0.25f), ((((a + b) + d) + e) * 0.25f), ((((b + c) + e) + f) * 0.25f), ((((d + e) + g) + h) * 0.25f), ((((e + f) + h) + i) * 0.25f));\n    loop {\n        let _e150 = n;\n        if (_e150 < 5i) {\n        } else {\n            break;\n        }\n        {\n            let _e153 = n;\n            weight = select(0.125f, 0.5f, (_e153 == 0i));\n            if karis {\n                let _e161 = n;\n                let _e163 = luminance(boxes[_e161]);\n                let _e166 = weight;\n                weight = (_e166 / (1f + _e163));\n            }\n            let _e169 = n;\n            let _e171 = weight;\n            let _e173 = result;\n            result = (_e173 + (boxes[_e169] * _e171));\n            let _e176 = weight;\n            let _e177 = total_weight;\n            total_weight = (_e177 + _e176);\n        }\n        continuing {\n            let _e180 = n;\n            n = (_e180 + 1i);\n        }\n    }\n    let _e182 = result;\n    let _e183 = total_weight;\n    return (_e182 / vec3(_e183));\n}\n\nfn threshold(color_1: vec3<f32>) -> vec3<f32> {\n    var soft: f32;\n\n    let brightness = max(color_1.x, max(color_1.y, color_1.z));\n    let _e8 = bloom.threshold;\n    let _e11 = bloom.knee;\n    let knee = (_e8 * _e11);\n    let _e15 = bloom.threshold;\n    soft = clamp(((brightness - _e15) + knee), 0f, (2f * knee));\n    let _e23 = soft;\n    let _e24 = soft;\n    soft = ((_e23 * _e24) / ((4f * knee) + 0.00001f));\n    let _e31 = soft;\n    let _e34 = bloom.threshold;\n    let contribution = (max(_e31, (brightness - _e34)) / max(brightness, 0.00001f));\n    return (color_1 * contribution);\n}\n\nfn upsample(uv_1: vec2<f32>) -> vec3<f32> {\n    var result_1: vec3<f32>;\n\n    let _e2 = bloom.filter_radius;\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let r = (vec2(_e2) / vec2<f32>(_e5));\n    let _e12 = textureSample(t_diffuse, s_diffuse, uv_1);\n    result_1 = (_e12.xyz * 4f);\n    let _e24 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), 0f)));\n    let _e28 = result_1;\n    result_1 = (_e28 + (_e24.xyz * 2f));\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, 0f)));\n    let _e40 = result_1;\n    result_1 = (_e40 + (_e36.xyz * 2f));\n    let _e49 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, -(r.y))));\n    let _e53 = result_1;\n    result_1 = (_e53 + (_e49.xyz * 2f));\n    let _e61 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, r.y)));\n    let _e65 = result_1;\n    result_1 = (_e65 + (_e61.xyz * 2f));\n    let _e75 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), -(r.y))));\n    let _e77 = result_1;\n    result_1 = (_e77 + _e75.xyz);\n    let _e86 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, -(r.y))));\n    let _e88 = result_1;\n    result_1 = (_e88 + _e86.xyz);\n    let _e97 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), r.y)));\n    let _e99 = result_1;\n    result_1 = (_e99 + _e97.xyz);\n    let _e107 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, r.y)));\n    let _e109 = result_1;\n    result_1 = (_e109 + _e107.xyz);\n    let _e111 = result_1;\n    return (_e111 / vec3(16f));\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e13 = model.x;\n    model.x = ((_e13 * 2f) - 1f);\n    let _e20 = model.y;\n    model.y = ((_e20 * 2f) - 1f);\n    let _e26 = model;\n    out.clip_position = _e26;\n    let _e27 = out;\n    return _e27;\n}\n";
+        }
+        pub mod fs_prefilter {
+            pub const NAME: &'static str = "fs_prefilter";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct BloomUniforms {\n    threshold: f32,\n    knee: f32,\n    intensity: f32,\n    filter_radius: f32,\n    level_count: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar t_bloom: texture_2d<f32>;\n@group(3) @binding(1) \nvar s_bloom: sampler;\n@group(4) @binding(0) \nvar<uniform> bloom: BloomUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn downsample(uv: vec2<f32>, karis: bool) -> vec3<f32> {\n    var result: vec3<f32> = vec3(0f);\n    var total_weight: f32 = 0f;\n    var n: i32 = 0i;\n    var weight: f32;\n\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let texel = (vec2(1f) / vec2<f32>(_e5));\n    let _e18 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 2f))));\n    let a = _e18.xyz;\n    let _e27 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, 2f))));\n    let b = _e27.xyz;\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 2f))));\n    let c = _e36.xyz;\n    let _e45 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 0f))));\n    let d = _e45.xyz;\n    let _e49 = textureSample(t_diffuse, s_diffuse, uv);\n    let e = _e49.xyz;\n    let _e58 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 0f))));\n    let f = _e58.xyz;\n    let _e67 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, -2f))));\n    let g = _e67.xyz;\n    let _e76 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, -2f))));\n    let h = _e76.xyz;\n    let _e85 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, -2f))));\n    let i = _e85.xyz;\n    let _e94 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, 1f))));\n    let j = _e94.xyz;\n    let _e103 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, 1f))));\n    let k = _e103.xyz;\n    let _e112 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, -1f))));\n    let l = _e112.xyz;\n    let _e121 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, -1f))));\n    let m = _e121.xyz;\n    let boxes = array<vec3<f32>, 5>(((((j + k) + l) + m) * 0.25f), ((((a + b) + d) + e) * 0.25f), ((((b + c) + e) + f) * 0.25f), ((((d + e) + g) + h) * 0.25f), ((((e + f) + h) + i) * 0.25f));\n    loop {\n        let _e150 = n;\n        if (_e150 < 5i) {\n        } else {\n            break;\n        }\n        {\n            let _e153 = n;\n            weight = select(0.125f, 0.5f, (_e153 == 0i));\n            if karis {\n                let _e161 = n;\n                let _e163 = luminance(boxes[_e161]);\n                let _e166 = weight;\n                weight = (_e166 / (1f + _e163));\n            }\n            let _e169 = n;\n            let _e171 = weight;\n            let _e173 = result;\n            result = (_e173 + (boxes[_e169] * _e171));\n            let _e176 = weight;\n            let _e177 = total_weight;\n            total_weight = (_e177 + _e176);\n        }\n        continuing {\n            let _e180 = n;\n            n = (_e180 + 1i);\n        }\n    }\n    let _e182 = result;\n    let _e183 = total_weight;\n    return (_e182 / vec3(_e183));\n}\n\nfn threshold(color_1: vec3<f32>) -> vec3<f32> {\n    var soft: f32;\n\n    let brightness = max(color_1.x, max(color_1.y, color_1.z));\n    let _e8 = bloom.threshold;\n    let _e11 = bloom.knee;\n    let knee = (_e8 * _e11);\n    let _e15 = bloom.threshold;\n    soft = clamp(((brightness - _e15) + knee), 0f, (2f * knee));\n    let _e23 = soft;\n    let _e24 = soft;\n    soft = ((_e23 * _e24) / ((4f * knee) + 0.00001f));\n    let _e31 = soft;\n    let _e34 = bloom.threshold;\n    let contribution = (max(_e31, (brightness - _e34)) / max(brightness, 0.00001f));\n    return (color_1 * contribution);\n}\n\nfn upsample(uv_1: vec2<f32>) -> vec3<f32> {\n    var result_1: vec3<f32>;\n\n    let _e2 = bloom.filter_radius;\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let r = (vec2(_e2) / vec2<f32>(_e5));\n    let _e12 = textureSample(t_diffuse, s_diffuse, uv_1);\n    result_1 = (_e12.xyz * 4f);\n    let _e24 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), 0f)));\n    let _e28 = result_1;\n    result_1 = (_e28 + (_e24.xyz * 2f));\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, 0f)));\n    let _e40 = result_1;\n    result_1 = (_e40 + (_e36.xyz * 2f));\n    let _e49 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, -(r.y))));\n    let _e53 = result_1;\n    result_1 = (_e53 + (_e49.xyz * 2f));\n    let _e61 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, r.y)));\n    let _e65 = result_1;\n    result_1 = (_e65 + (_e61.xyz * 2f));\n    let _e75 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), -(r.y))));\n    let _e77 = result_1;\n    result_1 = (_e77 + _e75.xyz);\n    let _e86 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, -(r.y))));\n    let _e88 = result_1;\n    result_1 = (_e88 + _e86.xyz);\n    let _e97 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), r.y)));\n    let _e99 = result_1;\n    result_1 = (_e99 + _e97.xyz);\n    let _e107 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, r.y)));\n    let _e109 = result_1;\n    result_1 = (_e109 + _e107.xyz);\n    let _e111 = result_1;\n    return (_e111 / vec3(16f));\n}\n\n@fragment \nfn fs_prefilter(in: VertexOutput) -> @location(0) vec4<f32> {\n    let _e3 = downsample(in.tex_coords, true);\n    let _e4 = threshold(_e3);\n    return vec4<f32>(_e4, 1f);\n}\n";
+        }
+        pub mod fs_downsample {
+            pub const NAME: &'static str = "fs_downsample";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct BloomUniforms {\n    threshold: f32,\n    knee: f32,\n    intensity: f32,\n    filter_radius: f32,\n    level_count: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar t_bloom: texture_2d<f32>;\n@group(3) @binding(1) \nvar s_bloom: sampler;\n@group(4) @binding(0) \nvar<uniform> bloom: BloomUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn downsample(uv: vec2<f32>, karis: bool) -> vec3<f32> {\n    var result: vec3<f32> = vec3(0f);\n    var total_weight: f32 = 0f;\n    var n: i32 = 0i;\n    var weight: f32;\n\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let texel = (vec2(1f) / vec2<f32>(_e5));\n    let _e18 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 2f))));\n    let a = _e18.xyz;\n    let _e27 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, 2f))));\n    let b = _e27.xyz;\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 2f))));\n    let c = _e36.xyz;\n    let _e45 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 0f))));\n    let d = _e45.xyz;\n    let _e49 = textureSample(t_diffuse, s_diffuse, uv);\n    let e = _e49.xyz;\n    let _e58 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 0f))));\n    let f = _e58.xyz;\n    let _e67 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, -2f))));\n    let g = _e67.xyz;\n    let _e76 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, -2f))));\n    let h = _e76.xyz;\n    let _e85 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, -2f))));\n    let i = _e85.xyz;\n    let _e94 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, 1f))));\n    let j = _e94.xyz;\n    let _e103 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, 1f))));\n    let k = _e103.xyz;\n    let _e112 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, -1f))));\n    let l = _e112.xyz;\n    let _e121 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, -1f))));\n    let m = _e121.xyz;\n    let boxes = array<vec3<f32>, 5>(((((j + k) + l) + m) * 0.25f), ((((a + b) + d) + e) * 0.25f), ((((b + c) + e) + f) * 0.25f), ((((d + e) + g) + h) * 0.25f), ((((e + f) + h) + i) * 0.25f));\n    loop {\n        let _e150 = n;\n        if (_e150 < 5i) {\n        } else {\n            break;\n        }\n        {\n            let _e153 = n;\n            weight = select(0.125f, 0.5f, (_e153 == 0i));\n            if karis {\n                let _e161 = n;\n                let _e163 = luminance(boxes[_e161]);\n                let _e166 = weight;\n                weight = (_e166 / (1f + _e163));\n            }\n            let _e169 = n;\n            let _e171 = weight;\n            let _e173 = result;\n            result = (_e173 + (boxes[_e169] * _e171));\n            let _e176 = weight;\n            let _e177 = total_weight;\n            total_weight = (_e177 + _e176);\n        }\n        continuing {\n            let _e180 = n;\n            n = (_e180 + 1i);\n        }\n    }\n    let _e182 = result;\n    let _e183 = total_weight;\n    return (_e182 / vec3(_e183));\n}\n\nfn threshold(color_1: vec3<f32>) -> vec3<f32> {\n    var soft: f32;\n\n    let brightness = max(color_1.x, max(color_1.y, color_1.z));\n    let _e8 = bloom.threshold;\n    let _e11 = bloom.knee;\n    let knee = (_e8 * _e11);\n    let _e15 = bloom.threshold;\n    soft = clamp(((brightness - _e15) + knee), 0f, (2f * knee));\n    let _e23 = soft;\n    let _e24 = soft;\n    soft = ((_e23 * _e24) / ((4f * knee) + 0.00001f));\n    let _e31 = soft;\n    let _e34 = bloom.threshold;\n    let contribution = (max(_e31, (brightness - _e34)) / max(brightness, 0.00001f));\n    return (color_1 * contribution);\n}\n\nfn upsample(uv_1: vec2<f32>) -> vec3<f32> {\n    var result_1: vec3<f32>;\n\n    let _e2 = bloom.filter_radius;\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let r = (vec2(_e2) / vec2<f32>(_e5));\n    let _e12 = textureSample(t_diffuse, s_diffuse, uv_1);\n    result_1 = (_e12.xyz * 4f);\n    let _e24 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), 0f)));\n    let _e28 = result_1;\n    result_1 = (_e28 + (_e24.xyz * 2f));\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, 0f)));\n    let _e40 = result_1;\n    result_1 = (_e40 + (_e36.xyz * 2f));\n    let _e49 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, -(r.y))));\n    let _e53 = result_1;\n    result_1 = (_e53 + (_e49.xyz * 2f));\n    let _e61 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, r.y)));\n    let _e65 = result_1;\n    result_1 = (_e65 + (_e61.xyz * 2f));\n    let _e75 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), -(r.y))));\n    let _e77 = result_1;\n    result_1 = (_e77 + _e75.xyz);\n    let _e86 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, -(r.y))));\n    let _e88 = result_1;\n    result_1 = (_e88 + _e86.xyz);\n    let _e97 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), r.y)));\n    let _e99 = result_1;\n    result_1 = (_e99 + _e97.xyz);\n    let _e107 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, r.y)));\n    let _e109 = result_1;\n    result_1 = (_e109 + _e107.xyz);\n    let _e111 = result_1;\n    return (_e111 / vec3(16f));\n}\n\n@fragment \nfn fs_downsample(in: VertexOutput) -> @location(0) vec4<f32> {\n    let _e3 = downsample(in.tex_coords, false);\n    return vec4<f32>(_e3, 1f);\n}\n";
+        }
+        pub mod fs_upsample {
+            pub const NAME: &'static str = "fs_upsample";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct BloomUniforms {\n    threshold: f32,\n    knee: f32,\n    intensity: f32,\n    filter_radius: f32,\n    level_count: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar t_bloom: texture_2d<f32>;\n@group(3) @binding(1) \nvar s_bloom: sampler;\n@group(4) @binding(0) \nvar<uniform> bloom: BloomUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn downsample(uv: vec2<f32>, karis: bool) -> vec3<f32> {\n    var result: vec3<f32> = vec3(0f);\n    var total_weight: f32 = 0f;\n    var n: i32 = 0i;\n    var weight: f32;\n\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let texel = (vec2(1f) / vec2<f32>(_e5));\n    let _e18 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 2f))));\n    let a = _e18.xyz;\n    let _e27 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, 2f))));\n    let b = _e27.xyz;\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 2f))));\n    let c = _e36.xyz;\n    let _e45 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 0f))));\n    let d = _e45.xyz;\n    let _e49 = textureSample(t_diffuse, s_diffuse, uv);\n    let e = _e49.xyz;\n    let _e58 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 0f))));\n    let f = _e58.xyz;\n    let _e67 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, -2f))));\n    let g = _e67.xyz;\n    let _e76 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, -2f))));\n    let h = _e76.xyz;\n    let _e85 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, -2f))));\n    let i = _e85.xyz;\n    let _e94 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, 1f))));\n    let j = _e94.xyz;\n    let _e103 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, 1f))));\n    let k = _e103.xyz;\n    let _e112 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, -1f))));\n    let l = _e112.xyz;\n    let _e121 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, -1f))));\n    let m = _e121.xyz;\n    let boxes = array<vec3<f32>, 5>(((((j + k) + l) + m) * 0.25f), ((((a + b) + d) + e) * 0.25f), ((((b + c) + e) + f) * 0.25f), ((((d + e) + g) + h) * 0.25f), ((((e + f) + h) + i) * 0.25f));\n    loop {\n        let _e150 = n;\n        if (_e150 < 5i) {\n        } else {\n            break;\n        }\n        {\n            let _e153 = n;\n            weight = select(0.125f, 0.5f, (_e153 == 0i));\n            if karis {\n                let _e161 = n;\n                let _e163 = luminance(boxes[_e161]);\n                let _e166 = weight;\n                weight = (_e166 / (1f + _e163));\n            }\n            let _e169 = n;\n            let _e171 = weight;\n            let _e173 = result;\n            result = (_e173 + (boxes[_e169] * _e171));\n            let _e176 = weight;\n            let _e177 = total_weight;\n            total_weight = (_e177 + _e176);\n        }\n        continuing {\n            let _e180 = n;\n            n = (_e180 + 1i);\n        }\n    }\n    let _e182 = result;\n    let _e183 = total_weight;\n    return (_e182 / vec3(_e183));\n}\n\nfn threshold(color_1: vec3<f32>) -> vec3<f32> {\n    var soft: f32;\n\n    let brightness = max(color_1.x, max(color_1.y, color_1.z));\n    let _e8 = bloom.threshold;\n    let _e11 = bloom.knee;\n    let knee = (_e8 * _e11);\n    let _e15 = bloom.threshold;\n    soft = clamp(((brightness - _e15) + knee), 0f, (2f * knee));\n    let _e23 = soft;\n    let _e24 = soft;\n    soft = ((_e23 * _e24) / ((4f * knee) + 0.00001f));\n    let _e31 = soft;\n    let _e34 = bloom.threshold;\n    let contribution = (max(_e31, (brightness - _e34)) / max(brightness, 0.00001f));\n    return (color_1 * contribution);\n}\n\nfn upsample(uv_1: vec2<f32>) -> vec3<f32> {\n    var result_1: vec3<f32>;\n\n    let _e2 = bloom.filter_radius;\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let r = (vec2(_e2) / vec2<f32>(_e5));\n    let _e12 = textureSample(t_diffuse, s_diffuse, uv_1);\n    result_1 = (_e12.xyz * 4f);\n    let _e24 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), 0f)));\n    let _e28 = result_1;\n    result_1 = (_e28 + (_e24.xyz * 2f));\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, 0f)));\n    let _e40 = result_1;\n    result_1 = (_e40 + (_e36.xyz * 2f));\n    let _e49 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, -(r.y))));\n    let _e53 = result_1;\n    result_1 = (_e53 + (_e49.xyz * 2f));\n    let _e61 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, r.y)));\n    let _e65 = result_1;\n    result_1 = (_e65 + (_e61.xyz * 2f));\n    let _e75 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), -(r.y))));\n    let _e77 = result_1;\n    result_1 = (_e77 + _e75.xyz);\n    let _e86 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, -(r.y))));\n    let _e88 = result_1;\n    result_1 = (_e88 + _e86.xyz);\n    let _e97 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), r.y)));\n    let _e99 = result_1;\n    result_1 = (_e99 + _e97.xyz);\n    let _e107 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, r.y)));\n    let _e109 = result_1;\n    result_1 = (_e109 + _e107.xyz);\n    let _e111 = result_1;\n    return (_e111 / vec3(16f));\n}\n\n@fragment \nfn fs_upsample(in: VertexOutput) -> @location(0) vec4<f32> {\n    let _e4 = textureSample(t_bloom, s_bloom, in.tex_coords);\n    let _e7 = upsample(in.tex_coords);\n    let color_2 = (_e4.xyz + _e7);\n    return vec4<f32>(color_2, 1f);\n}\n";
+        }
+        pub mod fs_composite {
+            pub const NAME: &'static str = "fs_composite";
+            ///The sourcecode for the shader, as a constant string, excluding any other entry points. This is useful when the `minify` feature is enabled for this crate, as it allows more aggressive minification to be performed with the knowledge of the specific entry point that will be used.
+            pub const EXCLUSIVE_SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct BloomUniforms {\n    threshold: f32,\n    knee: f32,\n    intensity: f32,\n    filter_radius: f32,\n    level_count: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar t_bloom: texture_2d<f32>;\n@group(3) @binding(1) \nvar s_bloom: sampler;\n@group(4) @binding(0) \nvar<uniform> bloom: BloomUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn downsample(uv: vec2<f32>, karis: bool) -> vec3<f32> {\n    var result: vec3<f32> = vec3(0f);\n    var total_weight: f32 = 0f;\n    var n: i32 = 0i;\n    var weight: f32;\n\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let texel = (vec2(1f) / vec2<f32>(_e5));\n    let _e18 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 2f))));\n    let a = _e18.xyz;\n    let _e27 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, 2f))));\n    let b = _e27.xyz;\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 2f))));\n    let c = _e36.xyz;\n    let _e45 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 0f))));\n    let d = _e45.xyz;\n    let _e49 = textureSample(t_diffuse, s_diffuse, uv);\n    let e = _e49.xyz;\n    let _e58 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 0f))));\n    let f = _e58.xyz;\n    let _e67 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, -2f))));\n    let g = _e67.xyz;\n    let _e76 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, -2f))));\n    let h = _e76.xyz;\n    let _e85 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, -2f))));\n    let i = _e85.xyz;\n    let _e94 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, 1f))));\n    let j = _e94.xyz;\n    let _e103 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, 1f))));\n    let k = _e103.xyz;\n    let _e112 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, -1f))));\n    let l = _e112.xyz;\n    let _e121 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, -1f))));\n    let m = _e121.xyz;\n    let boxes = array<vec3<f32>, 5>(((((j + k) + l) + m) * 0.25f), ((((a + b) + d) + e) * 0.25f), ((((b + c) + e) + f) * 0.25f), ((((d + e) + g) + h) * 0.25f), ((((e + f) + h) + i) * 0.25f));\n    loop {\n        let _e150 = n;\n        if (_e150 < 5i) {\n        } else {\n            break;\n        }\n        {\n            let _e153 = n;\n            weight = select(0.125f, 0.5f, (_e153 == 0i));\n            if karis {\n                let _e161 = n;\n                let _e163 = luminance(boxes[_e161]);\n                let _e166 = weight;\n                weight = (_e166 / (1f + _e163));\n            }\n            let _e169 = n;\n            let _e171 = weight;\n            let _e173 = result;\n            result = (_e173 + (boxes[_e169] * _e171));\n            let _e176 = weight;\n            let _e177 = total_weight;\n            total_weight = (_e177 + _e176);\n        }\n        continuing {\n            let _e180 = n;\n            n = (_e180 + 1i);\n        }\n    }\n    let _e182 = result;\n    let _e183 = total_weight;\n    return (_e182 / vec3(_e183));\n}\n\nfn threshold(color_1: vec3<f32>) -> vec3<f32> {\n    var soft: f32;\n\n    let brightness = max(color_1.x, max(color_1.y, color_1.z));\n    let _e8 = bloom.threshold;\n    let _e11 = bloom.knee;\n    let knee = (_e8 * _e11);\n    let _e15 = bloom.threshold;\n    soft = clamp(((brightness - _e15) + knee), 0f, (2f * knee));\n    let _e23 = soft;\n    let _e24 = soft;\n    soft = ((_e23 * _e24) / ((4f * knee) + 0.00001f));\n    let _e31 = soft;\n    let _e34 = bloom.threshold;\n    let contribution = (max(_e31, (brightness - _e34)) / max(brightness, 0.00001f));\n    return (color_1 * contribution);\n}\n\nfn upsample(uv_1: vec2<f32>) -> vec3<f32> {\n    var result_1: vec3<f32>;\n\n    let _e2 = bloom.filter_radius;\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let r = (vec2(_e2) / vec2<f32>(_e5));\n    let _e12 = textureSample(t_diffuse, s_diffuse, uv_1);\n    result_1 = (_e12.xyz * 4f);\n    let _e24 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), 0f)));\n    let _e28 = result_1;\n    result_1 = (_e28 + (_e24.xyz * 2f));\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, 0f)));\n    let _e40 = result_1;\n    result_1 = (_e40 + (_e36.xyz * 2f));\n    let _e49 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, -(r.y))));\n    let _e53 = result_1;\n    result_1 = (_e53 + (_e49.xyz * 2f));\n    let _e61 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, r.y)));\n    let _e65 = result_1;\n    result_1 = (_e65 + (_e61.xyz * 2f));\n    let _e75 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), -(r.y))));\n    let _e77 = result_1;\n    result_1 = (_e77 + _e75.xyz);\n    let _e86 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, -(r.y))));\n    let _e88 = result_1;\n    result_1 = (_e88 + _e86.xyz);\n    let _e97 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), r.y)));\n    let _e99 = result_1;\n    result_1 = (_e99 + _e97.xyz);\n    let _e107 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, r.y)));\n    let _e109 = result_1;\n    result_1 = (_e109 + _e107.xyz);\n    let _e111 = result_1;\n    return (_e111 / vec3(16f));\n}\n\n@fragment \nfn fs_composite(in: VertexOutput) -> @location(0) vec4<f32> {\n    let scene = textureSample(t_diffuse, s_diffuse, in.tex_coords);\n    let _e8 = textureSample(t_bloom, s_bloom, in.tex_coords);\n    let blurred = _e8.xyz;\n    let _e13 = bloom.intensity;\n    let _e17 = bloom.level_count;\n    let color_2 = (scene.xyz + ((blurred * _e13) / vec3(f32(_e17))));\n    return vec4<f32>(color_2, scene.w);\n}\n";
+        }
+    }
+    #[allow(unused)]
+    ///Equivalent Rust definitions of the types defined in this module.
+    pub mod types {
+        #[allow(unused, non_camel_case_types)]
+        #[repr(C)]
+        #[derive(Debug, PartialEq, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+        pub struct BloomUniforms {
+            pub threshold: f32,
+            pub knee: f32,
+            pub intensity: f32,
+            pub filter_radius: f32,
+            pub level_count: u32,
+        }
+    }
+    #[allow(unused)]
+    use types::*;
+    ///The sourcecode for the shader, as a constant string.
+    pub const SOURCE: &'static str = "struct GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    time: f32,\n    screen_size: vec2<f32>,\n}\n\nstruct ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX {\n    view: mat4x4<f32>,\n    projection: mat4x4<f32>,\n    camera_pos: vec3<f32>,\n    inverse_view: mat4x4<f32>,\n}\n\nstruct VertexInput {\n    @location(0) position: vec4<f32>,\n    @location(1) tex_coords: vec2<f32>,\n}\n\nstruct InstanceInput {\n    @location(2) uv_scale: vec2<f32>,\n    @location(3) uv_offset: vec2<f32>,\n    @location(4) tint: vec4<f32>,\n    @location(5) model_1_: vec4<f32>,\n    @location(6) model_2_: vec4<f32>,\n    @location(7) model_3_: vec4<f32>,\n    @location(8) model_4_: vec4<f32>,\n}\n\nstruct VertexOutput {\n    @builtin(position) clip_position: vec4<f32>,\n    @location(0) tex_coords: vec2<f32>,\n}\n\nstruct BloomUniforms {\n    threshold: f32,\n    knee: f32,\n    intensity: f32,\n    filter_radius: f32,\n    level_count: u32,\n}\n\n@group(0) @binding(0) \nvar t_diffuse: texture_2d<f32>;\n@group(0) @binding(1) \nvar s_diffuse: sampler;\n@group(1) @binding(0) \nvar<uniform> global_uniforms: GlobalUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(2) @binding(0) \nvar<uniform> view_proj_uniforms: ViewProjectionUniformsX_naga_oil_mod_XM5WG6YTBNQX;\n@group(3) @binding(0) \nvar t_bloom: texture_2d<f32>;\n@group(3) @binding(1) \nvar s_bloom: sampler;\n@group(4) @binding(0) \nvar<uniform> bloom: BloomUniforms;\n\nfn luminance(color: vec3<f32>) -> f32 {\n    return dot(color, vec3<f32>(0.2126f, 0.7152f, 0.0722f));\n}\n\nfn downsample(uv: vec2<f32>, karis: bool) -> vec3<f32> {\n    var result: vec3<f32> = vec3(0f);\n    var total_weight: f32 = 0f;\n    var n: i32 = 0i;\n    var weight: f32;\n\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let texel = (vec2(1f) / vec2<f32>(_e5));\n    let _e18 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 2f))));\n    let a = _e18.xyz;\n    let _e27 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, 2f))));\n    let b = _e27.xyz;\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 2f))));\n    let c = _e36.xyz;\n    let _e45 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, 0f))));\n    let d = _e45.xyz;\n    let _e49 = textureSample(t_diffuse, s_diffuse, uv);\n    let e = _e49.xyz;\n    let _e58 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, 0f))));\n    let f = _e58.xyz;\n    let _e67 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-2f, -2f))));\n    let g = _e67.xyz;\n    let _e76 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(0f, -2f))));\n    let h = _e76.xyz;\n    let _e85 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(2f, -2f))));\n    let i = _e85.xyz;\n    let _e94 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, 1f))));\n    let j = _e94.xyz;\n    let _e103 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, 1f))));\n    let k = _e103.xyz;\n    let _e112 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(-1f, -1f))));\n    let l = _e112.xyz;\n    let _e121 = textureSample(t_diffuse, s_diffuse, (uv + (texel * vec2<f32>(1f, -1f))));\n    let m = _e121.xyz;\n    let boxes = array<vec3<f32>, 5>(((((j + k) + l) + m) * 0.25f), ((((a + b) + d) + e) * 0.25f), ((((b + c) + e) + f) * 0.25f), ((((d + e) + g) + h) * 0.25f), ((((e + f) + h) + i) * 0.25f));\n    loop {\n        let _e150 = n;\n        if (_e150 < 5i) {\n        } else {\n            break;\n        }\n        {\n            let _e153 = n;\n            weight = select(0.125f, 0.5f, (_e153 == 0i));\n            if karis {\n                let _e161 = n;\n                let _e163 = luminance(boxes[_e161]);\n                let _e166 = weight;\n                weight = (_e166 / (1f + _e163));\n            }\n            let _e169 = n;\n            let _e171 = weight;\n            let _e173 = result;\n            result = (_e173 + (boxes[_e169] * _e171));\n            let _e176 = weight;\n            let _e177 = total_weight;\n            total_weight = (_e177 + _e176);\n        }\n        continuing {\n            let _e180 = n;\n            n = (_e180 + 1i);\n        }\n    }\n    let _e182 = result;\n    let _e183 = total_weight;\n    return (_e182 / vec3(_e183));\n}\n\nfn threshold(color_1: vec3<f32>) -> vec3<f32> {\n    var soft: f32;\n\n    let brightness = max(color_1.x, max(color_1.y, color_1.z));\n    let _e8 = bloom.threshold;\n    let _e11 = bloom.knee;\n    let knee = (_e8 * _e11);\n    let _e15 = bloom.threshold;\n    soft = clamp(((brightness - _e15) + knee), 0f, (2f * knee));\n    let _e23 = soft;\n    let _e24 = soft;\n    soft = ((_e23 * _e24) / ((4f * knee) + 0.00001f));\n    let _e31 = soft;\n    let _e34 = bloom.threshold;\n    let contribution = (max(_e31, (brightness - _e34)) / max(brightness, 0.00001f));\n    return (color_1 * contribution);\n}\n\nfn upsample(uv_1: vec2<f32>) -> vec3<f32> {\n    var result_1: vec3<f32>;\n\n    let _e2 = bloom.filter_radius;\n    let _e5 = textureDimensions(t_diffuse, 0i);\n    let r = (vec2(_e2) / vec2<f32>(_e5));\n    let _e12 = textureSample(t_diffuse, s_diffuse, uv_1);\n    result_1 = (_e12.xyz * 4f);\n    let _e24 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), 0f)));\n    let _e28 = result_1;\n    result_1 = (_e28 + (_e24.xyz * 2f));\n    let _e36 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, 0f)));\n    let _e40 = result_1;\n    result_1 = (_e40 + (_e36.xyz * 2f));\n    let _e49 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, -(r.y))));\n    let _e53 = result_1;\n    result_1 = (_e53 + (_e49.xyz * 2f));\n    let _e61 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(0f, r.y)));\n    let _e65 = result_1;\n    result_1 = (_e65 + (_e61.xyz * 2f));\n    let _e75 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), -(r.y))));\n    let _e77 = result_1;\n    result_1 = (_e77 + _e75.xyz);\n    let _e86 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, -(r.y))));\n    let _e88 = result_1;\n    result_1 = (_e88 + _e86.xyz);\n    let _e97 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(-(r.x), r.y)));\n    let _e99 = result_1;\n    result_1 = (_e99 + _e97.xyz);\n    let _e107 = textureSample(t_diffuse, s_diffuse, (uv_1 + vec2<f32>(r.x, r.y)));\n    let _e109 = result_1;\n    result_1 = (_e109 + _e107.xyz);\n    let _e111 = result_1;\n    return (_e111 / vec3(16f));\n}\n\n@vertex \nfn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {\n    var out: VertexOutput;\n    var model: vec4<f32>;\n\n    out.tex_coords = (instance.uv_offset + (instance.uv_scale * vertex.tex_coords));\n    model = vertex.position;\n    let _e13 = model.x;\n    model.x = ((_e13 * 2f) - 1f);\n    let _e20 = model.y;\n    model.y = ((_e20 * 2f) - 1f);\n    let _e26 = model;\n    out.clip_position = _e26;\n    let _e27 = out;\n    return _e27;\n}\n\n@fragment \nfn fs_prefilter(in: VertexOutput) -> @location(0) vec4<f32> {\n    let _e3 = downsample(in.tex_coords, true);\n    let _e4 = threshold(_e3);\n    return vec4<f32>(_e4, 1f);\n}\n\n@fragment \nfn fs_downsample(in_1: VertexOutput) -> @location(0) vec4<f32> {\n    let _e3 = downsample(in_1.tex_coords, false);\n    return vec4<f32>(_e3, 1f);\n}\n\n@fragment \nfn fs_upsample(in_2: VertexOutput) -> @location(0) vec4<f32> {\n    let _e4 = textureSample(t_bloom, s_bloom, in_2.tex_coords);\n    let _e7 = upsample(in_2.tex_coords);\n    let color_2 = (_e4.xyz + _e7);\n    return vec4<f32>(color_2, 1f);\n}\n\n@fragment \nfn fs_composite(in_3: VertexOutput) -> @location(0) vec4<f32> {\n    let scene = textureSample(t_diffuse, s_diffuse, in_3.tex_coords);\n    let _e8 = textureSample(t_bloom, s_bloom, in_3.tex_coords);\n    let blurred = _e8.xyz;\n    let _e13 = bloom.intensity;\n    let _e17 = bloom.level_count;\n    let color_3 = (scene.xyz + ((blurred * _e13) / vec3(f32(_e17))));\n    return vec4<f32>(color_3, scene.w);\n}\n";
+    ///Shader module descriptor.
+    pub const DESCRIPTOR: ::wgpu::ShaderModuleDescriptor = ::wgpu::ShaderModuleDescriptor {
+        label: Some("bloom"),
+        source: ::wgpu::ShaderSource::Wgsl(::std::borrow::Cow::Borrowed(SOURCE)),
+    };
+}
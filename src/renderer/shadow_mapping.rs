@@ -1,17 +1,38 @@
-use crate::geom::{ModelVertexData, Point};
+use crate::geom::{ModelVertexData, Point, SkinnedVertexData};
 
 use super::{
-    instance::InstanceRenderData, lighting::LightsUniform, shaders, Display,
-    InstanceDataWithNormalMatrix, PipelineRef, RenderState, RenderTarget, Texture, TextureBuilder,
-    TextureRef,
+    instance::InstanceRenderData,
+    lighting::LightsUniform,
+    shaders,
+    skinning::{SkinnedInstanceData, SkinnedScene},
+    Display, InstanceDataWithNormalMatrix, PipelineRef, RenderState, RenderTarget, Texture,
+    TextureBuilder, TextureRef,
 };
 
 pub const MAX_LIGHTS: usize = 8;
+/// Shadow cascades of a directional light, each takes a layer of the shadow
+/// map.
+pub const MAX_CASCADES: usize = 4;
+/// Point lights beyond this many don't cast shadows.
+pub const MAX_POINT_SHADOWS: usize = 4;
+pub const POINT_SHADOW_MAP_SIZE: u32 = 512;
+/// Six layers per point light plus an unused one: the GL backend treats square
+/// textures with a multiple of six layers as cube maps, which then can't be
+/// viewed as a 2D array.
+const POINT_SHADOW_MAP_LAYERS: u32 = 6 * MAX_POINT_SHADOWS as u32 + 1;
 
 pub struct ShadowMappingPass {
     shadow_map_pipeline: PipelineRef<ModelVertexData, InstanceDataWithNormalMatrix>,
+    point_shadow_map_pipeline: PipelineRef<ModelVertexData, InstanceDataWithNormalMatrix>,
+    skinned_shadow_map_pipeline: PipelineRef<SkinnedVertexData, SkinnedInstanceData>,
+    skinned_point_shadow_map_pipeline: PipelineRef<SkinnedVertexData, SkinnedInstanceData>,
+    /// Layers are shared by spot lights and directional light cascades, see
+    /// `LightsUniform::shadow_map_views`.
     shadow_map: Texture,
     shadow_map_target_views: [wgpu::TextureView; MAX_LIGHTS],
+    /// Six layers per point light, one for each cube face.
+    point_shadow_map: Texture,
+    point_shadow_map_target_views: [wgpu::TextureView; 6 * MAX_POINT_SHADOWS],
     pub shadow_map_debug_textures: [TextureRef; MAX_LIGHTS],
     pub depth_bias_state: wgpu::DepthBiasState,
     last_depth_bias_state: wgpu::DepthBiasState,
@@ -28,32 +49,44 @@ impl ShadowMappingPass {
                 wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
             )
             .build(display.device(), Point::new(2048, 2048));
-        let shadow_map_target_views = std::array::from_fn(|i| {
-            shadow_map
-                .texture
-                .create_view(&wgpu::TextureViewDescriptor {
-                    label: Some("shadow"),
-                    format: None,
-                    dimension: Some(wgpu::TextureViewDimension::D2),
-                    aspect: wgpu::TextureAspect::All,
-                    base_mip_level: 0,
-                    mip_level_count: None,
-                    base_array_layer: i as u32,
-                    array_layer_count: Some(1),
-                })
-        });
+        let shadow_map_target_views =
+            std::array::from_fn(|i| Self::layer_view(&shadow_map, "shadow", i));
+        let point_shadow_map = TextureBuilder::depth()
+            .with_label("point_shadow_map")
+            .with_layers(POINT_SHADOW_MAP_LAYERS)
+            .with_address_mode(wgpu::AddressMode::ClampToEdge)
+            .with_filter_mode(wgpu::FilterMode::Linear)
+            .with_usage(
+                wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            )
+            .build(
+                display.device(),
+                Point::new(POINT_SHADOW_MAP_SIZE, POINT_SHADOW_MAP_SIZE),
+            );
+        let point_shadow_map_target_views =
+            std::array::from_fn(|i| Self::layer_view(&point_shadow_map, "point shadow face", i));
         let shadow_map_debug_textures = std::array::from_fn(|i| {
             state.load_texture(
                 display,
                 TextureBuilder::render_target()
                     .with_label(&format!("shadow map debug {}", i))
+                    .with_usage(
+                        wgpu::TextureUsages::RENDER_ATTACHMENT
+                            | wgpu::TextureUsages::TEXTURE_BINDING
+                            | wgpu::TextureUsages::COPY_SRC,
+                    )
                     .build(display.device(), Point::new(2048, 2048)),
             )
         });
         Self {
             shadow_map_pipeline: Default::default(),
+            point_shadow_map_pipeline: Default::default(),
+            skinned_shadow_map_pipeline: Default::default(),
+            skinned_point_shadow_map_pipeline: Default::default(),
             shadow_map,
             shadow_map_target_views,
+            point_shadow_map,
+            point_shadow_map_target_views,
             depth_bias_state: wgpu::DepthBiasState {
                 constant: 1,
                 slope_scale: 0.025,
@@ -64,67 +97,235 @@ impl ShadowMappingPass {
         }
     }
 
+    fn layer_view(texture: &Texture, label: &str, layer: usize) -> wgpu::TextureView {
+        texture.texture.create_view(&wgpu::TextureViewDescriptor {
+            label: Some(label),
+            format: None,
+            dimension: Some(wgpu::TextureViewDimension::D2),
+            aspect: wgpu::TextureAspect::All,
+            base_mip_level: 0,
+            mip_level_count: None,
+            base_array_layer: layer as u32,
+            array_layer_count: Some(1),
+        })
+    }
+
     fn build_shadow_map_pipeline(&mut self, state: &mut RenderState, display: &Display) {
+        let mut bias = self.depth_bias_state;
+        if !display
+            .downlevel_flags()
+            .contains(wgpu::DownlevelFlags::DEPTH_BIAS_CLAMP)
+        {
+            bias.clamp = 0.0;
+        }
+        let depth_stencil_state = wgpu::DepthStencilState {
+            format: TextureBuilder::DEFAULT_DEPTH_FORMAT,
+            depth_write_enabled: true,
+            depth_compare: wgpu::CompareFunction::LessEqual,
+            stencil: Default::default(),
+            bias,
+        };
+        let device = display.device();
+        let shadow_map_shader = device.create_shader_module(shaders::shadow_map::DESCRIPTOR);
+        let color_target_states = vec![Some(wgpu::ColorTargetState {
+            format: TextureBuilder::DEFAULT_RENDER_FORMAT,
+            blend: None,
+            write_mask: wgpu::ColorWrites::all(),
+        })];
         self.shadow_map_pipeline = state
             .pipeline_builder()
             .with_label("Shadow Map Render Pipeline")
             .with_key(self.shadow_map_pipeline)
             .with_cull_mode(Some(wgpu::Face::Front))
-            .with_color_target_states(vec![Some(wgpu::ColorTargetState {
-                format: TextureBuilder::DEFAULT_RENDER_FORMAT,
-                blend: None,
-                write_mask: wgpu::ColorWrites::all(),
-            })])
-            .with_depth_stencil_state(Some(wgpu::DepthStencilState {
-                format: TextureBuilder::DEFAULT_DEPTH_FORMAT,
-                depth_write_enabled: true,
-                depth_compare: wgpu::CompareFunction::LessEqual,
-                stencil: Default::default(),
-                bias: self.depth_bias_state,
-            }))
-            .build(
-                display.device(),
-                &display
-                    .device()
-                    .create_shader_module(shaders::shadow_map::DESCRIPTOR),
-            );
+            .with_color_target_states(color_target_states.clone())
+            .with_depth_stencil_state(Some(depth_stencil_state.clone()))
+            .build(device, &shadow_map_shader);
+        let joints_bgl = device
+            .create_bind_group_layout(&shaders::shadow_map::globals::joint_matrices::layout());
+        self.skinned_shadow_map_pipeline = state
+            .pipeline_builder()
+            .with_label("Skinned Shadow Map Render Pipeline")
+            .with_key(self.skinned_shadow_map_pipeline)
+            .with_vertex_entry_point("vs_skinned")
+            .with_cull_mode(Some(wgpu::Face::Front))
+            .with_color_target_states(color_target_states)
+            .with_depth_stencil_state(Some(depth_stencil_state.clone()))
+            .with_extra_bind_group_layouts(vec![&joints_bgl])
+            .build(device, &shadow_map_shader);
+        // Cube faces are depth only, there are no debug textures for them.
+        let depth_only_shader = device.create_shader_module(shaders::depth_only::DESCRIPTOR);
+        self.point_shadow_map_pipeline = state
+            .pipeline_builder()
+            .with_label("Point Shadow Map Render Pipeline")
+            .with_key(self.point_shadow_map_pipeline)
+            .with_cull_mode(Some(wgpu::Face::Front))
+            .with_color_target_states(vec![])
+            .with_depth_stencil_state(Some(depth_stencil_state.clone()))
+            .build(device, &depth_only_shader);
+        let joints_bgl = device
+            .create_bind_group_layout(&shaders::depth_only::globals::joint_matrices::layout());
+        self.skinned_point_shadow_map_pipeline = state
+            .pipeline_builder()
+            .with_label("Skinned Point Shadow Map Render Pipeline")
+            .with_key(self.skinned_point_shadow_map_pipeline)
+            .with_vertex_entry_point("vs_skinned")
+            .with_cull_mode(Some(wgpu::Face::Front))
+            .with_color_target_states(vec![])
+            .with_depth_stencil_state(Some(depth_stencil_state))
+            .with_extra_bind_group_layouts(vec![&joints_bgl])
+            .build(device, &depth_only_shader);
     }
 
     pub fn shadow_map_texture(&self) -> &Texture {
         &self.shadow_map
     }
 
+    pub fn point_shadow_map_texture(&self) -> &Texture {
+        &self.point_shadow_map
+    }
+
     pub fn run(
         &mut self,
         state: &mut RenderState,
         display: &Display,
         lights_uniform: &LightsUniform,
         scene: &[InstanceRenderData<ModelVertexData, InstanceDataWithNormalMatrix>],
+        skinned_scene: &SkinnedScene,
     ) {
         if self.depth_bias_state != self.last_depth_bias_state {
             self.build_shadow_map_pipeline(state, display);
             self.last_depth_bias_state = self.depth_bias_state;
         }
 
-        let command_buffers = lights_uniform.lights.iter().enumerate().map(|(i, light)| {
-            state
-                .render_pass(
-                    &display,
-                    "Shadow Mapping Pass",
-                    &[RenderTarget::TextureRef(self.shadow_map_debug_textures[i])],
-                    Some(RenderTarget::TextureView(&self.shadow_map_target_views[i])),
-                    &light.view_proj_uniforms(&lights_uniform.view_frustum),
-                    |r| {
-                        for render_data in scene {
-                            r.draw_instance(&InstanceRenderData {
-                                pipeline: Some(self.shadow_map_pipeline),
-                                ..*render_data
-                            });
-                        }
-                    },
-                )
-                .command_buffer()
-        });
+        let mut command_buffers = vec![];
+        for view in lights_uniform.shadow_map_views() {
+            command_buffers.push(
+                state
+                    .render_pass(
+                        &display,
+                        "Shadow Mapping Pass",
+                        &[RenderTarget::TextureRef(
+                            self.shadow_map_debug_textures[view.layer],
+                        )],
+                        Some(RenderTarget::TextureView(
+                            &self.shadow_map_target_views[view.layer],
+                        )),
+                        &view.view_proj,
+                        |r| {
+                            for render_data in scene {
+                                r.draw_instance(&InstanceRenderData {
+                                    pipeline: Some(self.shadow_map_pipeline),
+                                    material: None,
+                                    ..*render_data
+                                });
+                            }
+                            r.set_bind_group(
+                                shaders::shadow_map::globals::joint_matrices::GROUP,
+                                skinned_scene.bind_group(),
+                                &[],
+                            );
+                            for render_data in &skinned_scene.instances {
+                                r.draw_instance(&InstanceRenderData {
+                                    pipeline: Some(self.skinned_shadow_map_pipeline),
+                                    material: None,
+                                    ..*render_data
+                                });
+                            }
+                        },
+                    )
+                    .command_buffer(),
+            );
+        }
+        for (i, shadow_index) in lights_uniform.point_shadow_slots() {
+            let faces = lights_uniform.lights[i]
+                .cube_face_view_proj_uniforms()
+                .unwrap();
+            for (face, view_proj) in faces.iter().enumerate() {
+                let target = &self.point_shadow_map_target_views[6 * shadow_index + face];
+                command_buffers.push(
+                    state
+                        .render_pass(
+                            display,
+                            "Point Shadow Mapping Pass",
+                            &[],
+                            Some(RenderTarget::TextureView(target)),
+                            view_proj,
+                            |r| {
+                                for render_data in scene {
+                                    r.draw_instance(&InstanceRenderData {
+                                        pipeline: Some(self.point_shadow_map_pipeline),
+                                        material: None,
+                                        ..*render_data
+                                    });
+                                }
+                                r.set_bind_group(
+                                    shaders::depth_only::globals::joint_matrices::GROUP,
+                                    skinned_scene.bind_group(),
+                                    &[],
+                                );
+                                for render_data in &skinned_scene.instances {
+                                    r.draw_instance(&InstanceRenderData {
+                                        pipeline: Some(self.skinned_point_shadow_map_pipeline),
+                                        material: None,
+                                        ..*render_data
+                                    });
+                                }
+                            },
+                        )
+                        .command_buffer(),
+                );
+            }
+        }
         display.queue().submit(command_buffers);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use glam::vec3;
+
+    use crate::renderer::{
+        lighting::{Light, LightKind},
+        test_scene::{brightness, spot_light, LitScene, FLOOR_Y},
+        tonemapping::Tonemapper,
+    };
+
+    #[test]
+    fn test_spot_light_shadow() {
+        let mut scene = LitScene::new(spot_light());
+        scene.render();
+
+        // The light shines past the cube onto the floor behind it, both
+        // points are inside its cone.
+        let color = scene.capture_color(Tonemapper::None);
+        let shadowed = scene.pixel_at(&color, vec3(-1.8, FLOOR_Y, -1.8));
+        let lit = scene.pixel_at(&color, vec3(-2.5, FLOOR_Y, -2.0));
+        assert!(
+            brightness(shadowed) + 150 < brightness(lit),
+            "{:?} {:?}",
+            shadowed,
+            lit
+        );
+    }
+
+    #[test]
+    fn test_point_light_shadow() {
+        let mut scene = LitScene::new(Light::from(LightKind::Point {
+            position: vec3(-1.0, 1.5, -2.5),
+            radius: 10.0,
+        }));
+        scene.render();
+
+        // Two floor points mirrored around the light, only one of them is
+        // behind the cube.
+        let color = scene.capture_color(Tonemapper::None);
+        let shadowed = scene.pixel_at(&color, vec3(0.8, FLOOR_Y, 2.2));
+        let lit = scene.pixel_at(&color, vec3(-2.8, FLOOR_Y, 2.2));
+        assert!(
+            brightness(shadowed) + 100 < brightness(lit),
+            "{:?} {:?}",
+            shadowed,
+            lit
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Instance data and joint matrices for drawing skinned meshes.
+
+use glam::{Mat4, Vec3};
+use wgpu::{vertex_attr_array, VertexAttribute, VertexBufferLayout, VertexStepMode};
+
+use crate::{
+    color::Color,
+    geom::{Rect, SkinnedVertexData},
+};
+
+use super::{
+    instance::InstanceRenderData, light_clusters::StorageBuffer, shaders, Display, InstanceData,
+    VertexLayout,
+};
+
+/// Instance data of skinned meshes. There is no normal matrix, normals are
+/// transformed by the skinned model matrix, so scaling should be uniform.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct SkinnedInstanceData {
+    pub subtexture: Rect,
+    pub tint: Color,
+    pub transform: Mat4,
+    /// Returned by `SkinnedScene::add_joint_matrices` for the instance's
+    /// skeleton.
+    pub joint_offset: u32,
+    _padding: [u32; 3],
+}
+
+impl SkinnedInstanceData {
+    const ATTRIBUTES: [VertexAttribute; 8] = vertex_attr_array![
+        // uv_scale: vec2<f32>
+        0 => Float32x2,
+        // uv_offset: vec2<f32>
+        1 => Float32x2,
+        // tint: vec4<f32>
+        2 => Float32x4,
+        // model_N: vec4<f32> * 4
+        3 => Float32x4,
+        4 => Float32x4,
+        5 => Float32x4,
+        6 => Float32x4,
+        // joint_offset: u32
+        7 => Uint32,
+    ];
+
+    pub fn new(transform: Mat4, joint_offset: u32) -> Self {
+        Self {
+            transform,
+            joint_offset,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for SkinnedInstanceData {
+    fn default() -> Self {
+        Self {
+            subtexture: Rect::new(0., 0., 1., 1.),
+            tint: Color::WHITE,
+            transform: Mat4::IDENTITY,
+            joint_offset: 0,
+            _padding: [0; 3],
+        }
+    }
+}
+
+impl VertexLayout for SkinnedInstanceData {
+    fn vertex_layout() -> VertexBufferLayout<'static> {
+        VertexBufferLayout {
+            array_stride: std::mem::size_of::<Self>() as wgpu::BufferAddress,
+            step_mode: VertexStepMode::Instance,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+impl InstanceData for SkinnedInstanceData {
+    fn sort_position(&self) -> Vec3 {
+        self.transform.w_axis.truncate()
+    }
+
+    fn is_transparent(&self) -> bool {
+        self.tint.a < 1.0
+    }
+}
+
+/// Skinned instances for the forward and shadow mapping passes, along with
+/// the joint matrices of their skeletons. Meant to be refilled every frame:
+/// `clear` it, add the joint matrices and instances, then `upload`.
+pub struct SkinnedScene {
+    pub instances: Vec<InstanceRenderData<SkinnedVertexData, SkinnedInstanceData>>,
+    joint_matrices: Vec<Mat4>,
+    buffer: StorageBuffer,
+    bind_group_layout: wgpu::BindGroupLayout,
+    bind_group: wgpu::BindGroup,
+}
+
+impl SkinnedScene {
+    pub fn new(display: &Display) -> Self {
+        let device = display.device();
+        let buffer = StorageBuffer::new(device, "joint matrices", std::mem::size_of::<Mat4>());
+        let bind_group_layout =
+            device.create_bind_group_layout(&shaders::forward::globals::joint_matrices::layout());
+        let bind_group = Self::create_bind_group(device, &bind_group_layout, &buffer);
+        Self {
+            instances: vec![],
+            joint_matrices: vec![],
+            buffer,
+            bind_group_layout,
+            bind_group,
+        }
+    }
+
+    fn create_bind_group(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        buffer: &StorageBuffer,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("joint matrices bind group"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: buffer.buffer.as_entire_binding(),
+            }],
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.instances.clear();
+        self.joint_matrices.clear();
+    }
+
+    /// Adds the matrices of a posed skeleton, see `Skeleton::joint_matrices`,
+    /// returning the `joint_offset` for instances using them.
+    pub fn add_joint_matrices(&mut self, matrices: &[Mat4]) -> u32 {
+        let offset = self.joint_matrices.len() as u32;
+        self.joint_matrices.extend_from_slice(matrices);
+        offset
+    }
+
+    pub fn upload(&mut self, display: &Display) {
+        if self
+            .buffer
+            .write(display, bytemuck::cast_slice(&self.joint_matrices))
+        {
+            self.bind_group =
+                Self::create_bind_group(display.device(), &self.bind_group_layout, &self.buffer);
+        }
+    }
+
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::vec3;
+
+    use super::*;
+    use crate::renderer::{
+        model::LoadModel,
+        test_scene::{spot_light, LitScene},
+        tonemapping::Tonemapper,
+    };
+
+    #[test]
+    fn test_skinned_mesh() {
+        // A vertical strip standing on the cube, bending at its middle joint
+        // through the clip.
+        let render = |time: f32| {
+            let mut scene = LitScene::new(spot_light());
+            let path = concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/res/models/gltf_test/skinned.gltf"
+            );
+            let model = scene.display.device().load_model(path).unwrap();
+            let model = scene.state.prepare_model(&scene.display, model);
+            let skin = &model.skins[0];
+            let pose = skin.animations[0].pose(&skin.skeleton, time);
+            let joint_offset = scene
+                .skinned_scene
+                .add_joint_matrices(&skin.skeleton.joint_matrices(&pose));
+            scene.skinned_scene.instances.push(InstanceRenderData {
+                mesh: model.skinned_meshes[0].mesh,
+                instance: SkinnedInstanceData::new(
+                    Mat4::from_translation(vec3(-2.0, 1.0, 0.0)),
+                    joint_offset,
+                ),
+                texture: None,
+                material: None,
+                pipeline: None,
+            });
+            scene.render();
+            let color = scene.capture_color(Tonemapper::None);
+            [vec3(0.0, 2.0, 0.0), vec3(0.0, 2.7, 0.0)]
+                .map(|position| scene.pixel_at(&color, position).0 != [0, 0, 0, 255])
+        };
+        let [lower, upper] = render(0.0);
+        assert!(lower && upper);
+        // The lower half stays in place, the upper one moves aside.
+        let [lower, upper] = render(0.5);
+        assert!(lower && !upper);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::geom::ModelVertexData;
+
+use super::{
+    instance::InstanceRenderData, mesh::LoadMesh, shaders, state::BindingType, BasicInstanceData,
+    Display, DrawOrder, MeshRef, PipelineRef, RenderPass, RenderState,
+};
+
+/// Draws a cubemap behind everything else in a render pass, at the far plane
+/// and only where nothing has been drawn yet.
+pub struct SkyboxPass {
+    pipeline: PipelineRef<ModelVertexData, BasicInstanceData>,
+    cube: MeshRef<ModelVertexData>,
+}
+
+impl SkyboxPass {
+    /// Format of the cubemap binding. Other filterable color formats, like
+    /// the default 8-bit one, can be bound as well.
+    pub const CUBEMAP_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+    pub fn new(
+        state: &mut RenderState,
+        display: &Display,
+        color_format: wgpu::TextureFormat,
+        depth_format: wgpu::TextureFormat,
+    ) -> Self {
+        let pipeline = state
+            .pipeline_builder()
+            .with_label("Skybox")
+            .with_color_target_states(vec![Some(wgpu::ColorTargetState {
+                format: color_format,
+                blend: None,
+                write_mask: wgpu::ColorWrites::ALL,
+            })])
+            .with_depth_stencil_state(Some(wgpu::DepthStencilState {
+                format: depth_format,
+                depth_write_enabled: false,
+                depth_compare: wgpu::CompareFunction::LessEqual,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }))
+            // The camera is inside the cube.
+            .with_cull_mode(None)
+            .with_extra_bindings(vec![BindingType::Texture {
+                format: Self::CUBEMAP_FORMAT,
+                view_dimension: wgpu::TextureViewDimension::Cube,
+            }])
+            .build(
+                display.device(),
+                &display
+                    .device()
+                    .create_shader_module(shaders::skybox::DESCRIPTOR),
+            );
+        let cube = state.prepare_mesh(display.device().load_cube_mesh());
+        Self { pipeline, cube }
+    }
+
+    /// Draws the skybox immediately, call before any draws that depend on
+    /// what is behind them, such as transparent ones. Geometry has to be in
+    /// the depth buffer already, e.g. from a depth pre-pass.
+    pub fn draw(&self, r: &mut RenderPass, cubemap: &wgpu::BindGroup) {
+        r.set_draw_order(DrawOrder::Immediate);
+        r.set_bind_group(shaders::skybox::globals::t_skybox::GROUP, cubemap, &[]);
+        r.draw_instance(&InstanceRenderData {
+            mesh: self.cube,
+            instance: BasicInstanceData::default(),
+            texture: None,
+            material: None,
+            pipeline: Some(self.pipeline),
+        });
+        r.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::renderer::{
+        test_scene::{directional_light, load_colored_cubemap, LitScene},
+        tonemapping::Tonemapper,
+    };
+
+    #[test]
+    fn test_skybox() {
+        let mut scene = LitScene::new(directional_light());
+        scene.forward_pass.skybox = Some(load_colored_cubemap(&mut scene, "skybox"));
+        scene.render();
+
+        // The camera looks along -Z, above the floor is the yellow face.
+        let color = scene.capture_color(Tonemapper::None);
+        let [r, g, b, _] = color.get_pixel(color.width() / 2, 2).0;
+        assert!(r > 150 && g > 150 && b < 20, "{:?}", [r, g, b]);
+    }
+}
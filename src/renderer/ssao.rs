@@ -214,6 +214,7 @@ impl SSAOPass {
                         mesh: quad,
                         instance: BasicInstanceData::default(),
                         texture: None,
+                        material: None,
                         pipeline: Some(self.pipeline),
                     });
                 },
@@ -242,6 +243,7 @@ impl SSAOPass {
                                 ..Default::default()
                             },
                             texture: Some(self.output_texture),
+                            material: None,
                             pipeline: Some(self.blur_pipeline),
                         });
                     },
@@ -269,6 +271,7 @@ impl SSAOPass {
                                 ..Default::default()
                             },
                             texture: Some(self.blur_temp_buffer),
+                            material: None,
                             pipeline: Some(self.blur_pipeline),
                         });
                     },
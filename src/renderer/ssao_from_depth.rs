@@ -2,6 +2,7 @@ use std::ops::Deref;
 
 use bytemuck::Zeroable;
 use glam::{vec2, vec4, Vec2, Vec4};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 
 use crate::{
     camera::Camera,
@@ -23,14 +24,14 @@ impl SSAOKernel {
     const DEFAULT_RADIUS: f32 = 0.3;
     const DEFAULT_BIAS: f32 = 0.025;
 
-    fn generate_items() -> [Vec4; Self::SIZE] {
+    fn generate_items(rng: &mut impl Rng) -> [Vec4; Self::SIZE] {
         std::array::from_fn(|i| {
             let scale = i as f32 / Self::SIZE as f32;
-            let v = rand::random::<f32>()
+            let v = rng.gen::<f32>()
                 * vec4(
-                    2.0 * rand::random::<f32>() - 1.0,
-                    2.0 * rand::random::<f32>() - 1.0,
-                    rand::random::<f32>(),
+                    2.0 * rng.gen::<f32>() - 1.0,
+                    2.0 * rng.gen::<f32>() - 1.0,
+                    rng.gen::<f32>(),
                     0.0,
                 )
                 .normalize();
@@ -38,8 +39,8 @@ impl SSAOKernel {
         })
     }
 
-    fn new(noise_texture_scale: Vec2, camera: &Camera) -> Self {
-        let items = Self::generate_items();
+    fn new(noise_texture_scale: Vec2, camera: &Camera, rng: &mut impl Rng) -> Self {
+        let items = Self::generate_items(rng);
         let aspect_ratio = camera.aspect_ratio();
         let tan_half_fov = (camera.fov_radians() / 2.0).tan();
         Self {
@@ -92,10 +93,12 @@ impl SSAOPass {
         depth_target: &Texture,
         camera: &Camera,
     ) -> Self {
+        // Seeded so the occlusion map comes out the same on every run.
+        let mut rng = StdRng::seed_from_u64(0);
         let noise: [Vec4; Self::NOISE_SCALE * Self::NOISE_SCALE] = std::array::from_fn(|_| {
             vec4(
-                2.0 * rand::random::<f32>() - 1.0,
-                2.0 * rand::random::<f32>() - 1.0,
+                2.0 * rng.gen::<f32>() - 1.0,
+                2.0 * rng.gen::<f32>() - 1.0,
                 0.0,
                 1.0,
             )
@@ -116,7 +119,7 @@ impl SSAOPass {
         let noise_texture = BoundTexture::new(display.device(), &noise_texture_bgl, noise_texture);
         let (kernel, uniform_bgl) = state.create_uniform_bind_group(
             display.device(),
-            SSAOKernel::new(size.as_vec2() / Self::NOISE_SCALE as f32, camera),
+            SSAOKernel::new(size.as_vec2() / Self::NOISE_SCALE as f32, camera, &mut rng),
         );
         let output_texture = state.load_texture(
             &display,
@@ -127,29 +130,38 @@ impl SSAOPass {
                 .with_usage(
                     wgpu::TextureUsages::TEXTURE_BINDING
                         | wgpu::TextureUsages::COPY_DST
+                        | wgpu::TextureUsages::COPY_SRC
                         | wgpu::TextureUsages::RENDER_ATTACHMENT,
                 )
                 .build(display.device(), size),
         );
-        let depth_buffer_bgl = display
-            .device()
-            .create_bind_group_layout(&shaders::ssao_from_depth::globals::group3::layout());
+        // Not the generated layout, which would make depth filterable.
+        let depth_buffer_bgl =
+            display
+                .device()
+                .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                    label: Some("ssao depth buffer"),
+                    entries: &[wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            multisampled: false,
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        },
+                        count: None,
+                    }],
+                });
         let depth_buffer_bind_group =
             display
                 .device()
                 .create_bind_group(&wgpu::BindGroupDescriptor {
                     label: Some("forward pass depth target"),
                     layout: &depth_buffer_bgl,
-                    entries: &[
-                        wgpu::BindGroupEntry {
-                            binding: 0,
-                            resource: wgpu::BindingResource::TextureView(&depth_target.view),
-                        },
-                        wgpu::BindGroupEntry {
-                            binding: 1,
-                            resource: wgpu::BindingResource::Sampler(&depth_target.sampler),
-                        },
-                    ],
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(&depth_target.view),
+                    }],
                 });
         let pipeline = state
             .pipeline_builder()
@@ -247,6 +259,7 @@ impl SSAOPass {
                         mesh: quad,
                         instance: BasicInstanceData::default(),
                         texture: None,
+                        material: None,
                         pipeline: Some(self.pipeline),
                     });
                 },
@@ -280,6 +293,7 @@ impl SSAOPass {
                                 ..Default::default()
                             },
                             texture: Some(self.output_texture),
+                            material: None,
                             pipeline: Some(self.blur_pipeline),
                         });
                     },
@@ -312,6 +326,7 @@ impl SSAOPass {
                                 ..Default::default()
                             },
                             texture: Some(self.blur_temp_buffer),
+                            material: None,
                             pipeline: Some(self.blur_pipeline),
                         });
                     },
@@ -325,7 +340,7 @@ impl SSAOPass {
         ui.add(egui::Slider::new(&mut self.kernel.radius, 0.0..=5.0).text("radius"));
         ui.add(egui::Slider::new(&mut self.kernel.bias, 0.0..=2.0).text("bias"));
         if ui.add(egui::Button::new("Regenerate")).clicked() {
-            self.kernel.items = SSAOKernel::generate_items();
+            self.kernel.items = SSAOKernel::generate_items(&mut rand::thread_rng());
         }
 
         ui.separator();
@@ -341,3 +356,45 @@ impl SSAOPass {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use glam::vec3;
+
+    use super::*;
+    use crate::renderer::test_scene::{brightness, directional_light, LitScene, FLOOR_Y};
+
+    #[test]
+    fn test_occlusion_map() {
+        let mut scene = LitScene::new(directional_light());
+        scene.render();
+
+        let camera = scene.camera;
+        let size = scene.size();
+        let mut ssao_pass = SSAOPass::new(
+            &mut scene.state,
+            &scene.display,
+            size,
+            &scene.forward_pass.depth_target,
+            &camera,
+        );
+        let occlusion_map = ssao_pass.run(
+            &mut scene.state,
+            &scene.display,
+            &ViewProjectionUniforms::for_camera(&camera),
+        );
+        let occlusion = scene
+            .state
+            .capture_texture(&mut scene.display, occlusion_map)
+            .unwrap();
+        // The floor is occluded where it meets the cube and open further out.
+        let corner = scene.pixel_at(&occlusion, vec3(0.0, FLOOR_Y, 1.1));
+        let open = scene.pixel_at(&occlusion, vec3(0.0, FLOOR_Y, 2.5));
+        assert!(
+            brightness(corner) + 15 < brightness(open),
+            "{:?} {:?}",
+            corner,
+            open
+        );
+    }
+}
@@ -2,20 +2,28 @@ use std::{
     collections::HashMap,
     hash::Hash,
     ops::{Deref, DerefMut, Range},
+    path::{Path, PathBuf},
     sync::{Arc, Mutex},
 };
 
+use anyhow::Context;
 use bytemuck::Zeroable;
 use glam::{Mat4, Quat, Vec4};
+use image::RgbaImage;
 use slotmap::SlotMap;
 use wgpu::BindGroupLayout;
 
 use super::{
     display::Display,
     instance::{InstanceRenderData, InstanceStorage},
-    mesh::{LoadMesh, Mesh, RawMeshRef, UntypedMesh},
+    material::{BoundMaterial, Material, MaterialRef},
+    mesh::{LoadMesh, Mesh, MeshIndex, RawMeshRef, UntypedMesh},
+    mipmaps::MipmapGenerator,
+    model::{Model, PreparedModel, PreparedModelMesh, PreparedSkinnedMesh, TextureSource},
+    profiler::GpuProfiler,
     shader_type::GlobalUniforms,
     shaders,
+    stats::{DrawStats, FrameDrawStats, PassDrawStats},
     text::{RenderableFont, TextDisplayOptions},
     texture::{Texture, TextureBuilder},
     BasicInstanceData, BindGroup, Bindable, InstanceData, MeshRef, OffscreenFramebuffer,
@@ -31,6 +39,14 @@ use crate::{
 
 pub type BoundTexture = BindGroup<Texture>;
 
+struct StoredPipeline {
+    pipeline: wgpu::RenderPipeline,
+    /// Whether the layout has a material at
+    /// `RenderPass::MATERIAL_BIND_GROUP_INDEX`, which render passes then bind
+    /// for each draw.
+    binds_material: bool,
+}
+
 pub type ViewProjectionUniforms = shaders::global::types::ViewProjectionUniforms;
 
 impl ViewProjectionUniforms {
@@ -151,7 +167,13 @@ pub trait Bindings {
 #[derive(Copy, Clone, Hash, Eq, PartialEq)]
 pub enum BindingType {
     Uniform,
-    Texture { format: wgpu::TextureFormat },
+    Texture {
+        format: wgpu::TextureFormat,
+        view_dimension: wgpu::TextureViewDimension,
+    },
+    // Material uniforms followed by the texture and sampler of each material
+    // texture slot.
+    Material,
     Direct(wgpu::ShaderStages, wgpu::BindingType),
 }
 
@@ -168,7 +190,10 @@ impl BindingType {
                 },
                 count: None,
             }],
-            BindingType::Texture { format } => {
+            BindingType::Texture {
+                format,
+                view_dimension,
+            } => {
                 let sample_type = format
                     .sample_type(Some(wgpu::TextureAspect::All), None)
                     .expect(&format!(
@@ -181,7 +206,7 @@ impl BindingType {
                         visibility: wgpu::ShaderStages::FRAGMENT,
                         ty: wgpu::BindingType::Texture {
                             multisampled: false,
-                            view_dimension: wgpu::TextureViewDimension::D2,
+                            view_dimension,
                             sample_type,
                         },
                         count: None,
@@ -203,6 +228,37 @@ impl BindingType {
                     },
                 ]
             }
+            BindingType::Material => {
+                let mut entries = vec![wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                }];
+                for i in 0..Material::TEXTURE_SLOTS as u32 {
+                    entries.push(wgpu::BindGroupLayoutEntry {
+                        binding: 2 * i + 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            multisampled: false,
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        },
+                        count: None,
+                    });
+                    entries.push(wgpu::BindGroupLayoutEntry {
+                        binding: 2 * i + 2,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    });
+                }
+                entries
+            }
             BindingType::Direct(visibility, ty) => vec![wgpu::BindGroupLayoutEntry {
                 binding: 0,
                 visibility,
@@ -228,16 +284,31 @@ pub struct RenderState {
 
     texture_manager: SlotMap<TextureRef, BoundTexture>,
     default_texture: TextureRef,
+    /// Flat tangent space normal map used for materials without one.
+    default_normal_texture: TextureRef,
+    /// Textures loaded by `load_texture_file`, so shared ones are only
+    /// loaded once.
+    texture_files: HashMap<(PathBuf, wgpu::TextureFormat), TextureRef>,
+    mipmap_generator: MipmapGenerator,
+    display_framebuffer: Option<OffscreenFramebuffer>,
+
+    material_manager: SlotMap<MaterialRef, BoundMaterial>,
+    default_material: MaterialRef,
 
     mesh_manager: SlotMap<RawMeshRef, UntypedMesh>,
     // pub(crate) pipeline_cache: wgpu::PipelineCache,
-    pipelines: SlotMap<RawPipelineRef, wgpu::RenderPipeline>,
+    pipelines: SlotMap<RawPipelineRef, StoredPipeline>,
     default_pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
     text_pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+
+    pub profiler: GpuProfiler,
+    draw_stats: FrameDrawStats,
+    last_frame_draw_stats: FrameDrawStats,
 }
 
 impl RenderState {
     const MAX_COLOR_ATTACHMENTS: usize = 8;
+    const IMAGE_TEXTURE_ANISOTROPY: u16 = 16;
 
     pub fn new(
         display: &Display,
@@ -264,6 +335,10 @@ impl RenderState {
         let mut s = Self {
             bind_group_layouts: HashMap::default(),
             texture_manager: SlotMap::with_key(),
+            material_manager: SlotMap::with_key(),
+            texture_files: HashMap::default(),
+            mipmap_generator: MipmapGenerator::new(device),
+            display_framebuffer: None,
             mesh_manager,
             pipelines: SlotMap::with_key(),
             global_uniforms,
@@ -273,6 +348,11 @@ impl RenderState {
             default_pipeline: Default::default(),
             text_pipeline: Default::default(),
             default_texture: Default::default(),
+            default_normal_texture: Default::default(),
+            default_material: Default::default(),
+            profiler: GpuProfiler::new(display),
+            draw_stats: Default::default(),
+            last_frame_draw_stats: Default::default(),
             // pipeline_cache,
         };
 
@@ -287,6 +367,28 @@ impl RenderState {
                     Point::new(2, 2),
                 ),
         );
+        s.default_normal_texture = s.load_texture(
+            display,
+            TextureBuilder::labeled("default_normal_texture")
+                .with_format(wgpu::TextureFormat::Rgba8Unorm)
+                .from_raw_bytes(
+                    display.device(),
+                    display.queue(),
+                    &[128, 128, 255, 255].repeat(4),
+                    Point::new(2, 2),
+                ),
+        );
+        s.display_framebuffer = display.offscreen_texture().map(|color| {
+            let color = s.load_texture(display, color.share(display.device()));
+            let depth = s.load_texture(display, display.depth_texture().share(display.device()));
+            OffscreenFramebuffer {
+                color,
+                depth: Some(depth),
+                size: display.size_pixels(),
+                format: display.format(),
+            }
+        });
+        s.default_material = s.load_material(display, Material::default());
         s.quad_mesh = s.prepare_mesh(display.device().load_quad_mesh());
         s.default_pipeline = s
             .pipeline_builder()
@@ -299,6 +401,23 @@ impl RenderState {
         s
     }
 
+    /// A headless display of `size` and a render state using the default
+    /// flat and text shaders, for tests.
+    #[cfg(test)]
+    pub(crate) fn headless(size: Point<u32>) -> (Display, Self) {
+        let display = pollster::block_on(Display::headless(size));
+        let state = Self::new(
+            &display,
+            &display
+                .device()
+                .create_shader_module(wgpu::include_wgsl!("../../res/shaders/flat.wgsl")),
+            &display
+                .device()
+                .create_shader_module(wgpu::include_wgsl!("../../res/shaders/text.wgsl")),
+        );
+        (display, state)
+    }
+
     pub fn bind_group_layout(
         &mut self,
         device: &wgpu::Device,
@@ -345,7 +464,12 @@ impl RenderState {
         &mut self,
         key: impl Into<Option<RawPipelineRef>>,
         pipeline: wgpu::RenderPipeline,
+        binds_material: bool,
     ) -> PipelineRef<V, I> {
+        let pipeline = StoredPipeline {
+            pipeline,
+            binds_material,
+        };
         match key.into() {
             Some(key) => {
                 *self.pipelines.get_mut(key).unwrap() = pipeline;
@@ -382,6 +506,7 @@ impl RenderState {
             alloc.get(view_projection)
         };
 
+        let first_query = self.profiler.begin_pass(name);
         let mut encoder = display.command_encoder();
         {
             let color_attachments: [Option<wgpu::RenderPassColorAttachment>;
@@ -389,7 +514,9 @@ impl RenderState {
                 color_targets.get(i).map(|target| {
                     let view = match target {
                         RenderTarget::TextureView(view) => *view,
-                        RenderTarget::TextureRef(texture) => &self.get_texture(*texture).view,
+                        RenderTarget::TextureRef(texture) => {
+                            self.get_texture(*texture).attachment_view()
+                        }
                     };
                     wgpu::RenderPassColorAttachment {
                         view,
@@ -407,7 +534,9 @@ impl RenderState {
                 depth_stencil_attachment: depth_target.map(|target| {
                     let view = match target {
                         RenderTarget::TextureView(view) => view,
-                        RenderTarget::TextureRef(texture) => &self.get_texture(texture).view,
+                        RenderTarget::TextureRef(texture) => {
+                            self.get_texture(texture).attachment_view()
+                        }
                     };
                     wgpu::RenderPassDepthStencilAttachment {
                         view,
@@ -418,7 +547,7 @@ impl RenderState {
                         stencil_ops: None,
                     }
                 }),
-                timestamp_writes: None,
+                timestamp_writes: self.profiler.timestamp_writes(first_query),
                 occlusion_query_set: None,
             });
             raw_pass.set_bind_group(
@@ -434,17 +563,44 @@ impl RenderState {
             );
             let default_texture = self.default_texture;
             let mut render_pass = RenderPass::new(self, display, &mut raw_pass);
+            render_pass.view = view_projection.view;
             render_pass.bind_texture(default_texture);
             pass(&mut render_pass);
-            render_pass.flush_draw_calls();
+            render_pass.flush();
+            let mut stats = render_pass.stats;
+            stats.instance_bytes_uploaded = self.instance_storage.upload(display);
+            self.draw_stats.passes.push(PassDrawStats {
+                name: name.to_owned(),
+                stats,
+            });
         }
         PartialRenderPass { display, encoder }
     }
 
-    pub fn after_frame(&mut self) {
+    pub fn after_frame(&mut self, display: &Display) {
+        self.profiler.end_frame(display);
+        self.last_frame_draw_stats = std::mem::take(&mut self.draw_stats);
         self.view_proj_bind_groups.lock().unwrap().reset();
     }
 
+    /// The color and depth targets a headless display renders into. After
+    /// the display is resized its new targets are shared under the same
+    /// texture refs.
+    pub fn display_framebuffer(&mut self, display: &Display) -> Option<OffscreenFramebuffer> {
+        let mut framebuffer = self.display_framebuffer?;
+        let color = display.offscreen_texture()?;
+        if framebuffer.size != display.size_pixels() {
+            self.replace_texture(display, framebuffer.color, color.share(display.device()));
+            if let Some(depth) = framebuffer.depth {
+                let depth_texture = display.depth_texture().share(display.device());
+                self.replace_texture(display, depth, depth_texture);
+            }
+            framebuffer.size = display.size_pixels();
+            self.display_framebuffer = Some(framebuffer);
+        }
+        Some(framebuffer)
+    }
+
     pub fn create_offscreen_framebuffer(
         &mut self,
         display: &Display,
@@ -504,13 +660,281 @@ impl RenderState {
             BoundTexture::new(display.device(), &layout, value);
     }
 
+    /// Fills the mip chain of a render target built `with_mipmaps` from its
+    /// first level, after drawing into it.
+    pub fn generate_mipmaps(&mut self, display: &Display, texture: TextureRef) {
+        let mut encoder = display.command_encoder();
+        self.mipmap_generator.generate(
+            display.device(),
+            &mut encoder,
+            &self.texture_manager[texture].texture,
+        );
+        display.queue().submit([encoder.finish()]);
+    }
+
+    /// Loads an image file as a repeating, mipmapped texture, or
+    /// returns the texture already loaded from it with the same format.
+    pub fn load_texture_file(
+        &mut self,
+        display: &Display,
+        path: impl AsRef<Path>,
+        format: wgpu::TextureFormat,
+    ) -> anyhow::Result<TextureRef> {
+        let path = path.as_ref();
+        let key = (path.to_owned(), format);
+        if let Some(texture) = self.texture_files.get(&key) {
+            return Ok(*texture);
+        }
+        let image = image::open(path)
+            .with_context(|| format!("failed to load texture {}", path.display()))?
+            .into_rgba8();
+        let texture = self.load_image_texture(display, &path.to_string_lossy(), &image, format);
+        self.texture_files.insert(key, texture);
+        Ok(texture)
+    }
+
+    /// Uploads an image as a repeating, mipmapped and anisotropically
+    /// filtered texture.
+    fn load_image_texture(
+        &mut self,
+        display: &Display,
+        label: &str,
+        image: &RgbaImage,
+        format: wgpu::TextureFormat,
+    ) -> TextureRef {
+        let texture = TextureBuilder::labeled(label)
+            .with_format(format)
+            .with_address_mode(wgpu::AddressMode::Repeat)
+            .with_mipmaps()
+            .with_anisotropy(Self::IMAGE_TEXTURE_ANISOTROPY)
+            .from_image(display.device(), display.queue(), image);
+        self.load_texture(display, texture)
+    }
+
+    /// Uploads the meshes of a model and creates its materials, flattening its
+    /// node hierarchy into mesh instances. Textures that fail to load are
+    /// logged and left empty.
+    pub fn prepare_model(&mut self, display: &Display, model: Model) -> PreparedModel {
+        let instances = model.mesh_instances();
+        let skinned_instances = model.skinned_mesh_instances();
+        let mut embedded_textures = HashMap::new();
+        let materials: Vec<_> = model
+            .materials
+            .into_iter()
+            .map(|m| {
+                let mut load = |source: Option<TextureSource>, format| match source? {
+                    TextureSource::File(path) => self
+                        .load_texture_file(display, path, format)
+                        .map_err(|e| log::warn!("{:#}", e))
+                        .ok(),
+                    TextureSource::Embedded(index) => {
+                        let texture =
+                            *embedded_textures.entry((index, format)).or_insert_with(|| {
+                                self.load_image_texture(
+                                    display,
+                                    "embedded model texture",
+                                    &model.images[index],
+                                    format,
+                                )
+                            });
+                        Some(texture)
+                    }
+                };
+                let linear = wgpu::TextureFormat::Rgba8Unorm;
+                let material = Material {
+                    albedo_texture: load(m.textures.albedo, TextureBuilder::DEFAULT_FORMAT),
+                    normal_texture: load(m.textures.normal, linear),
+                    metallic_roughness_texture: load(m.textures.metallic_roughness, linear),
+                    occlusion_texture: load(m.textures.occlusion, linear),
+                    emissive_texture: load(m.textures.emissive, TextureBuilder::DEFAULT_FORMAT),
+                    ..m.material
+                };
+                self.load_material(display, material)
+            })
+            .collect();
+        let meshes: Vec<_> = model
+            .meshes
+            .into_iter()
+            .map(|m| (self.prepare_mesh(m.mesh), m.material.map(|i| materials[i])))
+            .collect();
+        let skinned_meshes: Vec<_> = model
+            .skinned_meshes
+            .into_iter()
+            .map(|m| (self.prepare_mesh(m.mesh), m.material.map(|i| materials[i])))
+            .collect();
+        PreparedModel {
+            meshes: instances
+                .into_iter()
+                .map(|(index, transform)| PreparedModelMesh {
+                    mesh: meshes[index].0,
+                    material: meshes[index].1,
+                    transform,
+                })
+                .collect(),
+            skinned_meshes: skinned_instances
+                .into_iter()
+                .map(|(index, skin)| PreparedSkinnedMesh {
+                    mesh: skinned_meshes[index].0,
+                    material: skinned_meshes[index].1,
+                    skin,
+                })
+                .collect(),
+            skins: model.skins,
+        }
+    }
+
+    fn bind_material(&mut self, display: &Display, material: Material) -> BoundMaterial {
+        let layout = self.bind_group_layout(display.device(), BindingType::Material);
+        let textures = material
+            .textures()
+            .map(|texture| texture.map(|t| self.get_texture(t)));
+        let [albedo, normal, metallic_roughness, occlusion, emissive] = textures;
+        let white = || self.get_texture(self.default_texture);
+        BoundMaterial::new(
+            display.device(),
+            display.queue(),
+            &layout,
+            material,
+            [
+                albedo.unwrap_or_else(white),
+                normal.unwrap_or_else(|| self.get_texture(self.default_normal_texture)),
+                metallic_roughness.unwrap_or_else(white),
+                occlusion.unwrap_or_else(white),
+                emissive.unwrap_or_else(white),
+            ],
+        )
+    }
+
+    pub fn load_material(&mut self, display: &Display, material: Material) -> MaterialRef {
+        let bound = self.bind_material(display, material);
+        self.material_manager.insert(bound)
+    }
+
+    pub fn get_material(&self, material: impl Into<Option<MaterialRef>>) -> &BoundMaterial {
+        self.material_manager
+            .get(material.into().unwrap_or(self.default_material))
+            .unwrap()
+    }
+
+    /// Replaces a material, rebinding its textures.
+    pub fn replace_material(
+        &mut self,
+        display: &Display,
+        material_ref: MaterialRef,
+        value: Material,
+    ) {
+        let bound = self.bind_material(display, value);
+        *self.material_manager.get_mut(material_ref).unwrap() = bound;
+    }
+
+    /// Updates the factors of a material without rebinding its textures.
+    pub fn update_material_factors(
+        &mut self,
+        display: &Display,
+        material_ref: MaterialRef,
+        value: &Material,
+    ) {
+        self.material_manager
+            .get_mut(material_ref)
+            .unwrap()
+            .update_factors(display.queue(), value);
+    }
+
+    pub fn default_material(&self) -> MaterialRef {
+        self.default_material
+    }
+
     pub fn prepare_mesh<V: VertexData>(&mut self, mesh: Mesh<V>) -> MeshRef<V> {
         self.mesh_manager.insert(mesh.inner).into()
     }
 
+    /// Registers an empty mesh to be filled with `update_mesh`, with room for
+    /// the given number of vertices and u16 indices before it has to grow.
+    pub fn create_dynamic_mesh<V: VertexData>(
+        &mut self,
+        display: &Display,
+        vertex_capacity: usize,
+        index_capacity: usize,
+    ) -> MeshRef<V> {
+        let mesh =
+            UntypedMesh::dynamic::<V, u16>(display.device(), vertex_capacity, index_capacity);
+        self.mesh_manager.insert(mesh).into()
+    }
+
+    /// Replaces the geometry of a mesh in place, growing its buffers when
+    /// needed. Like other buffer writes it applies to the whole frame, so
+    /// update a mesh at most once per frame, before recording passes drawing
+    /// it.
+    pub fn update_mesh<V: VertexData, I: MeshIndex>(
+        &mut self,
+        display: &Display,
+        mesh: MeshRef<V>,
+        vertices: &[V],
+        indices: &[I],
+    ) {
+        self.mesh_manager
+            .get_mut(mesh.raw())
+            .unwrap()
+            .update(display, vertices, indices);
+    }
+
     pub fn default_texture(&self) -> TextureRef {
         self.default_texture
     }
+
+    /// Draw stats of the passes in the last completed frame.
+    pub fn draw_stats(&self) -> &FrameDrawStats {
+        &self.last_frame_draw_stats
+    }
+}
+
+/// How a `RenderPass` submits the instances given to `draw_instance`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DrawOrder {
+    /// Draw in submission order, starting a new batch whenever the pipeline,
+    /// mesh, material or texture changes.
+    ///
+    /// This is the default as 2D passes rely on it to draw over what came
+    /// before without a depth test, e.g. text over sprites over a fullscreen
+    /// quad, and passes of a single fullscreen draw have nothing to sort.
+    #[default]
+    Immediate,
+    /// Queue instances until the pass is flushed, then draw opaque instances
+    /// grouped by pipeline, material, texture and mesh and front to back
+    /// within a group, followed by transparent ones from back to front. Used
+    /// by the 3D geometry passes.
+    ///
+    /// Anything set directly on the underlying `wgpu::RenderPass` applies to
+    /// all queued draws, so call `flush` before changing bindings mid-pass.
+    Sorted,
+}
+
+struct QueuedDraw {
+    pipeline: Option<RawPipelineRef>,
+    material: Option<MaterialRef>,
+    texture: Option<TextureRef>,
+    mesh: RawMeshRef,
+    transparent: bool,
+    depth: f32,
+    bytes: Range<usize>,
+}
+
+impl QueuedDraw {
+    /// Opaque draws first, grouped to minimize state changes and front to
+    /// back within a group so hidden fragments fail the depth test early,
+    /// then transparent ones from back to front. The camera looks down -z,
+    /// so closer draws have a larger depth.
+    fn draw_order(a: &Self, b: &Self) -> std::cmp::Ordering {
+        a.transparent.cmp(&b.transparent).then_with(|| {
+            if a.transparent {
+                a.depth.total_cmp(&b.depth)
+            } else {
+                (a.pipeline, a.material, a.texture, a.mesh)
+                    .cmp(&(b.pipeline, b.material, b.texture, b.mesh))
+                    .then_with(|| b.depth.total_cmp(&a.depth))
+            }
+        })
+    }
 }
 
 pub struct RenderPass<'a, 'p> {
@@ -521,7 +945,17 @@ pub struct RenderPass<'a, 'p> {
     active_mesh: Option<RawMeshRef>,
     active_pipeline: Option<RawPipelineRef>,
     active_texture: Option<TextureRef>,
-    current_draw_range: Range<u32>,
+    /// `None` until a pipeline binding materials is active, the material
+    /// group may hold something else before that.
+    active_material: Option<Option<MaterialRef>>,
+    batch_instances: u32,
+    batch_stride: usize,
+    stats: DrawStats,
+
+    draw_order: DrawOrder,
+    view: Mat4,
+    queued_draws: Vec<QueuedDraw>,
+    queued_instance_bytes: Vec<u8>,
 }
 
 impl<'a, 'p> Deref for RenderPass<'a, 'p> {
@@ -542,6 +976,7 @@ impl<'a, 'p> RenderPass<'a, 'p> {
     const TEXTURE_BIND_GROUP_INDEX: u32 = 0;
     const GLOBAL_UNIFORMS_BIND_GROUP_INDEX: u32 = 1;
     pub const VIEW_PROJECTION_UNIFORMS_BIND_GROUP_INDEX: u32 = 2;
+    pub const MATERIAL_BIND_GROUP_INDEX: u32 = 3;
 
     pub fn new(
         render_state: &'a mut RenderState,
@@ -555,7 +990,14 @@ impl<'a, 'p> RenderPass<'a, 'p> {
             active_mesh: None,
             active_pipeline: None,
             active_texture: None,
-            current_draw_range: 0..0,
+            active_material: None,
+            batch_instances: 0,
+            batch_stride: 0,
+            stats: Default::default(),
+            draw_order: DrawOrder::default(),
+            view: Mat4::IDENTITY,
+            queued_draws: vec![],
+            queued_instance_bytes: vec![],
         }
     }
 
@@ -564,17 +1006,18 @@ impl<'a, 'p> RenderPass<'a, 'p> {
     }
 
     pub(super) fn set_active_pipeline_raw(&mut self, raw: Option<RawPipelineRef>) {
-        if raw == self.active_pipeline {
+        let raw = raw.unwrap_or(self.render_state.default_pipeline.raw());
+        if Some(raw) == self.active_pipeline {
             return;
         }
 
-        let p = self
-            .render_state
-            .pipelines
-            .get(raw.unwrap_or(self.render_state.default_pipeline.raw()))
-            .unwrap();
-        self.raw_pass.set_pipeline(p);
-        self.active_pipeline = raw;
+        let p = self.render_state.pipelines.get(raw).unwrap();
+        self.raw_pass.set_pipeline(&p.pipeline);
+        if !p.binds_material {
+            self.active_material = None;
+        }
+        self.active_pipeline = Some(raw);
+        self.stats.pipeline_switches += 1;
     }
 
     pub fn set_active_mesh<V: VertexData>(&mut self, mesh: MeshRef<V>) {
@@ -582,6 +1025,9 @@ impl<'a, 'p> RenderPass<'a, 'p> {
     }
 
     pub(super) fn set_active_mesh_raw(&mut self, raw: RawMeshRef) {
+        if self.active_mesh != Some(raw) {
+            self.stats.mesh_binds += 1;
+        }
         self.active_mesh = Some(raw);
     }
 
@@ -594,6 +1040,19 @@ impl<'a, 'p> RenderPass<'a, 'p> {
                 .deref(),
             &[],
         );
+        self.stats.texture_binds += 1;
+    }
+
+    pub fn bind_material(&mut self, material: impl Into<Option<MaterialRef>>) {
+        self.raw_pass.set_bind_group(
+            Self::MATERIAL_BIND_GROUP_INDEX,
+            self.render_state
+                .get_material(material.into())
+                .bind_group()
+                .deref(),
+            &[],
+        );
+        self.stats.material_binds += 1;
     }
 
     pub fn bind_texture_data(&mut self, texture_data: &BoundTexture) {
@@ -602,6 +1061,12 @@ impl<'a, 'p> RenderPass<'a, 'p> {
             texture_data.bind_group().deref(),
             &[],
         );
+        self.stats.texture_binds += 1;
+    }
+
+    /// Counters for the draws recorded so far in this pass.
+    pub fn stats(&self) -> &DrawStats {
+        &self.stats
     }
 
     pub fn draw_raw_mesh_ex(
@@ -615,7 +1080,9 @@ impl<'a, 'p> RenderPass<'a, 'p> {
         self.raw_pass
             .set_vertex_buffer(0, mesh.vertex_buffer.slice(..));
         self.raw_pass
-            .set_index_buffer(mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint16);
+            .set_index_buffer(mesh.index_buffer.slice(..), mesh.index_format);
+        self.stats.draw_calls += 1;
+        self.stats.instances += instances.len() as u32;
         self.raw_pass.draw_indexed(
             mesh_indices.unwrap_or(0..mesh.num_indices),
             base_vertex,
@@ -627,28 +1094,111 @@ impl<'a, 'p> RenderPass<'a, 'p> {
         self.draw_raw_mesh_ex(mesh.raw(), 0, None, 0..1)
     }
 
+    pub fn draw_order(&self) -> DrawOrder {
+        self.draw_order
+    }
+
+    pub fn set_draw_order(&mut self, draw_order: DrawOrder) {
+        if draw_order != self.draw_order {
+            self.flush();
+            self.draw_order = draw_order;
+        }
+    }
+
     #[inline]
     pub fn draw_instance<V: VertexData, I: InstanceData>(
         &mut self,
         instance: &InstanceRenderData<V, I>,
     ) {
         let pipeline = instance.pipeline.map(|p| p.raw());
-        if pipeline != self.active_pipeline {
+        let mesh = instance.mesh.raw();
+        match self.draw_order {
+            DrawOrder::Immediate => self.submit_instance(
+                pipeline,
+                mesh,
+                instance.material,
+                instance.texture,
+                bytemuck::bytes_of(&instance.instance),
+            ),
+            DrawOrder::Sorted => {
+                let start = self.queued_instance_bytes.len();
+                self.queued_instance_bytes
+                    .extend_from_slice(bytemuck::bytes_of(&instance.instance));
+                self.queued_draws.push(QueuedDraw {
+                    pipeline,
+                    material: instance.material,
+                    texture: instance.texture,
+                    mesh,
+                    transparent: instance.is_transparent(),
+                    depth: (self.view * instance.sort_position().extend(1.0)).z,
+                    bytes: start..self.queued_instance_bytes.len(),
+                });
+            }
+        }
+    }
+
+    fn submit_instance(
+        &mut self,
+        pipeline: Option<RawPipelineRef>,
+        mesh: RawMeshRef,
+        material: Option<MaterialRef>,
+        texture: Option<TextureRef>,
+        instance_bytes: &[u8],
+    ) {
+        let pipeline = pipeline.unwrap_or(self.render_state.default_pipeline.raw());
+        if Some(pipeline) != self.active_pipeline {
             self.flush_draw_calls();
-            self.set_active_pipeline_raw(pipeline);
+            self.set_active_pipeline_raw(Some(pipeline));
         }
-        let mesh = instance.mesh.raw();
         if mesh != self.active_mesh.unwrap_or_default() {
             self.flush_draw_calls();
             self.set_active_mesh_raw(mesh);
         }
-        if instance.texture != self.active_texture {
+        let binds_material = self.render_state.pipelines[pipeline].binds_material;
+        if binds_material && Some(material) != self.active_material {
+            self.flush_draw_calls();
+            self.active_material = Some(material);
+            self.bind_material(material);
+        }
+        if texture != self.active_texture {
             self.flush_draw_calls();
-            self.active_texture = instance.texture;
-            self.bind_texture(instance.texture);
+            self.active_texture = texture;
+            self.bind_texture(texture);
         }
-        self.current_draw_range.end += 1;
-        self.render_state.instance_storage.add(&instance.instance);
+        // Batches have a single stride, so a different instance type needs
+        // a new one.
+        if instance_bytes.len() != self.batch_stride {
+            self.flush_draw_calls();
+            self.batch_stride = instance_bytes.len();
+        }
+        self.batch_instances += 1;
+        self.render_state.instance_storage.add_bytes(instance_bytes);
+    }
+
+    fn submit_queued_draws(&mut self) {
+        let mut draws = std::mem::take(&mut self.queued_draws);
+        let bytes = std::mem::take(&mut self.queued_instance_bytes);
+        draws.sort_by(QueuedDraw::draw_order);
+        for draw in &draws {
+            self.submit_instance(
+                draw.pipeline,
+                draw.mesh,
+                draw.material,
+                draw.texture,
+                &bytes[draw.bytes.clone()],
+            );
+        }
+        // Keep the allocations around for the next flush.
+        self.queued_draws = draws;
+        self.queued_draws.clear();
+        self.queued_instance_bytes = bytes;
+        self.queued_instance_bytes.clear();
+    }
+
+    /// Issues all pending draws. Happens automatically at the end of a pass.
+    pub fn flush(&mut self) {
+        self.submit_queued_draws();
+        self.flush_draw_calls();
     }
 
     #[inline]
@@ -673,6 +1223,7 @@ impl<'a, 'p> RenderPass<'a, 'p> {
                 subtexture,
             },
             texture,
+            material: None,
             pipeline: None,
         });
     }
@@ -715,29 +1266,87 @@ impl<'a, 'p> RenderPass<'a, 'p> {
                 },
                 mesh: self.render_state.quad_mesh,
                 texture: Some(font.texture()),
+                material: None,
                 pipeline: Some(self.render_state.text_pipeline),
             });
         }
     }
 
     fn flush_draw_calls(&mut self) {
-        if self.current_draw_range.is_empty() {
+        if self.batch_instances == 0 {
             return;
         }
-        if self.active_pipeline.is_none() {
-            self.set_active_pipeline_raw(Some(self.render_state.default_pipeline.raw()));
+        let storage = &mut self.render_state.instance_storage;
+        let batch = storage.end_batch(self.display, self.batch_stride);
+        if batch.reallocated {
+            self.stats.instance_buffer_reallocations += 1;
+        }
+        // Zero sized instance data has nothing to bind.
+        if !batch.range.is_empty() {
+            self.raw_pass
+                .set_vertex_buffer(1, storage.buffer().slice(batch.range));
         }
-        self.render_state
-            .instance_storage
-            .update_buffer(self.display);
-        self.raw_pass
-            .set_vertex_buffer(1, self.render_state.instance_storage.buffer().slice(..));
         self.draw_raw_mesh_ex(
             self.active_mesh.expect("no active mesh"),
             0,
             None,
-            self.current_draw_range.clone(),
+            0..self.batch_instances,
         );
-        self.current_draw_range.start = self.current_draw_range.end
+        self.batch_instances = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use slotmap::SlotMap;
+
+    use super::*;
+
+    #[test]
+    fn test_queued_draw_order() {
+        let mut pipelines = SlotMap::<RawPipelineRef, ()>::with_key();
+        let mut materials = SlotMap::<MaterialRef, ()>::with_key();
+        let mut meshes = SlotMap::<RawMeshRef, ()>::with_key();
+        let (pipeline_a, pipeline_b) = (pipelines.insert(()), pipelines.insert(()));
+        let (material_a, material_b) = (materials.insert(()), materials.insert(()));
+        let mesh = meshes.insert(());
+        let draw = |i: usize, pipeline, material, transparent, depth| QueuedDraw {
+            pipeline: Some(pipeline),
+            material: Some(material),
+            texture: None,
+            mesh,
+            transparent,
+            depth,
+            bytes: i..i + 1,
+        };
+        let mut draws = vec![
+            draw(0, pipeline_a, material_a, true, -2.0),
+            draw(1, pipeline_b, material_a, false, -8.0),
+            draw(2, pipeline_a, material_b, false, -1.0),
+            draw(3, pipeline_a, material_a, false, -6.0),
+            draw(4, pipeline_a, material_a, true, -9.0),
+            draw(5, pipeline_b, material_a, false, -3.0),
+            draw(6, pipeline_a, material_a, false, -4.0),
+            draw(7, pipeline_a, material_a, true, -5.0),
+        ];
+        draws.sort_by(QueuedDraw::draw_order);
+        let order: Vec<_> = draws.iter().map(|d| d.bytes.start).collect();
+        // Opaque grouped by pipeline then material, front to back in each
+        // group, then transparent back to front.
+        assert_eq!(order, [6, 3, 2, 5, 1, 4, 7, 0]);
+    }
+
+    #[test]
+    fn test_display_framebuffer_resize() {
+        let (mut display, mut state) = RenderState::headless(Point::new(4, 4));
+        let framebuffer = state.display_framebuffer(&display).unwrap();
+
+        display.resize(winit::dpi::PhysicalSize::new(8, 6));
+        let resized = state.display_framebuffer(&display).unwrap();
+        assert_eq!(resized.color, framebuffer.color);
+        assert_eq!(resized.size_pixels(), Point::new(8, 6));
+        for texture in [resized.color, resized.depth.unwrap()] {
+            assert_eq!(state.get_texture(texture).size_pixels(), Point::new(8, 6));
+        }
     }
 }
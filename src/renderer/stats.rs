@@ -0,0 +1,186 @@
+use std::ops::AddAssign;
+
+/// Counters collected by a `RenderPass`, mostly useful to see how well draws
+/// are being batched.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DrawStats {
+    pub draw_calls: u32,
+    pub instances: u32,
+    pub pipeline_switches: u32,
+    pub texture_binds: u32,
+    pub material_binds: u32,
+    pub mesh_binds: u32,
+    pub instance_bytes_uploaded: u64,
+    /// Times the instance buffer had to be reallocated to fit more instances.
+    pub instance_buffer_reallocations: u32,
+}
+
+impl DrawStats {
+    /// Average number of instances per draw call.
+    pub fn instances_per_draw(&self) -> f32 {
+        if self.draw_calls == 0 {
+            0.0
+        } else {
+            self.instances as f32 / self.draw_calls as f32
+        }
+    }
+}
+
+impl AddAssign for DrawStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.draw_calls += rhs.draw_calls;
+        self.instances += rhs.instances;
+        self.pipeline_switches += rhs.pipeline_switches;
+        self.texture_binds += rhs.texture_binds;
+        self.material_binds += rhs.material_binds;
+        self.mesh_binds += rhs.mesh_binds;
+        self.instance_bytes_uploaded += rhs.instance_bytes_uploaded;
+        self.instance_buffer_reallocations += rhs.instance_buffer_reallocations;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PassDrawStats {
+    pub name: String,
+    pub stats: DrawStats,
+}
+
+/// Draw stats of every pass run through `RenderState::render_pass` in a frame.
+#[derive(Debug, Default, Clone)]
+pub struct FrameDrawStats {
+    pub passes: Vec<PassDrawStats>,
+}
+
+impl FrameDrawStats {
+    pub fn total(&self) -> DrawStats {
+        let mut total = DrawStats::default();
+        for pass in &self.passes {
+            total += pass.stats;
+        }
+        total
+    }
+
+    /// Stats of the first pass called `name`.
+    pub fn pass(&self, name: &str) -> Option<&DrawStats> {
+        self.passes
+            .iter()
+            .find(|p| p.name == name)
+            .map(|p| &p.stats)
+    }
+
+    pub fn debug_ui(&self, ui: &mut egui::Ui) {
+        egui::Grid::new("draw_stats")
+            .num_columns(8)
+            .striped(true)
+            .show(ui, |ui| {
+                for header in [
+                    "pass",
+                    "draws",
+                    "instances",
+                    "pipelines",
+                    "textures",
+                    "materials",
+                    "meshes",
+                    "upload",
+                ] {
+                    ui.label(header);
+                }
+                ui.end_row();
+                let total = self.total();
+                let rows = self
+                    .passes
+                    .iter()
+                    .map(|p| (p.name.as_str(), &p.stats))
+                    .chain([("total", &total)]);
+                for (name, stats) in rows {
+                    ui.label(name);
+                    ui.label(stats.draw_calls.to_string());
+                    ui.label(stats.instances.to_string());
+                    ui.label(stats.pipeline_switches.to_string());
+                    ui.label(stats.texture_binds.to_string());
+                    ui.label(stats.material_binds.to_string());
+                    ui.label(stats.mesh_binds.to_string());
+                    ui.label(format!(
+                        "{:.1}KiB ({} realloc)",
+                        stats.instance_bytes_uploaded as f32 / 1024.0,
+                        stats.instance_buffer_reallocations
+                    ));
+                    ui.end_row();
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        geom::Point,
+        renderer::{
+            instance::InstanceRenderData, state::ViewProjectionUniforms, BasicInstanceData,
+            RenderState, RenderTarget, TextureBuilder,
+        },
+    };
+
+    #[test]
+    fn test_frame_draw_stats() {
+        let (display, mut state) = RenderState::headless(Point::new(4, 4));
+        let flat = display
+            .device()
+            .create_shader_module(wgpu::include_wgsl!("../../res/shaders/flat.wgsl"));
+        let framebuffer = state.display_framebuffer(&display).unwrap();
+        let (color, depth) = (framebuffer.color, framebuffer.depth.unwrap());
+        let other_pipeline = state
+            .pipeline_builder()
+            .with_label("Other Pipeline")
+            .build(display.device(), &flat);
+        let other_texture = state.load_texture(
+            &display,
+            TextureBuilder::labeled("other").build(display.device(), Point::new(1, 1)),
+        );
+        let quad = |texture, pipeline| InstanceRenderData {
+            mesh: state.quad_mesh(),
+            instance: BasicInstanceData::default(),
+            texture,
+            material: None,
+            pipeline,
+        };
+        let draws = [
+            // Asking for the default pipeline explicitly doesn't break the
+            // batch.
+            quad(None, None),
+            quad(None, Some(state.default_pipeline())),
+            quad(None, None),
+            quad(Some(other_texture), None),
+            quad(Some(other_texture), None),
+            quad(Some(other_texture), Some(other_pipeline)),
+        ];
+
+        state
+            .render_pass(
+                &display,
+                "Stats Pass",
+                &[RenderTarget::TextureRef(color)],
+                Some(RenderTarget::TextureRef(depth)),
+                &ViewProjectionUniforms::default(),
+                |r| {
+                    for draw in &draws {
+                        r.draw_instance(draw);
+                    }
+                },
+            )
+            .submit();
+        state.after_frame(&display);
+
+        let stats = *state.draw_stats().pass("Stats Pass").unwrap();
+        assert_eq!(stats.draw_calls, 3);
+        assert_eq!(stats.instances, 6);
+        assert_eq!(stats.instances_per_draw(), 2.0);
+        assert_eq!(stats.pipeline_switches, 2);
+        assert_eq!(stats.mesh_binds, 1);
+        // The default texture is bound when the pass starts.
+        assert_eq!(stats.texture_binds, 2);
+        // Neither pipeline has a material bind group.
+        assert_eq!(stats.material_binds, 0);
+        assert_eq!(state.draw_stats().total(), stats);
+    }
+}
@@ -0,0 +1,252 @@
+//! MikkTSpace tangent generation for normal mapping.
+
+use std::collections::HashMap;
+
+use glam::{Vec2, Vec3, Vec4};
+
+use crate::geom::{ModelVertexData, SkinnedVertexData};
+
+/// Vertex data with a tangent that can be generated from its positions,
+/// normals and texture coordinates.
+pub trait TangentVertex {
+    fn position(&self) -> Vec3;
+    fn normal(&self) -> Vec3;
+    fn tex_coords(&self) -> Vec2;
+    fn set_tangent(&mut self, tangent: Vec4);
+}
+
+impl TangentVertex for ModelVertexData {
+    fn position(&self) -> Vec3 {
+        self.position.truncate()
+    }
+
+    fn normal(&self) -> Vec3 {
+        self.normal
+    }
+
+    fn tex_coords(&self) -> Vec2 {
+        self.tex_coords
+    }
+
+    fn set_tangent(&mut self, tangent: Vec4) {
+        self.tangent = tangent;
+    }
+}
+
+impl TangentVertex for SkinnedVertexData {
+    fn position(&self) -> Vec3 {
+        self.position.truncate()
+    }
+
+    fn normal(&self) -> Vec3 {
+        self.normal
+    }
+
+    fn tex_coords(&self) -> Vec2 {
+        self.tex_coords
+    }
+
+    fn set_tangent(&mut self, tangent: Vec4) {
+        self.tangent = tangent;
+    }
+}
+
+/// Unindexed triangle list, three vertices per face.
+struct Geometry<'a, V> {
+    vertices: &'a mut [V],
+}
+
+impl<V: TangentVertex> bevy_mikktspace::Geometry for Geometry<'_, V> {
+    fn num_faces(&self) -> usize {
+        self.vertices.len() / 3
+    }
+
+    fn num_vertices_of_face(&self, _face: usize) -> usize {
+        3
+    }
+
+    fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.vertices[face * 3 + vert].position().to_array()
+    }
+
+    fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.vertices[face * 3 + vert].normal().to_array()
+    }
+
+    fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+        self.vertices[face * 3 + vert].tex_coords().to_array()
+    }
+
+    fn set_tangent_encoded(&mut self, tangent: [f32; 4], face: usize, vert: usize) {
+        self.vertices[face * 3 + vert].set_tangent(Vec4::from(tangent));
+    }
+}
+
+/// Computes MikkTSpace tangents for a triangle list with counter-clockwise
+/// faces, so before rewinding them for the pipelines. The `w` of each tangent
+/// is the handedness of the bitangent, `cross(normal, tangent.xyz) * w`, which
+/// points along increasing `tex_coords.y`.
+///
+/// Tangents are generated per face corner, then corners that are identical in
+/// every attribute are welded back together, so vertices shared by faces with
+/// different tangents get split. `vertices` and `indices` are replaced by the
+/// welded mesh. Returns false and leaves them untouched if no tangents could
+/// be generated.
+pub fn generate_tangents<V: TangentVertex + bytemuck::Pod>(
+    vertices: &mut Vec<V>,
+    indices: &mut Vec<u32>,
+) -> bool {
+    let mut corners: Vec<V> = indices.iter().map(|&i| vertices[i as usize]).collect();
+    if !bevy_mikktspace::generate_tangents(&mut Geometry {
+        vertices: &mut corners,
+    }) {
+        return false;
+    }
+    let mut welded = Vec::new();
+    let mut lookup = HashMap::new();
+    *indices = corners
+        .iter()
+        .map(|corner| {
+            *lookup.entry(bytemuck::bytes_of(corner)).or_insert_with(|| {
+                welded.push(*corner);
+                welded.len() as u32 - 1
+            })
+        })
+        .collect();
+    *vertices = welded;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{vec2, vec3, vec4};
+
+    use super::*;
+    use crate::{
+        geom::{cube, Point},
+        renderer::{
+            test_scene::{brightness, spot_light, LitScene},
+            tonemapping::Tonemapper,
+            TextureBuilder,
+        },
+    };
+
+    #[test]
+    fn test_generate_tangents() {
+        // A quad facing +z with texture coordinates going right and down, and
+        // a mirrored copy of it.
+        let quad = |mirrored: bool| {
+            [(-1.0, 1.0), (1.0, 1.0), (1.0, -1.0), (-1.0, -1.0)].map(|(x, y)| {
+                let u = if mirrored { -x } else { x };
+                ModelVertexData {
+                    position: vec4(x, y, 0.0, 1.0),
+                    tex_coords: vec2(0.5 + 0.5 * u, 0.5 - 0.5 * y),
+                    normal: Vec3::Z,
+                    ..Default::default()
+                }
+            })
+        };
+        for (mirrored, expected) in [
+            (false, vec4(1.0, 0.0, 0.0, -1.0)),
+            (true, vec4(-1.0, 0.0, 0.0, 1.0)),
+        ] {
+            let mut vertices = quad(mirrored).to_vec();
+            let mut indices = vec![0, 2, 1, 0, 3, 2];
+            assert!(generate_tangents(&mut vertices, &mut indices));
+            // Nothing to split.
+            assert_eq!(vertices.len(), 4);
+            assert_eq!(indices, [0, 1, 2, 0, 3, 1]);
+            for v in vertices {
+                assert!(v.tangent.abs_diff_eq(expected, 1e-6), "{}", v.tangent);
+                let bitangent = v.normal.cross(v.tangent.truncate()) * v.tangent.w;
+                assert!(bitangent.abs_diff_eq(vec3(0.0, -1.0, 0.0), 1e-6));
+            }
+        }
+
+        // The cube's tangents are written out by hand, its faces are wound
+        // clockwise.
+        let mut vertices = cube::VERTICES.to_vec();
+        let cube_indices: Vec<u32> = cube::INDICES
+            .chunks_exact(3)
+            .flat_map(|x| [x[0], x[2], x[1]])
+            .map(u32::from)
+            .collect();
+        let mut indices = cube_indices.clone();
+        assert!(generate_tangents(&mut vertices, &mut indices));
+        for (&i, &original) in indices.iter().zip(&cube_indices) {
+            let expected = cube::VERTICES[original as usize].tangent;
+            assert!(vertices[i as usize].tangent.abs_diff_eq(expected, 1e-6));
+        }
+    }
+
+    #[test]
+    fn test_mirrored_seam() {
+        // Two quads facing +z sharing the vertices at x = 0, with texture
+        // coordinates mirrored there.
+        let mut vertices: Vec<_> = [1.0, -1.0]
+            .into_iter()
+            .flat_map(|y| {
+                [-1.0, 0.0, 1.0].map(|x: f32| ModelVertexData {
+                    position: vec4(x, y, 0.0, 1.0),
+                    tex_coords: vec2(x.abs(), 0.5 - 0.5 * y),
+                    normal: Vec3::Z,
+                    ..Default::default()
+                })
+            })
+            .collect();
+        let mut indices = vec![0, 4, 1, 0, 3, 4, 1, 5, 2, 1, 4, 5];
+        assert!(generate_tangents(&mut vertices, &mut indices));
+
+        // The seam vertices are split between the halves.
+        assert_eq!(vertices.len(), 8);
+        for face in indices.chunks_exact(3) {
+            let face: Vec<_> = face.iter().map(|&i| vertices[i as usize]).collect();
+            let centroid: f32 = face.iter().map(|v| v.position.x).sum();
+            let expected = if centroid < 0.0 {
+                vec4(-1.0, 0.0, 0.0, 1.0)
+            } else {
+                vec4(1.0, 0.0, 0.0, -1.0)
+            };
+            for v in face {
+                assert!(v.tangent.abs_diff_eq(expected, 1e-6), "{}", v.tangent);
+            }
+        }
+    }
+
+    #[test]
+    fn test_normal_map() {
+        // Brightness along a row across the cube's top face, which is lit
+        // from one side.
+        let row = |normal_map: bool| {
+            let mut scene = LitScene::new(spot_light());
+            if normal_map {
+                // Ridges running along the texture's v axis
+                let size = Point::new(16, 16);
+                let data: Vec<u8> = (0..size.x * size.y)
+                    .flat_map(|i| {
+                        let u = (i % size.x) as f32 / size.x as f32;
+                        let n = vec3(0.6 * (4.0 * std::f32::consts::TAU * u).sin(), 0.0, 1.0)
+                            .normalize();
+                        let encode = |c: f32| (255.0 * (0.5 + 0.5 * c)).round() as u8;
+                        [encode(n.x), encode(n.y), encode(n.z), 255]
+                    })
+                    .collect();
+                let normal_texture = TextureBuilder::labeled("ridges")
+                    .with_format(wgpu::TextureFormat::Rgba8Unorm)
+                    .from_raw_bytes(scene.display.device(), scene.display.queue(), &data, size);
+                scene.cube_material.normal_texture =
+                    Some(scene.state.load_texture(&scene.display, normal_texture));
+            }
+            scene.render();
+            let color = scene.capture_color(Tonemapper::None);
+            (0..19)
+                .map(|i| brightness(scene.pixel_at(&color, vec3(-0.9 + 0.1 * i as f32, 1.0, 0.0))))
+                .collect::<Vec<_>>()
+        };
+        // The flat face only gets darker away from the light, the ridges
+        // turn some texels back towards it.
+        let rises = |row: Vec<u32>| row.windows(2).any(|w| w[1] > w[0] + 15);
+        assert!(!rises(row(false)));
+        assert!(rises(row(true)));
+    }
+}
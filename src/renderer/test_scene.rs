@@ -0,0 +1,240 @@
+//! A small lit scene shared by the renderer's tests: passes render it and
+//! assert on the pixels where a feature is expected to show.
+
+use std::time::Duration;
+
+use bytemuck::Zeroable;
+use glam::{vec3, Vec3};
+use image::{Rgba, RgbaImage};
+
+use crate::{
+    camera::Camera,
+    geom::Point,
+    renderer::{
+        forward::ForwardGeometryPass,
+        instance::InstanceRenderData,
+        lighting::{Light, LightKind},
+        mesh::LoadMesh,
+        shader_type::GlobalUniforms,
+        shadow_mapping::ShadowMappingPass,
+        skinning::SkinnedScene,
+        state::ViewProjectionUniforms,
+        tonemapping::{Tonemapper, TonemappingPass},
+        BasicInstanceData, Display, InstanceDataWithNormalMatrix, Material, RenderState,
+        TextureBuilder, TextureRef,
+    },
+    transform::{Transform, Transform3D},
+};
+
+/// Height of the floor's top surface.
+pub const FLOOR_Y: f32 = -1.25;
+
+/// A unit cube with `cube_material` on a floor, lit by `light` with shadows
+/// and by `clustered_lights` without. Tests add to the scene through the
+/// fields before calling `render`.
+pub struct LitScene {
+    pub display: Display,
+    pub state: RenderState,
+    pub light: Light,
+    pub clustered_lights: Vec<Light>,
+    pub cube_material: Material,
+    pub camera: Camera,
+    pub skinned_scene: SkinnedScene,
+    pub forward_pass: ForwardGeometryPass,
+    pub shadow_pass: ShadowMappingPass,
+}
+
+impl LitScene {
+    pub fn new(light: Light) -> Self {
+        let size = Point::new(128, 96);
+        let (display, mut state) = RenderState::headless(size);
+        state.global_uniforms.update(
+            display.queue(),
+            GlobalUniforms {
+                screen_size: size.as_vec2(),
+                ..Zeroable::zeroed()
+            },
+        );
+        let skinned_scene = SkinnedScene::new(&display);
+        let shadow_pass = ShadowMappingPass::new(&mut state, &display);
+        let forward_pass = ForwardGeometryPass::new(
+            &mut state,
+            &display,
+            size,
+            shadow_pass.shadow_map_texture(),
+            shadow_pass.point_shadow_map_texture(),
+        );
+        let mut camera = Camera::new(vec3(0.0, 3.0, 6.0), size.x as f32 / size.y as f32);
+        // Pitched down from the default to look at the cube
+        camera.update_angle(0.0, 15.0f32.to_radians());
+        Self {
+            display,
+            state,
+            light,
+            clustered_lights: vec![],
+            cube_material: Material::default(),
+            camera,
+            skinned_scene,
+            forward_pass,
+            shadow_pass,
+        }
+    }
+
+    pub fn size(&self) -> Point<u32> {
+        self.state
+            .get_texture(self.forward_pass.color_target)
+            .size_pixels()
+    }
+
+    pub fn render(&mut self) {
+        let camera = self.camera;
+        let view_proj = ViewProjectionUniforms::for_camera(&camera);
+        let Self {
+            display,
+            state,
+            forward_pass,
+            shadow_pass,
+            skinned_scene,
+            ..
+        } = self;
+        let cube = state.prepare_mesh(display.device().load_cube_mesh());
+        let cube_material = state.load_material(display, self.cube_material.clone());
+        let scene: Vec<_> = [
+            (Transform3D::default(), Some(cube_material)),
+            (
+                Transform3D {
+                    position: vec3(0.0, -1.5, 0.0),
+                    scale: vec3(8.0, 0.25, 8.0),
+                    ..Default::default()
+                },
+                None,
+            ),
+        ]
+        .iter()
+        .map(|&(t, material)| InstanceRenderData {
+            mesh: cube,
+            instance: InstanceDataWithNormalMatrix::from_basic(
+                BasicInstanceData {
+                    transform: t.as_mat4(),
+                    ..Default::default()
+                },
+                view_proj.view,
+            ),
+            texture: None,
+            material,
+            pipeline: None,
+        })
+        .collect();
+
+        forward_pass
+            .lights_uniform
+            .update_with(display.queue(), |u| {
+                u.lights = vec![self.light];
+                u.view_frustum = camera.frustum();
+            });
+        skinned_scene.upload(display);
+        forward_pass.depth_prepass(state, display, &view_proj, &scene, skinned_scene);
+        shadow_pass.run(
+            state,
+            display,
+            &forward_pass.lights_uniform,
+            &scene,
+            skinned_scene,
+        );
+        forward_pass.light_clusters.lights = self.clustered_lights.clone();
+        forward_pass.light_clusters.update(display, &camera);
+        let occlusion_map = state.default_texture();
+        forward_pass.run(
+            state,
+            display,
+            &view_proj,
+            &scene,
+            skinned_scene,
+            occlusion_map,
+        );
+    }
+
+    /// Tonemaps the HDR color target to 8 bit sRGB, at the default manual
+    /// exposure.
+    pub fn capture_color(&mut self, tonemapper: Tonemapper) -> RgbaImage {
+        self.capture_tonemapped(self.forward_pass.color_target, tonemapper)
+    }
+
+    pub fn capture_tonemapped(&mut self, hdr: TextureRef, tonemapper: Tonemapper) -> RgbaImage {
+        let (display, state) = (&mut self.display, &mut self.state);
+        let size = state.get_texture(hdr).size_pixels();
+        let mut tonemapping_pass =
+            TonemappingPass::new(state, display, size, TextureBuilder::DEFAULT_RENDER_FORMAT);
+        tonemapping_pass.tonemapper = tonemapper;
+        let output = tonemapping_pass.run(state, display, hdr, Duration::ZERO);
+        state.capture_texture(display, output).unwrap()
+    }
+
+    /// The pixel of `image` that `position` projects to.
+    #[track_caller]
+    pub fn pixel_at(&self, image: &RgbaImage, position: Vec3) -> Rgba<u8> {
+        let camera = &self.camera;
+        let clip = camera.perspective_matrix() * camera.view_matrix() * position.extend(1.0);
+        let ndc = clip.truncate() / clip.w;
+        let x = (0.5 + 0.5 * ndc.x) * image.width() as f32;
+        let y = (0.5 - 0.5 * ndc.y) * image.height() as f32;
+        assert!(
+            ndc.x.abs() < 1.0 && ndc.y.abs() < 1.0,
+            "{} is outside the view",
+            position
+        );
+        *image.get_pixel(x as u32, y as u32)
+    }
+}
+
+/// Sum of the color channels.
+pub fn brightness(pixel: Rgba<u8>) -> u32 {
+    pixel.0[..3].iter().map(|&c| c as u32).sum()
+}
+
+pub fn spot_light() -> Light {
+    Light::from(LightKind::Spot {
+        position: vec3(2.0, 6.0, 2.0),
+        direction: -Vec3::ONE,
+        fov_degrees: 60.0,
+        reach: 20.0,
+    })
+}
+
+pub fn directional_light() -> Light {
+    Light::from(LightKind::Directional {
+        theta: -60.0,
+        phi: 135.0,
+    })
+}
+
+/// A different color for each cube face, with the top of each face brighter.
+pub fn colored_cube_faces() -> [RgbaImage; 6] {
+    let colors = [
+        [255, 0, 0],
+        [0, 255, 255],
+        [0, 255, 0],
+        [255, 0, 255],
+        [0, 0, 255],
+        [255, 255, 0],
+    ];
+    colors.map(|[r, g, b]| {
+        RgbaImage::from_fn(8, 8, |_, y| {
+            let shade = |c: u8| (c as u32 * (16 - y) / 16) as u8;
+            Rgba([shade(r), shade(g), shade(b), 255])
+        })
+    })
+}
+
+/// A cubemap of `colored_cube_faces`.
+pub fn load_colored_cubemap(scene: &mut LitScene, label: &str) -> TextureRef {
+    let cubemap = TextureBuilder::cubemap()
+        .with_label(label)
+        .with_filter_mode(wgpu::FilterMode::Linear)
+        .from_cube_images(
+            scene.display.device(),
+            scene.display.queue(),
+            &colored_cube_faces(),
+        );
+    scene.state.load_texture(&scene.display, cubemap)
+}
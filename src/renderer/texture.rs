@@ -1,8 +1,12 @@
+use std::sync::Arc;
+
 use image::{EncodableLayout, RgbaImage};
 
 use crate::geom::Point;
 use crate::renderer::Bindable;
 
+use super::cubemap::EquirectangularImage;
+use super::mipmaps::{mip_level_count, MipmapGenerator};
 use super::state::BindingType;
 
 slotmap::new_key_type! {
@@ -20,7 +24,11 @@ pub struct TextureBuilder<'a> {
     compare_func: Option<wgpu::CompareFunction>,
     usage: Option<wgpu::TextureUsages>,
     layers: Option<u32>,
+    view_dimension: Option<wgpu::TextureViewDimension>,
     sampler_border_color: Option<wgpu::SamplerBorderColor>,
+    mipmaps: bool,
+    anisotropy_clamp: Option<u16>,
+    lod_clamp: Option<(f32, f32)>,
     // TODO: more
 }
 
@@ -48,6 +56,16 @@ impl<'a> TextureBuilder<'a> {
         Self::default().with_format(Self::DEFAULT_RENDER_FORMAT)
     }
 
+    /// Six layers viewed as a cube, in wgpu's face order +X, -X, +Y, -Y, +Z,
+    /// -Z.
+    pub fn cubemap() -> Self {
+        Self {
+            layers: Some(6),
+            view_dimension: Some(wgpu::TextureViewDimension::Cube),
+            ..Default::default()
+        }
+    }
+
     pub fn with_label(self, label: &'a str) -> Self {
         let label = Some(label);
         Self { label, ..self }
@@ -103,6 +121,33 @@ impl<'a> TextureBuilder<'a> {
         }
     }
 
+    /// Allocate a full mip chain. Textures created with `from_raw_bytes` get
+    /// it generated on upload, render targets have to fill it with
+    /// `RenderState::generate_mipmaps` after drawing into them.
+    pub fn with_mipmaps(self) -> Self {
+        Self {
+            mipmaps: true,
+            ..self
+        }
+    }
+
+    /// Anisotropic filtering with up to `clamp` samples, 1 disables it.
+    /// Switches all filter modes to linear, which anisotropy requires.
+    pub fn with_anisotropy(self, clamp: u16) -> Self {
+        Self {
+            anisotropy_clamp: Some(clamp),
+            ..self.with_filter_mode(wgpu::FilterMode::Linear)
+        }
+    }
+
+    /// Limits the mip levels the sampler picks from.
+    pub fn with_lod_clamp(self, min: f32, max: f32) -> Self {
+        Self {
+            lod_clamp: Some((min, max)),
+            ..self
+        }
+    }
+
     pub fn from_raw_bytes(
         mut self,
         device: &wgpu::Device,
@@ -120,6 +165,7 @@ impl<'a> TextureBuilder<'a> {
         } else {
             self.usage = Some(wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST);
         }
+        let mipmaps = self.mipmaps;
         let texture = self.build(device, size);
         let bytes_per_pixel = texture
             .format()
@@ -135,6 +181,11 @@ impl<'a> TextureBuilder<'a> {
             },
             texture.texture.size(),
         );
+        if mipmaps {
+            let mut encoder = device.create_command_encoder(&Default::default());
+            MipmapGenerator::new(device).generate(device, &mut encoder, &texture.texture);
+            queue.submit([encoder.finish()]);
+        }
         texture
     }
 
@@ -149,13 +200,67 @@ impl<'a> TextureBuilder<'a> {
         self.from_raw_bytes(device, queue, image.as_bytes(), Point::new(width, height))
     }
 
+    /// Builds a cubemap from six square images, see `cubemap` for the order.
+    pub fn from_cube_images(
+        self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        faces: &[RgbaImage; 6],
+    ) -> Texture {
+        let (width, height) = faces[0].dimensions();
+        assert!(
+            width == height && faces.iter().all(|f| f.dimensions() == (width, height)),
+            "cubemap faces must be square and of the same size"
+        );
+        let bytes: Vec<u8> = faces.iter().flat_map(|f| f.as_bytes()).copied().collect();
+        Self {
+            layers: Some(6),
+            view_dimension: Some(wgpu::TextureViewDimension::Cube),
+            ..self
+        }
+        .from_raw_bytes(device, queue, &bytes, Point::new(width, height))
+    }
+
+    /// Builds an `Rgba16Float` cubemap with `face_size` pixel faces from an
+    /// HDR panorama.
+    pub fn from_equirectangular(
+        self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        image: &EquirectangularImage,
+        face_size: u32,
+    ) -> Texture {
+        let texels = image.to_cube_faces(face_size);
+        Self {
+            format: Some(wgpu::TextureFormat::Rgba16Float),
+            layers: Some(6),
+            view_dimension: Some(wgpu::TextureViewDimension::Cube),
+            ..self
+        }
+        .from_raw_bytes(
+            device,
+            queue,
+            bytemuck::cast_slice(&texels),
+            Point::new(face_size, face_size),
+        )
+    }
+
     pub fn build(mut self, device: &wgpu::Device, size: Point<u32>) -> Texture {
         let format = self.format.unwrap_or(Self::DEFAULT_FORMAT);
-        let mut view_formats = vec![format];
-        let usage = self.usage.unwrap_or(
+        let mut usage = self.usage.unwrap_or(
             wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
         );
         let is_depth = format.has_depth_aspect();
+        let mip_level_count = if self.mipmaps {
+            if is_depth {
+                panic!("Can't generate mipmaps for depth texture {:?}", self.label);
+            }
+            // The mip chain is generated by rendering from one level to the next.
+            usage |= wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING;
+            mip_level_count(size.x, size.y)
+        } else {
+            1
+        };
         if is_depth {
             if !usage.contains(wgpu::TextureUsages::RENDER_ATTACHMENT) {
                 panic!("Creating a depth texture without render attachment usage, is that really your intention?");
@@ -163,15 +268,6 @@ impl<'a> TextureBuilder<'a> {
             self.mag_filter.get_or_insert(wgpu::FilterMode::Linear);
             self.min_filter.get_or_insert(wgpu::FilterMode::Linear);
             self.mipmap_filter.get_or_insert(wgpu::FilterMode::Nearest);
-        } else {
-            let other = if format.is_srgb() {
-                format.remove_srgb_suffix()
-            } else {
-                format.add_srgb_suffix()
-            };
-            if format != other {
-                view_formats.push(other);
-            }
         }
         let texture = device.create_texture(&wgpu::TextureDescriptor {
             label: self.label,
@@ -180,17 +276,42 @@ impl<'a> TextureBuilder<'a> {
                 height: size.y,
                 depth_or_array_layers: self.layers.unwrap_or(1),
             },
-            mip_level_count: 1,
+            mip_level_count,
             sample_count: 1,
             dimension: wgpu::TextureDimension::D2,
             format,
             usage,
-            view_formats: &view_formats,
+            // No alternate srgb/linear view formats, nothing views textures
+            // that way and downlevel adapters (GL) don't support it.
+            view_formats: &[],
         });
 
-        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let view_dimension = self
+            .view_dimension
+            .unwrap_or(wgpu::TextureViewDimension::D2);
+        let view = texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: self.view_dimension,
+            ..Default::default()
+        });
+        // Render passes can only draw into a single mip level.
+        let attachment_view = (mip_level_count > 1).then(|| {
+            texture.create_view(&wgpu::TextureViewDescriptor {
+                mip_level_count: Some(1),
+                ..Default::default()
+            })
+        });
 
-        let address_mode = self.address_mode.unwrap_or(Self::DEFAULT_ADDRESS_MODE);
+        let mut address_mode = self.address_mode.unwrap_or(Self::DEFAULT_ADDRESS_MODE);
+        if address_mode == wgpu::AddressMode::ClampToBorder
+            && !device
+                .features()
+                .contains(wgpu::Features::ADDRESS_MODE_CLAMP_TO_BORDER)
+        {
+            // Some (mostly software) adapters can't do this, edge clamping is the closest thing.
+            address_mode = wgpu::AddressMode::ClampToEdge;
+            self.sampler_border_color = None;
+        }
+        let (lod_min_clamp, lod_max_clamp) = self.lod_clamp.unwrap_or((0.0, 100.0));
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
             address_mode_u: address_mode,
             address_mode_v: address_mode,
@@ -199,14 +320,17 @@ impl<'a> TextureBuilder<'a> {
             min_filter: self.min_filter.unwrap_or(Self::DEFAULT_FILTER_MODE),
             mipmap_filter: self.mipmap_filter.unwrap_or(Self::DEFAULT_FILTER_MODE),
             compare: self.compare_func,
-            lod_min_clamp: 0.0,
-            lod_max_clamp: 100.0,
+            lod_min_clamp,
+            lod_max_clamp,
+            anisotropy_clamp: self.anisotropy_clamp.unwrap_or(1),
             border_color: self.sampler_border_color,
             ..Default::default()
         });
         Texture {
-            texture,
+            texture: Arc::new(texture),
             view,
+            attachment_view,
+            view_dimension,
             sampler,
         }
     }
@@ -214,8 +338,10 @@ impl<'a> TextureBuilder<'a> {
 
 #[derive(Debug)]
 pub struct Texture {
-    pub texture: wgpu::Texture,
+    pub texture: Arc<wgpu::Texture>,
     pub view: wgpu::TextureView,
+    attachment_view: Option<wgpu::TextureView>,
+    view_dimension: wgpu::TextureViewDimension,
     pub sampler: wgpu::Sampler,
 }
 
@@ -234,6 +360,37 @@ impl Texture {
     pub fn format(&self) -> wgpu::TextureFormat {
         self.texture.format()
     }
+
+    /// Another handle to the same texture, with its own views and a sampler
+    /// using default filtering (depth comparison for depth textures).
+    pub fn share(&self, device: &wgpu::Device) -> Texture {
+        let view = self.texture.create_view(&wgpu::TextureViewDescriptor {
+            dimension: Some(self.view_dimension),
+            ..Default::default()
+        });
+        let attachment_view = self.attachment_view.as_ref().map(|_| {
+            self.texture.create_view(&wgpu::TextureViewDescriptor {
+                mip_level_count: Some(1),
+                ..Default::default()
+            })
+        });
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            compare: self.is_depth().then_some(wgpu::CompareFunction::LessEqual),
+            ..Default::default()
+        });
+        Texture {
+            texture: self.texture.clone(),
+            view,
+            attachment_view,
+            view_dimension: self.view_dimension,
+            sampler,
+        }
+    }
+
+    /// View of the first mip level for rendering into the texture.
+    pub fn attachment_view(&self) -> &wgpu::TextureView {
+        self.attachment_view.as_ref().unwrap_or(&self.view)
+    }
 }
 
 impl Bindable for Texture {
@@ -253,6 +410,7 @@ impl Bindable for Texture {
     fn binding_type(&self) -> BindingType {
         BindingType::Texture {
             format: self.format(),
+            view_dimension: self.view_dimension,
         }
     }
 }
@@ -0,0 +1,392 @@
+//! Maps the linear HDR scene color to the display range, with manual or
+//! automatic exposure.
+
+use std::{ops::Deref, time::Duration};
+
+use bytemuck::Zeroable;
+
+use crate::geom::{BasicVertexData, Point};
+
+use super::{
+    instance::InstanceRenderData,
+    shaders::tonemapping as shader,
+    state::{BindingType, ViewProjectionUniforms},
+    BasicInstanceData, Display, PipelineRef, RenderState, RenderTarget, TextureBuilder, TextureRef,
+    UniformBindGroup,
+};
+
+pub type TonemappingUniforms = shader::types::TonemappingUniforms;
+
+impl Default for TonemappingUniforms {
+    fn default() -> Self {
+        Self {
+            min_luminance: 0.03,
+            max_luminance: 8.0,
+            ..Zeroable::zeroed()
+        }
+    }
+}
+
+/// Curve compressing HDR color into the displayable range. The values match
+/// `TonemappingUniforms::tonemapper` in the shader.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Tonemapper {
+    /// Clip at 1.0.
+    None = 0,
+    #[default]
+    Aces = 1,
+    Reinhard = 2,
+    AgX = 3,
+}
+
+impl Tonemapper {
+    pub const ALL: [Tonemapper; 4] = [
+        Tonemapper::None,
+        Tonemapper::Aces,
+        Tonemapper::Reinhard,
+        Tonemapper::AgX,
+    ];
+}
+
+pub struct TonemappingPass {
+    pub tonemapper: Tonemapper,
+    /// Expose the average scene luminance to middle grey, adapting to changes
+    /// over time like an eye would.
+    pub auto_exposure: bool,
+    /// Exposure compensation in stops, applied on top of auto exposure.
+    pub exposure: f32,
+    /// Rate per second at which auto exposure follows the scene.
+    pub adaptation_speed: f32,
+    uniforms: UniformBindGroup<TonemappingUniforms>,
+    pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+    luminance_pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+    adaptation_pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+    /// Log luminance of the scene, averaged down its mip chain.
+    luminance: TextureRef,
+    /// Previous and current adapted luminance, swapped every frame.
+    adapted_luminance: [TextureRef; 2],
+    current: usize,
+    adapted: bool,
+    output_texture: TextureRef,
+}
+
+impl TonemappingPass {
+    pub const LUMINANCE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R16Float;
+
+    const LUMINANCE_SIZE: u32 = 256;
+
+    pub fn new(
+        state: &mut RenderState,
+        display: &Display,
+        size: Point<u32>,
+        output_format: wgpu::TextureFormat,
+    ) -> Self {
+        let luminance = state.load_texture(
+            display,
+            TextureBuilder::labeled("scene luminance")
+                .with_format(Self::LUMINANCE_FORMAT)
+                .with_filter_mode(wgpu::FilterMode::Linear)
+                .with_mipmaps()
+                .build(
+                    display.device(),
+                    Point::new(Self::LUMINANCE_SIZE, Self::LUMINANCE_SIZE),
+                ),
+        );
+        let adapted_luminance = ["adapted luminance 0", "adapted luminance 1"].map(|label| {
+            state.load_texture(
+                display,
+                TextureBuilder::labeled(label)
+                    .with_format(Self::LUMINANCE_FORMAT)
+                    .build(display.device(), Point::new(1, 1)),
+            )
+        });
+        let output_texture = state.load_texture(
+            display,
+            TextureBuilder::render_target()
+                .with_label("tonemapped")
+                .with_format(output_format)
+                .with_usage(
+                    wgpu::TextureUsages::RENDER_ATTACHMENT
+                        | wgpu::TextureUsages::TEXTURE_BINDING
+                        | wgpu::TextureUsages::COPY_SRC,
+                )
+                .build(display.device(), size),
+        );
+
+        let (uniforms, uniform_bgl) =
+            state.create_uniform_bind_group(display.device(), Default::default());
+        let module = display
+            .device()
+            .create_shader_module(shader::DESCRIPTOR.clone());
+        let mut pipeline = |label, entry_point, format| {
+            state
+                .pipeline_builder()
+                .with_label(label)
+                .with_fragment_entry_point(entry_point)
+                .with_extra_bindings(vec![BindingType::Texture {
+                    format: Self::LUMINANCE_FORMAT,
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                }])
+                .with_extra_bind_group_layouts(vec![&uniform_bgl])
+                .with_color_target_states(vec![Some(wgpu::ColorTargetState {
+                    blend: None,
+                    format,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })])
+                .with_depth_stencil_state(None)
+                .build(display.device(), &module)
+        };
+        let luminance_pipeline =
+            pipeline("Luminance Pipeline", "fs_luminance", Self::LUMINANCE_FORMAT);
+        let adaptation_pipeline = pipeline(
+            "Luminance Adaptation Pipeline",
+            "fs_adapt",
+            Self::LUMINANCE_FORMAT,
+        );
+        let pipeline = pipeline("Tonemapping Pipeline", "fs_main", output_format);
+        Self {
+            tonemapper: Default::default(),
+            auto_exposure: false,
+            exposure: 0.0,
+            adaptation_speed: 1.5,
+            uniforms,
+            pipeline,
+            luminance_pipeline,
+            adaptation_pipeline,
+            luminance,
+            adapted_luminance,
+            current: 0,
+            adapted: false,
+            output_texture,
+        }
+    }
+
+    /// Tonemaps `hdr`, `frame_delta` drives exposure adaptation.
+    pub fn run(
+        &mut self,
+        state: &mut RenderState,
+        display: &Display,
+        hdr: TextureRef,
+        frame_delta: Duration,
+    ) -> TextureRef {
+        let previous = self.current;
+        if self.auto_exposure {
+            self.current = 1 - self.current;
+        } else {
+            // Start from the current scene instead of a stale value once
+            // auto exposure gets enabled.
+            self.adapted = false;
+        }
+        let adaptation = if self.adapted {
+            1.0 - (-frame_delta.as_secs_f32() * self.adaptation_speed).exp()
+        } else {
+            1.0
+        };
+        let luminance_level =
+            (state.get_texture(self.luminance).texture.mip_level_count() - 1) as f32;
+        self.uniforms.update_with(display.queue(), |u| {
+            u.tonemapper = self.tonemapper as u32;
+            u.auto_exposure = self.auto_exposure as u32;
+            u.exposure = self.exposure;
+            u.adaptation = adaptation;
+            u.luminance_level = luminance_level;
+        });
+        if self.auto_exposure {
+            self.fullscreen_pass(
+                state,
+                display,
+                "Luminance Pass",
+                self.luminance,
+                hdr,
+                previous,
+                self.luminance_pipeline,
+            );
+            state.generate_mipmaps(display, self.luminance);
+            self.fullscreen_pass(
+                state,
+                display,
+                "Luminance Adaptation Pass",
+                self.adapted_luminance[self.current],
+                self.luminance,
+                previous,
+                self.adaptation_pipeline,
+            );
+            self.adapted = true;
+        }
+        self.fullscreen_pass(
+            state,
+            display,
+            "Tonemapping Pass",
+            self.output_texture,
+            hdr,
+            self.current,
+            self.pipeline,
+        );
+        self.output_texture
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn fullscreen_pass(
+        &self,
+        state: &mut RenderState,
+        display: &Display,
+        label: &str,
+        target: TextureRef,
+        source: TextureRef,
+        adapted_luminance: usize,
+        pipeline: PipelineRef<BasicVertexData, BasicInstanceData>,
+    ) {
+        let quad = state.quad_mesh();
+        let adapted_luminance = self.adapted_luminance[adapted_luminance];
+        let adapted_luminance = state.get_texture(adapted_luminance).bind_group().clone();
+        state
+            .render_pass(
+                display,
+                label,
+                &[RenderTarget::TextureRef(target)],
+                None,
+                &ViewProjectionUniforms::default(),
+                |r| {
+                    use shader::globals::*;
+                    r.set_bind_group(adapted_luminance::GROUP, adapted_luminance.deref(), &[]);
+                    r.set_bind_group(params::GROUP, self.uniforms.bind_group().deref(), &[]);
+                    r.draw_instance(&InstanceRenderData {
+                        mesh: quad,
+                        instance: BasicInstanceData::default(),
+                        texture: Some(source),
+                        material: None,
+                        pipeline: Some(pipeline),
+                    });
+                },
+            )
+            .submit();
+    }
+
+    pub fn debug_ui(&mut self, ui: &mut egui::Ui) {
+        egui::ComboBox::from_label("tonemapper")
+            .selected_text(format!("{:?}", self.tonemapper))
+            .show_ui(ui, |ui| {
+                for tonemapper in Tonemapper::ALL {
+                    ui.selectable_value(
+                        &mut self.tonemapper,
+                        tonemapper,
+                        format!("{:?}", tonemapper),
+                    );
+                }
+            });
+        ui.add(egui::Slider::new(&mut self.exposure, -8.0..=8.0).text("exposure (EV)"));
+
+        ui.separator();
+        ui.label("Auto exposure");
+        ui.add(egui::Checkbox::new(&mut self.auto_exposure, "enabled"));
+        ui.add(
+            egui::Slider::new(&mut self.adaptation_speed, 0.1..=10.0)
+                .logarithmic(true)
+                .text("adaptation speed"),
+        );
+        ui.add(
+            egui::Slider::new(&mut self.uniforms.min_luminance, 0.001..=1.0)
+                .logarithmic(true)
+                .text("min luminance"),
+        );
+        ui.add(
+            egui::Slider::new(&mut self.uniforms.max_luminance, 1.0..=100.0)
+                .logarithmic(true)
+                .text("max luminance"),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A texture filled with `value`, given as half float bits.
+    fn flat_hdr(
+        state: &mut RenderState,
+        display: &Display,
+        size: Point<u32>,
+        value: u16,
+    ) -> TextureRef {
+        let pixels = vec![value; (size.x * size.y * 4) as usize];
+        state.load_texture(
+            display,
+            TextureBuilder::labeled("hdr")
+                .with_format(wgpu::TextureFormat::Rgba16Float)
+                .from_raw_bytes(
+                    display.device(),
+                    display.queue(),
+                    bytemuck::cast_slice(&pixels),
+                    size,
+                ),
+        )
+    }
+
+    #[test]
+    fn test_tonemappers() {
+        let size = Point::new(4, 4);
+        let (mut display, mut state) = RenderState::headless(size);
+        // 1.0 in half precision.
+        let hdr = flat_hdr(&mut state, &display, size, 0x3c00);
+        let mut pass = TonemappingPass::new(
+            &mut state,
+            &display,
+            size,
+            TextureBuilder::DEFAULT_RENDER_FORMAT,
+        );
+
+        // None clips 1.0 to white, ACES maps it to 0.62, Reinhard to 0.5 and AgX
+        // to 0.59, here in sRGB.
+        for (tonemapper, expected) in [
+            (Tonemapper::None, 255),
+            (Tonemapper::Aces, 206),
+            (Tonemapper::Reinhard, 188),
+            (Tonemapper::AgX, 202),
+        ] {
+            pass.tonemapper = tonemapper;
+            let output = pass.run(&mut state, &display, hdr, Duration::ZERO);
+            let pixel = state
+                .capture_texture(&mut display, output)
+                .unwrap()
+                .get_pixel(2, 2)
+                .0;
+            for c in &pixel[..3] {
+                assert!(c.abs_diff(expected) <= 2, "{:?}: {:?}", tonemapper, pixel);
+            }
+        }
+    }
+
+    #[test]
+    fn test_auto_exposure() {
+        let size = Point::new(16, 16);
+        let (mut display, mut state) = RenderState::headless(size);
+        // 4.0 in half precision.
+        let hdr = flat_hdr(&mut state, &display, size, 0x4400);
+        let mut pass = TonemappingPass::new(
+            &mut state,
+            &display,
+            size,
+            TextureBuilder::DEFAULT_RENDER_FORMAT,
+        );
+        pass.tonemapper = Tonemapper::Reinhard;
+
+        // Reinhard maps 4.0 to 0.8, about 231 in sRGB.
+        let output = pass.run(&mut state, &display, hdr, Duration::ZERO);
+        let manual = state
+            .capture_texture(&mut display, output)
+            .unwrap()
+            .get_pixel(8, 8)
+            .0;
+        assert!((225..=236).contains(&manual[0]), "{:?}", manual);
+
+        // Exposed to middle grey, 0.18 maps to about 0.15, 108 in sRGB.
+        pass.auto_exposure = true;
+        let output = pass.run(&mut state, &display, hdr, Duration::ZERO);
+        let auto = state
+            .capture_texture(&mut display, output)
+            .unwrap()
+            .get_pixel(8, 8)
+            .0;
+        assert!((100..=116).contains(&auto[0]), "{:?}", auto);
+    }
+}
@@ -1,4 +1,12 @@
-use std::time::{Duration, Instant};
+use std::{
+    collections::VecDeque,
+    io::Write,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use slotmap::SlotMap;
 
 #[derive(Debug, Clone)]
 pub struct TickTimer {
@@ -28,6 +36,444 @@ impl TickTimer {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct FixedTimestep {
+    pub step: Duration,
+    /// Upper bound on steps run in a single frame. Any time beyond that is
+    /// dropped, so a slow frame can't snowball into ever more steps.
+    pub max_steps_per_frame: u32,
+    accumulator: Duration,
+}
+
+impl FixedTimestep {
+    pub const DEFAULT_MAX_STEPS_PER_FRAME: u32 = 8;
+
+    pub fn new(step: Duration) -> Self {
+        assert!(!step.is_zero(), "fixed timestep must be non-zero");
+        Self {
+            step,
+            max_steps_per_frame: Self::DEFAULT_MAX_STEPS_PER_FRAME,
+            accumulator: Duration::ZERO,
+        }
+    }
+
+    pub fn from_tick_rate(ticks_per_second: f32) -> Self {
+        Self::new(Duration::from_secs_f32(1.0 / ticks_per_second))
+    }
+
+    /// Accumulates `delta` and returns the number of whole steps to run.
+    fn advance(&mut self, delta: Duration) -> u32 {
+        self.accumulator += delta;
+        let mut steps = 0;
+        while self.accumulator >= self.step {
+            if steps == self.max_steps_per_frame {
+                log::debug!(
+                    "fixed timestep fell behind, dropping {:?}",
+                    self.accumulator
+                );
+                // Keep the fractional part so interpolation stays smooth.
+                self.accumulator = Duration::from_nanos(
+                    (self.accumulator.as_nanos() % self.step.as_nanos()) as u64,
+                );
+                break;
+            }
+            self.accumulator -= self.step;
+            steps += 1;
+        }
+        steps
+    }
+
+    /// How far between the last step and the next one we are, in `0.0..1.0`.
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.step.as_secs_f32()
+    }
+}
+
+slotmap::new_key_type! {
+    pub struct TimerRef;
+}
+
+#[derive(Debug, Clone)]
+struct Timer {
+    duration: Duration,
+    remaining: Duration,
+    repeating: bool,
+}
+
+/// One-shot and repeating timers, advanced by game time.
+#[derive(Debug, Default)]
+pub struct Timers {
+    timers: SlotMap<TimerRef, Timer>,
+    fired: Vec<TimerRef>,
+}
+
+impl Timers {
+    fn add(&mut self, duration: Duration, repeating: bool) -> TimerRef {
+        self.timers.insert(Timer {
+            duration,
+            remaining: duration,
+            repeating,
+        })
+    }
+
+    /// Starts a timer that fires once after `duration` and is then removed.
+    pub fn after(&mut self, duration: Duration) -> TimerRef {
+        self.add(duration, false)
+    }
+
+    /// Starts a timer that fires every `interval`.
+    pub fn every(&mut self, interval: Duration) -> TimerRef {
+        assert!(
+            !interval.is_zero(),
+            "repeating timer interval must be non-zero"
+        );
+        self.add(interval, true)
+    }
+
+    pub fn cancel(&mut self, timer: TimerRef) {
+        self.timers.remove(timer);
+    }
+
+    /// Time left until `timer` next fires, `None` once a one-shot timer has
+    /// fired or the timer was cancelled.
+    pub fn remaining(&self, timer: TimerRef) -> Option<Duration> {
+        self.timers.get(timer).map(|t| t.remaining)
+    }
+
+    /// Whether `timer` fired during the last `advance`.
+    pub fn just_fired(&self, timer: TimerRef) -> bool {
+        self.fired.contains(&timer)
+    }
+
+    /// Timers which fired during the last `advance`, a repeating timer is
+    /// listed once per interval that elapsed.
+    pub fn fired(&self) -> impl Iterator<Item = TimerRef> + '_ {
+        self.fired.iter().copied()
+    }
+
+    pub fn advance(&mut self, delta: Duration) {
+        self.fired.clear();
+        self.timers.retain(|key, timer| {
+            let mut elapsed = delta;
+            while elapsed >= timer.remaining {
+                elapsed -= timer.remaining;
+                self.fired.push(key);
+                if !timer.repeating {
+                    return false;
+                }
+                timer.remaining = timer.duration;
+            }
+            timer.remaining -= elapsed;
+            true
+        });
+    }
+}
+
+/// Something that can be used once every `duration` of game time.
+#[derive(Debug, Clone)]
+pub struct Cooldown {
+    pub duration: Duration,
+    ready_at: Duration,
+}
+
+impl Cooldown {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            ready_at: Duration::ZERO,
+        }
+    }
+
+    pub fn is_ready(&self, clock: &GameClock) -> bool {
+        clock.elapsed >= self.ready_at
+    }
+
+    pub fn remaining(&self, clock: &GameClock) -> Duration {
+        self.ready_at.saturating_sub(clock.elapsed)
+    }
+
+    /// Starts the cooldown if it's ready, returning whether it was.
+    pub fn trigger(&mut self, clock: &GameClock) -> bool {
+        let ready = self.is_ready(clock);
+        if ready {
+            self.ready_at = clock.elapsed + self.duration;
+        }
+        ready
+    }
+
+    pub fn reset(&mut self) {
+        self.ready_at = Duration::ZERO;
+    }
+}
+
+/// Game time, which can be scaled or paused independently of real (UI) time.
+#[derive(Debug)]
+pub struct GameClock {
+    time_scale: f32,
+    paused: bool,
+    delta: Duration,
+    elapsed: Duration,
+    pub timers: Timers,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self {
+            time_scale: 1.0,
+            paused: false,
+            delta: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            timers: Default::default(),
+        }
+    }
+}
+
+impl GameClock {
+    pub fn advance(&mut self, real_delta: Duration) {
+        self.delta = if self.paused {
+            Duration::ZERO
+        } else {
+            real_delta.mul_f64(self.time_scale as f64)
+        };
+        self.elapsed += self.delta;
+        self.timers.advance(self.delta);
+    }
+
+    /// Game time passed during the last frame, zero while paused.
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    /// Total game time, which doesn't include time spent paused.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn time(&self) -> f32 {
+        self.elapsed.as_secs_f32()
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        assert!(time_scale >= 0.0, "time scale must not be negative");
+        self.time_scale = time_scale;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FrameSample {
+    pub frame: usize,
+    pub duration: Duration,
+    pub spike: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameStats {
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+/// Rolling history of frame durations. A frame counts as a spike if it took
+/// more than `spike_threshold` times the mean of the frames before it.
+#[derive(Debug)]
+pub struct FrameHistory {
+    samples: VecDeque<FrameSample>,
+    pub capacity: usize,
+    pub spike_threshold: f32,
+    spike_count: usize,
+    frame_counter: usize,
+}
+
+impl Default for FrameHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl FrameHistory {
+    pub const DEFAULT_CAPACITY: usize = 600;
+    pub const DEFAULT_SPIKE_THRESHOLD: f32 = 2.0;
+    /// Frames needed before the spike detector kicks in.
+    const MIN_SPIKE_SAMPLES: usize = 10;
+
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+            spike_threshold: Self::DEFAULT_SPIKE_THRESHOLD,
+            spike_count: 0,
+            frame_counter: 0,
+        }
+    }
+
+    pub fn push(&mut self, duration: Duration) {
+        let spike = self.samples.len() >= Self::MIN_SPIKE_SAMPLES
+            && duration.as_secs_f32() > self.spike_threshold * self.mean().as_secs_f32();
+        if spike {
+            self.spike_count += 1;
+            log::debug!(
+                "frame {} took {:.2}ms",
+                self.frame_counter,
+                duration.as_secs_f64() * 1000.0
+            );
+        }
+        while self.samples.len() >= self.capacity.max(1) {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(FrameSample {
+            frame: self.frame_counter,
+            duration,
+            spike,
+        });
+        self.frame_counter += 1;
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.spike_count = 0;
+    }
+
+    pub fn samples(&self) -> impl ExactSizeIterator<Item = &FrameSample> + '_ {
+        self.samples.iter()
+    }
+
+    pub fn last(&self) -> Option<&FrameSample> {
+        self.samples.back()
+    }
+
+    /// Number of spikes since the history was created or last cleared.
+    pub fn spike_count(&self) -> usize {
+        self.spike_count
+    }
+
+    fn mean(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().map(|s| s.duration).sum::<Duration>() / self.samples.len() as u32
+    }
+
+    /// Statistics over the frames currently in the history.
+    pub fn stats(&self) -> Option<FrameStats> {
+        let mut sorted: Vec<_> = self.samples.iter().map(|s| s.duration).collect();
+        sorted.sort_unstable();
+        // Nearest-rank percentile.
+        let percentile = |p: f32| {
+            let rank = (p * sorted.len() as f32).ceil() as usize;
+            sorted[rank.clamp(1, sorted.len()) - 1]
+        };
+        Some(FrameStats {
+            min: *sorted.first()?,
+            max: *sorted.last()?,
+            mean: self.mean(),
+            p95: percentile(0.95),
+            p99: percentile(0.99),
+        })
+    }
+
+    /// Writes one `frame,duration_ms,spike` row per frame in the history.
+    pub fn write_csv(&self, mut writer: impl Write) -> std::io::Result<()> {
+        writeln!(writer, "frame,duration_ms,spike")?;
+        for sample in &self.samples {
+            writeln!(
+                writer,
+                "{},{:.4},{}",
+                sample.frame,
+                sample.duration.as_secs_f64() * 1000.0,
+                sample.spike as u8
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn save_csv(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("failed to create {}", path.display()))?;
+        self.write_csv(std::io::BufWriter::new(file))
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn debug_ui(&mut self, ui: &mut egui::Ui) {
+        let ms = |d: Duration| d.as_secs_f32() * 1000.0;
+        if let Some(stats) = self.stats() {
+            ui.label(format!(
+                "min {:.2}ms  max {:.2}ms  mean {:.2}ms",
+                ms(stats.min),
+                ms(stats.max),
+                ms(stats.mean)
+            ));
+            ui.label(format!(
+                "p95 {:.2}ms  p99 {:.2}ms  spikes {}",
+                ms(stats.p95),
+                ms(stats.p99),
+                self.spike_count
+            ));
+        }
+        ui.add(egui::Slider::new(&mut self.spike_threshold, 1.1..=10.0).text("spike threshold"));
+
+        // Bar plot of the history, scaled so that 2x the p99 fits.
+        let (rect, _) =
+            ui.allocate_exact_size(egui::vec2(ui.available_width(), 80.0), egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 0.0, egui::Color32::from_black_alpha(96));
+        let Some(stats) = self.stats() else {
+            return;
+        };
+        let scale_ms = (2.0 * ms(stats.p99)).max(1000.0 / 60.0);
+        let y_for = |ms: f32| rect.bottom() - rect.height() * (ms / scale_ms).min(1.0);
+        let bar_width = rect.width() / self.capacity.max(1) as f32;
+        for (i, sample) in self.samples.iter().enumerate() {
+            let x = rect.left() + i as f32 * bar_width;
+            let color = if sample.spike {
+                egui::Color32::RED
+            } else {
+                egui::Color32::LIGHT_GREEN
+            };
+            painter.rect_filled(
+                egui::Rect::from_x_y_ranges(
+                    x..=x + bar_width.max(1.0),
+                    y_for(ms(sample.duration))..=rect.bottom(),
+                ),
+                0.0,
+                color,
+            );
+        }
+        for (target, color) in [
+            (1000.0 / 60.0, egui::Color32::GRAY),
+            (ms(stats.p99), egui::Color32::YELLOW),
+        ] {
+            let y = y_for(target);
+            painter.hline(rect.x_range(), y, egui::Stroke::new(1.0, color));
+        }
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct FrameTiming {
     tick_timer: TickTimer,
@@ -35,12 +481,22 @@ pub struct FrameTiming {
     frame_counter: usize,
     frame_timer: Duration,
     sampled_fps: f32,
+    fixed_timestep: Option<FixedTimestep>,
+    pending_fixed_steps: u32,
+    game_clock: GameClock,
+    history: FrameHistory,
 }
 
 impl FrameTiming {
     pub fn update(&mut self) {
         self.frame_counter += 1;
         self.delta = self.tick_timer.tick();
+        self.history.push(self.delta);
+        self.game_clock.advance(self.delta);
+        // Fixed steps follow game time, so they stop while paused.
+        if let Some(fixed) = &mut self.fixed_timestep {
+            self.pending_fixed_steps = fixed.advance(self.game_clock.delta());
+        }
         self.frame_timer += self.delta;
         if self.frame_timer.as_secs_f32() > 0.5 {
             self.sampled_fps = self.frame_counter as f32 / self.frame_timer.as_secs_f32();
@@ -53,11 +509,136 @@ impl FrameTiming {
         self.sampled_fps
     }
 
+    /// Real time since startup, unaffected by the game clock. Use this for UI.
     pub fn time(&self) -> f32 {
         self.tick_timer.total_elapsed().as_secs_f32()
     }
 
+    /// Real duration of the last frame, unaffected by the game clock.
     pub fn delta(&self) -> Duration {
         self.delta
     }
+
+    pub fn history(&self) -> &FrameHistory {
+        &self.history
+    }
+
+    pub fn history_mut(&mut self) -> &mut FrameHistory {
+        &mut self.history
+    }
+
+    pub fn game_clock(&self) -> &GameClock {
+        &self.game_clock
+    }
+
+    pub fn game_clock_mut(&mut self) -> &mut GameClock {
+        &mut self.game_clock
+    }
+
+    pub fn set_fixed_timestep(&mut self, fixed_timestep: Option<FixedTimestep>) {
+        self.fixed_timestep = fixed_timestep;
+        self.pending_fixed_steps = 0;
+    }
+
+    pub fn fixed_timestep(&self) -> Option<&FixedTimestep> {
+        self.fixed_timestep.as_ref()
+    }
+
+    /// The duration of a single fixed step, or zero if fixed timestep mode is off.
+    pub fn fixed_delta(&self) -> Duration {
+        self.fixed_timestep
+            .as_ref()
+            .map_or(Duration::ZERO, |fixed| fixed.step)
+    }
+
+    /// Blend factor between the previous and current fixed step states, to
+    /// be used when rendering. Always 1.0 when fixed timestep mode is off.
+    pub fn interpolation_alpha(&self) -> f32 {
+        self.fixed_timestep
+            .as_ref()
+            .map_or(1.0, FixedTimestep::alpha)
+    }
+
+    /// Returns the number of fixed steps due this frame, resetting it to zero.
+    pub fn take_fixed_steps(&mut self) -> u32 {
+        std::mem::take(&mut self.pending_fixed_steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_timestep() {
+        let mut fixed = FixedTimestep::new(Duration::from_millis(10));
+        assert_eq!(fixed.advance(Duration::from_millis(25)), 2);
+        assert!((fixed.alpha() - 0.5).abs() < 1e-4);
+        assert_eq!(fixed.advance(Duration::from_millis(5)), 1);
+        assert_eq!(fixed.alpha(), 0.0);
+
+        // A long stall only runs the maximum number of steps.
+        assert_eq!(fixed.advance(Duration::from_millis(1003)), 8);
+        assert!((fixed.alpha() - 0.3).abs() < 1e-4);
+        assert_eq!(fixed.advance(Duration::ZERO), 0);
+    }
+
+    #[test]
+    fn test_game_clock() {
+        let mut clock = GameClock::default();
+        let one_shot = clock.timers.after(Duration::from_millis(150));
+        let repeating = clock.timers.every(Duration::from_millis(40));
+        let mut cooldown = Cooldown::new(Duration::from_millis(100));
+        assert!(cooldown.trigger(&clock));
+        assert!(!cooldown.trigger(&clock));
+
+        clock.set_time_scale(0.5);
+        clock.advance(Duration::from_millis(200));
+        assert_eq!(clock.delta(), Duration::from_millis(100));
+        assert_eq!(clock.timers.fired().count(), 2);
+        assert!(!clock.timers.just_fired(one_shot));
+        assert!(cooldown.trigger(&clock));
+
+        clock.pause();
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(clock.elapsed(), Duration::from_millis(100));
+        assert_eq!(clock.timers.fired().count(), 0);
+        assert!(!cooldown.is_ready(&clock));
+
+        clock.resume();
+        clock.set_time_scale(1.0);
+        clock.advance(Duration::from_millis(60));
+        assert!(clock.timers.just_fired(one_shot));
+        assert!(clock.timers.just_fired(repeating));
+        assert_eq!(clock.timers.remaining(one_shot), None);
+        assert_eq!(
+            clock.timers.remaining(repeating),
+            Some(Duration::from_millis(40))
+        );
+    }
+
+    #[test]
+    fn test_frame_history() {
+        let mut history = FrameHistory::new(100);
+        assert!(history.stats().is_none());
+        for i in 1..=150u64 {
+            history.push(Duration::from_millis(i));
+        }
+        let stats = history.stats().unwrap();
+        assert_eq!(stats.min, Duration::from_millis(51));
+        assert_eq!(stats.max, Duration::from_millis(150));
+        assert_eq!(stats.p95, Duration::from_millis(145));
+        assert_eq!(stats.p99, Duration::from_millis(149));
+        assert_eq!(history.spike_count(), 0);
+
+        history.push(Duration::from_millis(1000));
+        assert!(history.last().unwrap().spike);
+        assert_eq!(history.spike_count(), 1);
+
+        let mut csv = Vec::new();
+        history.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        assert_eq!(csv.lines().count(), 101);
+        assert_eq!(csv.lines().last(), Some("150,1000.0000,1"));
+    }
 }